@@ -10,7 +10,7 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 // These functions are ignored because they are not marked as `pub`: `create_session_progress_forwarder`, `get_battery_stream_receiver`, `get_ble_adapter`, `get_connection_status_receiver`, `get_data_dir`, `get_hr_store`, `get_hr_stream_receiver`, `get_or_create_battery_broadcast_sender`, `get_or_create_coaching_cue_broadcast_sender`, `get_or_create_connection_status_broadcast_sender`, `get_or_create_hr_broadcast_sender`, `get_or_create_session_progress_broadcast_sender`, `get_session_executor`, `get_session_progress_receiver`, `get_session_repository`, `load_plan`, `save_plan`, `subscribe_coaching_cue_stream`, `zone_from_number`, `zone_to_number`
 // These functions are ignored because they have generic arguments: `notify`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConnectionState`, `FlutterLogWriter`, `StubNotificationPort`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `flush`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `make_writer`, `write`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `flush`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `make_writer`, `write`
 // These functions are ignored (category: IgnoreBecauseExplicitAttribute): `emit_cue`, `subscribe_connection_status_stream`, `subscribe_hr_stream`, `subscribe_session_progress_stream`
 
 /// Initialize the panic handler for FFI safety.
@@ -446,6 +446,38 @@ Stream<ApiSessionProgress> createSessionProgressStream() =>
 Future<BigInt> emitSessionProgress({required ApiSessionProgress data}) =>
     RustLib.instance.api.crateApiEmitSessionProgress(data: data);
 
+/// Create a stream of phase transitions during workout execution.
+///
+/// Emits one event each time the running workout advances to the next phase,
+/// plus a final completion event when the last phase ends, so the app can
+/// announce phase changes without diffing [`create_session_progress_stream`].
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the phase transitions
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+Stream<ApiPhaseTransition> createPhaseTransitionStream() =>
+    RustLib.instance.api.crateApiCreatePhaseTransitionStream();
+
+/// Create a stream for receiving pace cues from the running workout.
+///
+/// One [`ApiPaceCue`] is emitted per tick while a workout is running and a BPM
+/// reading is available, telling the athlete whether to speed up, hold or
+/// slow down to stay in the target zone band.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the pace cues
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+Stream<ApiPaceCue> createPaceCueStream() =>
+    RustLib.instance.api.crateApiCreatePaceCueStream();
+
 /// Create a stream for receiving connection status updates.
 ///
 /// Sets up a stream that will receive real-time connection status updates
@@ -505,6 +537,38 @@ Stream<ApiConnectionStatus> createConnectionStatusStream() =>
 Future<BigInt> emitConnectionStatus({required ApiConnectionStatus status}) =>
     RustLib.instance.api.crateApiEmitConnectionStatus(status: status);
 
+/// Create a stream for receiving HR liveness ticks.
+///
+/// While the HR task runs, an [`ApiHrLiveness`] is emitted about once per
+/// second whether or not HR packets arrive, so the UI can tell "waiting for
+/// data" from a dead stream.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the liveness ticks
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+Stream<ApiHrLiveness> createHrLivenessStream() =>
+    RustLib.instance.api.crateApiCreateHrLivenessStream();
+
+/// Create a stream for receiving composite connection health updates.
+///
+/// While a device is connected, an [`ApiConnectionHealth`] combining RSSI,
+/// HR dropout rate and battery level is emitted every few seconds. Read it
+/// with [`connection_health_level`] and the other accessors.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the connection health data
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+Stream<ApiConnectionHealth> createConnectionHealthStream() =>
+    RustLib.instance.api.crateApiCreateConnectionHealthStream();
+
 /// Emit a coaching cue to all stream subscribers.
 ///
 /// Called by the coaching rule engine when a cue should be delivered to the user.
@@ -694,6 +758,22 @@ Future<String> exportSession({
   required ExportFormat format,
 }) => RustLib.instance.api.crateApiExportSession(id: id, format: format);
 
+/// Create a stream for receiving file export progress.
+///
+/// An [`ApiExportProgress`] is emitted when an export started with
+/// [`export_session_to_file`] or [`export_all_sessions`] begins, periodically
+/// while it is written, and once more with `done` set when the file is complete.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the export progress
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+Stream<ApiExportProgress> createExportProgressStream() =>
+    RustLib.instance.api.crateApiCreateExportProgressStream();
+
 /// Get the session ID from a session summary preview
 Future<String> sessionPreviewId({required ApiSessionSummaryPreview preview}) =>
     RustLib.instance.api.crateApiSessionPreviewId(preview: preview);
@@ -1113,15 +1193,24 @@ Future<ApiRestingHrStats> getRestingHrStats() =>
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApiCompletedSession>>
 abstract class ApiCompletedSession implements RustOpaqueInterface {}
 
+// Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApiConnectionHealth>>
+abstract class ApiConnectionHealth implements RustOpaqueInterface {}
+
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApiConnectionStatus>>
 abstract class ApiConnectionStatus implements RustOpaqueInterface {}
 
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApiFilteredHeartRate>>
 abstract class ApiFilteredHeartRate implements RustOpaqueInterface {}
 
+// Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApiPaceCue>>
+abstract class ApiPaceCue implements RustOpaqueInterface {}
+
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApiPhaseProgress>>
 abstract class ApiPhaseProgress implements RustOpaqueInterface {}
 
+// Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApiPhaseTransition>>
+abstract class ApiPhaseTransition implements RustOpaqueInterface {}
+
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ApiSample>>
 abstract class ApiSample implements RustOpaqueInterface {}
 
@@ -1388,6 +1477,80 @@ class ApiCue {
           generatedAtMillis == other.generatedAtMillis;
 }
 
+/// Progress of a file export started with [`export_session_to_file`].
+class ApiExportProgress {
+  /// ID of the session being exported.
+  final String sessionId;
+
+  /// Bytes written to the output file so far.
+  final BigInt bytesWritten;
+
+  /// Heart rate samples written so far (equals `total_samples` when done).
+  ///
+  /// Only CSV exports count samples as they go; other formats report 0 until
+  /// done and track progress through `bytes_written`.
+  final int samplesWritten;
+
+  /// Total number of heart rate samples in the session.
+  final int totalSamples;
+
+  /// Whether the export finished and the file is complete.
+  final bool done;
+
+  const ApiExportProgress({
+    required this.sessionId,
+    required this.bytesWritten,
+    required this.samplesWritten,
+    required this.totalSamples,
+    required this.done,
+  });
+
+  @override
+  int get hashCode =>
+      sessionId.hashCode ^
+      bytesWritten.hashCode ^
+      samplesWritten.hashCode ^
+      totalSamples.hashCode ^
+      done.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiExportProgress &&
+          runtimeType == other.runtimeType &&
+          sessionId == other.sessionId &&
+          bytesWritten == other.bytesWritten &&
+          samplesWritten == other.samplesWritten &&
+          totalSamples == other.totalSamples &&
+          done == other.done;
+}
+
+/// Liveness tick of the HR stream for FFI boundary (FRB-compatible).
+///
+/// Emitted by the HR task on a timer whether or not HR arrives, so the UI can
+/// tell "waiting for data" (fresh ticks, no HR yet) from "stream dead" (ticks
+/// stopped).
+class ApiHrLiveness {
+  /// Unix timestamp in milliseconds when the HR task last polled the adapter.
+  final BigInt timestamp;
+
+  /// Unix timestamp in milliseconds of the last HR sample, if any arrived.
+  final BigInt? lastHrTimestamp;
+
+  const ApiHrLiveness({required this.timestamp, this.lastHrTimestamp});
+
+  @override
+  int get hashCode => timestamp.hashCode ^ lastHrTimestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiHrLiveness &&
+          runtimeType == other.runtimeType &&
+          timestamp == other.timestamp &&
+          lastHrTimestamp == other.lastHrTimestamp;
+}
+
 /// A single day's training load metrics for FFI.
 class ApiLoadPoint {
   /// Unix timestamp in millis.
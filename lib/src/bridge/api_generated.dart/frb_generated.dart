@@ -65,7 +65,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => -1431539882;
+  int get rustContentHash => -1441700077;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -163,6 +163,8 @@ abstract class RustLibApi extends BaseApi {
 
   Stream<ApiCue> crateApiCreateCoachingCueStream();
 
+  Stream<ApiConnectionHealth> crateApiCreateConnectionHealthStream();
+
   Stream<ApiConnectionStatus> crateApiCreateConnectionStatusStream();
 
   Future<void> crateApiCreateCustomPlan({
@@ -173,8 +175,16 @@ abstract class RustLibApi extends BaseApi {
     required int maxHr,
   });
 
+  Stream<ApiExportProgress> crateApiCreateExportProgressStream();
+
+  Stream<ApiHrLiveness> crateApiCreateHrLivenessStream();
+
   Stream<ApiFilteredHeartRate> crateApiCreateHrStream();
 
+  Stream<ApiPaceCue> crateApiCreatePaceCueStream();
+
+  Stream<ApiPhaseTransition> crateApiCreatePhaseTransitionStream();
+
   Stream<ApiSessionProgress> crateApiCreateSessionProgressStream();
 
   Future<void> crateApiDeletePlan({required String name});
@@ -463,6 +473,15 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_ApiCompletedSessionPtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ApiConnectionHealth;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ApiConnectionHealth;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ApiConnectionHealthPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ApiConnectionStatus;
 
@@ -481,6 +500,14 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_ApiFilteredHeartRatePtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ApiPaceCue;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ApiPaceCue;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ApiPaceCuePtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ApiPhaseProgress;
 
@@ -490,6 +517,15 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_ApiPhaseProgressPtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ApiPhaseTransition;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ApiPhaseTransition;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ApiPhaseTransitionPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ApiSample;
 
@@ -1272,6 +1308,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sink"],
       );
 
+  @override
+  Stream<ApiConnectionHealth> crateApiCreateConnectionHealthStream() {
+    final sink = RustStreamSink<ApiConnectionHealth>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 =
+                cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth_Dco(
+                  sink,
+                );
+            return wire.wire__crate__api__create_connection_health_stream(
+              port_,
+              arg0,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiCreateConnectionHealthStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCreateConnectionHealthStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_connection_health_stream",
+        argNames: ["sink"],
+      );
+
   @override
   Stream<ApiConnectionStatus> crateApiCreateConnectionStatusStream() {
     final sink = RustStreamSink<ApiConnectionStatus>();
@@ -1348,6 +1419,70 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ["name", "phaseNames", "phaseZones", "phaseDurations", "maxHr"],
   );
 
+  @override
+  Stream<ApiExportProgress> crateApiCreateExportProgressStream() {
+    final sink = RustStreamSink<ApiExportProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 = cst_encode_StreamSink_api_export_progress_Dco(sink);
+            return wire.wire__crate__api__create_export_progress_stream(
+              port_,
+              arg0,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiCreateExportProgressStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCreateExportProgressStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_export_progress_stream",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<ApiHrLiveness> crateApiCreateHrLivenessStream() {
+    final sink = RustStreamSink<ApiHrLiveness>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 = cst_encode_StreamSink_api_hr_liveness_Dco(sink);
+            return wire.wire__crate__api__create_hr_liveness_stream(
+              port_,
+              arg0,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiCreateHrLivenessStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCreateHrLivenessStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_hr_liveness_stream",
+        argNames: ["sink"],
+      );
+
   @override
   Stream<ApiFilteredHeartRate> crateApiCreateHrStream() {
     final sink = RustStreamSink<ApiFilteredHeartRate>();
@@ -1377,6 +1512,73 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiCreateHrStreamConstMeta =>
       const TaskConstMeta(debugName: "create_hr_stream", argNames: ["sink"]);
 
+  @override
+  Stream<ApiPaceCue> crateApiCreatePaceCueStream() {
+    final sink = RustStreamSink<ApiPaceCue>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 =
+                cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue_Dco(
+                  sink,
+                );
+            return wire.wire__crate__api__create_pace_cue_stream(port_, arg0);
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiCreatePaceCueStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCreatePaceCueStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_pace_cue_stream",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<ApiPhaseTransition> crateApiCreatePhaseTransitionStream() {
+    final sink = RustStreamSink<ApiPhaseTransition>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            var arg0 =
+                cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition_Dco(
+                  sink,
+                );
+            return wire.wire__crate__api__create_phase_transition_stream(
+              port_,
+              arg0,
+            );
+          },
+          codec: DcoCodec(
+            decodeSuccessData: dco_decode_unit,
+            decodeErrorData: dco_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiCreatePhaseTransitionStreamConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCreatePhaseTransitionStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_phase_transition_stream",
+        argNames: ["sink"],
+      );
+
   @override
   Stream<ApiSessionProgress> crateApiCreateSessionProgressStream() {
     final sink = RustStreamSink<ApiSessionProgress>();
@@ -3918,6 +4120,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get rust_arc_decrement_strong_count_ApiCompletedSession => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiCompletedSession;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ApiConnectionHealth => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ApiConnectionHealth => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ApiConnectionStatus => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus;
@@ -3934,6 +4144,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get rust_arc_decrement_strong_count_ApiFilteredHeartRate => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiFilteredHeartRate;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ApiPaceCue => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ApiPaceCue => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ApiPhaseProgress => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress;
@@ -3942,6 +4160,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   get rust_arc_decrement_strong_count_ApiPhaseProgress => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ApiPhaseTransition => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ApiPhaseTransition => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_ApiSample => wire
       .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample;
@@ -4005,6 +4231,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ApiCompletedSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  ApiConnectionHealth
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiConnectionHealthImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   ApiConnectionStatus
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -4023,6 +4258,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ApiFilteredHeartRateImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  ApiPaceCue
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiPaceCueImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   ApiPhaseProgress
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -4032,6 +4276,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ApiPhaseProgressImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  ApiPhaseTransition
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiPhaseTransitionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   ApiSample
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -4182,6 +4435,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ApiCompletedSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  ApiConnectionHealth
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiConnectionHealthImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   ApiConnectionStatus
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -4200,6 +4462,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ApiFilteredHeartRateImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  ApiPaceCue
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiPaceCueImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   ApiPhaseProgress
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -4209,6 +4480,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ApiPhaseProgressImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  ApiPhaseTransition
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiPhaseTransitionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   ApiSample
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -4267,6 +4547,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RustStreamSink<ApiConnectionHealth>
+  dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth_Dco(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<ApiConnectionStatus>
   dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus_Dco(
@@ -4285,6 +4574,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<ApiPaceCue>
+  dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue_Dco(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ApiPhaseTransition>
+  dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition_Dco(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<ApiSessionProgress>
   dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSessionProgress_Dco(
@@ -4308,6 +4615,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<ApiExportProgress>
+  dco_decode_StreamSink_api_export_progress_Dco(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ApiHrLiveness> dco_decode_StreamSink_api_hr_liveness_Dco(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<LogMessage> dco_decode_StreamSink_log_message_Dco(
     dynamic raw,
@@ -4401,6 +4723,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiExportProgress dco_decode_api_export_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiExportProgress(
+      sessionId: dco_decode_String(arr[0]),
+      bytesWritten: dco_decode_u_64(arr[1]),
+      samplesWritten: dco_decode_u_32(arr[2]),
+      totalSamples: dco_decode_u_32(arr[3]),
+      done: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  ApiHrLiveness dco_decode_api_hr_liveness(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiHrLiveness(
+      timestamp: dco_decode_u_64(arr[0]),
+      lastHrTimestamp: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
   @protected
   ApiLoadPoint dco_decode_api_load_point(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -4965,21 +5314,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  ApiConnectionStatus
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
+  ApiConnectionHealth
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ApiConnectionStatusImpl.frbInternalSseDecode(
+    return ApiConnectionHealthImpl.frbInternalSseDecode(
       sse_decode_usize(deserializer),
       sse_decode_i_32(deserializer),
     );
   }
 
   @protected
-  ApiFilteredHeartRate
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiFilteredHeartRate(
-    SseDeserializer deserializer,
+  ApiConnectionStatus
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApiConnectionStatusImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ApiFilteredHeartRate
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiFilteredHeartRate(
+    SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return ApiFilteredHeartRateImpl.frbInternalSseDecode(
@@ -4988,6 +5349,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiPaceCue
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApiPaceCueImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   ApiPhaseProgress
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -5000,6 +5373,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiPhaseTransition
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApiPhaseTransitionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   ApiSample
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -5192,6 +5577,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiConnectionHealth
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApiConnectionHealthImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   ApiConnectionStatus
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -5216,6 +5613,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiPaceCue
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApiPaceCueImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   ApiPhaseProgress
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -5228,6 +5637,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiPhaseTransition
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ApiPhaseTransitionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
   @protected
   ApiSample
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -5300,6 +5721,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RustStreamSink<ApiConnectionHealth>
+  sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<ApiConnectionStatus>
   sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus_Dco(
@@ -5318,6 +5748,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<ApiPaceCue>
+  sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ApiPhaseTransition>
+  sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<ApiSessionProgress>
   sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSessionProgress_Dco(
@@ -5343,6 +5791,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<ApiExportProgress>
+  sse_decode_StreamSink_api_export_progress_Dco(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ApiHrLiveness> sse_decode_StreamSink_api_hr_liveness_Dco(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<LogMessage> sse_decode_StreamSink_log_message_Dco(
     SseDeserializer deserializer,
@@ -5453,6 +5916,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ApiExportProgress sse_decode_api_export_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionId = sse_decode_String(deserializer);
+    var var_bytesWritten = sse_decode_u_64(deserializer);
+    var var_samplesWritten = sse_decode_u_32(deserializer);
+    var var_totalSamples = sse_decode_u_32(deserializer);
+    var var_done = sse_decode_bool(deserializer);
+    return ApiExportProgress(
+      sessionId: var_sessionId,
+      bytesWritten: var_bytesWritten,
+      samplesWritten: var_samplesWritten,
+      totalSamples: var_totalSamples,
+      done: var_done,
+    );
+  }
+
+  @protected
+  ApiHrLiveness sse_decode_api_hr_liveness(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_lastHrTimestamp = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return ApiHrLiveness(
+      timestamp: var_timestamp,
+      lastHrTimestamp: var_lastHrTimestamp,
+    );
+  }
+
   @protected
   ApiLoadPoint sse_decode_api_load_point(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6176,6 +6669,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as ApiCompletedSessionImpl).frbInternalCstEncode(move: true);
   }
 
+  @protected
+  int
+  cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    ApiConnectionHealth raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    // ignore: invalid_use_of_internal_member
+    return (raw as ApiConnectionHealthImpl).frbInternalCstEncode(move: true);
+  }
+
   @protected
   int
   cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -6196,6 +6699,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as ApiFilteredHeartRateImpl).frbInternalCstEncode(move: true);
   }
 
+  @protected
+  int
+  cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    ApiPaceCue raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    // ignore: invalid_use_of_internal_member
+    return (raw as ApiPaceCueImpl).frbInternalCstEncode(move: true);
+  }
+
   @protected
   int
   cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -6206,6 +6719,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as ApiPhaseProgressImpl).frbInternalCstEncode(move: true);
   }
 
+  @protected
+  int
+  cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    ApiPhaseTransition raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    // ignore: invalid_use_of_internal_member
+    return (raw as ApiPhaseTransitionImpl).frbInternalCstEncode(move: true);
+  }
+
   @protected
   int
   cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -6372,6 +6895,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as ApiCompletedSessionImpl).frbInternalCstEncode();
   }
 
+  @protected
+  int
+  cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    ApiConnectionHealth raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    // ignore: invalid_use_of_internal_member
+    return (raw as ApiConnectionHealthImpl).frbInternalCstEncode();
+  }
+
   @protected
   int
   cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -6392,6 +6925,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as ApiFilteredHeartRateImpl).frbInternalCstEncode();
   }
 
+  @protected
+  int
+  cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    ApiPaceCue raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    // ignore: invalid_use_of_internal_member
+    return (raw as ApiPaceCueImpl).frbInternalCstEncode();
+  }
+
   @protected
   int
   cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -6402,6 +6945,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as ApiPhaseProgressImpl).frbInternalCstEncode();
   }
 
+  @protected
+  int
+  cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    ApiPhaseTransition raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    // ignore: invalid_use_of_internal_member
+    return (raw as ApiPhaseTransitionImpl).frbInternalCstEncode();
+  }
+
   @protected
   int
   cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -6556,6 +7109,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    ApiConnectionHealth self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApiConnectionHealthImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -6582,6 +7148,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    ApiPaceCue self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApiPaceCueImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -6595,6 +7174,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    ApiPhaseTransition self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApiPhaseTransitionImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -6803,6 +7395,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    ApiConnectionHealth self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApiConnectionHealthImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -6829,6 +7434,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    ApiPaceCue self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApiPaceCueImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -6842,6 +7460,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    ApiPhaseTransition self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ApiPhaseTransitionImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -6920,6 +7551,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth_Dco(
+    RustStreamSink<ApiConnectionHealth> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData:
+              dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus_Dco(
@@ -6958,6 +7608,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue_Dco(
+    RustStreamSink<ApiPaceCue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData:
+              dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition_Dco(
+    RustStreamSink<ApiPhaseTransition> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData:
+              dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSessionProgress_Dco(
@@ -7011,6 +7699,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_api_export_progress_Dco(
+    RustStreamSink<ApiExportProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_api_export_progress,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_api_hr_liveness_Dco(
+    RustStreamSink<ApiHrLiveness> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_api_hr_liveness,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_log_message_Dco(
     RustStreamSink<LogMessage> self,
@@ -7100,6 +7822,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.generatedAtMillis, serializer);
   }
 
+  @protected
+  void sse_encode_api_export_progress(
+    ApiExportProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sessionId, serializer);
+    sse_encode_u_64(self.bytesWritten, serializer);
+    sse_encode_u_32(self.samplesWritten, serializer);
+    sse_encode_u_32(self.totalSamples, serializer);
+    sse_encode_bool(self.done, serializer);
+  }
+
+  @protected
+  void sse_encode_api_hr_liveness(
+    ApiHrLiveness self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timestamp, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastHrTimestamp, serializer);
+  }
+
   @protected
   void sse_encode_api_load_point(ApiLoadPoint self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7786,6 +8531,35 @@ class ApiCompletedSessionImpl extends RustOpaque
   );
 }
 
+@sealed
+class ApiConnectionHealthImpl extends RustOpaque
+    implements ApiConnectionHealth {
+  // Not to be used by end users
+  ApiConnectionHealthImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ApiConnectionHealthImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_ApiConnectionHealth,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_ApiConnectionHealth,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_ApiConnectionHealthPtr,
+  );
+}
+
 @sealed
 class ApiConnectionStatusImpl extends RustOpaque
     implements ApiConnectionStatus {
@@ -7844,6 +8618,26 @@ class ApiFilteredHeartRateImpl extends RustOpaque
   );
 }
 
+@sealed
+class ApiPaceCueImpl extends RustOpaque implements ApiPaceCue {
+  // Not to be used by end users
+  ApiPaceCueImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ApiPaceCueImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ApiPaceCue,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ApiPaceCue,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ApiPaceCuePtr,
+  );
+}
+
 @sealed
 class ApiPhaseProgressImpl extends RustOpaque implements ApiPhaseProgress {
   // Not to be used by end users
@@ -7868,6 +8662,30 @@ class ApiPhaseProgressImpl extends RustOpaque implements ApiPhaseProgress {
   );
 }
 
+@sealed
+class ApiPhaseTransitionImpl extends RustOpaque implements ApiPhaseTransition {
+  // Not to be used by end users
+  ApiPhaseTransitionImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ApiPhaseTransitionImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_ApiPhaseTransition,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_ApiPhaseTransition,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_ApiPhaseTransitionPtr,
+  );
+}
+
 @sealed
 class ApiSampleImpl extends RustOpaque implements ApiSample {
   // Not to be used by end users
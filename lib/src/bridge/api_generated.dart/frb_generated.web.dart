@@ -25,6 +25,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_ApiCompletedSessionPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiCompletedSession;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ApiConnectionHealthPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_ApiConnectionStatusPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus;
@@ -33,10 +37,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_ApiFilteredHeartRatePtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiFilteredHeartRate;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ApiPaceCuePtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_ApiPhaseProgressPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ApiPhaseTransitionPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_ApiSamplePtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample;
@@ -70,6 +82,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ApiConnectionHealth
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    dynamic raw,
+  );
+
   @protected
   ApiConnectionStatus
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -82,12 +100,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ApiPaceCue
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    dynamic raw,
+  );
+
   @protected
   ApiPhaseProgress
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
     dynamic raw,
   );
 
+  @protected
+  ApiPhaseTransition
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    dynamic raw,
+  );
+
   @protected
   ApiSample
   dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -184,6 +214,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ApiConnectionHealth
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    dynamic raw,
+  );
+
   @protected
   ApiConnectionStatus
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -196,12 +232,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ApiPaceCue
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    dynamic raw,
+  );
+
   @protected
   ApiPhaseProgress
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
     dynamic raw,
   );
 
+  @protected
+  ApiPhaseTransition
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    dynamic raw,
+  );
+
   @protected
   ApiSample
   dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -238,6 +286,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<ApiConnectionHealth>
+  dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth_Dco(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<ApiConnectionStatus>
   dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus_Dco(
@@ -250,6 +304,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<ApiPaceCue>
+  dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue_Dco(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ApiPhaseTransition>
+  dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition_Dco(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<ApiSessionProgress>
   dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSessionProgress_Dco(
@@ -264,6 +330,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<ApiCue> dco_decode_StreamSink_api_cue_Dco(dynamic raw);
 
+  @protected
+  RustStreamSink<ApiExportProgress>
+  dco_decode_StreamSink_api_export_progress_Dco(dynamic raw);
+
+  @protected
+  RustStreamSink<ApiHrLiveness> dco_decode_StreamSink_api_hr_liveness_Dco(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<LogMessage> dco_decode_StreamSink_log_message_Dco(dynamic raw);
 
@@ -285,6 +360,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiCue dco_decode_api_cue(dynamic raw);
 
+  @protected
+  ApiExportProgress dco_decode_api_export_progress(dynamic raw);
+
+  @protected
+  ApiHrLiveness dco_decode_api_hr_liveness(dynamic raw);
+
   @protected
   ApiLoadPoint dco_decode_api_load_point(dynamic raw);
 
@@ -512,6 +593,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiConnectionHealth
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiConnectionStatus
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -524,12 +611,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiPaceCue
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiPhaseProgress
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiPhaseTransition
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiSample
   sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -626,6 +725,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiConnectionHealth
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiConnectionStatus
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -638,12 +743,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiPaceCue
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiPhaseProgress
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
     SseDeserializer deserializer,
   );
 
+  @protected
+  ApiPhaseTransition
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    SseDeserializer deserializer,
+  );
+
   @protected
   ApiSample
   sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -680,6 +797,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<ApiConnectionHealth>
+  sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth_Dco(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<ApiConnectionStatus>
   sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus_Dco(
@@ -692,6 +815,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<ApiPaceCue>
+  sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue_Dco(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ApiPhaseTransition>
+  sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition_Dco(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<ApiSessionProgress>
   sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSessionProgress_Dco(
@@ -708,6 +843,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<ApiExportProgress>
+  sse_decode_StreamSink_api_export_progress_Dco(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<ApiHrLiveness> sse_decode_StreamSink_api_hr_liveness_Dco(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<LogMessage> sse_decode_StreamSink_log_message_Dco(
     SseDeserializer deserializer,
@@ -733,6 +877,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ApiCue sse_decode_api_cue(SseDeserializer deserializer);
 
+  @protected
+  ApiExportProgress sse_decode_api_export_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiHrLiveness sse_decode_api_hr_liveness(SseDeserializer deserializer);
+
   @protected
   ApiLoadPoint sse_decode_api_load_point(SseDeserializer deserializer);
 
@@ -995,6 +1147,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     throw UnimplementedError();
   }
 
+  @protected
+  String
+  cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth_Dco(
+    RustStreamSink<ApiConnectionHealth> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData:
+              dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
   @protected
   String
   cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus_Dco(
@@ -1029,6 +1198,40 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     );
   }
 
+  @protected
+  String
+  cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue_Dco(
+    RustStreamSink<ApiPaceCue> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData:
+              dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String
+  cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition_Dco(
+    RustStreamSink<ApiPhaseTransition> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData:
+              dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
   @protected
   String
   cst_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSessionProgress_Dco(
@@ -1074,6 +1277,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     );
   }
 
+  @protected
+  String cst_encode_StreamSink_api_export_progress_Dco(
+    RustStreamSink<ApiExportProgress> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_api_export_progress,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
+  @protected
+  String cst_encode_StreamSink_api_hr_liveness_Dco(
+    RustStreamSink<ApiHrLiveness> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_String(
+      raw.setupAndSerialize(
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_api_hr_liveness,
+          decodeErrorData: dco_decode_AnyhowException,
+        ),
+      ),
+    );
+  }
+
   @protected
   String cst_encode_StreamSink_log_message_Dco(RustStreamSink<LogMessage> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1157,6 +1390,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ].jsify()!;
   }
 
+  @protected
+  JSAny cst_encode_api_export_progress(ApiExportProgress raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_String(raw.sessionId),
+      cst_encode_u_64(raw.bytesWritten),
+      cst_encode_u_32(raw.samplesWritten),
+      cst_encode_u_32(raw.totalSamples),
+      cst_encode_bool(raw.done),
+    ].jsify()!;
+  }
+
+  @protected
+  JSAny cst_encode_api_hr_liveness(ApiHrLiveness raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return [
+      cst_encode_u_64(raw.timestamp),
+      cst_encode_opt_box_autoadd_u_64(raw.lastHrTimestamp),
+    ].jsify()!;
+  }
+
   @protected
   JSAny cst_encode_api_load_point(ApiLoadPoint raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1596,6 +1850,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ApiCompletedSession raw,
   );
 
+  @protected
+  int
+  cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    ApiConnectionHealth raw,
+  );
+
   @protected
   int
   cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -1608,12 +1868,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ApiFilteredHeartRate raw,
   );
 
+  @protected
+  int
+  cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    ApiPaceCue raw,
+  );
+
   @protected
   int
   cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
     ApiPhaseProgress raw,
   );
 
+  @protected
+  int
+  cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    ApiPhaseTransition raw,
+  );
+
   @protected
   int
   cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -1710,6 +1982,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ApiCompletedSession raw,
   );
 
+  @protected
+  int
+  cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    ApiConnectionHealth raw,
+  );
+
   @protected
   int
   cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -1722,12 +2000,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     ApiFilteredHeartRate raw,
   );
 
+  @protected
+  int
+  cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    ApiPaceCue raw,
+  );
+
   @protected
   int
   cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
     ApiPhaseProgress raw,
   );
 
+  @protected
+  int
+  cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    ApiPhaseTransition raw,
+  );
+
   @protected
   int
   cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -1813,6 +2103,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    ApiConnectionHealth self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -1827,6 +2124,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    ApiPaceCue self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -1834,6 +2138,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    ApiPhaseTransition self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -1946,6 +2257,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    ApiConnectionHealth self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
@@ -1960,6 +2278,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    ApiPaceCue self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
@@ -1967,6 +2292,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    ApiPhaseTransition self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
@@ -2009,6 +2341,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth_Dco(
+    RustStreamSink<ApiConnectionHealth> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus_Dco(
@@ -2023,6 +2362,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void
+  sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue_Dco(
+    RustStreamSink<ApiPaceCue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition_Dco(
+    RustStreamSink<ApiPhaseTransition> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void
   sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSessionProgress_Dco(
@@ -2042,6 +2395,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_StreamSink_api_export_progress_Dco(
+    RustStreamSink<ApiExportProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_api_hr_liveness_Dco(
+    RustStreamSink<ApiHrLiveness> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_StreamSink_log_message_Dco(
     RustStreamSink<LogMessage> self,
@@ -2078,6 +2443,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_api_cue(ApiCue self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_api_export_progress(
+    ApiExportProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_hr_liveness(
+    ApiHrLiveness self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_api_load_point(ApiLoadPoint self, SseSerializer serializer);
 
@@ -2534,6 +2911,12 @@ class RustLibWire implements BaseWire {
     String sink,
   ) => wasmModule.wire__crate__api__create_coaching_cue_stream(port_, sink);
 
+  void wire__crate__api__create_connection_health_stream(
+    NativePortType port_,
+    String sink,
+  ) =>
+      wasmModule.wire__crate__api__create_connection_health_stream(port_, sink);
+
   void wire__crate__api__create_connection_status_stream(
     NativePortType port_,
     String sink,
@@ -2556,9 +2939,29 @@ class RustLibWire implements BaseWire {
     max_hr,
   );
 
+  void wire__crate__api__create_export_progress_stream(
+    NativePortType port_,
+    String sink,
+  ) => wasmModule.wire__crate__api__create_export_progress_stream(port_, sink);
+
+  void wire__crate__api__create_hr_liveness_stream(
+    NativePortType port_,
+    String sink,
+  ) => wasmModule.wire__crate__api__create_hr_liveness_stream(port_, sink);
+
   void wire__crate__api__create_hr_stream(NativePortType port_, String sink) =>
       wasmModule.wire__crate__api__create_hr_stream(port_, sink);
 
+  void wire__crate__api__create_pace_cue_stream(
+    NativePortType port_,
+    String sink,
+  ) => wasmModule.wire__crate__api__create_pace_cue_stream(port_, sink);
+
+  void wire__crate__api__create_phase_transition_stream(
+    NativePortType port_,
+    String sink,
+  ) => wasmModule.wire__crate__api__create_phase_transition_stream(port_, sink);
+
   void wire__crate__api__create_session_progress_stream(
     NativePortType port_,
     String sink,
@@ -3007,6 +3410,22 @@ class RustLibWire implements BaseWire {
         ptr,
       );
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
     int ptr,
@@ -3039,6 +3458,22 @@ class RustLibWire implements BaseWire {
         ptr,
       );
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
     int ptr,
@@ -3055,6 +3490,22 @@ class RustLibWire implements BaseWire {
         ptr,
       );
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
     int ptr,
@@ -3282,6 +3733,11 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     String sink,
   );
 
+  external void wire__crate__api__create_connection_health_stream(
+    NativePortType port_,
+    String sink,
+  );
+
   external void wire__crate__api__create_connection_status_stream(
     NativePortType port_,
     String sink,
@@ -3296,11 +3752,31 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int max_hr,
   );
 
+  external void wire__crate__api__create_export_progress_stream(
+    NativePortType port_,
+    String sink,
+  );
+
+  external void wire__crate__api__create_hr_liveness_stream(
+    NativePortType port_,
+    String sink,
+  );
+
   external void wire__crate__api__create_hr_stream(
     NativePortType port_,
     String sink,
   );
 
+  external void wire__crate__api__create_pace_cue_stream(
+    NativePortType port_,
+    String sink,
+  );
+
+  external void wire__crate__api__create_phase_transition_stream(
+    NativePortType port_,
+    String sink,
+  );
+
   external void wire__crate__api__create_session_progress_stream(
     NativePortType port_,
     String sink,
@@ -3712,6 +4188,16 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionHealth(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiConnectionStatus(
     int ptr,
@@ -3732,6 +4218,16 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPaceCue(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseProgress(
     int ptr,
@@ -3742,6 +4238,16 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiPhaseTransition(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerApiSample(
     int ptr,
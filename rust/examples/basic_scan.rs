@@ -6,7 +6,7 @@
 //!
 //! Run with: cargo run --example basic_scan
 
-use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig};
+use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig, MockHrMode};
 use heart_beat::ports::ble_adapter::BleAdapter;
 
 #[tokio::main]
@@ -28,6 +28,8 @@ async fn main() -> anyhow::Result<()> {
         spike_magnitude: 0,
         update_rate: 1.0,
         battery_level: 90,
+        hr_mode: MockHrMode::Static,
+        force_uint16_bpm: false,
     };
 
     let adapter = MockAdapter::with_config(config);
@@ -97,6 +97,35 @@ impl NotificationPort for FileAndConsoleNotifier {
                 format!("🔋 Low Battery: {}%", percentage)
             }
             NotificationEvent::ConnectionLost => "❌ Connection Lost!".to_string(),
+            NotificationEvent::ContactLost => "🫀 Sensor Contact Lost!".to_string(),
+            NotificationEvent::SessionStarted => "▶️  Session Started".to_string(),
+            NotificationEvent::PhaseStarted { name, zone } => {
+                format!("⏱️  Phase Started: {} (Zone {:?})", name, zone)
+            }
+            NotificationEvent::PhaseEndingSoon {
+                remaining_secs,
+                next_zone,
+            } => {
+                format!(
+                    "⏳ Phase Ending: {}s left (next: {:?})",
+                    remaining_secs, next_zone
+                )
+            }
+            NotificationEvent::MaxHrExceeded {
+                observed,
+                configured,
+            } => {
+                format!(
+                    "📈 Max HR Exceeded: {} BPM (plan: {} BPM)",
+                    observed, configured
+                )
+            }
+            NotificationEvent::SessionCompleted { summary } => {
+                format!(
+                    "🏁 Session Complete: {}s, avg {} BPM",
+                    summary.duration_secs, summary.avg_hr
+                )
+            }
             NotificationEvent::WorkoutReady { plan_name } => {
                 format!("✅ Ready: {}", plan_name)
             }
@@ -8,7 +8,7 @@
 //!
 //! Run with: cargo run --example mock_session
 
-use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig};
+use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig, MockHrMode};
 use heart_beat::domain::filters::KalmanFilter;
 use heart_beat::domain::heart_rate::{parse_heart_rate, Zone};
 use heart_beat::domain::training_plan::{
@@ -37,34 +37,46 @@ async fn main() -> anyhow::Result<()> {
                 duration_secs: 120, // 2 minutes
                 target_zone: Zone::Zone2,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             },
             TrainingPhase {
                 name: "Interval 1".to_string(),
                 duration_secs: 60, // 1 minute
                 target_zone: Zone::Zone4,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             },
             TrainingPhase {
                 name: "Recovery".to_string(),
                 duration_secs: 60, // 1 minute
                 target_zone: Zone::Zone2,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             },
             TrainingPhase {
                 name: "Interval 2".to_string(),
                 duration_secs: 60, // 1 minute
                 target_zone: Zone::Zone4,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             },
             TrainingPhase {
                 name: "Cooldown".to_string(),
                 duration_secs: 60, // 1 minute
                 target_zone: Zone::Zone1,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             },
         ],
         max_hr: 200,
         created_at: chrono::Utc::now(),
+        warmup_grace_secs: None,
+        resting_hr: None,
     };
 
     println!("Training Plan: {}", training_plan.name);
@@ -96,6 +108,8 @@ async fn main() -> anyhow::Result<()> {
         spike_magnitude: 12,
         update_rate: 2.0, // 2 Hz for faster demo
         battery_level: 90,
+        hr_mode: MockHrMode::Static,
+        force_uint16_bpm: false,
     };
 
     let adapter = MockAdapter::with_config(config);
@@ -10,7 +10,7 @@
 //!
 //! Run with: cargo run --example stream_hr
 
-use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig};
+use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig, MockHrMode};
 use heart_beat::domain::filters::KalmanFilter;
 use heart_beat::domain::heart_rate::{parse_heart_rate, FilteredHeartRate};
 use heart_beat::domain::hrv::calculate_rmssd;
@@ -35,6 +35,8 @@ async fn main() -> anyhow::Result<()> {
         spike_magnitude: 10,
         update_rate: 1.0, // 1 Hz (once per second)
         battery_level: 85,
+        hr_mode: MockHrMode::Static,
+        force_uint16_bpm: false,
     };
 
     let adapter = MockAdapter::with_config(config);
@@ -97,6 +99,7 @@ async fn main() -> anyhow::Result<()> {
                             raw_bpm,
                             filtered_bpm,
                             rmssd,
+                            rmssd_trend: None,
                             filter_variance: None, // Not using Kalman filter in this example
                             battery_level: Some(85),
                             timestamp: std::time::SystemTime::now()
@@ -104,6 +107,9 @@ async fn main() -> anyhow::Result<()> {
                                 .unwrap()
                                 .as_millis() as u64,
                             receive_timestamp_micros: None,
+                            stale: false,
+                            intensity_factor: None,
+                            avg_bpm_10s: None,
                         };
 
                         // Display the results
@@ -6,18 +6,19 @@
 
 use crate::domain::battery::BatteryLevel;
 use crate::domain::heart_rate::DiscoveredDevice;
-use crate::domain::reconnection::{ConnectionStatus, ReconnectionPolicy};
-use crate::ports::ble_adapter::BleAdapter;
+use crate::domain::reconnection::{CircuitBreaker, ConnectionStatus, ReconnectionPolicy};
+use crate::domain::sensor::SensorLocation;
+use crate::ports::ble_adapter::{BleAdapter, BleError, HrNotification};
 use crate::ports::notification::{NotificationEvent, NotificationPort};
 use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use btleplug::api::{
-    Central, CentralEvent, Characteristic, Manager as _, Peripheral as _, ScanFilter,
+    Central, CentralEvent, CentralState, Characteristic, Manager as _, Peripheral as _, ScanFilter,
 };
 use btleplug::platform::{Adapter, Manager, Peripheral};
 use futures::StreamExt;
 use std::sync::Arc;
-use std::time::SystemTime;
+use std::time::{Instant, SystemTime};
 use tokio::sync::{mpsc, Mutex};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
@@ -68,18 +69,76 @@ fn ensure_jvm_attached() -> Result<()> {
     Ok(())
 }
 
+/// Translate a btleplug initialization error into a typed [`BleError`].
+fn map_btleplug_error(e: btleplug::Error) -> BleError {
+    match e {
+        btleplug::Error::PermissionDenied => BleError::PermissionDenied,
+        other => BleError::ManagerUnavailable(other.to_string()),
+    }
+}
+
 /// Heart Rate Service UUID (0x180D)
 const HR_SERVICE_UUID: Uuid = Uuid::from_u128(0x0000180D_0000_1000_8000_00805F9B34FB);
 
 /// Heart Rate Measurement Characteristic UUID (0x2A37)
 const HR_MEASUREMENT_UUID: Uuid = Uuid::from_u128(0x00002A37_0000_1000_8000_00805F9B34FB);
 
+/// Body Sensor Location Characteristic UUID (0x2A38)
+const BODY_SENSOR_LOCATION_UUID: Uuid = Uuid::from_u128(0x00002A38_0000_1000_8000_00805F9B34FB);
+
 /// Battery Service UUID (0x180F)
 const BATTERY_SERVICE_UUID: Uuid = Uuid::from_u128(0x0000180F_0000_1000_8000_00805F9B34FB);
 
 /// Battery Level Characteristic UUID (0x2A19)
 const BATTERY_LEVEL_UUID: Uuid = Uuid::from_u128(0x00002A19_0000_1000_8000_00805F9B34FB);
 
+/// A Bluetooth adapter present on the system, see [`BtleplugAdapter::list_adapters`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdapterInfo {
+    /// Position in the platform's adapter list, for [`AdapterSelector::Index`].
+    pub index: usize,
+    /// Platform-specific description, e.g. "hci0 (usb:v1D6Bp0246d0537)" on Linux.
+    pub description: String,
+}
+
+/// Which Bluetooth adapter [`BtleplugAdapter::with_adapter`] should use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdapterSelector {
+    /// The adapter at this position in [`BtleplugAdapter::list_adapters`].
+    Index(usize),
+    /// The adapter whose description starts with this address or id
+    /// (case-insensitive), e.g. "hci1" on Linux.
+    Address(String),
+}
+
+/// Resolve `selector` to an index into the adapters described by `descriptions`.
+fn select_adapter_index(descriptions: &[String], selector: &AdapterSelector) -> Result<usize> {
+    if descriptions.is_empty() {
+        return Err(BleError::NoAdapter.into());
+    }
+    match selector {
+        AdapterSelector::Index(index) if *index < descriptions.len() => Ok(*index),
+        AdapterSelector::Index(index) => Err(anyhow!(
+            "Bluetooth adapter index {} is out of range ({} adapter(s) available)",
+            index,
+            descriptions.len()
+        )),
+        AdapterSelector::Address(address) => {
+            let wanted = address.to_lowercase();
+            descriptions
+                .iter()
+                .position(|d| d.to_lowercase().starts_with(&wanted))
+                .ok_or_else(|| {
+                    anyhow!(
+                        "No Bluetooth adapter matches '{}' (available: {})",
+                        address,
+                        descriptions.join(", ")
+                    )
+                })
+        }
+    }
+}
+
 /// Real BLE adapter implementation using btleplug.
 ///
 /// This adapter uses the platform-specific BLE backend (BlueZ on Linux, CoreBluetooth
@@ -91,6 +150,8 @@ pub struct BtleplugAdapter {
     discovered_devices: Arc<Mutex<Vec<DiscoveredDevice>>>,
     /// Currently connected peripheral
     connected_peripheral: Arc<Mutex<Option<Peripheral>>>,
+    /// Suspends reconnection after repeated failed cycles
+    circuit_breaker: Arc<Mutex<CircuitBreaker>>,
 }
 
 impl BtleplugAdapter {
@@ -100,28 +161,139 @@ impl BtleplugAdapter {
     ///
     /// # Errors
     ///
-    /// Returns an error if no BLE adapter is available on the system.
+    /// Returns an error wrapping a [`BleError`] if the BLE manager cannot be
+    /// created, permission is denied, or no BLE adapter is available on the system.
     pub async fn new() -> Result<Self> {
-        // Ensure thread is attached to JVM for Android
-        ensure_jvm_attached()?;
+        Self::with_adapter(AdapterSelector::Index(0)).await
+    }
 
-        let manager = Manager::new()
+    /// List the Bluetooth adapters on the system, in platform order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error wrapping a [`BleError`] if the BLE manager cannot be
+    /// created or permission is denied.
+    pub async fn list_adapters() -> Result<Vec<AdapterInfo>> {
+        let adapters = Self::platform_adapters().await?;
+        Ok(Self::describe_adapters(&adapters)
             .await
-            .context("Failed to create BLE manager")?;
-
-        let adapters = manager.adapters().await.context("Failed to get adapters")?;
-        let adapter = adapters
             .into_iter()
-            .next()
-            .ok_or_else(|| anyhow!("No BLE adapter found"))?;
+            .enumerate()
+            .map(|(index, description)| AdapterInfo { index, description })
+            .collect())
+    }
+
+    /// Create an adapter instance on a specific Bluetooth adapter.
+    ///
+    /// Useful on machines with several radios (e.g. built-in plus a USB
+    /// dongle), where the first one is not necessarily the right one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the BLE manager cannot be created, no adapter is
+    /// available, or none matches `selector`.
+    pub async fn with_adapter(selector: AdapterSelector) -> Result<Self> {
+        let adapters = Self::platform_adapters().await?;
+        let descriptions = Self::describe_adapters(&adapters).await;
+        let index = select_adapter_index(&descriptions, &selector)?;
+        let adapter = adapters.into_iter().nth(index).ok_or(BleError::NoAdapter)?;
+        tracing::info!("Using Bluetooth adapter {}: {}", index, descriptions[index]);
 
         Ok(Self {
             adapter: Arc::new(adapter),
             discovered_devices: Arc::new(Mutex::new(Vec::new())),
             connected_peripheral: Arc::new(Mutex::new(None)),
+            circuit_breaker: Arc::new(Mutex::new(CircuitBreaker::default())),
         })
     }
 
+    /// Get all platform Bluetooth adapters.
+    async fn platform_adapters() -> Result<Vec<Adapter>> {
+        // Ensure thread is attached to JVM for Android
+        ensure_jvm_attached()?;
+
+        let manager = Manager::new().await.map_err(map_btleplug_error)?;
+        Ok(manager.adapters().await.map_err(map_btleplug_error)?)
+    }
+
+    /// Describe each adapter, falling back to its position if the platform
+    /// cannot report details.
+    async fn describe_adapters(adapters: &[Adapter]) -> Vec<String> {
+        let mut descriptions = Vec::with_capacity(adapters.len());
+        for (index, adapter) in adapters.iter().enumerate() {
+            descriptions.push(
+                adapter
+                    .adapter_info()
+                    .await
+                    .unwrap_or_else(|_| format!("adapter {}", index)),
+            );
+        }
+        descriptions
+    }
+
+    /// Check that the adapter is powered on.
+    ///
+    /// Returns `Ok(())` when the adapter reports it is powered on or the platform
+    /// cannot determine its state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error wrapping [`BleError::PoweredOff`] if the adapter is off.
+    pub async fn check_powered(&self) -> Result<()> {
+        ensure_jvm_attached()?;
+
+        match self.adapter.adapter_state().await {
+            Ok(CentralState::PoweredOff) => Err(BleError::PoweredOff.into()),
+            Ok(_) => Ok(()),
+            Err(e) => {
+                tracing::debug!("Adapter state unavailable: {}", e);
+                Ok(())
+            }
+        }
+    }
+
+    /// Get a platform-specific description of the adapter (e.g. "hci0").
+    pub async fn adapter_info(&self) -> Result<String> {
+        ensure_jvm_attached()?;
+
+        self.adapter
+            .adapter_info()
+            .await
+            .context("Failed to read adapter info")
+    }
+
+    /// Read where the connected sensor is worn (Body Sensor Location, 0x2A38).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no device is connected, the device does not expose
+    /// the characteristic, or the read fails.
+    pub async fn read_sensor_location(&self) -> Result<SensorLocation> {
+        ensure_jvm_attached()?;
+
+        let guard = self.connected_peripheral.lock().await;
+        let peripheral = guard
+            .as_ref()
+            .ok_or_else(|| anyhow!("No device connected"))?;
+
+        let location_char =
+            Self::get_characteristic(peripheral, HR_SERVICE_UUID, BODY_SENSOR_LOCATION_UUID)
+                .await?;
+
+        let value = peripheral
+            .read(&location_char)
+            .await
+            .context("Failed to read body sensor location")?;
+
+        // Body sensor location is a single byte
+        let byte = value
+            .first()
+            .copied()
+            .ok_or_else(|| anyhow!("Empty body sensor location response"))?;
+
+        Ok(SensorLocation::from_byte(byte))
+    }
+
     /// Find a peripheral by its device ID.
     async fn find_peripheral(&self, device_id: &str) -> Result<Peripheral> {
         // Ensure thread is attached to JVM for Android
@@ -136,7 +308,7 @@ impl BtleplugAdapter {
             }
         }
 
-        Err(anyhow!("Device not found: {}", device_id))
+        Err(BleError::DeviceNotFound(device_id.to_string()).into())
     }
 
     /// Get a characteristic from the connected peripheral.
@@ -320,7 +492,16 @@ impl BtleplugAdapter {
     /// # Returns
     ///
     /// * `Ok(())` if reconnection succeeds
-    /// * `Err(...)` if reconnection fails after all attempts or is cancelled
+    /// * `Err(...)` if reconnection fails after all attempts, is cancelled, or is
+    ///   blocked by the circuit breaker
+    ///
+    /// # Circuit breaker
+    ///
+    /// Each call is one reconnection cycle. After several consecutive failed
+    /// cycles the adapter's [`CircuitBreaker`] opens: further calls emit
+    /// `ConnectionStatus::CircuitOpen` and return an error without touching the
+    /// radio until the cooldown ends (then one probe cycle is allowed) or
+    /// [`reset_circuit_breaker`](Self::reset_circuit_breaker) is called.
     ///
     /// # Examples
     ///
@@ -345,6 +526,66 @@ impl BtleplugAdapter {
         policy: &ReconnectionPolicy,
         status_tx: mpsc::Sender<ConnectionStatus>,
         cancel_token: CancellationToken,
+    ) -> Result<()> {
+        {
+            let mut breaker = self.circuit_breaker.lock().await;
+            let now = Instant::now();
+            if !breaker.try_acquire(now) {
+                let retry_after = breaker.retry_after(now).unwrap_or_default();
+                if let Some(status) = breaker.status(now) {
+                    if let Err(e) = status_tx.send(status).await {
+                        tracing::warn!("Failed to send circuit open status: {}", e);
+                    }
+                }
+                tracing::info!(
+                    "Reconnection to device {} skipped: circuit breaker open for {:?}",
+                    device_id,
+                    retry_after
+                );
+                return Err(anyhow!(
+                    "Reconnection suspended after repeated failures (retry in {}s)",
+                    retry_after.as_secs()
+                ));
+            }
+        }
+
+        let result = self
+            .run_reconnect_cycle(device_id, policy, &status_tx, &cancel_token)
+            .await;
+
+        let mut breaker = self.circuit_breaker.lock().await;
+        match &result {
+            Ok(()) => breaker.record_success(),
+            Err(_) if cancel_token.is_cancelled() => breaker.cancel_probe(),
+            Err(_) => {
+                let now = Instant::now();
+                if breaker.record_failure(now) {
+                    tracing::warn!("Circuit breaker opened for device {}", device_id);
+                    if let Some(status) = breaker.status(now) {
+                        if let Err(e) = status_tx.send(status).await {
+                            tracing::warn!("Failed to send circuit open status: {}", e);
+                        }
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Close the reconnection circuit breaker so the next cycle runs immediately.
+    pub async fn reset_circuit_breaker(&self) {
+        self.circuit_breaker.lock().await.reset();
+        tracing::info!("Reconnection circuit breaker reset");
+    }
+
+    /// Run one reconnection cycle: up to `policy.max_attempts` connect attempts.
+    async fn run_reconnect_cycle(
+        &self,
+        device_id: &str,
+        policy: &ReconnectionPolicy,
+        status_tx: &mpsc::Sender<ConnectionStatus>,
+        cancel_token: &CancellationToken,
     ) -> Result<()> {
         let unlimited = policy.is_unlimited();
         tracing::info!(
@@ -451,6 +692,86 @@ impl BtleplugAdapter {
             }
         }
     }
+
+    /// Subscribe to HR notifications and forward each one through `wrap`.
+    ///
+    /// `wrap` receives the payload and the wall-clock time the notification
+    /// arrived, captured before any further processing.
+    async fn forward_hr_notifications<T: Send + 'static>(
+        &self,
+        wrap: fn(Vec<u8>, SystemTime) -> T,
+    ) -> Result<mpsc::Receiver<T>> {
+        // Ensure thread is attached to JVM for Android
+        ensure_jvm_attached()?;
+
+        let guard = self.connected_peripheral.lock().await;
+        let peripheral = guard
+            .as_ref()
+            .ok_or_else(|| anyhow!("No device connected"))?;
+
+        // Get the HR measurement characteristic
+        let hr_char =
+            Self::get_characteristic(peripheral, HR_SERVICE_UUID, HR_MEASUREMENT_UUID).await?;
+
+        // Subscribe to notifications
+        peripheral
+            .subscribe(&hr_char)
+            .await
+            .context("Failed to subscribe to HR notifications")?;
+
+        // Create channel for forwarding notifications
+        let (tx, rx) = mpsc::channel(32);
+
+        // Clone peripheral for the notification handler
+        let peripheral_clone = peripheral.clone();
+
+        // Spawn task to forward notifications
+        tokio::spawn(async move {
+            // Attach this worker thread to JVM (Android)
+            if let Err(e) = ensure_jvm_attached() {
+                tracing::error!("Failed to attach notification thread to JVM: {}", e);
+                return;
+            }
+
+            let mut notification_stream = match peripheral_clone.notifications().await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    tracing::error!("Failed to get notification stream: {}", e);
+                    return;
+                }
+            };
+
+            while let Some(notification) = notification_stream.next().await {
+                // Capture high-precision timestamp immediately upon BLE notification receipt
+                // Using monotonic clock for accurate duration measurement
+                let ble_receive_timestamp = std::time::Instant::now();
+                let received_at = SystemTime::now();
+
+                // Only forward HR measurement notifications
+                if notification.uuid != HR_MEASUREMENT_UUID {
+                    continue;
+                }
+
+                // Log BLE notification receipt with timestamp for debugging latency
+                tracing::debug!(
+                    "BLE notification received: {} bytes at {:?}",
+                    notification.value.len(),
+                    ble_receive_timestamp
+                );
+
+                if tx
+                    .send(wrap(notification.value, received_at))
+                    .await
+                    .is_err()
+                {
+                    tracing::debug!("HR notification receiver dropped");
+                    break;
+                }
+            }
+        });
+
+        Ok(rx)
+    }
 }
 
 #[async_trait]
@@ -627,71 +948,12 @@ impl BleAdapter for BtleplugAdapter {
     }
 
     async fn subscribe_hr(&self) -> Result<mpsc::Receiver<Vec<u8>>> {
-        // Ensure thread is attached to JVM for Android
-        ensure_jvm_attached()?;
-
-        let guard = self.connected_peripheral.lock().await;
-        let peripheral = guard
-            .as_ref()
-            .ok_or_else(|| anyhow!("No device connected"))?;
-
-        // Get the HR measurement characteristic
-        let hr_char =
-            Self::get_characteristic(peripheral, HR_SERVICE_UUID, HR_MEASUREMENT_UUID).await?;
+        self.forward_hr_notifications(|data, _| data).await
+    }
 
-        // Subscribe to notifications
-        peripheral
-            .subscribe(&hr_char)
+    async fn subscribe_hr_timestamped(&self) -> Result<mpsc::Receiver<HrNotification>> {
+        self.forward_hr_notifications(|data, received_at| HrNotification { data, received_at })
             .await
-            .context("Failed to subscribe to HR notifications")?;
-
-        // Create channel for forwarding notifications
-        let (tx, rx) = mpsc::channel(32);
-
-        // Clone peripheral for the notification handler
-        let peripheral_clone = peripheral.clone();
-
-        // Spawn task to forward notifications
-        tokio::spawn(async move {
-            // Attach this worker thread to JVM (Android)
-            if let Err(e) = ensure_jvm_attached() {
-                tracing::error!("Failed to attach notification thread to JVM: {}", e);
-                return;
-            }
-
-            let mut notification_stream = match peripheral_clone.notifications().await {
-                Ok(stream) => stream,
-                Err(e) => {
-                    tracing::error!("Failed to get notification stream: {}", e);
-                    return;
-                }
-            };
-
-            while let Some(notification) = notification_stream.next().await {
-                // Capture high-precision timestamp immediately upon BLE notification receipt
-                // Using monotonic clock for accurate duration measurement
-                let ble_receive_timestamp = std::time::Instant::now();
-
-                // Only forward HR measurement notifications
-                if notification.uuid != HR_MEASUREMENT_UUID {
-                    continue;
-                }
-
-                // Log BLE notification receipt with timestamp for debugging latency
-                tracing::debug!(
-                    "BLE notification received: {} bytes at {:?}",
-                    notification.value.len(),
-                    ble_receive_timestamp
-                );
-
-                if tx.send(notification.value).await.is_err() {
-                    tracing::debug!("HR notification receiver dropped");
-                    break;
-                }
-            }
-        });
-
-        Ok(rx)
     }
 
     async fn read_battery(&self) -> Result<Option<u8>> {
@@ -730,6 +992,25 @@ impl BleAdapter for BtleplugAdapter {
 
         Ok(Some(level))
     }
+
+    async fn read_rssi(&self) -> Result<Option<i16>> {
+        // Ensure thread is attached to JVM for Android
+        ensure_jvm_attached()?;
+
+        let guard = self.connected_peripheral.lock().await;
+        let peripheral = guard
+            .as_ref()
+            .ok_or_else(|| anyhow!("No device connected"))?;
+
+        // RSSI comes from the cached peripheral properties; not all platforms
+        // keep it updated while connected
+        let properties = peripheral
+            .properties()
+            .await
+            .context("Failed to read peripheral properties")?;
+
+        Ok(properties.and_then(|p| p.rssi))
+    }
 }
 
 #[cfg(test)]
@@ -750,6 +1031,13 @@ mod tests {
         assert_eq!(HR_MEASUREMENT_UUID, expected);
     }
 
+    #[test]
+    fn test_body_sensor_location_uuid() {
+        // Verify Body Sensor Location Characteristic UUID is correct (0x2A38)
+        let expected = Uuid::from_u128(0x00002A38_0000_1000_8000_00805F9B34FB);
+        assert_eq!(BODY_SENSOR_LOCATION_UUID, expected);
+    }
+
     #[test]
     fn test_battery_service_uuid() {
         // Verify Battery Service UUID is correct (0x180F)
@@ -772,6 +1060,18 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_map_btleplug_error() {
+        assert_eq!(
+            map_btleplug_error(btleplug::Error::PermissionDenied),
+            BleError::PermissionDenied
+        );
+        assert!(matches!(
+            map_btleplug_error(btleplug::Error::NotSupported("dbus".to_string())),
+            BleError::ManagerUnavailable(_)
+        ));
+    }
+
     #[tokio::test]
     async fn test_btleplug_adapter_new_error_handling() {
         // This test documents the expected behavior when no BLE adapter is available.
@@ -800,6 +1100,63 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_select_adapter_index() {
+        let descriptions = vec![
+            "hci0 (usb:v1D6Bp0246d0537)".to_string(),
+            "hci1 (usb:v0A12p0001d8891)".to_string(),
+        ];
+
+        assert_eq!(
+            select_adapter_index(&descriptions, &AdapterSelector::Index(1)).unwrap(),
+            1
+        );
+        assert_eq!(
+            select_adapter_index(&descriptions, &AdapterSelector::Address("HCI1".into())).unwrap(),
+            1
+        );
+
+        let err = select_adapter_index(&descriptions, &AdapterSelector::Index(2)).unwrap_err();
+        assert!(err.to_string().contains("out of range"), "{}", err);
+        assert!(
+            select_adapter_index(&descriptions, &AdapterSelector::Address("hci9".into())).is_err()
+        );
+
+        let err = select_adapter_index(&[], &AdapterSelector::Index(0)).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<BleError>(),
+            Some(BleError::NoAdapter)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_adapters_matches_platform() {
+        // Skip on systems without a BLE manager
+        let Ok(manager) = Manager::new().await else {
+            return;
+        };
+        let Ok(platform) = manager.adapters().await else {
+            return;
+        };
+
+        let listed = BtleplugAdapter::list_adapters().await.unwrap();
+        assert_eq!(listed.len(), platform.len());
+        for (i, info) in listed.iter().enumerate() {
+            assert_eq!(info.index, i);
+        }
+
+        let err = BtleplugAdapter::with_adapter(AdapterSelector::Index(platform.len()))
+            .await
+            .err()
+            .expect("Out-of-range adapter index should fail");
+        let msg = err.to_string();
+        assert!(
+            msg.contains("out of range") || msg.contains("No BLE adapter"),
+            "{}",
+            msg
+        );
+    }
+
     #[tokio::test]
     async fn test_disconnect_without_connection() {
         // Test disconnecting when no device is connected
@@ -1076,6 +1433,7 @@ mod tests {
                 adapter: self.adapter.clone(),
                 discovered_devices: self.discovered_devices.clone(),
                 connected_peripheral: self.connected_peripheral.clone(),
+                circuit_breaker: self.circuit_breaker.clone(),
             }
         }
     }
@@ -6,10 +6,12 @@
 
 use crate::ports::notification::{NotificationEvent, NotificationPort};
 use crate::state::session::ZoneDeviation;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use colored::Colorize;
 use flutter_rust_bridge::frb;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
 
 /// CLI notification adapter that prints colored notifications to stdout.
 ///
@@ -17,6 +19,12 @@ use flutter_rust_bridge::frb;
 /// with ANSI colors and emoji indicators. It's designed for CLI applications
 /// where users monitor their training session in a terminal.
 ///
+/// By default each notification is printed immediately. In buffered mode (see
+/// [`CliNotificationAdapter::buffered`]) notifications are queued instead and
+/// only written when [`CliNotificationAdapter::flush`] is called, so a live
+/// display loop can print them below its rendered frame rather than having
+/// them interleave with it.
+///
 /// # Color scheme
 /// - Zone deviations: Blue (too low), Red (too high), Green (in zone)
 /// - Phase transitions: Yellow
@@ -24,73 +32,161 @@ use flutter_rust_bridge::frb;
 /// - Connection loss: Red + bold
 /// - Workout ready: Green
 #[frb(opaque)]
-#[derive(Debug, Clone, Copy, Default)]
-pub struct CliNotificationAdapter;
+#[derive(Debug, Clone, Default)]
+pub struct CliNotificationAdapter {
+    /// Queued notification lines in buffered mode; `None` prints immediately.
+    buffer: Option<Arc<Mutex<Vec<String>>>>,
+}
 
 impl CliNotificationAdapter {
-    /// Create a new CLI notification adapter.
+    /// Create a new CLI notification adapter that prints immediately.
     pub fn new() -> Self {
-        Self
+        Self { buffer: None }
     }
-}
 
-#[async_trait]
-impl NotificationPort for CliNotificationAdapter {
-    async fn notify(&self, event: NotificationEvent) -> Result<()> {
+    /// Create a CLI notification adapter that queues notifications until flushed.
+    ///
+    /// Clones share the same queue, so the display loop can keep a clone and
+    /// flush what the executor's copy has queued.
+    pub fn buffered() -> Self {
+        Self {
+            buffer: Some(Arc::new(Mutex::new(Vec::new()))),
+        }
+    }
+
+    /// Number of notifications waiting to be flushed (always 0 when unbuffered).
+    pub fn pending(&self) -> usize {
+        self.buffer
+            .as_ref()
+            .and_then(|buffer| buffer.lock().ok().map(|queue| queue.len()))
+            .unwrap_or(0)
+    }
+
+    /// Write all queued notifications to stdout and clear the queue.
+    ///
+    /// Returns the number of notifications written. A no-op when unbuffered.
+    pub fn flush(&self) -> Result<usize> {
+        let stdout = std::io::stdout();
+        let mut handle = stdout.lock();
+        self.flush_to(&mut handle)
+    }
+
+    /// Write all queued notifications to `writer` and clear the queue.
+    ///
+    /// Returns the number of notifications written. A no-op when unbuffered.
+    pub fn flush_to<W: Write>(&self, writer: &mut W) -> Result<usize> {
+        let Some(buffer) = &self.buffer else {
+            return Ok(0);
+        };
+
+        let lines: Vec<String> = buffer
+            .lock()
+            .map_err(|e| anyhow!("Failed to lock notification buffer: {}", e))?
+            .drain(..)
+            .collect();
+
+        for line in &lines {
+            writeln!(writer, "{}", line)?;
+        }
+        writer.flush()?;
+        Ok(lines.len())
+    }
+
+    /// Format a notification event as a single colored terminal message.
+    fn format_event(event: NotificationEvent) -> String {
         match event {
             NotificationEvent::ZoneDeviation {
                 deviation,
                 current_bpm,
                 target_zone,
-            } => match deviation {
-                ZoneDeviation::TooLow => {
-                    println!(
-                        "{} BPM: {} (Target: {})",
-                        "⬇️  TOO LOW".blue().bold(),
-                        current_bpm,
-                        target_zone
-                    );
-                }
-                ZoneDeviation::TooHigh => {
-                    println!(
-                        "{} BPM: {} (Target: {})",
-                        "⬆️  TOO HIGH".red().bold(),
-                        current_bpm,
-                        target_zone
-                    );
-                }
-                ZoneDeviation::InZone => {
-                    println!(
-                        "{} BPM: {} (Target: {})",
-                        "✓ IN ZONE".green().bold(),
-                        current_bpm,
-                        target_zone
-                    );
-                }
-            },
+            } => {
+                let indicator = match deviation {
+                    ZoneDeviation::TooLow => "⬇️  TOO LOW".blue().bold(),
+                    ZoneDeviation::TooHigh => "⬆️  TOO HIGH".red().bold(),
+                    ZoneDeviation::InZone => "✓ IN ZONE".green().bold(),
+                };
+                format!(
+                    "{} BPM: {} (Target: {})",
+                    indicator,
+                    current_bpm,
+                    target_zone.display_name()
+                )
+            }
             NotificationEvent::PhaseTransition {
                 from_phase,
                 to_phase,
                 phase_name,
-            } => {
-                println!(
-                    "\n{} {} → {} ({})\n",
-                    "🔄 PHASE CHANGE".yellow().bold(),
-                    from_phase,
-                    to_phase,
-                    phase_name
-                );
-            }
+            } => format!(
+                "\n{} {} → {} ({})\n",
+                "🔄 PHASE CHANGE".yellow().bold(),
+                from_phase,
+                to_phase,
+                phase_name
+            ),
             NotificationEvent::BatteryLow { percentage } => {
-                println!("{} {}%", "🔋 LOW BATTERY".yellow().bold(), percentage);
+                format!("{} {}%", "🔋 LOW BATTERY".yellow().bold(), percentage)
             }
-            NotificationEvent::ConnectionLost => {
-                println!("{}", "❌ CONNECTION LOST".red().bold());
+            NotificationEvent::ConnectionLost => format!("{}", "❌ CONNECTION LOST".red().bold()),
+            NotificationEvent::ContactLost => {
+                format!("{}", "🫀 SENSOR CONTACT LOST".yellow().bold())
             }
+            NotificationEvent::SessionStarted => {
+                format!("{}", "▶️  SESSION STARTED".green().bold())
+            }
+            NotificationEvent::PhaseStarted { name, zone } => {
+                format!(
+                    "{} {} ({})",
+                    "⏱️  PHASE STARTED:".cyan().bold(),
+                    name,
+                    zone.display_name()
+                )
+            }
+            NotificationEvent::PhaseEndingSoon {
+                remaining_secs,
+                next_zone,
+            } => match next_zone {
+                Some(zone) => format!(
+                    "{} {}s, next: {}",
+                    "⏳ PHASE ENDING:".cyan().bold(),
+                    remaining_secs,
+                    zone.display_name()
+                ),
+                None => format!("{} {}s", "⏳ PHASE ENDING:".cyan().bold(), remaining_secs),
+            },
+            NotificationEvent::MaxHrExceeded {
+                observed,
+                configured,
+            } => format!(
+                "{} peaked at {} BPM, plan max is {} BPM",
+                "📈 MAX HR EXCEEDED:".yellow().bold(),
+                observed,
+                configured
+            ),
+            NotificationEvent::SessionCompleted { summary } => format!(
+                "{} {}s, avg {} BPM, max {} BPM",
+                "🏁 SESSION COMPLETE:".green().bold(),
+                summary.duration_secs,
+                summary.avg_hr,
+                summary.max_hr
+            ),
             NotificationEvent::WorkoutReady { plan_name } => {
-                println!("{} {}", "🏃 WORKOUT READY:".green().bold(), plan_name);
+                format!("{} {}", "🏃 WORKOUT READY:".green().bold(), plan_name)
             }
         }
+    }
+}
+
+#[async_trait]
+impl NotificationPort for CliNotificationAdapter {
+    async fn notify(&self, event: NotificationEvent) -> Result<()> {
+        let line = Self::format_event(event);
+        match &self.buffer {
+            Some(buffer) => buffer
+                .lock()
+                .map_err(|e| anyhow!("Failed to lock notification buffer: {}", e))?
+                .push(line),
+            None => println!("{}", line),
+        }
         Ok(())
     }
 }
@@ -182,4 +278,45 @@ mod tests {
             .await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_buffered_mode_emits_only_on_flush() {
+        let adapter = CliNotificationAdapter::buffered();
+        let display_handle = adapter.clone();
+
+        adapter
+            .notify(NotificationEvent::BatteryLow { percentage: 15 })
+            .await
+            .unwrap();
+        adapter
+            .notify(NotificationEvent::WorkoutReady {
+                plan_name: "Test Workout".to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(display_handle.pending(), 2);
+
+        let mut out = Vec::new();
+        assert_eq!(display_handle.flush_to(&mut out).unwrap(), 2);
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("15%"));
+        assert!(text.contains("Test Workout"));
+        assert_eq!(adapter.pending(), 0);
+
+        // Nothing left to emit on a second flush
+        let mut out = Vec::new();
+        assert_eq!(adapter.flush_to(&mut out).unwrap(), 0);
+        assert!(out.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unbuffered_mode_has_nothing_to_flush() {
+        let adapter = CliNotificationAdapter::new();
+        adapter
+            .notify(NotificationEvent::ConnectionLost)
+            .await
+            .unwrap();
+        assert_eq!(adapter.pending(), 0);
+        assert_eq!(adapter.flush_to(&mut Vec::new()).unwrap(), 0);
+    }
 }
@@ -3,22 +3,65 @@
 //! This adapter implements `SessionRepository` using JSON files stored in the
 //! user's home directory (~/.heart-beat/sessions/). Each session is stored as
 //! a separate JSON file with a filename format: {date}_{plan}_{id}.json
+//!
+//! Repositories created with `compressed = true` write gzip files
+//! (`.json.gz`) instead. Both formats are read transparently, so a directory
+//! may contain a mix of plain and compressed sessions.
+//!
+//! Unreadable files (e.g. from a partial write) are skipped by `list` and can
+//! be moved into a `corrupt/` subdirectory with
+//! [`FileSessionRepository::verify_and_quarantine`].
+//!
+//! With [`FileSessionRepository::with_write_coalescing`], repeated saves of the
+//! same session within a short window collapse into a single disk write. The
+//! latest state is written when the window ends or on
+//! [`flush`](SessionRepository::flush).
 
+use super::gzip;
 use crate::domain::session_history::CompletedSession;
-use crate::ports::session_repository::{SessionRepository, SessionSummaryPreview};
+use crate::ports::session_repository::{RepairReport, SessionRepository, SessionSummaryPreview};
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::fs;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Subdirectory of the sessions directory that unreadable files are moved to.
+const QUARANTINE_DIR: &str = "corrupt";
+
+/// Saves waiting to be written by a coalescing repository.
+#[derive(Debug, Default)]
+struct WriteBuffer {
+    /// Latest unwritten state of each session, keyed by session ID.
+    pending: HashMap<String, CompletedSession>,
+    /// When each session was last written to disk.
+    last_write: HashMap<String, Instant>,
+    /// Whether a deferred flush is already scheduled.
+    flush_scheduled: bool,
+}
 
 /// File-based implementation of SessionRepository.
 ///
 /// Stores sessions as JSON files in ~/.heart-beat/sessions/ directory.
-/// Each file is named: {YYYYMMDD}_{plan_name}_{session_id}.json
+/// Each file is named: {YYYYMMDD}_{plan_name}_{session_id}.json, with a
+/// `.gz` suffix when compression is enabled.
 #[derive(Debug, Clone)]
 pub struct FileSessionRepository {
     /// Directory where session files are stored.
     sessions_dir: PathBuf,
+    /// Whether new sessions are written gzip-compressed.
+    compressed: bool,
+    /// Window within which repeated saves of a session are coalesced.
+    coalesce_window: Option<Duration>,
+    /// Saves deferred by write coalescing, shared between clones.
+    buffer: Arc<Mutex<WriteBuffer>>,
+    /// Number of session files written to disk.
+    writes: Arc<AtomicUsize>,
 }
 
 impl FileSessionRepository {
@@ -47,12 +90,44 @@ impl FileSessionRepository {
     ///
     /// Returns an error if the directory cannot be created.
     pub async fn with_directory(sessions_dir: PathBuf) -> Result<Self> {
+        Self::with_options(sessions_dir, false).await
+    }
+
+    /// Create a new FileSessionRepository with a custom directory and
+    /// storage options.
+    ///
+    /// # Arguments
+    ///
+    /// * `sessions_dir` - Path to the directory where session files will be stored
+    /// * `compressed` - Write sessions as gzip-compressed `.json.gz` files
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created.
+    pub async fn with_options(sessions_dir: PathBuf, compressed: bool) -> Result<Self> {
         // Create the directory if it doesn't exist
         fs::create_dir_all(&sessions_dir)
             .await
             .with_context(|| format!("Failed to create sessions directory: {:?}", sessions_dir))?;
 
-        Ok(Self { sessions_dir })
+        Ok(Self {
+            sessions_dir,
+            compressed,
+            coalesce_window: None,
+            buffer: Arc::new(Mutex::new(WriteBuffer::default())),
+            writes: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    /// Coalesce repeated saves of the same session within `window`.
+    ///
+    /// The first save is written immediately; later saves within the window
+    /// only update an in-memory copy, which is written once when the window
+    /// ends. Call [`flush`](SessionRepository::flush) to write pending saves
+    /// right away, e.g. when a session stops.
+    pub fn with_write_coalescing(mut self, window: Duration) -> Self {
+        self.coalesce_window = Some(window);
+        self
     }
 
     /// Get the default sessions directory path.
@@ -76,13 +151,11 @@ impl FileSessionRepository {
     /// Parse session ID from filename.
     ///
     /// Extracts the session ID from a filename in the format:
-    /// {YYYYMMDD}--{plan_name}--{session_id}.json
+    /// {YYYYMMDD}--{plan_name}--{session_id}.json (optionally with `.gz`)
     fn parse_session_id(filename: &str) -> Option<String> {
-        if !filename.ends_with(".json") {
-            return None;
-        }
-
-        let without_ext = &filename[..filename.len() - 5]; // Remove ".json"
+        let without_ext = filename
+            .strip_suffix(".json.gz")
+            .or_else(|| filename.strip_suffix(".json"))?;
         let parts: Vec<&str> = without_ext.split("--").collect();
 
         // Format is: YYYYMMDD--planname--id
@@ -96,7 +169,12 @@ impl FileSessionRepository {
 
     /// Get the full path for a session file.
     fn session_path(&self, session: &CompletedSession) -> PathBuf {
-        self.sessions_dir.join(Self::session_filename(session))
+        let filename = Self::session_filename(session);
+        if self.compressed {
+            self.sessions_dir.join(format!("{}.gz", filename))
+        } else {
+            self.sessions_dir.join(filename)
+        }
     }
 
     /// Find a session file by ID.
@@ -121,49 +199,223 @@ impl FileSessionRepository {
         Ok(None)
     }
 
-    /// Load a session from a file.
+    /// Load a session from a file, decompressing `.gz` files.
     async fn load_session(&self, path: &PathBuf) -> Result<CompletedSession> {
-        let contents = fs::read_to_string(path)
+        let mut contents = fs::read(path)
             .await
             .with_context(|| format!("Failed to read session file: {:?}", path))?;
 
-        serde_json::from_str(&contents)
+        if path.extension().and_then(|s| s.to_str()) == Some("gz") {
+            contents = gzip::decompress(&contents)
+                .with_context(|| format!("Failed to decompress session file: {:?}", path))?;
+        }
+
+        serde_json::from_slice(&contents)
             .with_context(|| format!("Failed to parse session file: {:?}", path))
     }
 
+    /// Check every session file and report those that cannot be loaded.
+    ///
+    /// Unlike [`SessionRepository::verify`], unreadable files are moved into
+    /// the `corrupt/` subdirectory so they no longer appear in `list_ids`,
+    /// `get` or batch exports. Files that cannot be moved are reported with
+    /// `quarantined: false`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sessions directory cannot be read.
+    pub async fn verify_and_quarantine(&self) -> Result<Vec<RepairReport>> {
+        self.verify_files(true).await
+    }
+
+    /// Load every session file, reporting failures and optionally moving the
+    /// failing files into the quarantine directory.
+    async fn verify_files(&self, quarantine: bool) -> Result<Vec<RepairReport>> {
+        let mut entries = fs::read_dir(&self.sessions_dir).await.with_context(|| {
+            format!("Failed to read sessions directory: {:?}", self.sessions_dir)
+        })?;
+
+        let mut reports = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            let filename = entry.file_name().to_string_lossy().into_owned();
+            if !filename.ends_with(".json") && !filename.ends_with(".json.gz") {
+                continue;
+            }
+
+            let Err(e) = self.load_session(&path).await else {
+                continue;
+            };
+            tracing::warn!("Unreadable session file {:?}: {:#}", path, e);
+
+            let quarantined = quarantine && self.quarantine_file(&path, &filename).await;
+            reports.push(RepairReport {
+                id: Self::parse_session_id(&filename),
+                location: path.display().to_string(),
+                error: format!("{:#}", e),
+                quarantined,
+            });
+        }
+
+        reports.sort_by(|a, b| a.location.cmp(&b.location));
+        Ok(reports)
+    }
+
+    /// Move a session file into the quarantine directory.
+    ///
+    /// Returns `true` if the file was moved.
+    async fn quarantine_file(&self, path: &PathBuf, filename: &str) -> bool {
+        let dir = self.sessions_dir.join(QUARANTINE_DIR);
+        let moved = match fs::create_dir_all(&dir).await {
+            Ok(()) => fs::rename(path, dir.join(filename)).await,
+            Err(e) => Err(e),
+        };
+        match moved {
+            Ok(()) => {
+                tracing::info!("Quarantined session file {:?} into {:?}", path, dir);
+                true
+            }
+            Err(e) => {
+                tracing::error!("Failed to quarantine session file {:?}: {}", path, e);
+                false
+            }
+        }
+    }
+
     /// Create a session summary preview from a session file.
     ///
     /// This is optimized to avoid loading the full session (which may have
     /// thousands of HR samples) when just listing sessions.
     async fn create_preview(&self, path: &PathBuf) -> Result<SessionSummaryPreview> {
         let session = self.load_session(path).await?;
+        Ok(Self::preview_of(&session))
+    }
 
-        Ok(SessionSummaryPreview {
-            id: session.id,
-            plan_name: session.plan_name,
+    /// Build the list preview of a loaded session.
+    fn preview_of(session: &CompletedSession) -> SessionSummaryPreview {
+        SessionSummaryPreview {
+            id: session.id.clone(),
+            plan_name: session.plan_name.clone(),
             start_time: session.start_time,
             duration_secs: session.summary.duration_secs,
             avg_hr: session.summary.avg_hr,
             status: format!("{:?}", session.status),
-        })
+        }
     }
-}
 
-#[async_trait]
-impl SessionRepository for FileSessionRepository {
-    async fn save(&self, session: &CompletedSession) -> Result<()> {
+    /// Write a session file, replacing any copy in the other format.
+    async fn write_session(&self, session: &CompletedSession) -> Result<()> {
         let path = self.session_path(session);
 
         let json = serde_json::to_string_pretty(session)
             .with_context(|| format!("Failed to serialize session: {}", session.id))?;
 
-        fs::write(&path, json)
+        let bytes = if self.compressed {
+            gzip::compress(json.as_bytes())
+        } else {
+            json.into_bytes()
+        };
+
+        fs::write(&path, bytes)
             .await
             .with_context(|| format!("Failed to write session file: {:?}", path))?;
 
+        // Drop a copy stored in the other format so the session isn't listed twice
+        let other = if self.compressed {
+            path.with_extension("")
+        } else {
+            path.with_extension("json.gz")
+        };
+        if fs::try_exists(&other).await.unwrap_or(false) {
+            fs::remove_file(&other)
+                .await
+                .with_context(|| format!("Failed to remove session file: {:?}", other))?;
+        }
+
+        self.writes.fetch_add(1, Ordering::Relaxed);
         Ok(())
     }
 
+    /// Flush pending saves once the coalescing window has passed.
+    fn schedule_flush(&self, window: Duration) {
+        let repo = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(window).await;
+            if let Err(e) = repo.flush().await {
+                tracing::warn!("Failed to write coalesced session saves: {:#}", e);
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl SessionRepository for FileSessionRepository {
+    async fn save(&self, session: &CompletedSession) -> Result<()> {
+        let Some(window) = self.coalesce_window else {
+            return self.write_session(session).await;
+        };
+
+        let now = Instant::now();
+        let mut buffer = self.buffer.lock().await;
+        let recently_written = buffer
+            .last_write
+            .get(&session.id)
+            .is_some_and(|at| now.duration_since(*at) < window);
+
+        if !recently_written {
+            buffer.pending.remove(&session.id);
+            buffer.last_write.insert(session.id.clone(), now);
+            drop(buffer);
+            return self.write_session(session).await;
+        }
+
+        buffer.pending.insert(session.id.clone(), session.clone());
+        if !buffer.flush_scheduled {
+            buffer.flush_scheduled = true;
+            self.schedule_flush(window);
+        }
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<()> {
+        let pending: Vec<CompletedSession> = {
+            let mut buffer = self.buffer.lock().await;
+            buffer.flush_scheduled = false;
+            buffer.pending.drain().map(|(_, s)| s).collect()
+        };
+
+        // Keep going after a failure so one bad session doesn't hold back the rest
+        let mut first_error = None;
+        let mut failed = 0;
+        for session in pending.iter() {
+            match self.write_session(session).await {
+                Ok(()) => {
+                    let mut buffer = self.buffer.lock().await;
+                    buffer.last_write.insert(session.id.clone(), Instant::now());
+                }
+                Err(e) => {
+                    // Put it back for the next flush unless a newer save replaced it
+                    let mut buffer = self.buffer.lock().await;
+                    buffer
+                        .pending
+                        .entry(session.id.clone())
+                        .or_insert_with(|| session.clone());
+                    failed += 1;
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        match first_error {
+            Some(e) => Err(e.context(format!(
+                "Failed to write {} of {} pending sessions",
+                failed,
+                pending.len()
+            ))),
+            None => Ok(()),
+        }
+    }
+
     async fn list(&self) -> Result<Vec<SessionSummaryPreview>> {
         let mut entries = fs::read_dir(&self.sessions_dir).await.with_context(|| {
             format!("Failed to read sessions directory: {:?}", self.sessions_dir)
@@ -174,8 +426,9 @@ impl SessionRepository for FileSessionRepository {
         while let Some(entry) = entries.next_entry().await? {
             let path = entry.path();
 
-            // Skip non-JSON files
-            if path.extension().and_then(|s| s.to_str()) != Some("json") {
+            // Skip files that aren't (optionally compressed) session JSON
+            let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if !filename.ends_with(".json") && !filename.ends_with(".json.gz") {
                 continue;
             }
 
@@ -183,18 +436,54 @@ impl SessionRepository for FileSessionRepository {
             match self.create_preview(&path).await {
                 Ok(preview) => previews.push(preview),
                 Err(e) => {
-                    eprintln!("Warning: Failed to load session from {:?}: {}", path, e);
+                    tracing::warn!("Skipping unreadable session file {:?}: {:#}", path, e);
                 }
             }
         }
 
+        // Coalesced saves are newer than what is on disk
+        for session in self.buffer.lock().await.pending.values() {
+            let preview = Self::preview_of(session);
+            match previews.iter_mut().find(|p| p.id == preview.id) {
+                Some(existing) => *existing = preview,
+                None => previews.push(preview),
+            }
+        }
+
         // Sort by start time, most recent first
         previews.sort_by(|a, b| b.start_time.cmp(&a.start_time));
 
         Ok(previews)
     }
 
+    async fn list_ids(&self) -> Result<Vec<String>> {
+        let mut entries = fs::read_dir(&self.sessions_dir).await.with_context(|| {
+            format!("Failed to read sessions directory: {:?}", self.sessions_dir)
+        })?;
+
+        // IDs come from filenames only, so corrupt files are still listed
+        let mut ids = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            if let Some(id) = entry.file_name().to_str().and_then(Self::parse_session_id) {
+                ids.push(id);
+            }
+        }
+
+        ids.sort();
+        ids.dedup();
+        Ok(ids)
+    }
+
+    async fn verify(&self) -> Result<Vec<RepairReport>> {
+        self.verify_files(false).await
+    }
+
     async fn get(&self, id: &str) -> Result<Option<CompletedSession>> {
+        // A coalesced save is newer than what is on disk
+        if let Some(session) = self.buffer.lock().await.pending.get(id) {
+            return Ok(Some(session.clone()));
+        }
+
         match self.find_session_file(id).await? {
             Some(path) => {
                 let session = self.load_session(&path).await?;
@@ -205,6 +494,13 @@ impl SessionRepository for FileSessionRepository {
     }
 
     async fn delete(&self, id: &str) -> Result<()> {
+        // Drop any pending save so a deferred flush can't recreate the file
+        {
+            let mut buffer = self.buffer.lock().await;
+            buffer.pending.remove(id);
+            buffer.last_write.remove(id);
+        }
+
         match self.find_session_file(id).await? {
             Some(path) => {
                 fs::remove_file(&path)
@@ -267,8 +563,19 @@ mod tests {
                 avg_hr: 140,
                 max_hr: 160,
                 min_hr: 120,
+                hr_p50: 140,
+                hr_p90: 160,
+                hr_p95: 160,
                 time_in_zone: [0, 1800, 0, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         };
 
         let filename = FileSessionRepository::session_filename(&session);
@@ -287,6 +594,11 @@ mod tests {
             Some("xyz789".to_string())
         );
 
+        assert_eq!(
+            FileSessionRepository::parse_session_id("20240115--Easy_Run--abc123.json.gz"),
+            Some("abc123".to_string())
+        );
+
         assert_eq!(FileSessionRepository::parse_session_id("invalid.txt"), None);
 
         assert_eq!(
@@ -331,8 +643,19 @@ mod tests {
                 avg_hr: 130,
                 max_hr: 140,
                 min_hr: 120,
+                hr_p50: 130,
+                hr_p90: 140,
+                hr_p95: 140,
                 time_in_zone: [0, 900, 900, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         };
 
         // Save the session
@@ -371,8 +694,19 @@ mod tests {
                 avg_hr: 130,
                 max_hr: 140,
                 min_hr: 120,
+                hr_p50: 130,
+                hr_p90: 140,
+                hr_p95: 140,
                 time_in_zone: [0, 3600, 0, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         };
 
         let session2 = CompletedSession {
@@ -388,8 +722,19 @@ mod tests {
                 avg_hr: 140,
                 max_hr: 150,
                 min_hr: 130,
+                hr_p50: 140,
+                hr_p90: 150,
+                hr_p95: 150,
                 time_in_zone: [0, 1800, 1800, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         };
 
         repo.save(&session1).await.unwrap();
@@ -425,8 +770,19 @@ mod tests {
                 avg_hr: 130,
                 max_hr: 140,
                 min_hr: 120,
+                hr_p50: 130,
+                hr_p90: 140,
+                hr_p95: 140,
                 time_in_zone: [0, 3600, 0, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         };
 
         // Save and verify it exists
@@ -440,4 +796,299 @@ mod tests {
         // Deleting non-existent session should succeed silently
         repo.delete("nonexistent").await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_search_matches_plan_name_case_insensitively() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = FileSessionRepository::with_directory(temp_dir.path().to_path_buf())
+            .await
+            .unwrap();
+
+        for (id, plan_name) in [
+            ("tempo", "Tempo Run"),
+            ("easy", "Easy Run"),
+            ("hills", "Hill Repeats + TEMPO finish"),
+        ] {
+            let mut session = session_with_samples(id, 3);
+            session.plan_name = plan_name.to_string();
+            repo.save(&session).await.unwrap();
+        }
+
+        let mut ids: Vec<String> = repo
+            .search("tempo")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, vec!["hills", "tempo"]);
+
+        assert!(repo.search("interval").await.unwrap().is_empty());
+        assert_eq!(repo.search("  ").await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_list_ids_includes_unreadable_sessions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = FileSessionRepository::with_directory(temp_dir.path().to_path_buf())
+            .await
+            .unwrap();
+
+        repo.save(&session_with_samples("good", 5)).await.unwrap();
+        std::fs::write(
+            temp_dir.path().join("20240115--Broken--bad.json"),
+            "{ not json",
+        )
+        .unwrap();
+        std::fs::write(temp_dir.path().join("notes.txt"), "ignored").unwrap();
+
+        assert_eq!(repo.list().await.unwrap().len(), 1);
+        assert_eq!(repo.list_ids().await.unwrap(), vec!["bad", "good"]);
+        assert!(repo.get("bad").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_verify_reports_and_quarantines_corrupt_sessions() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = FileSessionRepository::with_directory(temp_dir.path().to_path_buf())
+            .await
+            .unwrap();
+
+        repo.save(&session_with_samples("good", 5)).await.unwrap();
+        let bad_path = temp_dir.path().join("20240115--Broken--bad.json");
+        // A partial write: valid JSON prefix cut off mid-document
+        std::fs::write(&bad_path, r#"{"id": "bad", "plan_name": "Bro"#).unwrap();
+
+        // Listing skips the corrupt file instead of failing
+        let previews = repo.list().await.unwrap();
+        assert_eq!(previews.len(), 1);
+        assert_eq!(previews[0].id, "good");
+
+        // Verification reports it without touching the file
+        let reports = repo.verify().await.unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].id.as_deref(), Some("bad"));
+        assert!(reports[0].error.contains("Failed to parse session file"));
+        assert!(!reports[0].quarantined);
+        assert!(bad_path.exists());
+
+        // Quarantine moves it out of the repository
+        let reports = repo.verify_and_quarantine().await.unwrap();
+        assert_eq!(reports.len(), 1);
+        assert!(reports[0].quarantined);
+        assert!(!bad_path.exists());
+        assert!(temp_dir
+            .path()
+            .join("corrupt")
+            .join("20240115--Broken--bad.json")
+            .exists());
+
+        assert_eq!(repo.list_ids().await.unwrap(), vec!["good"]);
+        assert!(repo.get("bad").await.unwrap().is_none());
+        assert!(repo.verify().await.unwrap().is_empty());
+        assert_eq!(repo.list().await.unwrap().len(), 1);
+    }
+
+    fn session_with_samples(id: &str, count: i64) -> CompletedSession {
+        let start = chrono::DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        CompletedSession {
+            id: id.to_string(),
+            plan_name: "Tempo Run".to_string(),
+            start_time: start,
+            end_time: start + chrono::Duration::seconds(count),
+            status: SessionStatus::Completed,
+            hr_samples: (0..count)
+                .map(|i| HrSample {
+                    timestamp: start + chrono::Duration::seconds(i),
+                    bpm: 120 + (i % 40) as u16,
+                })
+                .collect(),
+            phases_completed: 3,
+            summary: SessionSummary {
+                duration_secs: count as u32,
+                avg_hr: 140,
+                max_hr: 159,
+                min_hr: 120,
+                hr_p50: 140,
+                hr_p90: 159,
+                hr_p95: 159,
+                time_in_zone: [0, 0, count as u32, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
+            },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compressed_round_trip_in_both_modes() {
+        for compressed in [false, true] {
+            let temp_dir = tempfile::tempdir().unwrap();
+            let repo =
+                FileSessionRepository::with_options(temp_dir.path().to_path_buf(), compressed)
+                    .await
+                    .unwrap();
+
+            let session = session_with_samples("roundtrip", 600);
+            repo.save(&session).await.unwrap();
+
+            let expected_ext = if compressed { "gz" } else { "json" };
+            assert_eq!(
+                repo.session_path(&session)
+                    .extension()
+                    .and_then(|s| s.to_str()),
+                Some(expected_ext)
+            );
+            assert_eq!(repo.get("roundtrip").await.unwrap(), Some(session));
+
+            let previews = repo.list().await.unwrap();
+            assert_eq!(previews.len(), 1);
+            assert_eq!(previews[0].id, "roundtrip");
+            assert_eq!(previews[0].duration_secs, 600);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_compressed_file_is_smaller() {
+        let plain_dir = tempfile::tempdir().unwrap();
+        let gz_dir = tempfile::tempdir().unwrap();
+        let plain = FileSessionRepository::with_directory(plain_dir.path().to_path_buf())
+            .await
+            .unwrap();
+        let gz = FileSessionRepository::with_options(gz_dir.path().to_path_buf(), true)
+            .await
+            .unwrap();
+
+        let session = session_with_samples("size", 3600);
+        plain.save(&session).await.unwrap();
+        gz.save(&session).await.unwrap();
+
+        let plain_len = fs::metadata(plain.session_path(&session))
+            .await
+            .unwrap()
+            .len();
+        let gz_len = fs::metadata(gz.session_path(&session)).await.unwrap().len();
+        assert!(gz_len < plain_len / 4, "{} vs {}", gz_len, plain_len);
+    }
+
+    #[tokio::test]
+    async fn test_mixed_formats_are_read_and_resaved_once() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path().to_path_buf();
+        let plain = FileSessionRepository::with_directory(dir.clone())
+            .await
+            .unwrap();
+        let gz = FileSessionRepository::with_options(dir, true)
+            .await
+            .unwrap();
+
+        plain.save(&session_with_samples("old", 10)).await.unwrap();
+        gz.save(&session_with_samples("new", 10)).await.unwrap();
+        assert_eq!(plain.list().await.unwrap().len(), 2);
+        assert!(plain.get("new").await.unwrap().is_some());
+
+        // Re-saving in the other format replaces the original file
+        gz.save(&session_with_samples("old", 10)).await.unwrap();
+        assert_eq!(gz.list().await.unwrap().len(), 2);
+
+        gz.delete("old").await.unwrap();
+        assert!(gz.get("old").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_write_coalescing_collapses_rapid_saves() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = FileSessionRepository::with_directory(temp_dir.path().to_path_buf())
+            .await
+            .unwrap()
+            .with_write_coalescing(Duration::from_secs(60));
+
+        for count in [10, 20, 30] {
+            repo.save(&session_with_samples("live", count))
+                .await
+                .unwrap();
+        }
+        assert_eq!(repo.writes.load(Ordering::Relaxed), 1);
+
+        // Reads see the latest save even before it reaches disk
+        assert_eq!(
+            repo.get("live").await.unwrap().unwrap().hr_samples.len(),
+            30
+        );
+        assert_eq!(repo.list().await.unwrap()[0].duration_secs, 30);
+        let path = repo.find_session_file("live").await.unwrap().unwrap();
+        assert_eq!(repo.load_session(&path).await.unwrap().hr_samples.len(), 10);
+
+        repo.flush().await.unwrap();
+        assert_eq!(repo.writes.load(Ordering::Relaxed), 2);
+        assert_eq!(repo.load_session(&path).await.unwrap().hr_samples.len(), 30);
+
+        // Nothing left to write
+        repo.flush().await.unwrap();
+        assert_eq!(repo.writes.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn test_flush_keeps_failed_saves_and_writes_the_rest() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = FileSessionRepository::with_directory(temp_dir.path().to_path_buf())
+            .await
+            .unwrap()
+            .with_write_coalescing(Duration::from_secs(60));
+
+        for id in ["bad", "good"] {
+            repo.save(&session_with_samples(id, 10)).await.unwrap();
+        }
+        // A directory in place of the file makes the next write of "bad" fail
+        let bad_path = repo.find_session_file("bad").await.unwrap().unwrap();
+        fs::remove_file(&bad_path).await.unwrap();
+        fs::create_dir(&bad_path).await.unwrap();
+
+        for id in ["bad", "good"] {
+            repo.save(&session_with_samples(id, 20)).await.unwrap();
+        }
+        assert!(repo.flush().await.is_err());
+
+        let good_path = repo.find_session_file("good").await.unwrap().unwrap();
+        assert_eq!(
+            repo.load_session(&good_path)
+                .await
+                .unwrap()
+                .hr_samples
+                .len(),
+            20
+        );
+        assert_eq!(repo.get("bad").await.unwrap().unwrap().hr_samples.len(), 20);
+
+        fs::remove_dir(&bad_path).await.unwrap();
+        repo.flush().await.unwrap();
+        assert_eq!(
+            repo.load_session(&bad_path).await.unwrap().hr_samples.len(),
+            20
+        );
+    }
+
+    #[tokio::test]
+    async fn test_coalesced_save_is_written_after_window() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo = FileSessionRepository::with_directory(temp_dir.path().to_path_buf())
+            .await
+            .unwrap()
+            .with_write_coalescing(Duration::from_millis(100));
+
+        repo.save(&session_with_samples("live", 10)).await.unwrap();
+        repo.save(&session_with_samples("live", 20)).await.unwrap();
+        assert_eq!(repo.writes.load(Ordering::Relaxed), 1);
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert_eq!(repo.writes.load(Ordering::Relaxed), 2);
+    }
 }
@@ -0,0 +1,91 @@
+//! gzip (RFC 1952) encoding for stored files.
+//!
+//! Thin wrapper over flate2 so compressed session files can be read with
+//! standard tools such as `gunzip` or `zcat`.
+
+use anyhow::{bail, Context, Result};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::{Read, Write};
+
+/// gzip magic bytes.
+const MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// DEFLATE level used when compressing (same default as gzip).
+const COMPRESSION_LEVEL: u32 = 6;
+
+/// Largest decompressed size accepted, guarding against gzip bombs.
+///
+/// A multi-hour session with 1 Hz samples is a few MiB of JSON.
+const MAX_DECOMPRESSED_LEN: u64 = 64 * 1024 * 1024;
+
+/// Compress `data` into a gzip stream.
+pub(crate) fn compress(data: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::new(COMPRESSION_LEVEL));
+    // Writing into a Vec cannot fail
+    encoder
+        .write_all(data)
+        .and_then(|_| encoder.finish())
+        .expect("in-memory gzip compression failed")
+}
+
+/// Decompress a gzip stream, verifying its checksum and size.
+///
+/// Fails if the output would exceed [`MAX_DECOMPRESSED_LEN`].
+pub(crate) fn decompress(data: &[u8]) -> Result<Vec<u8>> {
+    decompress_capped(data, MAX_DECOMPRESSED_LEN)
+}
+
+fn decompress_capped(data: &[u8], max_len: u64) -> Result<Vec<u8>> {
+    if !data.starts_with(&MAGIC) {
+        bail!("Not a gzip stream");
+    }
+
+    let mut out = Vec::new();
+    GzDecoder::new(data)
+        .take(max_len + 1)
+        .read_to_end(&mut out)
+        .context("Failed to inflate gzip stream")?;
+    if out.len() as u64 > max_len {
+        bail!("gzip stream exceeds {} bytes when decompressed", max_len);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let data = b"{\"bpm\": 142}\n".repeat(500);
+        let compressed = compress(&data);
+
+        assert_eq!(&compressed[..2], &MAGIC);
+        assert!(compressed.len() < data.len());
+        assert_eq!(decompress(&compressed).unwrap(), data);
+        assert_eq!(decompress(&compress(b"")).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_rejects_corrupt_input() {
+        assert!(decompress(b"plain json").is_err());
+        assert!(decompress(b"").is_err());
+
+        let mut compressed = compress(b"some session data");
+        let last = compressed.len() - 5;
+        compressed[last] ^= 0xFF; // corrupt the checksum
+        assert!(decompress(&compressed).is_err());
+    }
+
+    #[test]
+    fn test_rejects_oversized_output() {
+        let data = vec![0u8; 4096];
+        let compressed = compress(&data);
+
+        assert_eq!(decompress_capped(&compressed, 4096).unwrap(), data);
+        assert!(decompress_capped(&compressed, 4095).is_err());
+    }
+}
@@ -5,12 +5,14 @@
 //! as the real btleplug adapter, allowing the application to work without physical
 //! heart rate monitor hardware.
 
-use crate::domain::heart_rate::DiscoveredDevice;
-use crate::ports::ble_adapter::BleAdapter;
+use crate::domain::heart_rate::{clamp_bpm, BpmBounds, DiscoveredDevice};
+use crate::domain::training_plan::TrainingPlan;
+use crate::ports::ble_adapter::{BleAdapter, BleError, HrNotification};
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use rand::Rng;
 use std::sync::Arc;
+use std::time::SystemTime;
 use tokio::sync::{mpsc, Mutex};
 use tokio::time::{self, Duration};
 
@@ -37,6 +39,16 @@ pub struct MockConfig {
 
     /// Battery level (0-100) to simulate.
     pub battery_level: u8,
+
+    /// How the simulated HR moves over time.
+    pub hr_mode: MockHrMode,
+
+    /// Encode every BPM in the 16-bit format (flags bit 0).
+    ///
+    /// Off by default, when the 8-bit format is used and 16 bits only for
+    /// BPMs above 255. Sensors may use either format for any value, so this
+    /// exercises the 16-bit decode path with ordinary heart rates.
+    pub force_uint16_bpm: bool,
 }
 
 impl Default for MockConfig {
@@ -48,10 +60,27 @@ impl Default for MockConfig {
             spike_magnitude: 20,
             update_rate: 1.0, // 1 Hz
             battery_level: 85,
+            hr_mode: MockHrMode::Static,
+            force_uint16_bpm: false,
         }
     }
 }
 
+/// How the mock adapter's simulated HR moves over time.
+#[derive(Debug, Clone, Default)]
+pub enum MockHrMode {
+    /// Stay around `baseline_bpm`.
+    #[default]
+    Static,
+
+    /// Follow a training plan.
+    ///
+    /// HR starts at `baseline_bpm` and moves toward each phase's target with
+    /// a realistic lag (see [`TrainingPlan::hr_simulator`]), timed from the start of the
+    /// HR subscription. Noise and spikes are added on top.
+    FollowPlan(TrainingPlan),
+}
+
 /// Mock BLE adapter that simulates heart rate data.
 ///
 /// This adapter generates realistic heart rate patterns without requiring physical
@@ -63,12 +92,18 @@ impl Default for MockConfig {
 pub struct MockAdapter {
     /// Configuration for simulated data generation
     config: MockConfig,
+    /// Range simulated BPMs are clamped to
+    bpm_bounds: BpmBounds,
     /// List of fake devices available for discovery
     discovered_devices: Arc<Mutex<Vec<DiscoveredDevice>>>,
     /// Whether a device is currently "connected"
     is_connected: Arc<Mutex<bool>>,
     /// ID of the connected device (if any)
     connected_device_id: Arc<Mutex<Option<String>>>,
+    /// Senders of active HR subscriptions, used by `inject_hr`
+    hr_subscribers: Arc<Mutex<Vec<mpsc::Sender<HrNotification>>>>,
+    /// Whether a payload was injected since connecting, which stops the simulated stream
+    hr_injected: Arc<Mutex<bool>>,
 }
 
 impl MockAdapter {
@@ -85,23 +120,89 @@ impl MockAdapter {
     pub fn with_config(config: MockConfig) -> Self {
         Self {
             config,
+            bpm_bounds: BpmBounds::PHYSIOLOGICAL,
             discovered_devices: Arc::new(Mutex::new(Vec::new())),
             is_connected: Arc::new(Mutex::new(false)),
             connected_device_id: Arc::new(Mutex::new(None)),
+            hr_subscribers: Arc::new(Mutex::new(Vec::new())),
+            hr_injected: Arc::new(Mutex::new(false)),
+        }
+    }
+
+    /// Set the range simulated BPMs are clamped to.
+    ///
+    /// Defaults to [`BpmBounds::PHYSIOLOGICAL`]; widen it to simulate efforts
+    /// beyond 220 BPM.
+    pub fn with_bpm_bounds(mut self, bounds: BpmBounds) -> Self {
+        self.bpm_bounds = bounds;
+        self
+    }
+
+    /// Deliver an exact HR measurement payload to all HR subscribers.
+    ///
+    /// The bytes reach subscribers as if received from hardware, so tests can
+    /// drive parser edge cases through a real subscription. The first
+    /// injection stops the simulated stream until the device disconnects, so
+    /// subscribers then only see injected payloads. Returns the number of
+    /// subscribers reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no device is connected.
+    pub async fn inject_hr(&self, payload: Vec<u8>) -> Result<usize> {
+        self.inject_hr_at(payload, SystemTime::now()).await
+    }
+
+    /// Deliver a payload like [`Self::inject_hr`], stamped as received at
+    /// `received_at`.
+    ///
+    /// Lets tests check that arrival times from the adapter are carried
+    /// through instead of being regenerated downstream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no device is connected.
+    pub async fn inject_hr_at(&self, payload: Vec<u8>, received_at: SystemTime) -> Result<usize> {
+        if !*self.is_connected.lock().await {
+            return Err(anyhow!("No device connected"));
+        }
+
+        *self.hr_injected.lock().await = true;
+
+        // Send without holding the lock, so a full channel can't block subscribing
+        let subscribers = {
+            let mut subscribers = self.hr_subscribers.lock().await;
+            subscribers.retain(|tx| !tx.is_closed());
+            subscribers.clone()
+        };
+        for tx in &subscribers {
+            // A receiver dropped since the check only misses this packet
+            let notification = HrNotification {
+                data: payload.clone(),
+                received_at,
+            };
+            let _ = tx.send(notification).await;
         }
+        Ok(subscribers.len())
     }
 
     /// Simulate the HR notification stream.
     ///
     /// This spawns a background task that generates heart rate packets at the
     /// configured update rate and sends them through the channel.
-    fn start_hr_stream(&self, tx: mpsc::Sender<Vec<u8>>) {
+    fn start_hr_stream(&self, tx: mpsc::Sender<HrNotification>) {
         let config = self.config.clone();
+        let bounds = self.bpm_bounds;
         let is_connected = self.is_connected.clone();
+        let hr_injected = self.hr_injected.clone();
 
         tokio::spawn(async move {
             let interval_duration = Duration::from_secs_f64(1.0 / config.update_rate);
             let mut interval = time::interval(interval_duration);
+            let mut simulator = match &config.hr_mode {
+                MockHrMode::Static => None,
+                MockHrMode::FollowPlan(plan) => Some(plan.hr_simulator(config.baseline_bpm)),
+            };
 
             loop {
                 interval.tick().await;
@@ -112,9 +213,20 @@ impl MockAdapter {
                     break;
                 }
 
+                // Injected payloads replace the simulated ones
+                if *hr_injected.lock().await {
+                    continue;
+                }
+
                 // Generate and send packet
-                let packet = Self::generate_hr_packet_static(&config);
-                if tx.send(packet).await.is_err() {
+                let packet = match simulator.as_mut() {
+                    Some(sim) => {
+                        let baseline = sim.step(interval_duration.as_secs_f64());
+                        Self::generate_hr_packet_around(&config, bounds, baseline)
+                    }
+                    None => Self::generate_hr_packet_static(&config, bounds),
+                };
+                if tx.send(HrNotification::now(packet)).await.is_err() {
                     tracing::debug!("Mock adapter: HR receiver dropped");
                     break;
                 }
@@ -126,23 +238,37 @@ impl MockAdapter {
     ///
     /// This creates a packet following the Bluetooth Heart Rate Measurement format:
     /// - Byte 0: Flags
-    /// - Byte 1-2: Heart rate value (UINT8 or UINT16 depending on flags)
+    /// - Byte 1-2: Heart rate value (UINT8 or UINT16 depending on flags; UINT16
+    ///   above 255 BPM or with `force_uint16_bpm`)
     /// - Remaining bytes: RR-intervals (optional)
     ///
     /// The generated data is designed to be parsed by the same parser that handles
     /// real BLE data, ensuring test coverage of the parsing logic.
-    fn generate_hr_packet_static(config: &MockConfig) -> Vec<u8> {
+    fn generate_hr_packet_static(config: &MockConfig, bounds: BpmBounds) -> Vec<u8> {
+        Self::generate_hr_packet_around(config, bounds, config.baseline_bpm as f64)
+    }
+
+    /// Generate a packet like [`Self::generate_hr_packet_static`] around
+    /// `baseline` BPM instead of the configured baseline.
+    fn generate_hr_packet_around(config: &MockConfig, bounds: BpmBounds, baseline: f64) -> Vec<u8> {
         let mut rng = rand::thread_rng();
 
         let noise: i16 = rng.gen_range(-(config.noise_range as i16)..=(config.noise_range as i16));
-        let mut bpm = (config.baseline_bpm as i16 + noise).max(30) as u16;
+        let mut bpm = clamp_bpm(baseline.round() + noise as f64, bounds);
 
         if rng.gen::<f64>() < config.spike_probability {
-            bpm = (bpm + config.spike_magnitude).min(220);
+            bpm = clamp_bpm(bpm + config.spike_magnitude as f64, bounds);
         }
-
-        let flags: u8 = 0b00010110;
-        let mut packet = vec![flags, bpm as u8];
+        let bpm = bpm as u16;
+
+        // Heart rates above 255 need the UINT16 format (flags bit 0)
+        let mut packet = match u8::try_from(bpm) {
+            Ok(bpm) if !config.force_uint16_bpm => vec![0b00010110, bpm],
+            _ => {
+                let [lo, hi] = bpm.to_le_bytes();
+                vec![0b00010111, lo, hi]
+            }
+        };
 
         let beat_interval_ms = 60000.0 / (bpm as f64);
         let rr_base = (beat_interval_ms * 1.024) as u16;
@@ -207,7 +333,7 @@ impl BleAdapter for MockAdapter {
         let device_exists = devices.iter().any(|d| d.id == device_id);
 
         if !device_exists {
-            return Err(anyhow!("Device not found: {}", device_id));
+            return Err(BleError::DeviceNotFound(device_id.to_string()).into());
         }
 
         // Simulate connection delay
@@ -228,12 +354,27 @@ impl BleAdapter for MockAdapter {
         }
 
         *self.is_connected.lock().await = false;
+        self.hr_subscribers.lock().await.clear();
+        *self.hr_injected.lock().await = false;
 
         tracing::info!("Mock adapter: Disconnected from {:?}", device_id);
         Ok(())
     }
 
     async fn subscribe_hr(&self) -> Result<mpsc::Receiver<Vec<u8>>> {
+        let mut stamped = self.subscribe_hr_timestamped().await?;
+        let (tx, rx) = mpsc::channel(32);
+        tokio::spawn(async move {
+            while let Some(notification) = stamped.recv().await {
+                if tx.send(notification.data).await.is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(rx)
+    }
+
+    async fn subscribe_hr_timestamped(&self) -> Result<mpsc::Receiver<HrNotification>> {
         if !*self.is_connected.lock().await {
             return Err(anyhow!("No device connected"));
         }
@@ -244,6 +385,7 @@ impl BleAdapter for MockAdapter {
         let (tx, rx) = mpsc::channel(32);
 
         // Start the simulated HR stream
+        self.hr_subscribers.lock().await.push(tx.clone());
         self.start_hr_stream(tx);
 
         Ok(rx)
@@ -372,6 +514,73 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_inject_hr_reaches_subscriber() {
+        use crate::domain::heart_rate::parse_heart_rate;
+
+        let adapter = MockAdapter::new();
+        assert!(adapter.inject_hr(vec![0x06, 72]).await.is_err());
+
+        adapter.start_scan().await.unwrap();
+        let devices = adapter.get_discovered_devices().await;
+        adapter.connect(&devices[0].id).await.unwrap();
+        let mut rx = adapter.subscribe_hr().await.unwrap();
+
+        // Flags: contact detected, energy expended and RR present; BPM 150,
+        // energy 300 kJ, RR 512 and 1024 (1/1024 s)
+        let payload = vec![0b00011110, 150, 0x2C, 0x01, 0x00, 0x02, 0x00, 0x04];
+        assert_eq!(adapter.inject_hr(payload.clone()).await.unwrap(), 1);
+
+        // Skip simulated packets until the injected one arrives
+        let received = tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                let packet = rx.recv().await.expect("stream should stay open");
+                if packet == payload {
+                    return packet;
+                }
+            }
+        })
+        .await
+        .expect("injected packet should arrive");
+
+        let measurement = parse_heart_rate(&received).unwrap();
+        assert_eq!(measurement.bpm, 150);
+        assert!(measurement.sensor_contact);
+        assert_eq!(measurement.rr_intervals, vec![512, 1024]);
+
+        // The simulated stream stays quiet after an injection
+        let next = tokio::time::timeout(Duration::from_millis(1500), rx.recv()).await;
+        assert!(next.is_err(), "simulated packet arrived after injection");
+    }
+
+    #[tokio::test]
+    async fn test_inject_hr_at_carries_receive_time() {
+        let adapter = MockAdapter::new();
+        adapter.start_scan().await.unwrap();
+        let devices = adapter.get_discovered_devices().await;
+        adapter.connect(&devices[0].id).await.unwrap();
+        let mut rx = adapter.subscribe_hr_timestamped().await.unwrap();
+
+        let received_at = SystemTime::UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        let payload = vec![0x06, 72];
+        adapter
+            .inject_hr_at(payload.clone(), received_at)
+            .await
+            .unwrap();
+
+        let notification = tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                let notification = rx.recv().await.expect("stream should stay open");
+                if notification.data == payload {
+                    return notification;
+                }
+            }
+        })
+        .await
+        .expect("injected packet should arrive");
+        assert_eq!(notification.received_at, received_at);
+    }
+
     #[tokio::test]
     async fn test_read_battery() {
         let adapter = MockAdapter::new();
@@ -409,7 +618,7 @@ mod tests {
     #[test]
     fn test_generate_hr_packet_format() {
         let config = MockConfig::default();
-        let packet = MockAdapter::generate_hr_packet_static(&config);
+        let packet = MockAdapter::generate_hr_packet_static(&config, BpmBounds::PHYSIOLOGICAL);
 
         assert!(
             packet.len() >= 2,
@@ -426,4 +635,84 @@ mod tests {
             assert_eq!((packet.len() - 2) % 2, 0, "RR data should be even length");
         }
     }
+
+    #[tokio::test]
+    async fn test_follow_plan_ramps_toward_phase_target() {
+        use crate::domain::heart_rate::{parse_heart_rate, Zone};
+
+        let adapter = MockAdapter::with_config(MockConfig {
+            baseline_bpm: 100,
+            noise_range: 0,
+            spike_probability: 0.0,
+            update_rate: 20.0,
+            hr_mode: MockHrMode::FollowPlan(TrainingPlan::steady("Hard", Zone::Zone5, 600, 200)),
+            ..Default::default()
+        });
+        adapter.start_scan().await.unwrap();
+        let devices = adapter.get_discovered_devices().await;
+        adapter.connect(&devices[0].id).await.unwrap();
+        let mut rx = adapter.subscribe_hr().await.unwrap();
+
+        let mut bpms = Vec::new();
+        for _ in 0..20 {
+            let packet = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            bpms.push(parse_heart_rate(&packet).unwrap().bpm);
+        }
+
+        // Starts at the baseline and climbs gradually, far from Zone 5 yet
+        assert!(bpms[0] <= 101, "first {}", bpms[0]);
+        assert!(bpms.windows(2).all(|w| w[1] >= w[0]));
+        assert!(bpms[19] > bpms[0] && bpms[19] < 110, "{:?}", bpms);
+    }
+
+    #[test]
+    fn test_generated_bpm_clamped_to_bounds() {
+        use crate::domain::heart_rate::parse_heart_rate;
+
+        let config = MockConfig {
+            baseline_bpm: 235,
+            noise_range: 0,
+            spike_probability: 0.0,
+            ..Default::default()
+        };
+
+        let packet = MockAdapter::generate_hr_packet_static(&config, BpmBounds::PHYSIOLOGICAL);
+        assert_eq!(parse_heart_rate(&packet).unwrap().bpm, 220);
+
+        let wide = BpmBounds::new(30, 240).unwrap();
+        let packet = MockAdapter::generate_hr_packet_static(&config, wide);
+        assert_eq!(parse_heart_rate(&packet).unwrap().bpm, 235);
+    }
+
+    #[tokio::test]
+    async fn test_forced_uint16_bpm_packets_decode() {
+        use crate::domain::heart_rate::parse_heart_rate;
+
+        let adapter = MockAdapter::with_config(MockConfig {
+            baseline_bpm: 142,
+            noise_range: 0,
+            spike_probability: 0.0,
+            update_rate: 20.0,
+            force_uint16_bpm: true,
+            ..Default::default()
+        });
+        adapter.start_scan().await.unwrap();
+        let devices = adapter.get_discovered_devices().await;
+        adapter.connect(&devices[0].id).await.unwrap();
+        let mut rx = adapter.subscribe_hr().await.unwrap();
+
+        let packet = tokio::time::timeout(Duration::from_secs(1), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(packet[0] & 0b1, 1, "16-bit format flag should be set");
+        assert_eq!(u16::from_le_bytes([packet[1], packet[2]]), 142);
+
+        let measurement = parse_heart_rate(&packet).unwrap();
+        assert_eq!(measurement.bpm, 142);
+        assert!(!measurement.rr_intervals.is_empty());
+    }
 }
@@ -209,4 +209,43 @@ mod tests {
             _ => panic!("Expected PhaseTransition event"),
         }
     }
+
+    #[tokio::test]
+    async fn test_session_lifecycle_events() {
+        use crate::domain::heart_rate::Zone;
+        use crate::domain::session_history::SessionSummary;
+
+        let adapter = MockNotificationAdapter::new();
+        let summary = SessionSummary::from_samples(&[], 60, [0; 5]);
+
+        adapter
+            .notify(NotificationEvent::SessionStarted)
+            .await
+            .unwrap();
+        adapter
+            .notify(NotificationEvent::PhaseStarted {
+                name: "Warmup".to_string(),
+                zone: Zone::Zone2,
+            })
+            .await
+            .unwrap();
+        adapter
+            .notify(NotificationEvent::SessionCompleted {
+                summary: summary.clone(),
+            })
+            .await
+            .unwrap();
+
+        let events = adapter.get_events().await;
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], NotificationEvent::SessionStarted));
+        assert!(matches!(
+            &events[1],
+            NotificationEvent::PhaseStarted { name, zone: Zone::Zone2 } if name == "Warmup"
+        ));
+        assert!(matches!(
+            &events[2],
+            NotificationEvent::SessionCompleted { summary: s } if *s == summary
+        ));
+    }
 }
@@ -6,11 +6,18 @@
 pub mod btleplug_adapter;
 pub mod cli_notification_adapter;
 pub mod file_session_repository;
+mod gzip;
 pub mod mock_adapter;
 pub mod mock_notification_adapter;
+pub mod notification_router;
+pub mod persisted_connection_state;
+pub mod retrying_notification_port;
 
-pub use btleplug_adapter::BtleplugAdapter;
+pub use btleplug_adapter::{AdapterInfo, AdapterSelector, BtleplugAdapter};
 pub use cli_notification_adapter::CliNotificationAdapter;
 pub use file_session_repository::FileSessionRepository;
-pub use mock_adapter::{MockAdapter, MockConfig};
+pub use mock_adapter::{MockAdapter, MockConfig, MockHrMode};
 pub use mock_notification_adapter::MockNotificationAdapter;
+pub use notification_router::NotificationRouter;
+pub use persisted_connection_state::PersistedConnectionState;
+pub use retrying_notification_port::RetryingNotificationPort;
@@ -0,0 +1,160 @@
+//! Notification router adapter.
+//!
+//! This module provides a `NotificationPort` implementation that forwards each
+//! event to another port chosen by the event's [`NotificationSeverity`]. This
+//! lets, for example, critical alerts drive haptic feedback while informational
+//! events only show a toast.
+
+use crate::ports::notification::{NotificationEvent, NotificationPort, NotificationSeverity};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Dispatches notifications to different ports by severity.
+///
+/// Events whose severity has no route go to the default port.
+///
+/// # Example
+///
+/// ```rust
+/// use heart_beat::adapters::{MockNotificationAdapter, NotificationRouter};
+/// use heart_beat::ports::notification::{NotificationEvent, NotificationPort, NotificationSeverity};
+/// use std::sync::Arc;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let info = Arc::new(MockNotificationAdapter::new());
+///     let critical = Arc::new(MockNotificationAdapter::new());
+///     let router = NotificationRouter::new(info.clone())
+///         .with_route(NotificationSeverity::Critical, critical.clone());
+///
+///     router.notify(NotificationEvent::ConnectionLost).await.unwrap();
+///
+///     assert_eq!(critical.event_count().await, 1);
+///     assert_eq!(info.event_count().await, 0);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct NotificationRouter {
+    /// Port for severities without a route
+    default_port: Arc<dyn NotificationPort>,
+    /// Ports for specific severities
+    routes: HashMap<NotificationSeverity, Arc<dyn NotificationPort>>,
+}
+
+impl NotificationRouter {
+    /// Create a router that sends every event to `default_port`.
+    pub fn new(default_port: Arc<dyn NotificationPort>) -> Self {
+        Self {
+            default_port,
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Send events of `severity` to `port` instead of the default port.
+    pub fn with_route(
+        mut self,
+        severity: NotificationSeverity,
+        port: Arc<dyn NotificationPort>,
+    ) -> Self {
+        self.routes.insert(severity, port);
+        self
+    }
+
+    /// The port that receives events of `severity`.
+    pub fn port_for(&self, severity: NotificationSeverity) -> &Arc<dyn NotificationPort> {
+        self.routes.get(&severity).unwrap_or(&self.default_port)
+    }
+}
+
+#[async_trait]
+impl NotificationPort for NotificationRouter {
+    async fn notify(&self, event: NotificationEvent) -> Result<()> {
+        self.port_for(event.severity()).notify(event).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::MockNotificationAdapter;
+    use crate::domain::heart_rate::Zone;
+    use crate::state::session::ZoneDeviation;
+
+    #[tokio::test]
+    async fn test_routes_by_severity() {
+        let info = Arc::new(MockNotificationAdapter::new());
+        let critical = Arc::new(MockNotificationAdapter::new());
+        let router = NotificationRouter::new(info.clone())
+            .with_route(NotificationSeverity::Critical, critical.clone());
+
+        router
+            .notify(NotificationEvent::ZoneDeviation {
+                deviation: ZoneDeviation::TooHigh,
+                current_bpm: 182,
+                target_zone: Zone::Zone3,
+            })
+            .await
+            .unwrap();
+        router
+            .notify(NotificationEvent::PhaseStarted {
+                name: "Cooldown".to_string(),
+                zone: Zone::Zone1,
+            })
+            .await
+            .unwrap();
+        // No Warning route: falls back to the default port
+        router
+            .notify(NotificationEvent::BatteryLow { percentage: 10 })
+            .await
+            .unwrap();
+
+        let critical_events = critical.get_events().await;
+        assert_eq!(critical_events.len(), 1);
+        assert!(matches!(
+            critical_events[0],
+            NotificationEvent::ZoneDeviation { .. }
+        ));
+
+        let info_events = info.get_events().await;
+        assert_eq!(info_events.len(), 2);
+        assert!(matches!(
+            info_events[0],
+            NotificationEvent::PhaseStarted { .. }
+        ));
+        assert!(matches!(
+            info_events[1],
+            NotificationEvent::BatteryLow { percentage: 10 }
+        ));
+    }
+
+    #[test]
+    fn test_event_severity() {
+        let deviation = |deviation| NotificationEvent::ZoneDeviation {
+            deviation,
+            current_bpm: 120,
+            target_zone: Zone::Zone2,
+        };
+        assert_eq!(
+            deviation(ZoneDeviation::TooLow).severity(),
+            NotificationSeverity::Critical
+        );
+        assert_eq!(
+            deviation(ZoneDeviation::InZone).severity(),
+            NotificationSeverity::Info
+        );
+        assert_eq!(
+            NotificationEvent::ContactLost.severity(),
+            NotificationSeverity::Critical
+        );
+        assert_eq!(
+            NotificationEvent::BatteryLow { percentage: 5 }.severity(),
+            NotificationSeverity::Warning
+        );
+        assert_eq!(
+            NotificationEvent::SessionStarted.severity(),
+            NotificationSeverity::Info
+        );
+    }
+}
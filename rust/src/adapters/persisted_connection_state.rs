@@ -0,0 +1,149 @@
+//! Persisted record of the last device connection.
+//!
+//! The CLI remembers the most recently connected device in
+//! `~/.heart-beat/connection_state.json` so that `devices info` can show it and
+//! `devices disconnect` can clear it between invocations.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// The last device connection, as stored on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedConnectionState {
+    /// Platform-specific identifier of the device.
+    pub device_id: String,
+    /// Advertised device name, if known.
+    #[serde(default)]
+    pub device_name: Option<String>,
+    /// When the connection was established.
+    pub last_connected: DateTime<Utc>,
+}
+
+impl PersistedConnectionState {
+    /// Create a record for a connection established now.
+    pub fn new(device_id: impl Into<String>, device_name: Option<String>) -> Self {
+        Self {
+            device_id: device_id.into(),
+            device_name,
+            last_connected: Utc::now(),
+        }
+    }
+
+    /// Default location of the state file: ~/.heart-beat/connection_state.json
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the home directory cannot be determined.
+    pub fn default_path() -> Result<PathBuf> {
+        let home = dirs::home_dir().context("Failed to determine home directory")?;
+        Ok(home.join(".heart-beat").join("connection_state.json"))
+    }
+
+    /// Load the state from `path`.
+    ///
+    /// Returns `Ok(None)` if the file does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not valid state JSON.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to read connection state: {:?}", path))
+            }
+        };
+
+        serde_json::from_str(&contents)
+            .map(Some)
+            .with_context(|| format!("Failed to parse connection state: {:?}", path))
+    }
+
+    /// Save the state to `path`, creating the parent directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {:?}", parent))?;
+        }
+
+        let json =
+            serde_json::to_string_pretty(self).context("Failed to serialize connection state")?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write connection state: {:?}", path))
+    }
+
+    /// Remove the state file at `path`.
+    ///
+    /// Returns `Ok(false)` if there was nothing to remove.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be removed.
+    pub fn clear(path: &Path) -> Result<bool> {
+        match std::fs::remove_file(path) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => {
+                Err(e).with_context(|| format!("Failed to remove connection state: {:?}", path))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("nested").join("connection_state.json");
+
+        let state = PersistedConnectionState::new("AA:BB:CC:DD:EE:FF", Some("Polar H10".into()));
+        state.save(&path).unwrap();
+
+        assert_eq!(PersistedConnectionState::load(&path).unwrap(), Some(state));
+    }
+
+    #[test]
+    fn test_missing_file_loads_none_and_clears_false() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("connection_state.json");
+
+        assert_eq!(PersistedConnectionState::load(&path).unwrap(), None);
+        assert!(!PersistedConnectionState::clear(&path).unwrap());
+
+        PersistedConnectionState::new("dev", None)
+            .save(&path)
+            .unwrap();
+        assert!(PersistedConnectionState::clear(&path).unwrap());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_legacy_file_without_name_and_corrupt_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("connection_state.json");
+
+        std::fs::write(
+            &path,
+            r#"{"device_id": "dev", "last_connected": "2024-01-15T10:30:00Z"}"#,
+        )
+        .unwrap();
+        let state = PersistedConnectionState::load(&path).unwrap().unwrap();
+        assert_eq!(state.device_id, "dev");
+        assert_eq!(state.device_name, None);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(PersistedConnectionState::load(&path).is_err());
+    }
+}
@@ -0,0 +1,220 @@
+//! Retrying notification adapter.
+//!
+//! This module provides a `NotificationPort` wrapper that retries failed
+//! deliveries with a short exponential backoff. Retries run in a background
+//! task, so callers such as the session executor's tick loop are never held
+//! up by the backoff. Callers ignore notification errors, so failures that
+//! remain after the last attempt are counted instead of being lost silently.
+
+use crate::ports::notification::{NotificationEvent, NotificationPort};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Default number of delivery attempts per notification.
+pub const DEFAULT_NOTIFY_ATTEMPTS: u32 = 3;
+
+/// Default delay before the first retry; doubled for every further retry.
+pub const DEFAULT_NOTIFY_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// Retries failed notifications and counts those that are never delivered.
+///
+/// The first attempt is made inline. If it fails, its error is returned and
+/// the remaining attempts are made by a spawned task, so `notify` never waits
+/// out a backoff; a retried notification may therefore arrive after later
+/// ones. Every error from the inner port is treated as transient. A
+/// notification that fails on every attempt is added to
+/// [`RetryingNotificationPort::failure_count`].
+///
+/// # Example
+///
+/// ```rust
+/// use heart_beat::adapters::{MockNotificationAdapter, RetryingNotificationPort};
+/// use heart_beat::ports::notification::{NotificationEvent, NotificationPort};
+/// use std::sync::Arc;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let inner = Arc::new(MockNotificationAdapter::new());
+///     let port = RetryingNotificationPort::new(inner.clone());
+///
+///     port.notify(NotificationEvent::SessionStarted).await.unwrap();
+///
+///     assert_eq!(inner.event_count().await, 1);
+///     assert_eq!(port.failure_count(), 0);
+/// }
+/// ```
+pub struct RetryingNotificationPort {
+    /// Port that delivers the notifications
+    inner: Arc<dyn NotificationPort>,
+    /// Delivery attempts per notification (at least 1)
+    max_attempts: u32,
+    /// Delay before the first retry
+    retry_delay: Duration,
+    /// Notifications that failed on every attempt, shared with retry tasks
+    failures: Arc<AtomicU64>,
+}
+
+impl RetryingNotificationPort {
+    /// Wrap `inner` with the default retry policy.
+    pub fn new(inner: Arc<dyn NotificationPort>) -> Self {
+        Self {
+            inner,
+            max_attempts: DEFAULT_NOTIFY_ATTEMPTS,
+            retry_delay: DEFAULT_NOTIFY_RETRY_DELAY,
+            failures: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Set the attempts per notification and the delay before the first retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - Delivery attempts including the first (at least 1)
+    /// * `retry_delay` - Delay before the first retry, doubled for each further retry
+    pub fn with_retry(mut self, max_attempts: u32, retry_delay: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Number of notifications that could not be delivered on any attempt.
+    pub fn failure_count(&self) -> u64 {
+        self.failures.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl NotificationPort for RetryingNotificationPort {
+    async fn notify(&self, event: NotificationEvent) -> Result<()> {
+        let err = match self.inner.notify(event.clone()).await {
+            Ok(()) => return Ok(()),
+            Err(e) => e,
+        };
+        if self.max_attempts <= 1 {
+            self.failures.fetch_add(1, Ordering::SeqCst);
+            tracing::warn!("Notification failed after 1 attempt: {:?}", err);
+            return Err(err);
+        }
+
+        tracing::debug!("Notification attempt 1 failed, retrying: {:?}", err);
+        let inner = self.inner.clone();
+        let failures = self.failures.clone();
+        let max_attempts = self.max_attempts;
+        let mut delay = self.retry_delay;
+        tokio::spawn(async move {
+            for attempt in 2..=max_attempts {
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                match inner.notify(event.clone()).await {
+                    Ok(()) => return,
+                    Err(e) if attempt == max_attempts => {
+                        failures.fetch_add(1, Ordering::SeqCst);
+                        tracing::warn!("Notification failed after {} attempts: {:?}", attempt, e);
+                    }
+                    Err(e) => {
+                        tracing::debug!("Notification attempt {} failed: {:?}", attempt, e);
+                    }
+                }
+            }
+        });
+        Err(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::MockNotificationAdapter;
+    use anyhow::anyhow;
+    use std::sync::atomic::AtomicU32;
+
+    /// Port that fails a set number of times before delivering to a mock.
+    struct FlakyPort {
+        failures_left: AtomicU32,
+        attempts: AtomicU32,
+        delivered: MockNotificationAdapter,
+    }
+
+    impl FlakyPort {
+        fn failing(times: u32) -> Self {
+            Self {
+                failures_left: AtomicU32::new(times),
+                attempts: AtomicU32::new(0),
+                delivered: MockNotificationAdapter::new(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl NotificationPort for FlakyPort {
+        async fn notify(&self, event: NotificationEvent) -> Result<()> {
+            self.attempts.fetch_add(1, Ordering::SeqCst);
+            let failing = self
+                .failures_left
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            if failing {
+                return Err(anyhow!("output unavailable"));
+            }
+            self.delivered.notify(event).await
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_retries_until_delivered() {
+        let inner = Arc::new(FlakyPort::failing(2));
+        let port =
+            RetryingNotificationPort::new(inner.clone()).with_retry(3, Duration::from_millis(1));
+
+        // The first attempt fails; the retries happen in the background
+        assert!(port
+            .notify(NotificationEvent::SessionStarted)
+            .await
+            .is_err());
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        assert_eq!(inner.attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(inner.delivered.event_count().await, 1);
+        assert_eq!(port.failure_count(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_counts_undeliverable_notifications() {
+        let inner = Arc::new(FlakyPort::failing(u32::MAX));
+        let port =
+            RetryingNotificationPort::new(inner.clone()).with_retry(2, Duration::from_millis(1));
+
+        assert!(port
+            .notify(NotificationEvent::ConnectionLost)
+            .await
+            .is_err());
+        assert!(port.notify(NotificationEvent::ContactLost).await.is_err());
+        tokio::time::sleep(Duration::from_secs(1)).await;
+
+        assert_eq!(inner.attempts.load(Ordering::SeqCst), 4);
+        assert_eq!(inner.delivered.event_count().await, 0);
+        assert_eq!(port.failure_count(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_notify_does_not_wait_for_backoff() {
+        let inner = Arc::new(FlakyPort::failing(1));
+        let port =
+            RetryingNotificationPort::new(inner.clone()).with_retry(3, Duration::from_secs(60));
+
+        let before = tokio::time::Instant::now();
+        assert!(port
+            .notify(NotificationEvent::SessionStarted)
+            .await
+            .is_err());
+        assert_eq!(tokio::time::Instant::now(), before);
+        assert_eq!(inner.delivered.event_count().await, 0);
+
+        tokio::time::sleep(Duration::from_secs(61)).await;
+        assert_eq!(inner.attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(inner.delivered.event_count().await, 1);
+    }
+}
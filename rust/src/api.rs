@@ -3,18 +3,33 @@
 //! This module provides the FFI boundary between Rust core logic and Flutter UI.
 //! It orchestrates domain, state, and adapter components without containing business logic.
 
-use crate::adapters::btleplug_adapter::BtleplugAdapter;
+use crate::adapters::btleplug_adapter::{AdapterSelector, BtleplugAdapter};
 use crate::adapters::file_session_repository::FileSessionRepository;
+use crate::adapters::notification_router::NotificationRouter;
+use crate::adapters::persisted_connection_state::PersistedConnectionState;
+use crate::adapters::retrying_notification_port::RetryingNotificationPort;
 use crate::coaching::{CueContext, DoNotDisturbWindow, LowHrRule, RuleEngine, TargetZoneRule, InactivityRule, OverworkRule};
-use crate::domain::filters::KalmanFilter;
-use crate::domain::heart_rate::{parse_heart_rate, DiscoveredDevice, FilteredHeartRate};
-use crate::domain::training_plan::TrainingPlan;
+use crate::domain::clock::{arrival_instant, clock, unix_millis, MonotonicClock};
+use crate::domain::filters::{
+    is_valid_bpm, rr_quality, KalmanFilter, DEFAULT_MAX_SLOPE_BPM_PER_SEC,
+};
+use crate::domain::heart_rate::{
+    intensity_factor, merge_discovered_devices, parse_heart_rate, DiscoveredDevice,
+    FilteredHeartRate, RollingAverage, ROLLING_AVG_WINDOW_MS,
+};
+use crate::domain::hrv::{HrvWindow, DEFAULT_HRV_WINDOW};
+use crate::domain::reconnection::ReconnectionPolicy;
+use crate::domain::sensor::ContactMonitor;
+use crate::domain::session_history::{SessionContextMeta, MAX_RPE};
+use crate::domain::training_plan::{self, PhaseTarget, PlanTemplate, TemplateParams, TrainingPlan};
 use crate::frb_generated::StreamSink;
 use crate::debug_http;
-use crate::hr_store::HrStore;
+use crate::hr_store::{HrStore, RawHrLogger};
 use crate::logging::{emit_log, subscribe_log_stream};
-use crate::ports::{BleAdapter, NotificationPort, SessionRepository};
-use crate::scheduler::executor::SessionExecutor;
+use crate::ports::{
+    BleAdapter, BleError, HrNotification, NotificationPort, NotificationSeverity, SessionRepository,
+};
+use crate::scheduler::executor::{latest_session_epoch, DisconnectPolicy, SessionExecutor};
 use crate::state::{ConnectionEvent, ConnectionStateMachine};
 use axum;
 use anyhow::{anyhow, Result};
@@ -24,11 +39,13 @@ use std::io::Write;
 use std::panic;
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::Duration;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, watch};
+use tokio_util::sync::CancellationToken;
 use tracing::error;
 use tracing_subscriber::{
     fmt::{format::FmtSpan, MakeWriter},
-    EnvFilter,
+    layer::SubscriberExt,
+    reload, EnvFilter, Registry,
 };
 
 // Re-export for FRB-generated code
@@ -42,20 +59,44 @@ pub use crate::domain::heart_rate::{
     DiscoveredDevice as ApiDiscoveredDevice, FilteredHeartRate as ApiFilteredHeartRate, Zone,
 };
 pub use crate::domain::session_history::CompletedSession as ApiCompletedSession;
+pub use crate::domain::training_plan::PlanChange as ApiPlanChange;
+pub use crate::domain::training_plan::PlanSummary as ApiPlanSummary;
+pub use crate::ports::session_repository::RepairReport as ApiRepairReport;
 pub use crate::ports::session_repository::SessionSummaryPreview as ApiSessionSummaryPreview;
 
 // Re-export SessionProgress types for FRB code generation
 pub use crate::domain::session_progress::{
-    PhaseProgress as ApiPhaseProgress, SessionProgress as ApiSessionProgress,
+    ConnectivityDuringSession as ApiConnectivityDuringSession, PhaseProgress as ApiPhaseProgress,
+    PhaseTransition as ApiPhaseTransition, SessionProgress as ApiSessionProgress,
     SessionState as ApiSessionState, ZoneStatus as ApiZoneStatus,
 };
 
+// Re-export pacer types for FRB code generation
+pub use crate::domain::pacer::{
+    Direction as ApiPaceDirection, PaceCue as ApiPaceCue, Severity as ApiPaceSeverity,
+};
+
+// Re-export connection health types for FRB code generation
+pub use crate::domain::connection_health::{
+    ConnectionHealth as ApiConnectionHealth, HealthLevel as ApiHealthLevel,
+};
+
+// Re-export sensor types for FRB code generation
+pub use crate::domain::hrv::Trend as ApiTrend;
+pub use crate::domain::sensor::SensorLocation as ApiSensorLocation;
+
 // Re-export reconnection types for FRB code generation
 pub use crate::domain::reconnection::ConnectionStatus as ApiConnectionStatus;
 
 // Re-export hr_store types for FRB code generation
 pub use crate::hr_store::Sample as ApiSample;
 
+mod errors;
+pub use errors::{
+    api_error_code, api_error_code_str, api_error_message, ApiError, ApiErrorCode, PlanError,
+    PlanSaveError, SessionError, StartWorkoutError,
+};
+
 /// Format for exporting session data.
 ///
 /// Specifies the output format when exporting a completed training session.
@@ -67,6 +108,20 @@ pub enum ExportFormat {
     Json,
     /// Export as human-readable text summary with statistics
     Summary,
+    /// Export as compact versioned binary for syncing (see [`export_session_bytes`])
+    Binary,
+}
+
+impl ExportFormat {
+    /// File extension (without the dot) used for files in this format.
+    pub fn file_extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Summary => "txt",
+            ExportFormat::Binary => "hbs",
+        }
+    }
 }
 
 /// Battery level data for FFI boundary (FRB-compatible).
@@ -83,11 +138,61 @@ pub struct ApiBatteryLevel {
     pub timestamp: u64,
 }
 
+/// Liveness tick of the HR stream for FFI boundary (FRB-compatible).
+///
+/// Emitted by the HR task on a timer whether or not HR arrives, so the UI can
+/// tell "waiting for data" (fresh ticks, no HR yet) from "stream dead" (ticks
+/// stopped).
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ApiHrLiveness {
+    /// Unix timestamp in milliseconds when the HR task last polled the adapter.
+    pub timestamp: u64,
+    /// Unix timestamp in milliseconds of the last HR sample, if any arrived.
+    pub last_hr_timestamp: Option<u64>,
+}
+
+/// Point-in-time view of the app state for FFI boundary (FRB-compatible).
+///
+/// Returned by [`get_app_snapshot`] so the UI can render immediately after
+/// a restart without waiting for the next event on each stream.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ApiAppSnapshot {
+    /// Latest connection status (Disconnected if none was reported yet).
+    pub connection_status: ApiConnectionStatus,
+    /// Device ID of the connected device, if any.
+    pub connected_device_id: Option<String>,
+    /// Progress of the running or paused workout, if any.
+    pub session_progress: Option<ApiSessionProgress>,
+    /// Most recent heart rate measurement of the current connection and workout, if any.
+    pub last_hr: Option<ApiFilteredHeartRate>,
+    /// Most recent battery reading, if any.
+    pub battery: Option<ApiBatteryLevel>,
+}
+
+/// A phase that comes after the current one, for FFI boundary (FRB-compatible).
+///
+/// Returned by [`get_upcoming_phases`] for "next up" previews.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ApiUpcomingPhase {
+    /// Position of the phase in the plan (0-based).
+    pub phase_index: u32,
+    /// Human-readable name of the phase.
+    pub name: String,
+    /// Target heart rate zone of the phase.
+    pub target_zone: Zone,
+    /// Planned duration of the phase in seconds.
+    pub duration_secs: u32,
+}
+
 // Global data directory for storing app data (plans, sessions, etc.)
 // On Android, this must be set via set_data_dir() before using file-based APIs.
 // On desktop, it falls back to ~/.heart-beat if not set.
 static DATA_DIR: OnceLock<Mutex<Option<std::path::PathBuf>>> = OnceLock::new();
 
+// Per-profile data directories registered via set_data_dir_for(), keyed by namespace.
+static NAMESPACE_DATA_DIRS: OnceLock<Mutex<std::collections::HashMap<String, std::path::PathBuf>>> =
+    OnceLock::new();
+
 // Global state for HR data streaming
 static HR_CHANNEL_CAPACITY: usize = 100;
 
@@ -97,12 +202,49 @@ static BATTERY_CHANNEL_CAPACITY: usize = 10;
 // Global state for session progress streaming
 static SESSION_PROGRESS_CHANNEL_CAPACITY: usize = 100;
 
+// Global state for phase transition streaming
+static PHASE_TRANSITION_CHANNEL_CAPACITY: usize = 20;
+
+// Global state for pace cue streaming
+static PACE_CUE_CHANNEL_CAPACITY: usize = 20;
+
 // Global state for connection status streaming
 static CONNECTION_STATUS_CHANNEL_CAPACITY: usize = 10;
 
+// Global state for connection health streaming
+static CONNECTION_HEALTH_CHANNEL_CAPACITY: usize = 10;
+
+// Global state for HR liveness streaming
+static HR_LIVENESS_CHANNEL_CAPACITY: usize = 10;
+
+// Interval between HR liveness ticks
+const HR_LIVENESS_INTERVAL: Duration = Duration::from_secs(1);
+
+// Seconds between connection health updates
+const CONNECTION_HEALTH_INTERVAL_SECS: u32 = 5;
+
+// Number of one-second intervals the dropout rate is computed over
+const CONNECTION_HEALTH_DROPOUT_WINDOW: usize = 30;
+
+// How long disconnect waits for a cancelled connection task before aborting it
+const TASK_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+// Window within which repeated saves of a session are written to disk once
+const SESSION_SAVE_COALESCE_WINDOW: Duration = Duration::from_secs(5);
+
 // Global state for coaching cue streaming
 static COACHING_CUE_CHANNEL_CAPACITY: usize = 20;
 
+// Global state for export progress streaming
+static EXPORT_PROGRESS_CHANNEL_CAPACITY: usize = 20;
+
+// Emit an export progress update every this many samples
+const EXPORT_PROGRESS_SAMPLE_INTERVAL: usize = 1000;
+
+// Emit an export progress update every this many bytes for formats written
+// without per-sample callbacks (JSON, summary, binary)
+const EXPORT_PROGRESS_BYTE_INTERVAL: u64 = 64 * 1024;
+
 // Coaching cue for the FFI boundary (FRB-compatible).
 //
 // This is a copy of coaching::Cue adapted for the FFI boundary using u64
@@ -166,10 +308,44 @@ pub struct LogMessage {
 // Global state for log streaming
 static LOG_SINK: OnceLock<Mutex<Option<StreamSink<LogMessage>>>> = OnceLock::new();
 
+/// Handle to the filter of the global subscriber, used by [`set_log_level`].
+static LOG_FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
 // Global BLE adapter - shared between scan and connect operations
 // This is critical: we must use the same adapter instance that discovered the devices
 // to connect to them, otherwise btleplug won't find the peripheral.
-static BLE_ADAPTER: OnceLock<tokio::sync::Mutex<Option<Arc<BtleplugAdapter>>>> = OnceLock::new();
+static BLE_ADAPTER: OnceLock<tokio::sync::Mutex<Option<SharedBleAdapter>>> = OnceLock::new();
+
+/// The BLE adapter shared by scan and connect.
+#[derive(Clone)]
+enum SharedBleAdapter {
+    /// The platform adapter, created on first use
+    Btleplug(Arc<BtleplugAdapter>),
+    /// A substitute adapter injected by tests (see `set_ble_adapter`)
+    #[cfg(test)]
+    Injected(Arc<dyn BleAdapter + Send + Sync>),
+}
+
+impl SharedBleAdapter {
+    /// The adapter behind the `BleAdapter` port.
+    fn ble(&self) -> Arc<dyn BleAdapter + Send + Sync> {
+        match self {
+            Self::Btleplug(adapter) => adapter.clone(),
+            #[cfg(test)]
+            Self::Injected(adapter) => adapter.clone(),
+        }
+    }
+
+    /// The platform adapter, for features outside the port such as battery
+    /// polling and sensor location.
+    fn btleplug(&self) -> Option<Arc<BtleplugAdapter>> {
+        match self {
+            Self::Btleplug(adapter) => Some(adapter.clone()),
+            #[cfg(test)]
+            Self::Injected(_) => None,
+        }
+    }
+}
 
 /// Active connection state tracking for disconnect functionality.
 ///
@@ -177,13 +353,49 @@ static BLE_ADAPTER: OnceLock<tokio::sync::Mutex<Option<Arc<BtleplugAdapter>>>> =
 /// so they can be properly cleaned up during disconnect.
 struct ConnectionState {
     /// The connected BLE adapter instance
-    adapter: Arc<BtleplugAdapter>,
+    adapter: Arc<dyn BleAdapter + Send + Sync>,
     /// Device ID of the connected device
     device_id: String,
     /// Handle to the HR notification streaming task
     hr_task_handle: tokio::task::JoinHandle<()>,
     /// Handle to the battery polling task
     battery_task_handle: tokio::task::JoinHandle<()>,
+    /// Handle to the connection health monitor task
+    health_task_handle: tokio::task::JoinHandle<()>,
+    /// Cancelled to ask the background tasks to finish and clean up
+    cancel_token: CancellationToken,
+}
+
+impl ConnectionState {
+    /// Stop the background tasks of this connection.
+    ///
+    /// Signals the cancellation token so each task can finish its current
+    /// iteration and clean up, then waits up to `TASK_SHUTDOWN_TIMEOUT` for
+    /// each before aborting it. Returns `true` if every task exited on its own.
+    async fn stop_tasks(&mut self) -> bool {
+        self.cancel_token.cancel();
+        let hr = stop_task("HR", &mut self.hr_task_handle).await;
+        let battery = stop_task("battery", &mut self.battery_task_handle).await;
+        let health = stop_task("connection health", &mut self.health_task_handle).await;
+        hr && battery && health
+    }
+}
+
+/// Wait for a cancelled task to exit, aborting it after `TASK_SHUTDOWN_TIMEOUT`.
+///
+/// Returns `true` if the task exited on its own.
+async fn stop_task(name: &str, handle: &mut tokio::task::JoinHandle<()>) -> bool {
+    match tokio::time::timeout(TASK_SHUTDOWN_TIMEOUT, &mut *handle).await {
+        Ok(_) => {
+            tracing::debug!("{} task stopped", name);
+            true
+        }
+        Err(_) => {
+            tracing::warn!("{} task did not stop in time, aborting", name);
+            handle.abort();
+            false
+        }
+    }
 }
 
 // Global connection state storage
@@ -195,27 +407,226 @@ static COACHING_ENGINE: OnceLock<tokio::sync::Mutex<RuleEngine>> = OnceLock::new
 // Global HR store for persisting heart rate samples to JSONL
 static HR_STORE: OnceLock<tokio::sync::Mutex<Option<Arc<HrStore>>>> = OnceLock::new();
 
+// Optional raw HR sample log, active between start_raw_logging() and stop_raw_logging()
+static RAW_HR_LOGGER: OnceLock<tokio::sync::Mutex<Option<RawHrLogger>>> = OnceLock::new();
+
 // Global LowHrRule for sustained low-HR alerting.
 // Updated via update_health_settings() whenever the user changes settings.
 // Uses std::sync::Mutex so update_health_settings (sync fn) can lock without .await.
 static LOW_HR_RULE: OnceLock<std::sync::Mutex<LowHrRule>> = OnceLock::new();
 
+// Lactate threshold heart rate used for the live intensity factor.
+// `None` until the user configures it via set_lthr().
+static LTHR: OnceLock<std::sync::Mutex<Option<u16>>> = OnceLock::new();
+
+// Seconds HR must stay in a new zone before saved time-in-zone counts the change.
+// 0 (no smoothing) until the user configures it via set_zone_smoothing().
+static ZONE_MIN_DWELL_SECS: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(0);
+
+// Most recent values published on the HR, battery, liveness and connection status streams,
+// cached so get_app_snapshot() and get_recent_hr() can answer without a live subscription.
+static LATEST_VALUES: OnceLock<std::sync::Mutex<LatestValues>> = OnceLock::new();
+
+#[derive(Default)]
+struct LatestValues {
+    connection_status: Option<ApiConnectionStatus>,
+    hr: Option<ApiFilteredHeartRate>,
+    recent_hr: RecentHr,
+    battery: Option<ApiBatteryLevel>,
+    hr_liveness: Option<ApiHrLiveness>,
+}
+
+impl LatestValues {
+    /// Record an HR sample and publish it to latest-value subscribers.
+    fn push_hr(&mut self, data: ApiFilteredHeartRate) {
+        self.recent_hr.push(data.clone());
+        self.set_hr(Some(data));
+    }
+
+    /// Forget the HR samples of the previous connection or session.
+    fn clear_hr(&mut self) {
+        self.recent_hr.samples.clear();
+        self.set_hr(None);
+    }
+
+    /// Set the latest HR sample; the watch channel always mirrors it.
+    fn set_hr(&mut self, hr: Option<ApiFilteredHeartRate>) {
+        get_or_create_hr_watch_sender().send_replace(hr.clone());
+        self.hr = hr;
+    }
+}
+
+/// Number of HR samples kept for get_recent_hr() unless changed with
+/// set_recent_hr_capacity() (5 minutes at 1 Hz).
+const DEFAULT_RECENT_HR_CAPACITY: u32 = 300;
+
+/// Bounded buffer of the most recently emitted HR samples, oldest first.
+struct RecentHr {
+    capacity: usize,
+    samples: std::collections::VecDeque<ApiFilteredHeartRate>,
+}
+
+impl Default for RecentHr {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_RECENT_HR_CAPACITY as usize,
+            samples: std::collections::VecDeque::new(),
+        }
+    }
+}
+
+impl RecentHr {
+    fn push(&mut self, sample: ApiFilteredHeartRate) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.samples.len() >= self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+}
+
+/// Read or update the latest-value cache used by get_app_snapshot() and get_recent_hr().
+fn update_latest_values<T>(update: impl FnOnce(&mut LatestValues) -> T) -> Option<T> {
+    let mutex = LATEST_VALUES.get_or_init(|| std::sync::Mutex::new(LatestValues::default()));
+    mutex.lock().ok().map(|mut guard| update(&mut guard))
+}
+
 /// Stub notification port for battery monitoring.
 /// This is a temporary implementation until full notification system is wired up.
-struct StubNotificationPort;
+///
+/// One instance is registered per severity, so warnings and critical alerts
+/// are logged at warn level while informational events stay at info.
+struct StubNotificationPort {
+    /// Severity of the events routed to this port
+    severity: NotificationSeverity,
+}
 
 #[async_trait]
 impl NotificationPort for StubNotificationPort {
     async fn notify(&self, event: crate::ports::NotificationEvent) -> Result<()> {
         // Just log the notification for now
-        tracing::info!("Notification: {:?}", event);
+        match self.severity {
+            NotificationSeverity::Info => tracing::info!("Notification: {:?}", event),
+            NotificationSeverity::Warning | NotificationSeverity::Critical => {
+                tracing::warn!("{:?} notification: {:?}", self.severity, event)
+            }
+        }
         Ok(())
     }
 }
 
+// Ports notifications are dispatched to by severity; see set_notification_port()
+static NOTIFICATION_ROUTES: OnceLock<std::sync::RwLock<NotificationRouter>> = OnceLock::new();
+
+/// Router with a separate stub port for each severity.
+fn default_notification_routes() -> NotificationRouter {
+    let stub = |severity| Arc::new(StubNotificationPort { severity });
+    NotificationRouter::new(stub(NotificationSeverity::Info))
+        .with_route(
+            NotificationSeverity::Warning,
+            stub(NotificationSeverity::Warning),
+        )
+        .with_route(
+            NotificationSeverity::Critical,
+            stub(NotificationSeverity::Critical),
+        )
+}
+
+fn notification_routes() -> &'static std::sync::RwLock<NotificationRouter> {
+    NOTIFICATION_ROUTES.get_or_init(|| std::sync::RwLock::new(default_notification_routes()))
+}
+
+/// Send notifications of `severity` to `port` from now on.
+///
+/// Platform outputs register here, e.g. a haptic port for critical alerts and
+/// a toast port for informational events. Until a port is registered for a
+/// severity, its events are only logged. Applies to the workout executor,
+/// battery monitor and contact checks, including ones already running.
+#[frb(ignore)]
+pub fn set_notification_port(severity: NotificationSeverity, port: Arc<dyn NotificationPort>) {
+    let mut routes = notification_routes()
+        .write()
+        .unwrap_or_else(|e| e.into_inner());
+    *routes = routes.clone().with_route(severity, port);
+}
+
+/// Forwards each event to the port currently registered for its severity.
+struct ConfiguredNotificationRouter;
+
+#[async_trait]
+impl NotificationPort for ConfiguredNotificationRouter {
+    async fn notify(&self, event: crate::ports::NotificationEvent) -> Result<()> {
+        // Clone the port out so the lock is not held across the delivery
+        let port = notification_routes()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .port_for(event.severity())
+            .clone();
+        port.notify(event).await
+    }
+}
+
+/// Router the executor, battery monitor and contact checks notify through.
+///
+/// Each severity goes to the port registered with [`set_notification_port`],
+/// or to a logging stub. Deliveries are retried, and the ones that still fail
+/// are counted by [`get_notification_failures`].
+fn notification_router() -> Arc<dyn NotificationPort> {
+    retrying_notifier()
+}
+
+fn retrying_notifier() -> Arc<RetryingNotificationPort> {
+    static NOTIFIER: OnceLock<Arc<RetryingNotificationPort>> = OnceLock::new();
+    NOTIFIER
+        .get_or_init(|| {
+            Arc::new(RetryingNotificationPort::new(Arc::new(
+                ConfiguredNotificationRouter,
+            )))
+        })
+        .clone()
+}
+
+/// Get the number of notifications that could not be delivered.
+///
+/// Counts notifications from the workout executor, battery monitor and
+/// contact checks that still failed after every retry. The count covers the
+/// lifetime of the process.
+///
+/// # Returns
+///
+/// Total number of undeliverable notifications
+pub fn get_notification_failures() -> u64 {
+    retrying_notifier().failure_count()
+}
+
+/// Feed a packet's BPM to the contact monitor and emit `ContactLost` when a
+/// run of zero-BPM packets shows the strap has lost skin contact.
+///
+/// Returns `true` if the notification was sent.
+async fn check_sensor_contact(
+    monitor: &mut ContactMonitor,
+    bpm: u16,
+    notifier: &dyn NotificationPort,
+) -> bool {
+    if !monitor.record(bpm) {
+        return false;
+    }
+    tracing::warn!("Sensor contact lost (zero BPM readings)");
+    if let Err(e) = notifier
+        .notify(crate::ports::NotificationEvent::ContactLost)
+        .await
+    {
+        tracing::error!("Failed to send contact lost notification: {}", e);
+    }
+    true
+}
+
 /// Get or create the global BLE adapter instance.
 /// Returns the same adapter across all calls to ensure device discovery persists.
-async fn get_ble_adapter() -> Result<Arc<BtleplugAdapter>> {
+/// An adapter injected with `set_ble_adapter` takes the place of the platform one.
+async fn get_ble_adapter() -> Result<SharedBleAdapter> {
     let mutex = BLE_ADAPTER.get_or_init(|| tokio::sync::Mutex::new(None));
     let mut guard = mutex.lock().await;
 
@@ -225,11 +636,62 @@ async fn get_ble_adapter() -> Result<Arc<BtleplugAdapter>> {
 
     // Create new adapter and store it
     tracing::info!("Creating new global BLE adapter");
-    let adapter = Arc::new(BtleplugAdapter::new().await?);
+    let adapter = SharedBleAdapter::Btleplug(Arc::new(BtleplugAdapter::new().await?));
     *guard = Some(adapter.clone());
     Ok(adapter)
 }
 
+/// Use `adapter` for all subsequent scan and connect calls instead of the
+/// platform adapter.
+#[cfg(test)]
+async fn set_ble_adapter(adapter: Arc<dyn BleAdapter + Send + Sync>) {
+    let mutex = BLE_ADAPTER.get_or_init(|| tokio::sync::Mutex::new(None));
+    *mutex.lock().await = Some(SharedBleAdapter::Injected(adapter));
+}
+
+/// Return the global API state to its initial, unset condition.
+///
+/// Stops the tasks of any active connection and drops the shared adapter,
+/// session executor, repositories, stores and data directory. Broadcast
+/// streams are kept, so existing subscribers stay attached.
+#[cfg(test)]
+async fn reset_globals() {
+    if let Some(mutex) = CONNECTION_STATE.get() {
+        if let Some(mut state) = mutex.lock().await.take() {
+            state.stop_tasks().await;
+        }
+    }
+    if let Some(mutex) = BLE_ADAPTER.get() {
+        *mutex.lock().await = None;
+    }
+    if let Some(mutex) = SESSION_EXECUTOR.get() {
+        *mutex.lock().await = None;
+    }
+    if let Some(mutex) = SESSION_REPOSITORY.get() {
+        *mutex.lock().await = None;
+    }
+    if let Some(mutex) = HR_STORE.get() {
+        *mutex.lock().await = None;
+    }
+    if let Some(mutex) = RAW_HR_LOGGER.get() {
+        *mutex.lock().await = None;
+    }
+    if let Some(mutex) = DATA_DIR.get() {
+        if let Ok(mut guard) = mutex.lock() {
+            *guard = None;
+        }
+    }
+    clear_lthr();
+    set_zone_smoothing(0);
+    *notification_routes()
+        .write()
+        .unwrap_or_else(|e| e.into_inner()) = default_notification_routes();
+    update_latest_values(|latest| {
+        latest.clear_hr();
+        *latest = LatestValues::default();
+    });
+}
+
 /// Custom writer that forwards logs to Flutter via StreamSink.
 ///
 /// This writer implements the std::io::Write trait and is used by tracing_subscriber
@@ -262,10 +724,7 @@ impl Write for FlutterLogWriter {
                     ("unknown".to_string(), rest.trim().to_string())
                 };
 
-                let timestamp = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64;
+                let timestamp = unix_millis();
 
                 let log_msg = LogMessage {
                     level,
@@ -460,6 +919,54 @@ pub fn update_health_settings(
     Ok(())
 }
 
+/// Set the lactate threshold heart rate (LTHR) used for the live intensity factor.
+///
+/// Once set, every emitted HR sample carries `filtered_bpm / lthr`, available
+/// via [`hr_intensity_factor`].
+pub fn set_lthr(bpm: u16) -> Result<()> {
+    if bpm == 0 {
+        return Err(anyhow!("LTHR must be greater than 0"));
+    }
+    *LTHR
+        .get_or_init(|| std::sync::Mutex::new(None))
+        .lock()
+        .map_err(|e| anyhow!("Failed to lock LTHR: {}", e))? = Some(bpm);
+    tracing::info!("LTHR set to {} bpm", bpm);
+    Ok(())
+}
+
+/// Clear the configured LTHR; HR samples no longer carry an intensity factor.
+pub fn clear_lthr() {
+    if let Some(mutex) = LTHR.get() {
+        if let Ok(mut guard) = mutex.lock() {
+            *guard = None;
+        }
+    }
+}
+
+/// Ignore brief zone excursions in the time-in-zone of saved workouts.
+///
+/// A zone change only counts once HR stays in the new zone for
+/// `min_dwell_secs`; shorter excursions count toward the previous zone, so a
+/// one-second blip into Zone 4 during a Zone 3 effort stays Zone 3 time.
+/// `0`, the default, turns smoothing off. Applies to workouts started
+/// afterwards.
+pub fn set_zone_smoothing(min_dwell_secs: u32) {
+    ZONE_MIN_DWELL_SECS.store(min_dwell_secs, std::sync::atomic::Ordering::SeqCst);
+    tracing::info!("Zone smoothing set to {} s", min_dwell_secs);
+}
+
+/// The configured zone smoothing in seconds (0 if off).
+fn zone_min_dwell_secs() -> u32 {
+    ZONE_MIN_DWELL_SECS.load(std::sync::atomic::Ordering::SeqCst)
+}
+
+/// The configured LTHR, if any.
+fn current_lthr() -> Option<u16> {
+    LTHR.get()
+        .and_then(|m| m.lock().ok().and_then(|guard| *guard))
+}
+
 /// Initialize logging and forward Rust tracing logs to Flutter.
 ///
 /// This function sets up a tracing subscriber that captures all Rust log messages
@@ -527,22 +1034,12 @@ pub fn init_logging(sink: StreamSink<LogMessage>) -> Result<()> {
     }
 
     // Create a tracing subscriber that uses our custom writer
-    let subscriber = tracing_subscriber::fmt()
-        .with_writer(FlutterLogWriter)
-        .with_env_filter(env_filter)
-        .with_target(true)
-        .with_level(true)
-        .with_thread_ids(false)
-        .with_thread_names(false)
-        .with_file(false)
-        .with_line_number(false)
-        .with_span_events(FmtSpan::NONE)
-        .without_time() // We add timestamp in FlutterLogWriter
-        .finish();
+    let (subscriber, filter_handle) = build_log_subscriber(env_filter);
 
     // Set the global subscriber
     tracing::subscriber::set_global_default(subscriber)
         .map_err(|e| anyhow!("Failed to set global tracing subscriber: {}", e))?;
+    let _ = LOG_FILTER_HANDLE.set(filter_handle);
 
     // Spawn a task to forward broadcast channel logs to Flutter sink
     // This ensures Flutter receives logs through the same fanout as Phase 2's debug server
@@ -562,6 +1059,69 @@ pub fn init_logging(sink: StreamSink<LogMessage>) -> Result<()> {
     Ok(())
 }
 
+/// Build the subscriber that formats logs for [`FlutterLogWriter`].
+///
+/// The filter sits in a reload layer, so the returned handle can change the
+/// log level while the subscriber is installed.
+fn build_log_subscriber(
+    env_filter: EnvFilter,
+) -> (
+    impl tracing::Subscriber + Send + Sync,
+    reload::Handle<EnvFilter, Registry>,
+) {
+    let (filter, handle) = reload::Layer::new(env_filter);
+    let fmt_layer = tracing_subscriber::fmt::layer()
+        .with_writer(FlutterLogWriter)
+        .with_target(true)
+        .with_level(true)
+        .with_thread_ids(false)
+        .with_thread_names(false)
+        .with_file(false)
+        .with_line_number(false)
+        .with_span_events(FmtSpan::NONE)
+        .without_time(); // We add timestamp in FlutterLogWriter
+
+    (Registry::default().with(filter).with(fmt_layer), handle)
+}
+
+/// Replace the filter behind `handle` with a single log level.
+fn apply_log_level(handle: &reload::Handle<EnvFilter, Registry>, level: &str) -> Result<()> {
+    let level_filter = level
+        .trim()
+        .parse::<tracing_subscriber::filter::LevelFilter>()
+        .map_err(|_| {
+            anyhow!(
+                "Invalid log level '{}' (expected trace, debug, info, warn, error or off)",
+                level
+            )
+        })?;
+
+    handle
+        .reload(EnvFilter::new(level_filter.to_string()))
+        .map_err(|e| anyhow!("Failed to update log filter: {}", e))
+}
+
+/// Change the log level at runtime.
+///
+/// Replaces the filter installed by [`init_logging`] (including any `RUST_LOG`
+/// directives) so verbosity can be raised in the field without restarting the app.
+///
+/// # Arguments
+///
+/// * `level` - One of `trace`, `debug`, `info`, `warn`, `error` or `off` (case-insensitive)
+///
+/// # Errors
+///
+/// Returns an error if the level is invalid or logging has not been initialized.
+pub fn set_log_level(level: String) -> Result<()> {
+    let handle = LOG_FILTER_HANDLE
+        .get()
+        .ok_or_else(|| anyhow!("Logging not initialized. Call init_logging() first"))?;
+    apply_log_level(handle, &level)?;
+    tracing::info!("Log level set to {}", level.trim());
+    Ok(())
+}
+
 /// Set the base data directory for storing app data.
 ///
 /// On Android, this must be called during app initialization before using any
@@ -591,7 +1151,7 @@ pub fn init_logging(sink: StreamSink<LogMessage>) -> Result<()> {
 ///   runApp(MyApp());
 /// }
 /// ```
-pub fn set_data_dir(path: String) -> Result<()> {
+pub fn set_data_dir(path: String) -> std::result::Result<(), ApiError> {
     let path_buf = std::path::PathBuf::from(&path);
 
     // Verify the path exists or can be created
@@ -610,6 +1170,69 @@ pub fn set_data_dir(path: String) -> Result<()> {
     Ok(())
 }
 
+/// Set the data directory for a named profile.
+///
+/// Lets several profiles (e.g. different users on a shared desktop) keep
+/// separate plans and sessions. Pass the same namespace to the `*_for` plan and
+/// session APIs (e.g. [`list_sessions_for`]) to operate on that profile.
+///
+/// Unlike [`set_data_dir`], the path does not need to exist yet; it is created
+/// on first use. Namespaces that were never registered resolve to
+/// `profiles/<namespace>` under the default data directory.
+///
+/// # Arguments
+///
+/// * `namespace` - Profile name; must be non-empty and contain no path separators
+/// * `path` - Directory to store this profile's data in
+pub fn set_data_dir_for(namespace: String, path: String) -> std::result::Result<(), ApiError> {
+    validate_namespace(&namespace)?;
+
+    NAMESPACE_DATA_DIRS
+        .get_or_init(|| Mutex::new(std::collections::HashMap::new()))
+        .lock()
+        .map_err(|e| anyhow!("Failed to lock NAMESPACE_DATA_DIRS: {}", e))?
+        .insert(namespace.clone(), std::path::PathBuf::from(&path));
+
+    tracing::info!(
+        "Data directory for profile '{}' set to: {}",
+        namespace,
+        path
+    );
+    Ok(())
+}
+
+/// Check that a data directory exists (creating it if needed) and is writable.
+///
+/// Writes and removes a small probe file inside the directory. Used by the CLI
+/// `doctor` command and useful for surfacing storage problems early in the app.
+///
+/// # Arguments
+///
+/// * `path` - Directory to check
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created, is not a directory,
+/// or a file cannot be written inside it.
+pub fn check_data_dir_writable(path: String) -> std::result::Result<(), ApiError> {
+    let dir = std::path::PathBuf::from(&path);
+
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir)
+            .map_err(|e| anyhow!("Failed to create data directory '{}': {}", path, e))?;
+    }
+    if !dir.is_dir() {
+        return Err(anyhow!("Data path '{}' is not a directory", path).into());
+    }
+
+    let probe = dir.join(".write-probe");
+    std::fs::write(&probe, b"ok")
+        .map_err(|e| anyhow!("Data directory '{}' is not writable: {}", path, e))?;
+    let _ = std::fs::remove_file(&probe);
+
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Debug HTTP server
 // ---------------------------------------------------------------------------
@@ -724,6 +1347,83 @@ fn get_data_dir() -> Result<std::path::PathBuf> {
     Ok(home.join(".heart-beat"))
 }
 
+/// Get the data directory for an optional profile namespace.
+///
+/// `None` is the default profile (see [`get_data_dir`]). A namespace resolves
+/// to the directory registered with [`set_data_dir_for`], or to
+/// `profiles/<namespace>` under the default data directory otherwise.
+fn get_data_dir_for(namespace: Option<&str>) -> Result<std::path::PathBuf> {
+    let Some(namespace) = namespace else {
+        return get_data_dir();
+    };
+    validate_namespace(namespace)?;
+
+    if let Some(mutex) = NAMESPACE_DATA_DIRS.get() {
+        if let Ok(guard) = mutex.lock() {
+            if let Some(path) = guard.get(namespace) {
+                return Ok(path.clone());
+            }
+        }
+    }
+
+    Ok(get_data_dir()?.join("profiles").join(namespace))
+}
+
+/// Reject namespaces that could escape the profiles directory.
+fn validate_namespace(namespace: &str) -> Result<()> {
+    if namespace.is_empty()
+        || namespace == "."
+        || namespace == ".."
+        || namespace.contains(['/', '\\'])
+    {
+        return Err(anyhow!("Invalid profile namespace: '{}'", namespace));
+    }
+    Ok(())
+}
+
+/// List the Bluetooth adapters on this system.
+///
+/// Returns one description per adapter (e.g. "hci0 (usb:...)" on Linux); the
+/// position in the list is the index to pass to [`select_ble_adapter`].
+///
+/// # Errors
+///
+/// Returns an error if the BLE manager cannot be created or permission is denied.
+pub async fn list_ble_adapters() -> Result<Vec<String>> {
+    Ok(BtleplugAdapter::list_adapters()
+        .await?
+        .into_iter()
+        .map(|info| info.description)
+        .collect())
+}
+
+/// Use the Bluetooth adapter at `index` (see [`list_ble_adapters`]) for all
+/// subsequent scan and connect calls.
+///
+/// Without this the first adapter is used, which on machines with several
+/// radios may not be the intended one.
+///
+/// # Errors
+///
+/// Returns an error if a device is connected (disconnect first), or if the
+/// index is out of range.
+pub async fn select_ble_adapter(index: u32) -> Result<()> {
+    // Hold the connection state until the new adapter is in place, so a
+    // connection cannot be stored between the check and the switch
+    let state_mutex = CONNECTION_STATE.get_or_init(|| tokio::sync::Mutex::new(None));
+    let connection = state_mutex.lock().await;
+    if connection.is_some() {
+        return Err(anyhow!("Disconnect before switching Bluetooth adapters"));
+    }
+
+    let adapter = BtleplugAdapter::with_adapter(AdapterSelector::Index(index as usize)).await?;
+    let mutex = BLE_ADAPTER.get_or_init(|| tokio::sync::Mutex::new(None));
+    *mutex.lock().await = Some(SharedBleAdapter::Btleplug(Arc::new(adapter)));
+    drop(connection);
+    tracing::info!("select_ble_adapter: Using Bluetooth adapter {}", index);
+    Ok(())
+}
+
 /// Scan for BLE heart rate devices.
 ///
 /// Initiates a BLE scan and returns all discovered devices advertising
@@ -739,7 +1439,7 @@ fn get_data_dir() -> Result<std::path::PathBuf> {
 /// - BLE adapter initialization fails
 /// - Scan operation fails
 /// - BLE is not available or permissions are missing
-pub async fn scan_devices() -> Result<Vec<DiscoveredDevice>> {
+pub async fn scan_devices() -> std::result::Result<Vec<DiscoveredDevice>, ApiError> {
     tracing::info!("scan_devices: Starting BLE scan");
 
     // Get the shared global adapter (same instance used for connect)
@@ -747,11 +1447,11 @@ pub async fn scan_devices() -> Result<Vec<DiscoveredDevice>> {
     let adapter = match get_ble_adapter().await {
         Ok(a) => {
             tracing::info!("scan_devices: Got BLE adapter successfully");
-            a
+            a.ble()
         }
         Err(e) => {
             tracing::error!("scan_devices: Failed to get adapter: {:?}", e);
-            return Err(e);
+            return Err(e.into());
         }
     };
 
@@ -759,7 +1459,7 @@ pub async fn scan_devices() -> Result<Vec<DiscoveredDevice>> {
     tracing::debug!("scan_devices: Starting scan");
     if let Err(e) = adapter.start_scan().await {
         tracing::error!("scan_devices: Failed to start scan: {:?}", e);
-        return Err(e);
+        return Err(e.into());
     }
     tracing::info!("scan_devices: Scan started, waiting 10 seconds");
 
@@ -775,6 +1475,59 @@ pub async fn scan_devices() -> Result<Vec<DiscoveredDevice>> {
     Ok(devices)
 }
 
+/// Scan for BLE heart rate devices over several scan windows.
+///
+/// Devices that advertise only intermittently can be missed by a single
+/// window. This runs `windows` back-to-back scans of `window_secs` seconds
+/// each and returns the union of everything found, by device id, with the
+/// strongest RSSI seen for each device. At least one window is run.
+///
+/// # Errors
+///
+/// Returns an error if the BLE adapter is unavailable or a scan fails.
+pub async fn scan_devices_accumulate(
+    windows: u32,
+    window_secs: u32,
+) -> Result<Vec<DiscoveredDevice>> {
+    let adapter = get_ble_adapter().await?.ble();
+    let devices = accumulate_scan_windows(
+        adapter.as_ref(),
+        windows.max(1),
+        Duration::from_secs(window_secs as u64),
+    )
+    .await?;
+    tracing::info!(
+        "scan_devices_accumulate: Found {} devices in {} windows",
+        devices.len(),
+        windows.max(1)
+    );
+    Ok(devices)
+}
+
+/// Run `windows` scans of `window` each and merge their results.
+async fn accumulate_scan_windows(
+    adapter: &(dyn BleAdapter + Send + Sync),
+    windows: u32,
+    window: Duration,
+) -> Result<Vec<DiscoveredDevice>> {
+    let mut merged = Vec::new();
+    for _ in 0..windows {
+        // Stop the scan even when starting it failed, so the radio is not left scanning
+        let scanned = async {
+            adapter.start_scan().await?;
+            tokio::time::sleep(window).await;
+            Ok::<_, anyhow::Error>(())
+        }
+        .await;
+        let stopped = adapter.stop_scan().await;
+        scanned?;
+        stopped?;
+        let found = adapter.get_discovered_devices().await;
+        merge_discovered_devices(&mut merged, &found);
+    }
+    Ok(merged)
+}
+
 /// Connect to a BLE heart rate device.
 ///
 /// Establishes a connection to the specified device and transitions the
@@ -790,21 +1543,20 @@ pub async fn scan_devices() -> Result<Vec<DiscoveredDevice>> {
 /// - Device is not found
 /// - Connection fails
 /// - Connection timeout (15 seconds)
-pub async fn connect_device(device_id: String) -> Result<()> {
+pub async fn connect_device(device_id: String) -> std::result::Result<(), ApiError> {
     tracing::info!("connect_device: Connecting to device {}", device_id);
 
     // Disconnect from any existing connection first
     if let Some(state_mutex) = CONNECTION_STATE.get() {
         let mut state_guard = state_mutex.lock().await;
-        if let Some(old_state) = state_guard.take() {
+        if let Some(mut old_state) = state_guard.take() {
             tracing::info!(
                 "connect_device: Disconnecting from previous device {}",
                 old_state.device_id
             );
 
-            // Abort background tasks
-            old_state.hr_task_handle.abort();
-            old_state.battery_task_handle.abort();
+            // Stop background tasks
+            old_state.stop_tasks().await;
 
             // Disconnect the adapter
             if let Err(e) = old_state.adapter.disconnect().await {
@@ -816,11 +1568,15 @@ pub async fn connect_device(device_id: String) -> Result<()> {
         }
     }
 
+    // Samples of a previous device must not be backfilled into this connection
+    update_latest_values(LatestValues::clear_hr);
+
     // Emit Connecting status
     emit_connection_status(ApiConnectionStatus::Connecting);
 
     // Get the shared adapter (same instance that discovered the devices)
-    let adapter = get_ble_adapter().await?;
+    let shared = get_ble_adapter().await?;
+    let adapter = shared.ble();
 
     // Create state machine with adapter
     let mut state_machine = ConnectionStateMachine::new(adapter.clone());
@@ -848,204 +1604,28 @@ pub async fn connect_device(device_id: String) -> Result<()> {
             state_machine.handle(ConnectionEvent::ServicesDiscovered)?;
 
             // Subscribe to HR notifications and start emitting data
-            let mut hr_receiver = adapter
-                .subscribe_hr()
+            let hr_receiver = adapter
+                .subscribe_hr_timestamped()
                 .await
                 .map_err(|e| anyhow!("Failed to subscribe to HR: {}", e))?;
 
             tracing::info!("Subscribed to HR notifications, starting data stream");
 
-            // Start battery polling and capture the task handle
-            let adapter_clone_battery = adapter.clone();
-            let battery_task_handle = tokio::spawn(async move {
-                let (battery_tx, mut battery_rx) = tokio::sync::mpsc::channel(10);
-                let notification_port: Arc<dyn NotificationPort> = Arc::new(StubNotificationPort);
-
-                // Start battery polling task
-                let poll_result = adapter_clone_battery
-                    .start_battery_polling(battery_tx, notification_port)
-                    .await;
-
-                match poll_result {
-                    Ok(poll_handle) => {
-                        // Receive battery updates and emit to broadcast channel
-                        while let Some(battery_level) = battery_rx.recv().await {
-                            // Convert domain BatteryLevel to API BatteryLevel
-                            let api_battery = ApiBatteryLevel {
-                                level: battery_level.level,
-                                is_charging: battery_level.is_charging,
-                                timestamp: battery_level
-                                    .timestamp
-                                    .duration_since(std::time::UNIX_EPOCH)
-                                    .map(|d| d.as_millis() as u64)
-                                    .unwrap_or(0),
-                            };
-
-                            let receivers = emit_battery_data(api_battery);
-                            tracing::debug!("Emitted battery data to {} receivers", receivers);
-                        }
-
-                        tracing::warn!("Battery polling stream ended");
+            // Cancelled on disconnect so the tasks below can exit cleanly
+            let cancel_token = CancellationToken::new();
 
-                        // Cancel the polling task if the receiver ends
-                        poll_handle.abort();
-                    }
-                    Err(e) => {
-                        tracing::error!("Failed to start battery polling: {}", e);
-                    }
-                }
-            });
+            // Poll the battery and stream it; only the platform adapter supports polling
+            let battery_task_handle = match shared.btleplug() {
+                Some(btleplug) => spawn_battery_monitor(btleplug, cancel_token.clone()),
+                None => tokio::spawn(async {}),
+            };
 
             // Spawn background task to receive and emit HR data and capture handle
-            let hr_task_handle = tokio::spawn(async move {
-                // Initialize Kalman filter for this connection
-                // Using default parameters (process_noise=0.1, measurement_noise=2.0)
-                let mut kalman_filter = KalmanFilter::default();
-                // Duplicate suppression: track last sample timestamp to drop duplicates within 500ms
-                let mut last_sample_ts: Option<u64> = None;
-
-                while let Some(data) = hr_receiver.recv().await {
-                    // Capture high-precision timestamp immediately upon receiving notification
-                    let receive_timestamp = std::time::Instant::now();
-
-                    tracing::debug!("Received {} bytes of HR data", data.len());
-
-                    match parse_heart_rate(&data) {
-                        Ok(mut measurement) => {
-                            // Set the receive timestamp for latency tracking
-                            measurement.receive_timestamp = Some(receive_timestamp);
-                            // Apply Kalman filter to raw BPM measurement
-                            // filter_if_valid rejects physiologically implausible values
-                            let filtered_bpm_f64 =
-                                kalman_filter.filter_if_valid(measurement.bpm as f64);
-                            let filtered_bpm = filtered_bpm_f64.round() as u16;
-
-                            // Get filter variance (confidence indicator)
-                            let filter_variance = kalman_filter.variance();
-
-                            tracing::trace!(
-                                "HR filter: raw={} -> filtered={} (diff={}, variance={:.2})",
-                                measurement.bpm,
-                                filtered_bpm,
-                                measurement.bpm as i32 - filtered_bpm as i32,
-                                filter_variance
-                            );
-
-                            // Calculate RMSSD if RR-intervals are available
-                            let rmssd = if measurement.rr_intervals.len() >= 2 {
-                                let mut sum_squared_diff = 0.0;
-                                for i in 1..measurement.rr_intervals.len() {
-                                    let diff = measurement.rr_intervals[i] as f64
-                                        - measurement.rr_intervals[i - 1] as f64;
-                                    sum_squared_diff += diff * diff;
-                                }
-                                let rmssd_val = (sum_squared_diff
-                                    / (measurement.rr_intervals.len() - 1) as f64)
-                                    .sqrt();
-                                Some(rmssd_val)
-                            } else {
-                                None
-                            };
-
-                            // Get timestamp
-                            let timestamp = std::time::SystemTime::now()
-                                .duration_since(std::time::UNIX_EPOCH)
-                                .map(|d| d.as_millis() as u64)
-                                .unwrap_or(0);
-
-                            // Drop duplicates within 500ms (some straps spam)
-                            if let Some(last_ts) = last_sample_ts {
-                                if timestamp.saturating_sub(last_ts) < 500 {
-                                    tracing::trace!("Dropping duplicate HR sample within 500ms");
-                                    continue;
-                                }
-                            }
-                            last_sample_ts = Some(timestamp);
-
-                            // Convert receive_timestamp to microseconds for UI latency calculation
-                            let receive_timestamp_micros =
-                                measurement.receive_timestamp.map(|ts| {
-                                    // Use UNIX epoch as reference point for cross-platform compatibility
-                                    // Note: This combines monotonic (Instant) with wall-clock for UI consumption
-                                    let now_system = std::time::SystemTime::now();
-                                    let now_instant = std::time::Instant::now();
-                                    let elapsed_since_receive = now_instant.duration_since(ts);
-
-                                    // Calculate receive time in UNIX epoch microseconds
-                                    now_system
-                                        .duration_since(std::time::UNIX_EPOCH)
-                                        .map(|d| d.as_micros() as u64)
-                                        .unwrap_or(0)
-                                        .saturating_sub(elapsed_since_receive.as_micros() as u64)
-                                });
-
-                            let filtered_data = FilteredHeartRate {
-                                raw_bpm: measurement.bpm,
-                                filtered_bpm,
-                                rmssd,
-                                filter_variance: Some(filter_variance),
-                                battery_level: None, // TODO: Read battery periodically
-                                timestamp,
-                                receive_timestamp_micros,
-                                stale: false,
-                            };
-
-                            let receivers = emit_hr_data(filtered_data.clone());
-                            tracing::debug!("Emitted HR data to {} receivers", receivers);
-
-                            // Evaluate coaching rules on this HR sample
-                            let ctx = CueContext {
-                                sample: crate::domain::HrSample {
-                                    timestamp: chrono::DateTime::from_timestamp(
-                                        filtered_data.timestamp as i64 / 1000,
-                                        0,
-                                    )
-                                    .unwrap_or_else(chrono::Utc::now),
-                                    bpm: filtered_data.filtered_bpm,
-                                },
-                                rolling_avg_bpm: filtered_data.filtered_bpm as f64,
-                                zone_violation_secs: 0.0, // TODO: track over time
-                                overwork_secs: 0.0,       // TODO: track over time
-                                inactivity_secs: 0.0,     // TODO: track over time
-                                is_stale: filtered_data.stale,
-                                dnd_active: false, // TODO: compute from DND window
-                                dnd_window: DoNotDisturbWindow::default(),
-                            };
-
-                            if let Some(engine) = COACHING_ENGINE.get() {
-                                let mut engine_guard = engine.lock().await;
-                                if let Some(cue) = engine_guard.evaluate(&ctx) {
-                                    emit_cue(cue);
-                                }
-                            }
-
-                            // Also evaluate the LowHrRule (sustained low-HR alerting).
-                            if let Some(rule) = LOW_HR_RULE.get() {
-                                if let Ok(mut guard) = rule.lock() {
-                                    if let Some(cue) = guard.evaluate_mut(&ctx) {
-                                        emit_cue(cue);
-                                    }
-                                }
-                            }
-
-                            // Persist the sample to JSONL (ignore errors - store is best-effort)
-                            if let Ok(store) = get_hr_store().await {
-                                let rr_ref: Option<&[u16]> = if measurement.rr_intervals.is_empty() {
-                                    None
-                                } else {
-                                    Some(&measurement.rr_intervals)
-                                };
-                                let _ = store.append(timestamp, filtered_data.filtered_bpm, rr_ref).await;
-                            }
-                        }
-                        Err(e) => {
-                            tracing::error!("Failed to parse HR data: {}", e);
-                        }
-                    }
-                }
+            let hr_task_handle = spawn_hr_task(hr_receiver, cancel_token.clone());
 
-                tracing::warn!("HR notification stream ended");
-            });
+            // Monitor RSSI, dropouts and battery for the composite health indicator
+            let health_task_handle =
+                spawn_connection_health_monitor(adapter.clone(), cancel_token.clone());
 
             // Store connection state for later disconnect
             let connection_state = ConnectionState {
@@ -1053,6 +1633,8 @@ pub async fn connect_device(device_id: String) -> Result<()> {
                 device_id: device_id.clone(),
                 hr_task_handle,
                 battery_task_handle,
+                health_task_handle,
+                cancel_token,
             };
 
             let state_mutex = CONNECTION_STATE.get_or_init(|| tokio::sync::Mutex::new(None));
@@ -1063,28 +1645,361 @@ pub async fn connect_device(device_id: String) -> Result<()> {
                 device_id
             );
 
+            // Remember the device for auto_connect_last_device()
+            let device_name = adapter
+                .get_discovered_devices()
+                .await
+                .into_iter()
+                .find(|d| d.id == device_id)
+                .and_then(|d| d.name);
+            if let Err(e) = remember_last_device(&device_id, device_name) {
+                tracing::warn!("connect_device: Failed to remember device: {:#}", e);
+            }
+
             Ok(())
         }
         Ok(Err(e)) => {
             // Connection failed
             state_machine.handle(ConnectionEvent::ConnectionFailed)?;
             emit_connection_status(ApiConnectionStatus::Disconnected);
-            Err(anyhow!("Connection failed: {}", e))
+            Err(ApiError::from(e.context("Connection failed"))
+                .or_code(ApiErrorCode::ConnectionFailed))
         }
         Err(_) => {
             // Timeout
             state_machine.handle(ConnectionEvent::ConnectionFailed)?;
             emit_connection_status(ApiConnectionStatus::Disconnected);
-            Err(anyhow!("Connection timeout after 15 seconds"))
+            Err(ApiError::new(
+                ApiErrorCode::ConnectionFailed,
+                "Connection timeout after 15 seconds",
+            ))
+        }
+    }
+}
+
+/// How long auto-connect scans for the remembered device before each attempt.
+const AUTO_CONNECT_SCAN_SECS: u64 = 5;
+
+/// Path of the last-connected-device record in the data directory.
+///
+/// This is the same file the CLI uses, so a device connected from either is
+/// remembered by both.
+fn last_device_path() -> Result<std::path::PathBuf> {
+    Ok(get_data_dir()?.join("connection_state.json"))
+}
+
+/// Persist `device_id` as the last connected device.
+fn remember_last_device(device_id: &str, device_name: Option<String>) -> Result<()> {
+    PersistedConnectionState::new(device_id, device_name).save(&last_device_path()?)
+}
+
+/// Connect to the most recently connected device.
+///
+/// Looks up the device remembered by the last successful [`connect_device`]
+/// and connects to it, retrying with the default reconnection backoff.
+/// Before each attempt the device is scanned for (up to
+/// `AUTO_CONNECT_SCAN_SECS`) unless the adapter has already discovered it.
+/// Progress is reported on the connection status stream: `Reconnecting`
+/// before each retry and `ReconnectFailed` once all attempts are used up.
+///
+/// # Returns
+///
+/// The id of the connected device, or `None` if no device is remembered.
+///
+/// # Errors
+///
+/// Returns an error if the remembered device could not be connected to
+/// within the retry budget, or the stored record cannot be read.
+pub async fn auto_connect_last_device() -> std::result::Result<Option<String>, ApiError> {
+    let Some(last) = PersistedConnectionState::load(&last_device_path()?)? else {
+        tracing::info!("auto_connect_last_device: No remembered device");
+        return Ok(None);
+    };
+    let device_id = last.device_id;
+    tracing::info!(
+        "auto_connect_last_device: Connecting to {} ({})",
+        device_id,
+        last.device_name.as_deref().unwrap_or("unknown")
+    );
+
+    let policy = ReconnectionPolicy::default();
+    let mut attempt: u8 = 1;
+    loop {
+        let result = match find_device(&device_id).await {
+            Ok(()) => connect_device(device_id.clone()).await,
+            Err(e) => Err(e.into()),
+        };
+        let Err(e) = result else {
+            return Ok(Some(device_id));
+        };
+
+        tracing::warn!(
+            "auto_connect_last_device: Attempt {}/{} failed: {:#}",
+            attempt,
+            policy.max_attempts,
+            e
+        );
+        if attempt >= policy.max_attempts {
+            let reason = format!("Could not connect to {}: {:#}", device_id, e);
+            emit_connection_status(ApiConnectionStatus::ReconnectFailed {
+                reason: reason.clone(),
+            });
+            return Err(ApiError::new(e.code, reason));
+        }
+
+        attempt += 1;
+        emit_connection_status(ApiConnectionStatus::Reconnecting {
+            attempt,
+            max_attempts: policy.max_attempts,
+        });
+        tokio::time::sleep(policy.calculate_delay(attempt - 1)).await;
+    }
+}
+
+/// Scan until the adapter has discovered `device_id`.
+async fn find_device(device_id: &str) -> Result<()> {
+    let adapter = get_ble_adapter().await?.ble();
+    let discovered = |devices: Vec<DiscoveredDevice>| devices.iter().any(|d| d.id == device_id);
+    if discovered(adapter.get_discovered_devices().await) {
+        return Ok(());
+    }
+
+    adapter.start_scan().await?;
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(AUTO_CONNECT_SCAN_SECS);
+    let mut found = false;
+    while tokio::time::Instant::now() < deadline {
+        if discovered(adapter.get_discovered_devices().await) {
+            found = true;
+            break;
         }
+        tokio::time::sleep(Duration::from_millis(250)).await;
+    }
+    adapter.stop_scan().await?;
+
+    if found {
+        Ok(())
+    } else {
+        Err(BleError::DeviceNotFound(device_id.to_string()).into())
     }
 }
 
+/// Spawn the task that filters HR notifications and fans them out to the
+/// HR stream, coaching rules and the HR store.
+///
+/// Samples are timestamped with the arrival time the adapter stamped on each
+/// notification, not the time this task gets to process it.
+///
+/// Every `HR_LIVENESS_INTERVAL` the task also emits an [`ApiHrLiveness`] tick,
+/// even when no HR arrives; the ticks stop when the task exits.
+///
+/// The task ends when the notification stream closes or `cancel_token` is
+/// cancelled; cancellation is checked between samples, so the current sample
+/// is always fully processed. On exit the task drops its subscription so the
+/// adapter stops forwarding notifications.
+fn spawn_hr_task(
+    mut hr_receiver: tokio::sync::mpsc::Receiver<HrNotification>,
+    cancel_token: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        // Initialize Kalman filter for this connection
+        // Using default parameters (process_noise=0.1, measurement_noise=2.0)
+        let mut kalman_filter =
+            KalmanFilter::default().with_max_slope(DEFAULT_MAX_SLOPE_BPM_PER_SEC);
+        // Duplicate suppression: track last sample timestamp to drop duplicates within 500ms
+        let mut last_sample_ts: Option<u64> = None;
+        let mut contact_monitor = ContactMonitor::default();
+        let mut hrv_window = HrvWindow::new(DEFAULT_HRV_WINDOW);
+        let mut rolling_avg = RollingAverage::new(ROLLING_AVG_WINDOW_MS);
+        let mut liveness_ticker = tokio::time::interval(HR_LIVENESS_INTERVAL);
+        liveness_ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // Based on the wall clock at this connection's first notification
+        let mut conn_clock: Option<MonotonicClock> = None;
+
+        loop {
+            let HrNotification { data, received_at } = tokio::select! {
+                biased;
+                _ = cancel_token.cancelled() => break,
+                _ = liveness_ticker.tick() => {
+                    emit_hr_liveness(ApiHrLiveness {
+                        timestamp: conn_clock.unwrap_or_else(clock).now_millis(),
+                        last_hr_timestamp: last_sample_ts,
+                    });
+                    continue;
+                }
+                notification = hr_receiver.recv() => match notification {
+                    Some(notification) => notification,
+                    None => {
+                        tracing::warn!("HR notification stream ended");
+                        break;
+                    }
+                },
+            };
+
+            // Map the adapter's arrival time onto the connection's monotonic clock
+            let (wall_now, now) = (std::time::SystemTime::now(), std::time::Instant::now());
+            let connection_clock =
+                *conn_clock.get_or_insert_with(|| MonotonicClock::with_base(wall_now, now));
+            let receive_timestamp = arrival_instant(received_at, wall_now, now);
+
+            tracing::debug!("Received {} bytes of HR data", data.len());
+
+            match parse_heart_rate(&data) {
+                Ok(mut measurement) => {
+                    // Set the receive timestamp for latency tracking
+                    measurement.receive_timestamp = Some(receive_timestamp);
+                    // Many straps signal lost skin contact by sending 0 BPM
+                    check_sensor_contact(
+                        &mut contact_monitor,
+                        measurement.bpm,
+                        notification_router().as_ref(),
+                    )
+                    .await;
+
+                    // Timestamp of arrival at the adapter
+                    let timestamp = connection_clock.millis_at(receive_timestamp);
+
+                    // Apply Kalman filter to raw BPM measurement, trusting readings
+                    // with consistent RR-intervals more; physiologically implausible
+                    // values and impossibly fast changes are rejected
+                    let filtered_bpm_f64 = if is_valid_bpm(measurement.bpm) {
+                        kalman_filter.update_at(
+                            measurement.bpm as f64,
+                            rr_quality(&measurement.rr_intervals),
+                            timestamp,
+                        )
+                    } else {
+                        kalman_filter.estimate()
+                    };
+                    let filtered_bpm = filtered_bpm_f64.round() as u16;
+
+                    // Get filter variance (confidence indicator)
+                    let filter_variance = kalman_filter.variance();
+
+                    tracing::trace!(
+                        "HR filter: raw={} -> filtered={} (diff={}, variance={:.2})",
+                        measurement.bpm,
+                        filtered_bpm,
+                        measurement.bpm as i32 - filtered_bpm as i32,
+                        filter_variance
+                    );
+
+                    // Calculate RMSSD if RR-intervals are available
+                    let rmssd = if measurement.rr_intervals.len() >= 2 {
+                        let mut sum_squared_diff = 0.0;
+                        for i in 1..measurement.rr_intervals.len() {
+                            let diff = measurement.rr_intervals[i] as f64
+                                - measurement.rr_intervals[i - 1] as f64;
+                            sum_squared_diff += diff * diff;
+                        }
+                        let rmssd_val =
+                            (sum_squared_diff / (measurement.rr_intervals.len() - 1) as f64).sqrt();
+                        Some(rmssd_val)
+                    } else {
+                        None
+                    };
+
+                    // Drop duplicates within 500ms (some straps spam)
+                    if let Some(last_ts) = last_sample_ts {
+                        if timestamp.saturating_sub(last_ts) < 500 {
+                            tracing::trace!("Dropping duplicate HR sample within 500ms");
+                            continue;
+                        }
+                    }
+                    last_sample_ts = Some(timestamp);
+
+                    // Receive time in UNIX epoch microseconds for UI latency calculation
+                    let receive_timestamp_micros =
+                        Some(connection_clock.micros_at(receive_timestamp));
+
+                    // Classify the RMSSD trend over the recent RR-intervals
+                    hrv_window.extend(&measurement.rr_intervals);
+
+                    let filtered_data = FilteredHeartRate {
+                        raw_bpm: measurement.bpm,
+                        filtered_bpm,
+                        rmssd,
+                        rmssd_trend: hrv_window.rmssd_trend(),
+                        filter_variance: Some(filter_variance),
+                        battery_level: None, // TODO: Read battery periodically
+                        timestamp,
+                        receive_timestamp_micros,
+                        stale: false,
+                        intensity_factor: intensity_factor(filtered_bpm, current_lthr()),
+                        avg_bpm_10s: rolling_avg.push(timestamp, filtered_bpm),
+                    };
+
+                    let receivers = emit_hr_data(filtered_data.clone());
+                    tracing::debug!("Emitted HR data to {} receivers", receivers);
+
+                    // Append to the raw sample log if the user enabled it
+                    log_raw_hr_sample(&filtered_data).await;
+
+                    // Evaluate coaching rules on this HR sample
+                    let ctx = CueContext {
+                        sample: crate::domain::HrSample {
+                            timestamp: chrono::DateTime::from_timestamp(
+                                filtered_data.timestamp as i64 / 1000,
+                                0,
+                            )
+                            .unwrap_or_else(chrono::Utc::now),
+                            bpm: filtered_data.filtered_bpm,
+                        },
+                        rolling_avg_bpm: filtered_data.filtered_bpm as f64,
+                        zone_violation_secs: 0.0, // TODO: track over time
+                        overwork_secs: 0.0,       // TODO: track over time
+                        inactivity_secs: 0.0,     // TODO: track over time
+                        is_stale: filtered_data.stale,
+                        dnd_active: false, // TODO: compute from DND window
+                        dnd_window: DoNotDisturbWindow::default(),
+                    };
+
+                    if let Some(engine) = COACHING_ENGINE.get() {
+                        let mut engine_guard = engine.lock().await;
+                        if let Some(cue) = engine_guard.evaluate(&ctx) {
+                            emit_cue(cue);
+                        }
+                    }
+
+                    // Also evaluate the LowHrRule (sustained low-HR alerting).
+                    if let Some(rule) = LOW_HR_RULE.get() {
+                        if let Ok(mut guard) = rule.lock() {
+                            if let Some(cue) = guard.evaluate_mut(&ctx) {
+                                emit_cue(cue);
+                            }
+                        }
+                    }
+
+                    // Persist the sample to JSONL (ignore errors - store is best-effort)
+                    if let Ok(store) = get_hr_store().await {
+                        let rr_ref: Option<&[u16]> = if measurement.rr_intervals.is_empty() {
+                            None
+                        } else {
+                            Some(&measurement.rr_intervals)
+                        };
+                        let _ = store
+                            .append(timestamp, filtered_data.filtered_bpm, rr_ref)
+                            .await;
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Failed to parse HR data: {}", e);
+                }
+            }
+        }
+
+        // Unsubscribe before reporting the task finished
+        drop(hr_receiver);
+        tracing::debug!("HR task finished");
+    })
+}
+
 /// Disconnect from the currently connected device.
 ///
 /// Gracefully disconnects from the active BLE connection and transitions
-/// the state machine back to Idle. This function aborts background tasks
-/// (HR streaming and battery polling) and cleanly disconnects the BLE adapter.
+/// the state machine back to Idle. This function stops background tasks
+/// (HR streaming, battery polling and health monitoring), giving each a chance
+/// to finish its current iteration before it is aborted, and cleanly
+/// disconnects the BLE adapter.
 ///
 /// This function is idempotent - calling it when already disconnected is safe
 /// and will succeed without error.
@@ -1100,18 +2015,17 @@ pub async fn disconnect() -> Result<()> {
     let mut state_guard = state_mutex.lock().await;
 
     // Take the connection state (if any)
-    if let Some(connection_state) = state_guard.take() {
+    if let Some(mut connection_state) = state_guard.take() {
         tracing::info!(
             "disconnect: Disconnecting from device {}",
             connection_state.device_id
         );
 
-        // Abort background tasks
-        tracing::debug!("disconnect: Aborting HR task");
-        connection_state.hr_task_handle.abort();
-
-        tracing::debug!("disconnect: Aborting battery task");
-        connection_state.battery_task_handle.abort();
+        // Let background tasks finish and clean up before the link goes away
+        tracing::debug!("disconnect: Stopping background tasks");
+        if !connection_state.stop_tasks().await {
+            tracing::warn!("disconnect: Some background tasks had to be aborted");
+        }
 
         // Disconnect the BLE adapter (log error but don't fail if already disconnected)
         tracing::debug!("disconnect: Calling adapter.disconnect()");
@@ -1135,10 +2049,51 @@ pub async fn disconnect() -> Result<()> {
         tracing::info!("disconnect: No active connection to disconnect");
         emit_connection_status(ApiConnectionStatus::Disconnected);
     }
+    update_latest_values(LatestValues::clear_hr);
+
+    Ok(())
+}
 
+/// Close the reconnection circuit breaker.
+///
+/// After repeated failed reconnection cycles the adapter stops retrying and
+/// emits a `CircuitOpen` connection status. Call this (e.g. from a "Retry now"
+/// button) to allow the next reconnection cycle immediately instead of
+/// waiting out the cooldown.
+///
+/// # Errors
+///
+/// Returns an error if the BLE adapter cannot be initialized.
+pub async fn reset_connection_breaker() -> Result<()> {
+    if let Some(adapter) = get_ble_adapter().await?.btleplug() {
+        adapter.reset_circuit_breaker().await;
+    }
     Ok(())
 }
 
+/// Read where the connected heart rate sensor is worn.
+///
+/// Reads the Body Sensor Location characteristic (0x2A38) of the Heart Rate
+/// Service.
+///
+/// # Errors
+///
+/// Returns an error if no device is connected or the device does not report
+/// its location.
+pub async fn get_sensor_location() -> Result<ApiSensorLocation> {
+    let state_mutex = CONNECTION_STATE.get_or_init(|| tokio::sync::Mutex::new(None));
+    if state_mutex.lock().await.is_none() {
+        return Err(anyhow!("No device connected"));
+    }
+
+    get_ble_adapter()
+        .await?
+        .btleplug()
+        .ok_or_else(|| anyhow!("Sensor location is not available from this adapter"))?
+        .read_sensor_location()
+        .await
+}
+
 /// Start mock mode for testing without hardware.
 ///
 /// Creates a MockAdapter, connects to a simulated device, and spawns a
@@ -1177,22 +2132,22 @@ pub async fn start_mock_mode() -> Result<()> {
     emit_battery_data(ApiBatteryLevel {
         level: Some(battery_level),
         is_charging: false,
-        timestamp: std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64,
+        timestamp: unix_millis(),
     });
 
     // Spawn HR processing loop
     tokio::spawn(async move {
         let mut filter = KalmanFilter::new(0.1, 2.0);
         let mut rr_buffer: Vec<u16> = Vec::new();
+        let mut hrv_window = HrvWindow::new(DEFAULT_HRV_WINDOW);
+        let mut rolling_avg = RollingAverage::new(ROLLING_AVG_WINDOW_MS);
 
         while let Some(raw_data) = hr_rx.recv().await {
             if let Ok(measurement) = parse_heart_rate(&raw_data) {
                 let filtered_bpm = filter.update(measurement.bpm as f64) as u16;
 
-                // Collect RR intervals for RMSSD
+                // Collect RR intervals for RMSSD and its trend
+                hrv_window.extend(&measurement.rr_intervals);
                 for &rr in &measurement.rr_intervals {
                     rr_buffer.push(rr);
                     if rr_buffer.len() > 20 {
@@ -1206,21 +2161,23 @@ pub async fn start_mock_mode() -> Result<()> {
                     None
                 };
 
-                let timestamp = std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64;
+                let timestamp = unix_millis();
 
-                emit_hr_data(ApiFilteredHeartRate {
+                let data = ApiFilteredHeartRate {
                     raw_bpm: measurement.bpm,
                     filtered_bpm,
                     rmssd,
+                    rmssd_trend: hrv_window.rmssd_trend(),
                     filter_variance: Some(filter.variance()),
                     battery_level: Some(battery_level),
                     timestamp,
                     receive_timestamp_micros: None,
                     stale: false,
-                });
+                    intensity_factor: intensity_factor(filtered_bpm, current_lthr()),
+                    avg_bpm_10s: rolling_avg.push(timestamp, filtered_bpm),
+                };
+                log_raw_hr_sample(&data).await;
+                emit_hr_data(data);
             }
         }
 
@@ -1231,6 +2188,66 @@ pub async fn start_mock_mode() -> Result<()> {
     Ok(())
 }
 
+/// Start logging every received HR sample to a file.
+///
+/// While active, each `FilteredHeartRate` produced by the live connection (or
+/// mock mode) is appended as a JSON line to `path`, whether or not a workout is
+/// running. The file is rotated to `<path>.1` once it reaches 10 MiB. Calling
+/// this while logging is already active closes the previous log first.
+///
+/// # Arguments
+///
+/// * `path` - Destination JSONL file (created if missing, appended otherwise)
+///
+/// # Errors
+///
+/// Returns an error if the log file cannot be opened.
+pub async fn start_raw_logging(path: String) -> std::result::Result<(), ApiError> {
+    let logger = RawHrLogger::open(std::path::PathBuf::from(&path)).await?;
+
+    let mutex = RAW_HR_LOGGER.get_or_init(|| tokio::sync::Mutex::new(None));
+    let mut guard = mutex.lock().await;
+    if let Some(previous) = guard.replace(logger) {
+        previous.close().await?;
+    }
+
+    tracing::info!("start_raw_logging: Logging raw HR samples to {}", path);
+    Ok(())
+}
+
+/// Stop raw HR logging, flushing and closing the log file.
+///
+/// Does nothing if logging is not active.
+///
+/// # Errors
+///
+/// Returns an error if the final flush fails.
+pub async fn stop_raw_logging() -> std::result::Result<(), ApiError> {
+    let Some(mutex) = RAW_HR_LOGGER.get() else {
+        return Ok(());
+    };
+
+    if let Some(logger) = mutex.lock().await.take() {
+        let path = logger.path().display().to_string();
+        logger.close().await?;
+        tracing::info!("stop_raw_logging: Closed raw HR log {}", path);
+    }
+    Ok(())
+}
+
+/// Append a sample to the raw HR log if logging is active (errors are logged, not returned).
+async fn log_raw_hr_sample(data: &ApiFilteredHeartRate) {
+    let Some(mutex) = RAW_HR_LOGGER.get() else {
+        return;
+    };
+
+    if let Some(logger) = mutex.lock().await.as_mut() {
+        if let Err(e) = logger.append(data).await {
+            tracing::warn!("Failed to write raw HR sample: {}", e);
+        }
+    }
+}
+
 /// Create a stream for receiving filtered heart rate data.
 ///
 /// Sets up a stream that will receive real-time filtered heart rate measurements
@@ -1282,10 +2299,53 @@ fn get_or_create_hr_broadcast_sender() -> broadcast::Sender<ApiFilteredHeartRate
         .clone()
 }
 
+/// Create a stream that always delivers the most recent filtered heart rate.
+///
+/// Unlike [`create_hr_stream`], which forwards every sample and can fall
+/// behind, this stream is backed by a watch channel: a slow consumer skips
+/// intermediate samples and always receives the latest one. The current
+/// value, if any, is delivered right away.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the HR data
+pub async fn create_hr_latest_stream(sink: StreamSink<ApiFilteredHeartRate>) -> Result<()> {
+    let mut rx = subscribe_hr_latest();
+    rx.mark_changed();
+    tokio::spawn(async move {
+        while rx.changed().await.is_ok() {
+            let latest = rx.borrow_and_update().clone();
+            if let Some(data) = latest {
+                if sink.add(data).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Subscribe to the latest filtered heart rate.
+///
+/// The receiver holds `None` until the first sample is emitted, and again
+/// after a device connects or disconnects and when a workout starts.
+#[frb(ignore)]
+pub fn subscribe_hr_latest() -> watch::Receiver<Option<ApiFilteredHeartRate>> {
+    get_or_create_hr_watch_sender().subscribe()
+}
+
+/// Get or create the global watch sender holding the latest HR sample.
+fn get_or_create_hr_watch_sender() -> &'static watch::Sender<Option<ApiFilteredHeartRate>> {
+    static HR_LATEST_TX: OnceLock<watch::Sender<Option<ApiFilteredHeartRate>>> = OnceLock::new();
+
+    HR_LATEST_TX.get_or_init(|| watch::Sender::new(None))
+}
+
 /// Emit filtered heart rate data to all stream subscribers.
 ///
 /// This function should be called by the filtering pipeline when new filtered
-/// HR data is available. It broadcasts the data to all active stream subscribers.
+/// HR data is available. It broadcasts the data to all active stream subscribers
+/// and replaces the value seen by latest-value subscribers.
 ///
 /// # Arguments
 ///
@@ -1304,10 +2364,44 @@ fn get_or_create_hr_broadcast_sender() -> broadcast::Sender<ApiFilteredHeartRate
 /// emit_hr_data(filtered_data);
 /// ```
 pub fn emit_hr_data(data: ApiFilteredHeartRate) -> usize {
+    update_latest_values(|latest| latest.push_hr(data.clone()));
     let tx = get_or_create_hr_broadcast_sender();
     tx.send(data).unwrap_or_default()
 }
 
+/// Get the most recently emitted HR samples, oldest first.
+///
+/// Lets a UI that subscribes to the HR stream after samples were already
+/// emitted (e.g. on opening the chart mid-session) backfill its chart before
+/// live updates arrive. At most the buffer capacity is returned (see
+/// [`set_recent_hr_capacity`]). The buffer is cleared when a device connects
+/// or disconnects and when a workout starts.
+///
+/// # Arguments
+///
+/// * `count` - Maximum number of samples to return
+pub fn get_recent_hr(count: u32) -> Vec<ApiFilteredHeartRate> {
+    update_latest_values(|latest| {
+        let recent = &latest.recent_hr;
+        let skip = recent.samples.len().saturating_sub(count as usize);
+        recent.samples.iter().skip(skip).cloned().collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Set how many recent HR samples are kept for [`get_recent_hr`].
+///
+/// Shrinking the buffer drops the oldest samples; `0` disables it.
+/// Defaults to 300 samples.
+pub fn set_recent_hr_capacity(capacity: u32) {
+    update_latest_values(|latest| {
+        let recent = &mut latest.recent_hr;
+        recent.capacity = capacity as usize;
+        let excess = recent.samples.len().saturating_sub(recent.capacity);
+        recent.samples.drain(..excess);
+    });
+}
+
 // Accessor functions for ApiFilteredHeartRate (opaque type)
 
 /// Get the raw (unfiltered) BPM value from filtered heart rate data
@@ -1325,6 +2419,12 @@ pub fn hr_rmssd(data: &ApiFilteredHeartRate) -> Option<f64> {
     data.rmssd
 }
 
+/// Get the direction of the windowed RMSSD (Rising, Falling or Stable) from HR data
+/// Returns `None` until enough RR-intervals have been received
+pub fn hr_rmssd_trend(data: &ApiFilteredHeartRate) -> Option<ApiTrend> {
+    data.rmssd_trend
+}
+
 /// Get the filter variance (confidence indicator) in BPM²
 ///
 /// The variance represents the Kalman filter's estimated uncertainty:
@@ -1356,6 +2456,18 @@ pub fn hr_receive_timestamp_micros(data: &ApiFilteredHeartRate) -> Option<u64> {
     data.receive_timestamp_micros
 }
 
+/// Get the filtered BPM as a fraction of the configured LTHR (1.0 = at threshold)
+///
+/// Returns `None` if no LTHR has been set via [`set_lthr`].
+pub fn hr_intensity_factor(data: &ApiFilteredHeartRate) -> Option<f64> {
+    data.intensity_factor
+}
+
+/// Get the 10-second rolling average BPM from filtered heart rate data
+pub fn hr_avg_bpm_10s(data: &ApiFilteredHeartRate) -> Option<u16> {
+    data.avg_bpm_10s
+}
+
 /// Calculate the heart rate zone based on a maximum heart rate
 ///
 /// # Arguments
@@ -1378,6 +2490,21 @@ pub fn hr_zone(data: &ApiFilteredHeartRate, max_hr: u16) -> Zone {
     }
 }
 
+/// Get the short label of a zone (e.g. "Recovery").
+pub fn zone_label(zone: Zone) -> String {
+    zone.label().to_string()
+}
+
+/// Get the one-line description of a zone.
+pub fn zone_description(zone: Zone) -> String {
+    zone.description().to_string()
+}
+
+/// Get the display color of a zone as an (r, g, b) triple.
+pub fn zone_rgb(zone: Zone) -> (u8, u8, u8) {
+    zone.rgb()
+}
+
 /// Create a dummy battery level for testing (temporary helper for FRB codegen).
 ///
 /// This function helps FRB discover the ApiBatteryLevel type during code generation.
@@ -1470,6 +2597,7 @@ fn get_or_create_battery_broadcast_sender() -> broadcast::Sender<ApiBatteryLevel
 /// emit_battery_data(battery_data);
 /// ```
 pub fn emit_battery_data(data: ApiBatteryLevel) -> usize {
+    update_latest_values(|latest| latest.battery = Some(data.clone()));
     let tx = get_or_create_battery_broadcast_sender();
     tx.send(data).unwrap_or_default()
 }
@@ -1549,10 +2677,46 @@ fn get_or_create_session_progress_broadcast_sender() -> broadcast::Sender<ApiSes
 /// The number of receivers that received the data. Returns 0 if no receivers
 /// are currently subscribed.
 pub fn emit_session_progress(data: ApiSessionProgress) -> usize {
+    // Drop updates still in flight from a session that has been stopped
+    if data.session_epoch < MIN_PROGRESS_EPOCH.load(std::sync::atomic::Ordering::SeqCst) {
+        tracing::debug!(
+            "Dropping stale progress from session epoch {}",
+            data.session_epoch
+        );
+        return 0;
+    }
     let tx = get_or_create_session_progress_broadcast_sender();
     tx.send(data).unwrap_or_default()
 }
 
+// Lowest session epoch whose progress is still broadcast.
+// Raised by reset_progress_channel() so a stopped session cannot leak updates.
+static MIN_PROGRESS_EPOCH: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Stop broadcasting progress from all sessions started so far.
+///
+/// Progress updates of earlier sessions that are still buffered are dropped
+/// instead of reaching subscribers of the next workout. Called by
+/// [`stop_workout`].
+pub fn reset_progress_channel() {
+    MIN_PROGRESS_EPOCH.store(
+        latest_session_epoch() + 1,
+        std::sync::atomic::Ordering::SeqCst,
+    );
+}
+
+/// Epoch of the most recently started workout, or `None` if none was started.
+///
+/// Compare with [`session_progress_epoch`] to ignore progress of other
+/// sessions.
+pub async fn current_session_epoch() -> Result<Option<u64>> {
+    let executor_guard = get_session_executor().await?.lock().await;
+    Ok(executor_guard
+        .as_ref()
+        .map(|executor| executor.session_epoch())
+        .filter(|&epoch| epoch > 0))
+}
+
 /// Get a sender for session progress updates (internal use).
 ///
 /// This creates an unbounded mpsc sender that can be used by the SessionExecutor
@@ -1574,52 +2738,189 @@ fn create_session_progress_forwarder() -> tokio::sync::mpsc::UnboundedSender<Api
     tx
 }
 
-/// Create a stream for receiving connection status updates.
+/// Create a stream of phase transitions during workout execution.
 ///
-/// Sets up a stream that will receive real-time connection status updates
-/// during BLE device connection, reconnection attempts, and failures.
-/// This function is used by Flutter via FRB to create a reactive data stream.
+/// Emits one event each time the running workout advances to the next phase,
+/// plus a final completion event when the last phase ends, so the app can
+/// announce phase changes without diffing [`create_session_progress_stream`].
 ///
 /// # Arguments
 ///
-/// * `sink` - The FRB StreamSink that will receive the connection status data
+/// * `sink` - The FRB StreamSink that will receive the phase transitions
 ///
 /// # Returns
 ///
 /// Returns Ok(()) if the stream was successfully set up.
-///
-/// # Example
-///
-/// ```dart
-/// // In Flutter:
-/// final stream = await api.createConnectionStatusStream();
-/// stream.listen((status) {
-///   if (status.type == 'reconnecting') {
-///     print('Reconnecting... attempt ${status.attempt}/${status.max_attempts}');
-///   } else if (status.type == 'connected') {
-///     print('Connected to ${status.device_id}');
-///   }
-/// });
-/// ```
-pub async fn create_connection_status_stream(sink: StreamSink<ApiConnectionStatus>) -> Result<()> {
-    let mut rx = get_connection_status_receiver();
+pub async fn create_phase_transition_stream(sink: StreamSink<ApiPhaseTransition>) -> Result<()> {
+    let mut rx = subscribe_phase_transition_stream();
     tokio::spawn(async move {
-        while let Ok(data) = rx.recv().await {
-            sink.add(data).ok();
+        while let Ok(transition) = rx.recv().await {
+            sink.add(transition).ok();
         }
     });
     Ok(())
 }
 
-/// Subscribe to the real-time connection status stream.
+/// Subscribe to phase transition events from the running workout.
+///
+/// Each transition is emitted exactly once when the session advances to the
+/// next phase, plus a final completion event when the last phase ends.
 #[frb(ignore)]
-pub fn subscribe_connection_status_stream() -> broadcast::Receiver<ApiConnectionStatus> {
-    let tx = get_or_create_connection_status_broadcast_sender();
-    tx.subscribe()
+pub fn subscribe_phase_transition_stream() -> broadcast::Receiver<ApiPhaseTransition> {
+    get_or_create_phase_transition_broadcast_sender().subscribe()
 }
 
-fn get_connection_status_receiver() -> broadcast::Receiver<ApiConnectionStatus> {
-    subscribe_connection_status_stream()
+/// Get or create the global phase transition broadcast sender.
+fn get_or_create_phase_transition_broadcast_sender() -> broadcast::Sender<ApiPhaseTransition> {
+    static PHASE_TRANSITION_TX: OnceLock<broadcast::Sender<ApiPhaseTransition>> = OnceLock::new();
+
+    PHASE_TRANSITION_TX
+        .get_or_init(|| {
+            let (tx, _rx) = broadcast::channel(PHASE_TRANSITION_CHANNEL_CAPACITY);
+            tx
+        })
+        .clone()
+}
+
+/// Emit a phase transition to all stream subscribers.
+///
+/// # Returns
+///
+/// The number of receivers that received the event.
+pub fn emit_phase_transition(transition: ApiPhaseTransition) -> usize {
+    get_or_create_phase_transition_broadcast_sender()
+        .send(transition)
+        .unwrap_or_default()
+}
+
+/// Create a forwarder from the executor's phase transition channel to the broadcast stream.
+fn create_phase_transition_forwarder() -> tokio::sync::mpsc::UnboundedSender<ApiPhaseTransition> {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<ApiPhaseTransition>();
+
+    tokio::spawn(async move {
+        while let Some(transition) = rx.recv().await {
+            emit_phase_transition(transition);
+        }
+    });
+
+    tx
+}
+
+/// Create a stream for receiving pace cues from the running workout.
+///
+/// One [`ApiPaceCue`] is emitted per tick while a workout is running and a BPM
+/// reading is available, telling the athlete whether to speed up, hold or
+/// slow down to stay in the target zone band.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the pace cues
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+pub async fn create_pace_cue_stream(sink: StreamSink<ApiPaceCue>) -> Result<()> {
+    let mut rx = subscribe_pace_cue_stream();
+    tokio::spawn(async move {
+        while let Ok(cue) = rx.recv().await {
+            sink.add(cue).ok();
+        }
+    });
+    Ok(())
+}
+
+/// Subscribe to pace cues from the running workout.
+///
+/// One cue is emitted per tick while a workout is running and a BPM reading is
+/// available, describing which way and by how many BPM the athlete is off the
+/// target zone band.
+#[frb(ignore)]
+pub fn subscribe_pace_cue_stream() -> broadcast::Receiver<ApiPaceCue> {
+    get_or_create_pace_cue_broadcast_sender().subscribe()
+}
+
+/// Get or create the global pace cue broadcast sender.
+fn get_or_create_pace_cue_broadcast_sender() -> broadcast::Sender<ApiPaceCue> {
+    static PACE_CUE_TX: OnceLock<broadcast::Sender<ApiPaceCue>> = OnceLock::new();
+
+    PACE_CUE_TX
+        .get_or_init(|| {
+            let (tx, _rx) = broadcast::channel(PACE_CUE_CHANNEL_CAPACITY);
+            tx
+        })
+        .clone()
+}
+
+/// Emit a pace cue to all stream subscribers.
+///
+/// # Returns
+///
+/// The number of receivers that received the cue.
+pub fn emit_pace_cue(cue: ApiPaceCue) -> usize {
+    get_or_create_pace_cue_broadcast_sender()
+        .send(cue)
+        .unwrap_or_default()
+}
+
+/// Create a forwarder from the executor's pace cue channel to the broadcast stream.
+fn create_pace_cue_forwarder() -> tokio::sync::mpsc::UnboundedSender<ApiPaceCue> {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<ApiPaceCue>();
+
+    tokio::spawn(async move {
+        while let Some(cue) = rx.recv().await {
+            emit_pace_cue(cue);
+        }
+    });
+
+    tx
+}
+
+/// Create a stream for receiving connection status updates.
+///
+/// Sets up a stream that will receive real-time connection status updates
+/// during BLE device connection, reconnection attempts, and failures.
+/// This function is used by Flutter via FRB to create a reactive data stream.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the connection status data
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+///
+/// # Example
+///
+/// ```dart
+/// // In Flutter:
+/// final stream = await api.createConnectionStatusStream();
+/// stream.listen((status) {
+///   if (status.type == 'reconnecting') {
+///     print('Reconnecting... attempt ${status.attempt}/${status.max_attempts}');
+///   } else if (status.type == 'connected') {
+///     print('Connected to ${status.device_id}');
+///   }
+/// });
+/// ```
+pub async fn create_connection_status_stream(sink: StreamSink<ApiConnectionStatus>) -> Result<()> {
+    let mut rx = get_connection_status_receiver();
+    tokio::spawn(async move {
+        while let Ok(data) = rx.recv().await {
+            sink.add(data).ok();
+        }
+    });
+    Ok(())
+}
+
+/// Subscribe to the real-time connection status stream.
+#[frb(ignore)]
+pub fn subscribe_connection_status_stream() -> broadcast::Receiver<ApiConnectionStatus> {
+    let tx = get_or_create_connection_status_broadcast_sender();
+    tx.subscribe()
+}
+
+fn get_connection_status_receiver() -> broadcast::Receiver<ApiConnectionStatus> {
+    subscribe_connection_status_stream()
 }
 
 /// Get or create the global connection status broadcast sender.
@@ -1665,10 +2966,284 @@ fn get_or_create_connection_status_broadcast_sender() -> broadcast::Sender<ApiCo
 /// emit_connection_status(ConnectionStatus::ReconnectFailed { reason: "Max attempts exceeded".to_string() });
 /// ```
 pub fn emit_connection_status(status: ApiConnectionStatus) -> usize {
+    update_latest_values(|latest| latest.connection_status = Some(status.clone()));
     let tx = get_or_create_connection_status_broadcast_sender();
     tx.send(status).unwrap_or_default()
 }
 
+/// Create a stream for receiving HR liveness ticks.
+///
+/// While the HR task runs, an [`ApiHrLiveness`] is emitted about once per
+/// second whether or not HR packets arrive, so the UI can tell "waiting for
+/// data" from a dead stream.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the liveness ticks
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+pub async fn create_hr_liveness_stream(sink: StreamSink<ApiHrLiveness>) -> Result<()> {
+    let mut rx = subscribe_hr_liveness_stream();
+    tokio::spawn(async move {
+        while let Ok(liveness) = rx.recv().await {
+            sink.add(liveness).ok();
+        }
+    });
+    Ok(())
+}
+
+/// Subscribe to the HR liveness stream.
+///
+/// While the HR task runs, an [`ApiHrLiveness`] is emitted about once per
+/// second regardless of HR packets. Ticks stop when the task exits, e.g.
+/// because the device disconnected or its notification stream ended.
+#[frb(ignore)]
+pub fn subscribe_hr_liveness_stream() -> broadcast::Receiver<ApiHrLiveness> {
+    get_or_create_hr_liveness_broadcast_sender().subscribe()
+}
+
+/// Get or create the global HR liveness broadcast sender.
+fn get_or_create_hr_liveness_broadcast_sender() -> broadcast::Sender<ApiHrLiveness> {
+    static HR_LIVENESS_TX: OnceLock<broadcast::Sender<ApiHrLiveness>> = OnceLock::new();
+
+    HR_LIVENESS_TX
+        .get_or_init(|| {
+            let (tx, _rx) = broadcast::channel(HR_LIVENESS_CHANNEL_CAPACITY);
+            tx
+        })
+        .clone()
+}
+
+/// Emit an HR liveness tick to all stream subscribers.
+///
+/// # Returns
+///
+/// The number of receivers that received the tick.
+pub fn emit_hr_liveness(liveness: ApiHrLiveness) -> usize {
+    update_latest_values(|latest| latest.hr_liveness = Some(liveness.clone()));
+    get_or_create_hr_liveness_broadcast_sender()
+        .send(liveness)
+        .unwrap_or_default()
+}
+
+/// Get the last HR liveness tick.
+///
+/// Compare its timestamp with the current time to tell whether the HR
+/// stream is alive. Returns `None` if no HR task has run since startup.
+pub fn get_hr_liveness() -> Option<ApiHrLiveness> {
+    LATEST_VALUES
+        .get()
+        .and_then(|m| m.lock().ok())
+        .and_then(|latest| latest.hr_liveness.clone())
+}
+
+/// Get the time (Unix ms) the HR task last polled the adapter.
+pub fn hr_liveness_timestamp(liveness: &ApiHrLiveness) -> u64 {
+    liveness.timestamp
+}
+
+/// Get the time (Unix ms) of the last HR sample, if any arrived.
+pub fn hr_liveness_last_hr_timestamp(liveness: &ApiHrLiveness) -> Option<u64> {
+    liveness.last_hr_timestamp
+}
+
+/// Create a stream for receiving composite connection health updates.
+///
+/// While a device is connected, an [`ApiConnectionHealth`] combining RSSI,
+/// HR dropout rate and battery level is emitted every few seconds. Read it
+/// with [`connection_health_level`] and the other accessors.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the connection health data
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+pub async fn create_connection_health_stream(sink: StreamSink<ApiConnectionHealth>) -> Result<()> {
+    let mut rx = subscribe_connection_health_stream();
+    tokio::spawn(async move {
+        while let Ok(health) = rx.recv().await {
+            sink.add(health).ok();
+        }
+    });
+    Ok(())
+}
+
+/// Subscribe to the composite connection health stream.
+///
+/// While a device is connected, an [`ApiConnectionHealth`] combining RSSI,
+/// HR dropout rate and battery level is emitted every few seconds.
+#[frb(ignore)]
+pub fn subscribe_connection_health_stream() -> broadcast::Receiver<ApiConnectionHealth> {
+    get_or_create_connection_health_broadcast_sender().subscribe()
+}
+
+/// Get or create the global connection health broadcast sender.
+fn get_or_create_connection_health_broadcast_sender() -> broadcast::Sender<ApiConnectionHealth> {
+    static CONNECTION_HEALTH_TX: OnceLock<broadcast::Sender<ApiConnectionHealth>> = OnceLock::new();
+
+    CONNECTION_HEALTH_TX
+        .get_or_init(|| {
+            let (tx, _rx) = broadcast::channel(CONNECTION_HEALTH_CHANNEL_CAPACITY);
+            tx
+        })
+        .clone()
+}
+
+/// Emit a connection health update to all stream subscribers.
+///
+/// # Returns
+///
+/// The number of receivers that received the update.
+pub fn emit_connection_health(health: ApiConnectionHealth) -> usize {
+    get_or_create_connection_health_broadcast_sender()
+        .send(health)
+        .unwrap_or_default()
+}
+
+/// Spawn the battery monitor for a connected device.
+///
+/// Polls the battery level through the platform adapter and emits each
+/// reading on the battery broadcast stream. The task ends when polling stops
+/// or it is aborted on disconnect.
+fn spawn_battery_monitor(
+    adapter: Arc<BtleplugAdapter>,
+    cancel_token: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let (battery_tx, mut battery_rx) = tokio::sync::mpsc::channel(10);
+        let notification_port = notification_router();
+
+        // Start battery polling task
+        let poll_result = adapter
+            .start_battery_polling(battery_tx, notification_port)
+            .await;
+
+        match poll_result {
+            Ok(poll_handle) => {
+                // Receive battery updates and emit to broadcast channel
+                loop {
+                    let battery_level = tokio::select! {
+                        biased;
+                        _ = cancel_token.cancelled() => break,
+                        update = battery_rx.recv() => match update {
+                            Some(level) => level,
+                            None => {
+                                tracing::warn!("Battery polling stream ended");
+                                break;
+                            }
+                        },
+                    };
+
+                    // Convert domain BatteryLevel to API BatteryLevel
+                    let api_battery = ApiBatteryLevel {
+                        level: battery_level.level,
+                        is_charging: battery_level.is_charging,
+                        timestamp: clock().millis_at(arrival_instant(
+                            battery_level.timestamp,
+                            std::time::SystemTime::now(),
+                            std::time::Instant::now(),
+                        )),
+                    };
+
+                    let receivers = emit_battery_data(api_battery);
+                    tracing::debug!("Emitted battery data to {} receivers", receivers);
+                }
+
+                // Stop polling once nobody receives the updates
+                poll_handle.abort();
+            }
+            Err(e) => {
+                tracing::error!("Failed to start battery polling: {}", e);
+            }
+        }
+    })
+}
+
+/// Spawn the connection health monitor for a connected device.
+///
+/// Once per second the task checks the HR and battery broadcast streams to
+/// track missed samples and the latest battery level. Every
+/// `CONNECTION_HEALTH_INTERVAL_SECS` it reads the RSSI and emits the composite
+/// health. The task ends when the HR stream closes or `cancel_token` is
+/// cancelled on disconnect.
+fn spawn_connection_health_monitor(
+    adapter: Arc<dyn BleAdapter + Send + Sync>,
+    cancel_token: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    use crate::domain::connection_health::{assess_connection_health, DropoutCounter};
+
+    let mut hr_rx = subscribe_hr_stream();
+    let mut battery_rx = subscribe_battery_stream();
+
+    tokio::spawn(async move {
+        let mut dropouts = DropoutCounter::new(CONNECTION_HEALTH_DROPOUT_WINDOW);
+        let mut battery: Option<u8> = None;
+        // Allow for strap jitter: only count a miss when the gap exceeds 1.5s
+        let miss_after = Duration::from_millis(1500);
+        let mut last_sample = tokio::time::Instant::now();
+        let mut ticker = tokio::time::interval(Duration::from_secs(1));
+        ticker.tick().await; // First tick completes immediately, skip it
+        let mut tick_count = 0u32;
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = cancel_token.cancelled() => return,
+                _ = ticker.tick() => {}
+            }
+
+            loop {
+                match hr_rx.try_recv() {
+                    Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => {
+                        last_sample = tokio::time::Instant::now();
+                    }
+                    Err(broadcast::error::TryRecvError::Empty) => break,
+                    Err(broadcast::error::TryRecvError::Closed) => return,
+                }
+            }
+            dropouts.record(last_sample.elapsed() <= miss_after);
+
+            while let Ok(update) = battery_rx.try_recv() {
+                if update.level.is_some() {
+                    battery = update.level;
+                }
+            }
+
+            tick_count += 1;
+            if tick_count.is_multiple_of(CONNECTION_HEALTH_INTERVAL_SECS) {
+                let rssi = adapter.read_rssi().await.unwrap_or(None);
+                let health = assess_connection_health(rssi, dropouts.rate(), battery);
+                tracing::debug!("Connection health: {:?}", health);
+                emit_connection_health(health);
+            }
+        }
+    })
+}
+
+/// Get the composite level from a connection health update.
+pub fn connection_health_level(health: &ApiConnectionHealth) -> ApiHealthLevel {
+    health.level
+}
+
+/// Get the RSSI (dBm) from a connection health update, if known.
+pub fn connection_health_rssi(health: &ApiConnectionHealth) -> Option<i16> {
+    health.rssi
+}
+
+/// Get the HR dropout rate (0.0 to 1.0) from a connection health update.
+pub fn connection_health_dropout_rate(health: &ApiConnectionHealth) -> f64 {
+    health.dropout_rate
+}
+
+/// Get the battery percentage from a connection health update, if known.
+pub fn connection_health_battery(health: &ApiConnectionHealth) -> Option<u8> {
+    health.battery
+}
+
 /// Subscribe to the coaching cue stream.
 fn subscribe_coaching_cue_stream() -> broadcast::Receiver<ApiCue> {
     let tx = get_or_create_coaching_cue_broadcast_sender();
@@ -1768,6 +3343,20 @@ pub fn connection_status_failure_reason(status: &ApiConnectionStatus) -> Option<
     }
 }
 
+/// Check if the status reports reconnection suspended by the circuit breaker.
+pub fn connection_status_is_circuit_open(status: &ApiConnectionStatus) -> bool {
+    matches!(status, ApiConnectionStatus::CircuitOpen { .. })
+}
+
+/// Get the seconds until a probe is allowed from a CircuitOpen status.
+/// Returns None if the status is not CircuitOpen.
+pub fn connection_status_retry_after_secs(status: &ApiConnectionStatus) -> Option<u64> {
+    match status {
+        ApiConnectionStatus::CircuitOpen { retry_after_secs } => Some(*retry_after_secs),
+        _ => None,
+    }
+}
+
 /// Convert connection status to a human-readable string.
 pub fn connection_status_to_string(status: &ApiConnectionStatus) -> String {
     match status {
@@ -1781,6 +3370,9 @@ pub fn connection_status_to_string(status: &ApiConnectionStatus) -> String {
         ApiConnectionStatus::ReconnectFailed { reason } => {
             format!("Connection failed: {}", reason)
         }
+        ApiConnectionStatus::CircuitOpen { retry_after_secs } => {
+            format!("Reconnection paused (retry in {}s)", retry_after_secs)
+        }
     }
 }
 
@@ -1794,6 +3386,11 @@ pub fn emit_cue(cue: crate::coaching::Cue) -> usize {
 static SESSION_REPOSITORY: OnceLock<tokio::sync::Mutex<Option<Arc<FileSessionRepository>>>> =
     OnceLock::new();
 
+// Session repositories for namespaced profiles, keyed by sessions directory
+static NAMESPACE_SESSION_REPOSITORIES: OnceLock<
+    tokio::sync::Mutex<std::collections::HashMap<std::path::PathBuf, Arc<FileSessionRepository>>>,
+> = OnceLock::new();
+
 // Global session executor for workout execution
 static SESSION_EXECUTOR: OnceLock<
     tokio::sync::Mutex<Option<crate::scheduler::executor::SessionExecutor>>,
@@ -1812,11 +3409,46 @@ async fn get_session_repository() -> Result<Arc<FileSessionRepository>> {
     let data_dir = get_data_dir()?;
     let sessions_dir = data_dir.join("sessions");
     tracing::info!("Creating FileSessionRepository at {:?}", sessions_dir);
-    let repo = Arc::new(FileSessionRepository::with_directory(sessions_dir).await?);
+    let repo = Arc::new(
+        FileSessionRepository::with_directory(sessions_dir)
+            .await?
+            .with_write_coalescing(SESSION_SAVE_COALESCE_WINDOW),
+    );
     *guard = Some(repo.clone());
     Ok(repo)
 }
 
+/// Get or create the session repository for an optional profile namespace.
+///
+/// `None` returns the global repository from [`get_session_repository`].
+async fn get_session_repository_for(namespace: Option<&str>) -> Result<Arc<FileSessionRepository>> {
+    let Some(namespace) = namespace else {
+        return get_session_repository().await;
+    };
+
+    let sessions_dir = get_data_dir_for(Some(namespace))?.join("sessions");
+    let mutex = NAMESPACE_SESSION_REPOSITORIES
+        .get_or_init(|| tokio::sync::Mutex::new(std::collections::HashMap::new()));
+    let mut guard = mutex.lock().await;
+
+    if let Some(repo) = guard.get(&sessions_dir) {
+        return Ok(repo.clone());
+    }
+
+    tracing::info!(
+        "Creating FileSessionRepository for profile '{}' at {:?}",
+        namespace,
+        sessions_dir
+    );
+    let repo = Arc::new(
+        FileSessionRepository::with_directory(sessions_dir.clone())
+            .await?
+            .with_write_coalescing(SESSION_SAVE_COALESCE_WINDOW),
+    );
+    guard.insert(sessions_dir, repo.clone());
+    Ok(repo)
+}
+
 /// Get or create the global HR store instance for persisting samples to JSONL.
 async fn get_hr_store() -> Result<Arc<HrStore>> {
     let mutex = HR_STORE.get_or_init(|| tokio::sync::Mutex::new(None));
@@ -1837,22 +3469,25 @@ async fn get_hr_store() -> Result<Arc<HrStore>> {
 
 /// Returns all HR samples with timestamps in [start_ms, end_ms] across all daily files.
 /// Samples are returned in chronological order.
-pub async fn samples_in_range(start_ms: u64, end_ms: u64) -> Result<Vec<ApiSample>> {
+pub async fn samples_in_range(
+    start_ms: u64,
+    end_ms: u64,
+) -> std::result::Result<Vec<ApiSample>, ApiError> {
     let store = get_hr_store().await?;
-    store.samples_in_range(start_ms, end_ms).await
+    Ok(store.samples_in_range(start_ms, end_ms).await?)
 }
 
 /// Computes the rolling average BPM over the given window (in seconds) ending at the latest sample.
 /// Returns `None` if the store is empty or no samples fall within the window.
-pub async fn rolling_avg(window_secs: u64) -> Result<Option<f32>> {
+pub async fn rolling_avg(window_secs: u64) -> std::result::Result<Option<f32>, ApiError> {
     let store = get_hr_store().await?;
-    store.rolling_avg(window_secs).await
+    Ok(store.rolling_avg(window_secs).await?)
 }
 
 /// Returns the most recent HR sample, or `None` if the store is empty.
-pub async fn latest_sample() -> Result<Option<ApiSample>> {
+pub async fn latest_sample() -> std::result::Result<Option<ApiSample>, ApiError> {
     let store = get_hr_store().await?;
-    store.latest_sample().await
+    Ok(store.latest_sample().await?)
 }
 
 /// Returns the BPM of an HR sample.
@@ -1886,14 +3521,94 @@ pub fn samples_bpm_and_ts(samples: &[ApiSample]) -> Vec<(u16, u64)> {
 ///
 /// Returns an error if the sessions directory cannot be read or if the repository
 /// cannot be initialized.
-pub async fn list_sessions() -> Result<Vec<ApiSessionSummaryPreview>> {
+pub async fn list_sessions() -> std::result::Result<Vec<ApiSessionSummaryPreview>, ApiError> {
+    list_sessions_for(None).await
+}
+
+/// List all completed training sessions of a profile.
+///
+/// Same as [`list_sessions`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn list_sessions_for(
+    namespace: Option<String>,
+) -> std::result::Result<Vec<ApiSessionSummaryPreview>, ApiError> {
     tracing::info!("list_sessions: Listing all sessions");
-    let repo = get_session_repository().await?;
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
     let previews = repo.list().await?;
     tracing::info!("list_sessions: Found {} sessions", previews.len());
     Ok(previews)
 }
 
+/// Search completed sessions by plan name.
+///
+/// Matches `query` case-insensitively against each session's plan name and
+/// returns the matching previews, most recent first. Like [`list_sessions`],
+/// no heart rate samples are loaded. An empty query returns every session.
+///
+/// # Errors
+///
+/// Returns an error if the sessions directory cannot be read or if the repository
+/// cannot be initialized.
+pub async fn search_sessions(
+    query: String,
+) -> std::result::Result<Vec<ApiSessionSummaryPreview>, ApiError> {
+    search_sessions_for(query, None).await
+}
+
+/// Search completed sessions of a profile by plan name.
+///
+/// Same as [`search_sessions`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn search_sessions_for(
+    query: String,
+    namespace: Option<String>,
+) -> std::result::Result<Vec<ApiSessionSummaryPreview>, ApiError> {
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let previews = repo.search(&query).await?;
+    tracing::info!(
+        "search_sessions: {} sessions match {:?}",
+        previews.len(),
+        query
+    );
+    Ok(previews)
+}
+
+/// Check that every stored session can be read back.
+///
+/// Returns a report for each unreadable session file (for example one left
+/// behind by a partial write). Such files are already skipped by
+/// [`list_sessions`]; with `quarantine` set they are also moved into the
+/// `corrupt/` subdirectory of the sessions directory so they stop showing up
+/// in batch exports.
+///
+/// # Errors
+///
+/// Returns an error if the sessions directory cannot be read or if the
+/// repository cannot be initialized.
+pub async fn verify_sessions(
+    quarantine: bool,
+) -> std::result::Result<Vec<ApiRepairReport>, ApiError> {
+    verify_sessions_for(quarantine, None).await
+}
+
+/// Check that every stored session of a profile can be read back.
+///
+/// Same as [`verify_sessions`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn verify_sessions_for(
+    quarantine: bool,
+    namespace: Option<String>,
+) -> std::result::Result<Vec<ApiRepairReport>, ApiError> {
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let reports = if quarantine {
+        repo.verify_and_quarantine().await?
+    } else {
+        repo.verify().await?
+    };
+    tracing::info!("verify_sessions: {} unreadable sessions", reports.len());
+    Ok(reports)
+}
+
 /// Get a complete session by its ID.
 ///
 /// Loads the full session data including all heart rate samples and statistics.
@@ -1911,9 +3626,20 @@ pub async fn list_sessions() -> Result<Vec<ApiSessionSummaryPreview>> {
 ///
 /// Returns an error if the session file cannot be read or parsed, or if the
 /// repository cannot be initialized.
-pub async fn get_session(id: String) -> Result<Option<ApiCompletedSession>> {
-    tracing::info!("get_session: Getting session with id: {}", id);
-    let repo = get_session_repository().await?;
+pub async fn get_session(id: String) -> std::result::Result<Option<ApiCompletedSession>, ApiError> {
+    get_session_for(id, None).await
+}
+
+/// Get a complete session of a profile by its ID.
+///
+/// Same as [`get_session`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn get_session_for(
+    id: String,
+    namespace: Option<String>,
+) -> std::result::Result<Option<ApiCompletedSession>, ApiError> {
+    tracing::info!("get_session: Getting session with id: {}", id);
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
     let session = repo.get(&id).await?;
 
     if session.is_some() {
@@ -1925,6 +3651,96 @@ pub async fn get_session(id: String) -> Result<Option<ApiCompletedSession>> {
     Ok(session)
 }
 
+/// Record the temperature, perceived exertion and conditions of a session.
+///
+/// Loads the session, replaces its context and saves it back, so the values
+/// can later be correlated with its heart rate data.
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the session
+/// * `temperature_c` - Ambient temperature in degrees Celsius, if known
+/// * `rpe` - Rating of perceived exertion on the CR10 scale (0-10)
+/// * `conditions` - Free-form description of the conditions
+///
+/// # Errors
+///
+/// Returns an error if `rpe` is above 10, the session does not exist, or it
+/// cannot be saved.
+pub async fn set_session_context(
+    id: String,
+    temperature_c: Option<f64>,
+    rpe: u8,
+    conditions: Option<String>,
+) -> std::result::Result<(), ApiError> {
+    set_session_context_for(id, temperature_c, rpe, conditions, None).await
+}
+
+/// Record the temperature, perceived exertion and conditions of a session of a profile.
+///
+/// Same as [`set_session_context`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn set_session_context_for(
+    id: String,
+    temperature_c: Option<f64>,
+    rpe: u8,
+    conditions: Option<String>,
+    namespace: Option<String>,
+) -> std::result::Result<(), ApiError> {
+    if rpe > MAX_RPE {
+        return Err(anyhow!("RPE must be between 0 and {}, got {}", MAX_RPE, rpe).into());
+    }
+
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let mut session = repo
+        .get(&id)
+        .await?
+        .ok_or_else(|| SessionError::NotFound(id.clone()))?;
+    session.context = Some(SessionContextMeta {
+        temperature_c,
+        rpe,
+        conditions: conditions.filter(|c| !c.trim().is_empty()),
+    });
+    repo.save(&session).await?;
+    repo.flush().await?;
+
+    tracing::info!("set_session_context: Updated context of session {}", id);
+    Ok(())
+}
+
+/// Score (0-100) how well a session hit the zone distribution of its plan.
+///
+/// Loads the session and the plan it was run from (by name) and compares the
+/// time spent in each zone with the plan's target time per zone (see
+/// [`crate::domain::session_history::adherence_score`]).
+///
+/// # Errors
+///
+/// Returns an error if the session does not exist or its plan cannot be loaded.
+pub async fn session_adherence(session_id: String) -> std::result::Result<f64, ApiError> {
+    session_adherence_for(session_id, None).await
+}
+
+/// Score how well a session of a profile hit the zone distribution of its plan.
+///
+/// Same as [`session_adherence`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn session_adherence_for(
+    session_id: String,
+    namespace: Option<String>,
+) -> std::result::Result<f64, ApiError> {
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let session = repo
+        .get(&session_id)
+        .await?
+        .ok_or_else(|| SessionError::NotFound(session_id.clone()))?;
+    let plan = load_plan_for(&session.plan_name, namespace.as_deref()).await?;
+
+    Ok(crate::domain::session_history::adherence_score(
+        &session, &plan,
+    ))
+}
+
 /// Delete a session by its ID.
 ///
 /// Permanently removes the session and all its data from storage.
@@ -1937,9 +3753,20 @@ pub async fn get_session(id: String) -> Result<Option<ApiCompletedSession>> {
 ///
 /// Returns an error if the session file cannot be deleted or if the repository
 /// cannot be initialized. Succeeds silently if the session doesn't exist.
-pub async fn delete_session(id: String) -> Result<()> {
+pub async fn delete_session(id: String) -> std::result::Result<(), ApiError> {
+    delete_session_for(id, None).await
+}
+
+/// Delete a session of a profile by its ID.
+///
+/// Same as [`delete_session`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn delete_session_for(
+    id: String,
+    namespace: Option<String>,
+) -> std::result::Result<(), ApiError> {
     tracing::info!("delete_session: Deleting session with id: {}", id);
-    let repo = get_session_repository().await?;
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
     repo.delete(&id).await?;
     tracing::info!("delete_session: Successfully deleted session {}", id);
     Ok(())
@@ -1977,19 +3804,89 @@ pub async fn delete_session(id: String) -> Result<()> {
 /// # Ok::<(), anyhow::Error>(())
 /// # });
 /// ```
-pub async fn export_session(id: String, format: ExportFormat) -> Result<String> {
+pub async fn export_session(
+    id: String,
+    format: ExportFormat,
+) -> std::result::Result<String, ApiError> {
+    export_session_for(id, format, None).await
+}
+
+/// Export a session of a profile to a specified format.
+///
+/// Same as [`export_session`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_session_for(
+    id: String,
+    format: ExportFormat,
+    namespace: Option<String>,
+) -> std::result::Result<String, ApiError> {
+    let options = crate::domain::ExportOptions::default();
+    Ok(export_session_with(id, format, &options, namespace.as_deref()).await?)
+}
+
+/// Export a completed session with local timestamps.
+///
+/// Like [`export_session`], but CSV and summary exports render their
+/// timestamps at the given UTC offset (e.g. `540` for JST, `-300` for EST).
+/// JSON exports stay in UTC for interchange.
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the session to export
+/// * `format` - The desired export format (Csv, Json, or Summary)
+/// * `utc_offset_minutes` - Offset of the local timezone from UTC in minutes
+///
+/// # Errors
+///
+/// Returns an error if the offset is not within ±24 hours, or for any reason
+/// [`export_session`] fails.
+pub async fn export_session_local(
+    id: String,
+    format: ExportFormat,
+    utc_offset_minutes: i32,
+) -> std::result::Result<String, ApiError> {
+    export_session_local_for(id, format, utc_offset_minutes, None).await
+}
+
+/// Export a completed session of a profile with local timestamps.
+///
+/// Same as [`export_session_local`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_session_local_for(
+    id: String,
+    format: ExportFormat,
+    utc_offset_minutes: i32,
+    namespace: Option<String>,
+) -> std::result::Result<String, ApiError> {
+    let timezone = chrono::FixedOffset::east_opt(utc_offset_minutes.saturating_mul(60))
+        .ok_or_else(|| anyhow!("Invalid UTC offset: {} minutes", utc_offset_minutes))?;
+    let options = crate::domain::ExportOptions::default().with_timezone(timezone);
+    Ok(export_session_with(id, format, &options, namespace.as_deref()).await?)
+}
+
+async fn export_session_with(
+    id: String,
+    format: ExportFormat,
+    options: &crate::domain::ExportOptions,
+    namespace: Option<&str>,
+) -> Result<String> {
     tracing::info!("export_session: Exporting session {} as {:?}", id, format);
 
-    let repo = get_session_repository().await?;
+    let repo = get_session_repository_for(namespace).await?;
     let session = repo
         .get(&id)
         .await?
-        .ok_or_else(|| anyhow!("Session not found: {}", id))?;
+        .ok_or_else(|| SessionError::NotFound(id.clone()))?;
 
     let content = match format {
-        ExportFormat::Csv => crate::domain::export_to_csv(&session),
-        ExportFormat::Json => crate::domain::export_to_json(&session),
-        ExportFormat::Summary => crate::domain::export_to_summary(&session),
+        ExportFormat::Csv => crate::domain::export_to_csv_with(&session, options),
+        ExportFormat::Json => crate::domain::export_to_json_with(&session, options),
+        ExportFormat::Summary => crate::domain::export_to_summary_with(&session, options),
+        ExportFormat::Binary => {
+            return Err(anyhow!(
+                "Binary exports are not text. Use export_session_bytes instead"
+            ))
+        }
     };
 
     tracing::info!(
@@ -2001,6 +3898,409 @@ pub async fn export_session(id: String, format: ExportFormat) -> Result<String>
     Ok(content)
 }
 
+/// Export a completed session as bytes.
+///
+/// Unlike [`export_session`], this supports [`ExportFormat::Binary`], the
+/// compact layout intended for syncing to a companion service. Text formats
+/// are returned as their UTF-8 bytes.
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the session to export
+/// * `format` - The desired export format
+///
+/// # Errors
+///
+/// Returns an error if the session cannot be found or the session repository
+/// cannot be initialized.
+pub async fn export_session_bytes(
+    id: String,
+    format: ExportFormat,
+) -> std::result::Result<Vec<u8>, ApiError> {
+    export_session_bytes_for(id, format, None).await
+}
+
+/// Export a completed session of a profile as bytes.
+///
+/// Same as [`export_session_bytes`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_session_bytes_for(
+    id: String,
+    format: ExportFormat,
+    namespace: Option<String>,
+) -> std::result::Result<Vec<u8>, ApiError> {
+    if !matches!(format, ExportFormat::Binary) {
+        return Ok(export_session_for(id, format, namespace)
+            .await?
+            .into_bytes());
+    }
+
+    tracing::info!("export_session_bytes: Exporting session {} as binary", id);
+
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let session = repo
+        .get(&id)
+        .await?
+        .ok_or_else(|| SessionError::NotFound(id.clone()))?;
+
+    let content = crate::domain::export_to_binary(&session);
+
+    tracing::info!(
+        "export_session_bytes: Successfully exported session {} ({} bytes)",
+        id,
+        content.len()
+    );
+
+    Ok(content)
+}
+
+/// Progress of a file export started with [`export_session_to_file`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ApiExportProgress {
+    /// ID of the session being exported.
+    pub session_id: String,
+    /// Bytes written to the output file so far.
+    pub bytes_written: u64,
+    /// Heart rate samples written so far (equals `total_samples` when done).
+    ///
+    /// Only CSV exports count samples as they go; other formats report 0 until
+    /// done and track progress through `bytes_written`.
+    pub samples_written: u32,
+    /// Total number of heart rate samples in the session.
+    pub total_samples: u32,
+    /// Whether the export finished and the file is complete.
+    pub done: bool,
+}
+
+/// Export a completed session directly to a file.
+///
+/// Streams the export to disk using the writer-based exporters instead of
+/// building the whole document in memory and passing it across the FFI
+/// boundary. Progress is published on the export progress broadcast stream
+/// (see [`subscribe_export_progress_stream`]). Output is written to `<path>.part` and
+/// renamed into place once complete, so an interrupted export never leaves a
+/// truncated file at `path`.
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the session to export
+/// * `format` - The export format (CSV, JSON, or Summary)
+/// * `path` - Destination file path
+///
+/// # Returns
+///
+/// The number of bytes written.
+///
+/// # Errors
+///
+/// Returns an error if the session cannot be found or the file cannot be written.
+pub async fn export_session_to_file(
+    id: String,
+    format: ExportFormat,
+    path: String,
+) -> std::result::Result<u64, ApiError> {
+    export_session_to_file_for(id, format, path, None).await
+}
+
+/// Export a completed session of a profile directly to a file.
+///
+/// Same as [`export_session_to_file`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_session_to_file_for(
+    id: String,
+    format: ExportFormat,
+    path: String,
+    namespace: Option<String>,
+) -> std::result::Result<u64, ApiError> {
+    tracing::info!(
+        "export_session_to_file: Exporting session {} as {:?} to {}",
+        id,
+        format,
+        path
+    );
+
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let session = repo
+        .get(&id)
+        .await?
+        .ok_or_else(|| SessionError::NotFound(id.clone()))?;
+
+    let bytes = tokio::task::spawn_blocking(move || {
+        write_session_to_file(&session, format, std::path::Path::new(&path))
+    })
+    .await
+    .map_err(|e| anyhow!("Export task failed: {}", e))??;
+
+    tracing::info!(
+        "export_session_to_file: Successfully exported session {} ({} bytes)",
+        id,
+        bytes
+    );
+
+    Ok(bytes)
+}
+
+/// A session that could not be exported by [`export_all_sessions`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ApiSessionExportError {
+    /// ID of the session that failed.
+    pub session_id: String,
+    /// Why the export failed.
+    pub message: String,
+}
+
+/// Outcome of exporting every stored session with [`export_all_sessions`].
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ApiBatchExportResult {
+    /// Number of sessions written successfully.
+    pub exported: u32,
+    /// Sessions that could not be exported.
+    pub errors: Vec<ApiSessionExportError>,
+}
+
+/// Export every stored session to a directory, one file per session.
+///
+/// Files are named `<session id>.<ext>` (`csv`, `json` or `txt`). A session
+/// that cannot be read or written is recorded in the result's `errors` and the
+/// remaining sessions are still exported.
+///
+/// # Arguments
+///
+/// * `format` - The export format (CSV, JSON, or Summary)
+/// * `output_dir` - Destination directory, created if missing
+///
+/// # Errors
+///
+/// Returns an error only if the output directory cannot be created or the
+/// session list cannot be read.
+pub async fn export_all_sessions(
+    format: ExportFormat,
+    output_dir: String,
+) -> std::result::Result<ApiBatchExportResult, ApiError> {
+    export_all_sessions_for(format, output_dir, None).await
+}
+
+/// Export every stored session of a profile to a directory.
+///
+/// Same as [`export_all_sessions`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_all_sessions_for(
+    format: ExportFormat,
+    output_dir: String,
+    namespace: Option<String>,
+) -> std::result::Result<ApiBatchExportResult, ApiError> {
+    tracing::info!(
+        "export_all_sessions: Exporting all sessions as {:?} to {}",
+        format,
+        output_dir
+    );
+
+    let output_dir = std::path::PathBuf::from(output_dir);
+    tokio::fs::create_dir_all(&output_dir)
+        .await
+        .map_err(|e| anyhow!("Failed to create '{}': {}", output_dir.display(), e))?;
+
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let ids = repo.list_ids().await?;
+
+    let mut result = ApiBatchExportResult {
+        exported: 0,
+        errors: Vec::new(),
+    };
+
+    for id in ids {
+        let path = output_dir.join(format!("{}.{}", id, format.file_extension()));
+        let outcome = match repo.get(&id).await {
+            Ok(Some(session)) => {
+                tokio::task::spawn_blocking(move || write_session_to_file(&session, format, &path))
+                    .await
+                    .map_err(|e| anyhow!("Export task failed: {}", e))
+                    .and_then(|written| written)
+            }
+            Ok(None) => Err(SessionError::NotFound(id.clone()).into()),
+            Err(e) => Err(e),
+        };
+
+        match outcome {
+            Ok(_) => result.exported += 1,
+            Err(e) => {
+                tracing::warn!("export_all_sessions: Failed to export {}: {:#}", id, e);
+                result.errors.push(ApiSessionExportError {
+                    session_id: id,
+                    message: format!("{:#}", e),
+                });
+            }
+        }
+    }
+
+    tracing::info!(
+        "export_all_sessions: Exported {} sessions ({} failed)",
+        result.exported,
+        result.errors.len()
+    );
+
+    Ok(result)
+}
+
+/// Create a stream for receiving file export progress.
+///
+/// An [`ApiExportProgress`] is emitted when an export started with
+/// [`export_session_to_file`] or [`export_all_sessions`] begins, periodically
+/// while it is written, and once more with `done` set when the file is complete.
+///
+/// # Arguments
+///
+/// * `sink` - The FRB StreamSink that will receive the export progress
+///
+/// # Returns
+///
+/// Returns Ok(()) if the stream was successfully set up.
+pub async fn create_export_progress_stream(sink: StreamSink<ApiExportProgress>) -> Result<()> {
+    let mut rx = subscribe_export_progress_stream();
+    tokio::spawn(async move {
+        while let Ok(progress) = rx.recv().await {
+            sink.add(progress).ok();
+        }
+    });
+    Ok(())
+}
+
+/// Subscribe to the export progress stream.
+#[frb(ignore)]
+pub fn subscribe_export_progress_stream() -> broadcast::Receiver<ApiExportProgress> {
+    get_or_create_export_progress_broadcast_sender().subscribe()
+}
+
+/// Get or create the global export progress broadcast sender.
+fn get_or_create_export_progress_broadcast_sender() -> broadcast::Sender<ApiExportProgress> {
+    static EXPORT_PROGRESS_TX: OnceLock<broadcast::Sender<ApiExportProgress>> = OnceLock::new();
+
+    EXPORT_PROGRESS_TX
+        .get_or_init(|| {
+            let (tx, _rx) = broadcast::channel(EXPORT_PROGRESS_CHANNEL_CAPACITY);
+            tx
+        })
+        .clone()
+}
+
+/// Emit export progress to all stream subscribers.
+fn emit_export_progress(progress: ApiExportProgress) -> usize {
+    get_or_create_export_progress_broadcast_sender()
+        .send(progress)
+        .unwrap_or_default()
+}
+
+/// Writer adapter that counts the bytes passed through it.
+///
+/// `on_write` is called after every write with the byte counts before and
+/// after it.
+struct CountingWriter<'a, W: Write, F: FnMut(u64, u64)> {
+    inner: W,
+    bytes: &'a std::cell::Cell<u64>,
+    on_write: F,
+}
+
+impl<W: Write, F: FnMut(u64, u64)> Write for CountingWriter<'_, W, F> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        let before = self.bytes.get();
+        self.bytes.set(before + n as u64);
+        (self.on_write)(before, before + n as u64);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Stream a session export to `path`, emitting progress along the way.
+fn write_session_to_file(
+    session: &ApiCompletedSession,
+    format: ExportFormat,
+    path: &std::path::Path,
+) -> Result<u64> {
+    let mut part_path = path.as_os_str().to_owned();
+    part_path.push(".part");
+    let part_path = std::path::PathBuf::from(part_path);
+
+    let file = std::fs::File::create(&part_path)
+        .map_err(|e| anyhow!("Failed to create '{}': {}", part_path.display(), e))?;
+
+    let bytes = std::cell::Cell::new(0u64);
+    let total_samples = session.hr_samples.len() as u32;
+    let emit = |samples_written: u32, done: bool| {
+        emit_export_progress(ApiExportProgress {
+            session_id: session.id.clone(),
+            bytes_written: bytes.get(),
+            samples_written,
+            total_samples,
+            done,
+        });
+    };
+
+    // CSV reports progress per sample; the other formats by bytes written
+    let by_bytes = !matches!(format, ExportFormat::Csv);
+    emit(0, false);
+
+    let mut writer = CountingWriter {
+        inner: std::io::BufWriter::new(file),
+        bytes: &bytes,
+        on_write: |before: u64, after: u64| {
+            if by_bytes
+                && before / EXPORT_PROGRESS_BYTE_INTERVAL != after / EXPORT_PROGRESS_BYTE_INTERVAL
+            {
+                emit(0, false);
+            }
+        },
+    };
+    let result = match format {
+        ExportFormat::Csv => crate::domain::write_csv(session, &mut writer, |n| {
+            if n % EXPORT_PROGRESS_SAMPLE_INTERVAL == 0 {
+                emit(n as u32, false);
+            }
+        }),
+        ExportFormat::Json => crate::domain::write_json(session, &mut writer),
+        ExportFormat::Summary => crate::domain::write_summary(session, &mut writer),
+        ExportFormat::Binary => writer.write_all(&crate::domain::export_to_binary(session)),
+    }
+    .and_then(|_| writer.flush());
+    drop(writer);
+
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&part_path);
+        return Err(anyhow!("Failed to write '{}': {}", part_path.display(), e));
+    }
+
+    std::fs::rename(&part_path, path)
+        .map_err(|e| anyhow!("Failed to move export to '{}': {}", path.display(), e))?;
+
+    emit(total_samples, true);
+    Ok(bytes.get())
+}
+
+// Accessor functions for RepairReport (opaque type)
+
+/// Get the session ID of a repair report, if it is known.
+pub fn repair_report_session_id(report: &ApiRepairReport) -> Option<String> {
+    report.id.clone()
+}
+
+/// Get where the unreadable session is stored.
+pub fn repair_report_location(report: &ApiRepairReport) -> String {
+    report.location.clone()
+}
+
+/// Get why the session could not be read.
+pub fn repair_report_error(report: &ApiRepairReport) -> String {
+    report.error.clone()
+}
+
+/// Check if the session was moved into the quarantine directory.
+pub fn repair_report_quarantined(report: &ApiRepairReport) -> bool {
+    report.quarantined
+}
+
 // Accessor functions for SessionSummaryPreview (opaque type)
 
 /// Get the session ID from a session summary preview
@@ -2065,11 +4365,51 @@ pub fn session_phases_completed(session: &ApiCompletedSession) -> u32 {
     session.phases_completed
 }
 
+/// Get, per completed phase, whether HR stayed in its target zone
+///
+/// Empty for sessions saved before phase results were recorded.
+pub fn session_phase_targets_met(session: &ApiCompletedSession) -> Vec<bool> {
+    session.phase_results.iter().map(|r| r.target_met).collect()
+}
+
+/// Get how often the sensor reconnected during a completed session
+///
+/// `None` for sessions saved before the connection summary was recorded.
+pub fn session_reconnect_count(session: &ApiCompletedSession) -> Option<u32> {
+    session.connection_summary.map(|c| c.reconnect_count)
+}
+
+/// Get the seconds the sensor link was down during a completed session
+///
+/// `None` for sessions saved before the connection summary was recorded.
+pub fn session_total_dropout_secs(session: &ApiCompletedSession) -> Option<u32> {
+    session.connection_summary.map(|c| c.total_dropout_secs)
+}
+
+/// Get the weakest RSSI (dBm) seen during a completed session, if any was read
+pub fn session_worst_rssi(session: &ApiCompletedSession) -> Option<i16> {
+    session.connection_summary.and_then(|c| c.worst_rssi)
+}
+
 /// Get the duration in seconds from a completed session summary
 pub fn session_summary_duration_secs(session: &ApiCompletedSession) -> u32 {
     session.summary.duration_secs
 }
 
+/// Get the wall-clock duration in seconds, pauses included, from a completed session summary
+///
+/// Returns `None` for sessions saved before pause time was tracked.
+pub fn session_summary_elapsed_wall_secs(session: &ApiCompletedSession) -> Option<u32> {
+    session.summary.elapsed_wall_secs
+}
+
+/// Get the active duration in seconds, pauses excluded, from a completed session summary
+///
+/// Returns `None` for sessions saved before pause time was tracked.
+pub fn session_summary_active_duration_secs(session: &ApiCompletedSession) -> Option<u32> {
+    session.summary.active_duration_secs
+}
+
 /// Get the average heart rate from a completed session summary
 pub fn session_summary_avg_hr(session: &ApiCompletedSession) -> u16 {
     session.summary.avg_hr
@@ -2085,12 +4425,95 @@ pub fn session_summary_min_hr(session: &ApiCompletedSession) -> u16 {
     session.summary.min_hr
 }
 
+/// Get the median (50th percentile) heart rate from a completed session summary
+pub fn session_summary_hr_p50(session: &ApiCompletedSession) -> u16 {
+    session.summary.hr_p50
+}
+
+/// Get the 90th percentile heart rate from a completed session summary
+pub fn session_summary_hr_p90(session: &ApiCompletedSession) -> u16 {
+    session.summary.hr_p90
+}
+
+/// Get the 95th percentile heart rate from a completed session summary
+pub fn session_summary_hr_p95(session: &ApiCompletedSession) -> u16 {
+    session.summary.hr_p95
+}
+
 /// Get the time in zone array from a completed session summary
 /// Returns an array of 5 elements representing time spent in each zone (Zone1-Zone5) in seconds
 pub fn session_summary_time_in_zone(session: &ApiCompletedSession) -> Vec<u32> {
     session.summary.time_in_zone.to_vec()
 }
 
+/// Heart rate statistics for one zone of a completed session, for the FFI boundary.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ApiZoneHrStat {
+    /// Zone number (1-5).
+    pub zone: u8,
+    /// Lowest BPM recorded in the zone.
+    pub min_bpm: u16,
+    /// Mean BPM of the samples in the zone.
+    pub avg_bpm: u16,
+    /// Highest BPM recorded in the zone.
+    pub max_bpm: u16,
+}
+
+/// Get the min/avg/max heart rate within each zone from a completed session summary
+/// Only zones the session reached are returned, in zone order
+pub fn session_summary_zone_hr_stats(session: &ApiCompletedSession) -> Vec<ApiZoneHrStat> {
+    session
+        .summary
+        .zone_hr_stats
+        .iter()
+        .enumerate()
+        .filter_map(|(index, stat)| {
+            stat.map(|stat| ApiZoneHrStat {
+                zone: index as u8 + 1,
+                min_bpm: stat.min_bpm,
+                avg_bpm: stat.avg_bpm,
+                max_bpm: stat.max_bpm,
+            })
+        })
+        .collect()
+}
+
+/// Get the share of expected heart rate samples that were recorded, in percent (0-100)
+pub fn session_data_completeness_pct(session: &ApiCompletedSession) -> f64 {
+    session.data_completeness_pct()
+}
+
+/// Check if a completed session is a clean recording (few dropped samples)
+/// Sessions below 80% data completeness should be shown with a quality warning
+pub fn session_is_high_quality(session: &ApiCompletedSession) -> bool {
+    session.is_high_quality()
+}
+
+/// Get the BPM drop `window_secs` after the session's peak heart rate (use 60 for
+/// the standard one-minute recovery HR)
+/// Returns `None` if the session ended less than `window_secs` after its peak
+pub fn session_recovery_hr(session: &ApiCompletedSession, window_secs: u32) -> Option<u16> {
+    crate::domain::session_history::recovery_hr(session, window_secs)
+}
+
+/// Get the ambient temperature in °C recorded with a session
+/// Returns `None` if no context or no temperature was recorded
+pub fn session_temperature_c(session: &ApiCompletedSession) -> Option<f64> {
+    session.context.as_ref().and_then(|c| c.temperature_c)
+}
+
+/// Get the rating of perceived exertion (0-10) recorded with a session
+/// Returns `None` if no context was recorded
+pub fn session_rpe(session: &ApiCompletedSession) -> Option<u8> {
+    session.context.as_ref().map(|c| c.rpe)
+}
+
+/// Get the free-form conditions recorded with a session
+/// Returns `None` if no context or no conditions were recorded
+pub fn session_conditions(session: &ApiCompletedSession) -> Option<String> {
+    session.context.as_ref().and_then(|c| c.conditions.clone())
+}
+
 /// Get the number of heart rate samples in a completed session
 pub fn session_hr_samples_count(session: &ApiCompletedSession) -> usize {
     session.hr_samples.len()
@@ -2133,11 +4556,21 @@ pub fn session_hr_samples(session: &ApiCompletedSession) -> Vec<(i64, u16)> {
 ///
 /// Returns an error if the data directory cannot be determined or if there are
 /// issues reading the plans directory.
-pub async fn list_plans() -> Result<Vec<String>> {
+pub async fn list_plans() -> std::result::Result<Vec<String>, ApiError> {
+    list_plans_for(None).await
+}
+
+/// List all available training plans of a profile.
+///
+/// Same as [`list_plans`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn list_plans_for(
+    namespace: Option<String>,
+) -> std::result::Result<Vec<String>, ApiError> {
     tracing::info!("list_plans: Listing all training plans");
 
     // Get plans directory
-    let data_dir = get_data_dir()?;
+    let data_dir = get_data_dir_for(namespace.as_deref())?;
     let plans_dir = data_dir.join("plans");
 
     // Create directory if it doesn't exist
@@ -2167,23 +4600,118 @@ pub async fn list_plans() -> Result<Vec<String>> {
     Ok(plan_names)
 }
 
-/// Load a training plan by name from the plans directory.
+/// List all available training plans with their duration and difficulty.
 ///
-/// Internal helper function to load a plan from {data_dir}/plans/{name}.json
-async fn load_plan(name: &str) -> Result<TrainingPlan> {
-    let data_dir = get_data_dir()?;
-    let plans_dir = data_dir.join("plans");
-    let plan_path = plans_dir.join(format!("{}.json", name));
+/// Like [`list_plans`], but returns a [`ApiPlanSummary`] per plan so the UI
+/// can help the user pick a workout. Plans that fail to load are skipped.
+pub async fn list_plans_detailed() -> std::result::Result<Vec<ApiPlanSummary>, ApiError> {
+    list_plans_detailed_for(None).await
+}
+
+/// List all available training plans of a profile with their details.
+///
+/// Same as [`list_plans_detailed`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn list_plans_detailed_for(
+    namespace: Option<String>,
+) -> std::result::Result<Vec<ApiPlanSummary>, ApiError> {
+    let mut summaries = Vec::new();
+    for name in list_plans_for(namespace.clone()).await? {
+        match load_plan_for(&name, namespace.as_deref()).await {
+            // Report the name the plan is loaded by, in case the file was renamed
+            Ok(plan) => summaries.push(ApiPlanSummary {
+                name,
+                ..plan.summary()
+            }),
+            Err(e) => tracing::warn!("list_plans_detailed: Skipping plan '{}': {}", name, e),
+        }
+    }
+    Ok(summaries)
+}
+
+// Accessor functions for ApiPlanSummary (opaque type)
+
+/// Get the name of a plan summary
+pub fn plan_summary_name(summary: &ApiPlanSummary) -> String {
+    summary.name.clone()
+}
+
+/// Get the total duration in seconds of a plan summary
+pub fn plan_summary_duration_secs(summary: &ApiPlanSummary) -> u32 {
+    summary.total_duration_secs
+}
+
+/// Get the number of phases of a plan summary
+pub fn plan_summary_phase_count(summary: &ApiPlanSummary) -> u32 {
+    summary.phase_count
+}
+
+/// Get the difficulty (1 = easy, 5 = very hard) of a plan summary
+pub fn plan_summary_difficulty(summary: &ApiPlanSummary) -> u8 {
+    summary.difficulty
+}
+
+/// List what changed between a stored plan and an edited version of it.
+///
+/// Loads the plan `name` and compares it with `new_json` (same format as
+/// [`save_training_plan`]) without saving anything, so an editing UI can
+/// show the changes before they are applied. Identical plans yield an empty
+/// list.
+///
+/// # Errors
+///
+/// Fails with [`ApiErrorCode::PlanNotFound`] if the stored plan is missing,
+/// and [`ApiErrorCode::PlanInvalid`] if it is unreadable or `new_json` is not
+/// a valid plan.
+pub async fn diff_plans(
+    name: String,
+    new_json: String,
+) -> std::result::Result<Vec<ApiPlanChange>, ApiError> {
+    diff_plans_for(name, new_json, None).await
+}
+
+/// List what changed between a stored plan of a profile and an edited version.
+///
+/// Same as [`diff_plans`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn diff_plans_for(
+    name: String,
+    new_json: String,
+    namespace: Option<String>,
+) -> std::result::Result<Vec<ApiPlanChange>, ApiError> {
+    let old = load_plan_for(&name, namespace.as_deref()).await?;
+    let new: TrainingPlan =
+        serde_json::from_str(&new_json).map_err(|e| PlanError::InvalidJson(e.to_string()))?;
+    Ok(training_plan::diff(&old, &new))
+}
+
+/// Get a human-readable description of a plan change
+pub fn plan_change_description(change: &ApiPlanChange) -> String {
+    change.to_string()
+}
+
+/// Load a training plan by name from the plans directory.
+///
+/// Internal helper function to load a plan from {data_dir}/plans/{name}.json
+async fn load_plan(name: &str) -> Result<TrainingPlan> {
+    load_plan_for(name, None).await
+}
+
+/// Load a training plan by name from a profile's plans directory.
+///
+/// A missing or unparseable plan file is reported as a [`PlanError`].
+async fn load_plan_for(name: &str, namespace: Option<&str>) -> Result<TrainingPlan> {
+    let data_dir = get_data_dir_for(namespace)?;
+    let plans_dir = data_dir.join("plans");
+    let plan_path = plans_dir.join(format!("{}.json", name));
 
     if !plan_path.exists() {
-        return Err(anyhow!(
-            "Plan '{}' not found. Use list_plans() to see available plans.",
-            name
-        ));
+        return Err(PlanError::NotFound(name.to_string()).into());
     }
 
     let content = tokio::fs::read_to_string(&plan_path).await?;
-    let plan: TrainingPlan = serde_json::from_str(&content)?;
+    let plan: TrainingPlan =
+        serde_json::from_str(&content).map_err(|e| PlanError::InvalidJson(e.to_string()))?;
 
     Ok(plan)
 }
@@ -2193,7 +4721,12 @@ async fn load_plan(name: &str) -> Result<TrainingPlan> {
 /// Creates the plan file at {data_dir}/plans/{plan_name}.json.
 /// Overwrites if the plan already exists.
 async fn save_plan(plan: &TrainingPlan) -> Result<()> {
-    let data_dir = get_data_dir()?;
+    save_plan_for(plan, None).await
+}
+
+/// Save a training plan to a profile's plans directory.
+async fn save_plan_for(plan: &TrainingPlan, namespace: Option<&str>) -> Result<()> {
+    let data_dir = get_data_dir_for(namespace)?;
     let plans_dir = data_dir.join("plans");
 
     // Create plans directory if it doesn't exist
@@ -2226,10 +4759,8 @@ async fn save_plan(plan: &TrainingPlan) -> Result<()> {
 /// # Returns
 ///
 /// The number of plans created. Returns 0 if plans already exist.
-pub async fn seed_default_plans() -> Result<u32> {
+pub async fn seed_default_plans() -> std::result::Result<u32, ApiError> {
     use crate::domain::heart_rate::Zone;
-    use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
-    use chrono::Utc;
 
     let data_dir = get_data_dir()?;
     let plans_dir = data_dir.join("plans");
@@ -2251,137 +4782,78 @@ pub async fn seed_default_plans() -> Result<u32> {
         tokio::fs::create_dir_all(&plans_dir).await?;
     }
 
+    // `TrainingPlan::steady` names its phase after the plan; keep the
+    // descriptive phase names the seeded plans have always had.
+    let steady = |name: &str, phase_name: &str, zone: Zone, duration_secs: u32| {
+        let mut plan = TrainingPlan::steady(name, zone, duration_secs, 180);
+        plan.phases[0].name = phase_name.to_string();
+        plan
+    };
+
+    let plans = [
+        // 1. Easy Run - 30 minutes
+        steady("Easy Run", "Easy Pace", Zone::Zone2, 600)
+            .with_warmup(Zone::Zone2, 600)
+            .with_cooldown(Zone::Zone1, 600),
+        // 2. Tempo Run - 40 minutes
+        steady("Tempo Run", "Tempo", Zone::Zone3, 1200)
+            .with_warmup(Zone::Zone2, 600)
+            .with_cooldown(Zone::Zone1, 600),
+        // 3. Interval Training - 35 minutes: 5x (3 min Zone4, 2 min Zone2)
+        TrainingPlan::interval(
+            "Interval Training",
+            Zone::Zone4,
+            180,
+            Zone::Zone2,
+            120,
+            5,
+            180,
+        )
+        .with_warmup(Zone::Zone2, 300)
+        .with_cooldown(Zone::Zone1, 300),
+        // 4. Long Slow Distance - 60 minutes
+        steady("Long Slow Distance", "Steady Aerobic", Zone::Zone2, 3600),
+        // 5. Recovery Run - 20 minutes
+        steady("Recovery Run", "Easy Recovery", Zone::Zone1, 1200),
+    ];
+
     let mut count = 0;
+    for plan in &plans {
+        save_plan(plan).await?;
+        count += 1;
+    }
 
-    // 1. Easy Run - 30 minutes
-    let easy_run = TrainingPlan {
-        name: "Easy Run".to_string(),
-        phases: vec![
-            TrainingPhase {
-                name: "Warmup".to_string(),
-                target_zone: Zone::Zone2,
-                duration_secs: 600, // 10 min
-                transition: TransitionCondition::TimeElapsed,
-            },
-            TrainingPhase {
-                name: "Easy Pace".to_string(),
-                target_zone: Zone::Zone2,
-                duration_secs: 600, // 10 min
-                transition: TransitionCondition::TimeElapsed,
-            },
-            TrainingPhase {
-                name: "Cooldown".to_string(),
-                target_zone: Zone::Zone1,
-                duration_secs: 600, // 10 min
-                transition: TransitionCondition::TimeElapsed,
-            },
-        ],
-        created_at: Utc::now(),
-        max_hr: 180,
-    };
-    save_plan(&easy_run).await?;
-    count += 1;
+    tracing::info!("seed_default_plans: Created {} default plans", count);
+    Ok(count)
+}
 
-    // 2. Tempo Run - 40 minutes
-    let tempo_run = TrainingPlan {
-        name: "Tempo Run".to_string(),
-        phases: vec![
-            TrainingPhase {
-                name: "Warmup".to_string(),
-                target_zone: Zone::Zone2,
-                duration_secs: 600, // 10 min
-                transition: TransitionCondition::TimeElapsed,
-            },
-            TrainingPhase {
-                name: "Tempo".to_string(),
-                target_zone: Zone::Zone3,
-                duration_secs: 1200, // 20 min
-                transition: TransitionCondition::TimeElapsed,
-            },
-            TrainingPhase {
-                name: "Cooldown".to_string(),
-                target_zone: Zone::Zone1,
-                duration_secs: 600, // 10 min
-                transition: TransitionCondition::TimeElapsed,
-            },
-        ],
-        created_at: Utc::now(),
-        max_hr: 180,
-    };
-    save_plan(&tempo_run).await?;
-    count += 1;
-
-    // 3. Interval Training - 35 minutes
-    let mut interval_phases = vec![TrainingPhase {
-        name: "Warmup".to_string(),
-        target_zone: Zone::Zone2,
-        duration_secs: 300, // 5 min
-        transition: TransitionCondition::TimeElapsed,
-    }];
-
-    for i in 1..=5 {
-        interval_phases.push(TrainingPhase {
-            name: format!("Interval {}", i),
-            target_zone: Zone::Zone4,
-            duration_secs: 180, // 3 min
-            transition: TransitionCondition::TimeElapsed,
-        });
-        interval_phases.push(TrainingPhase {
-            name: format!("Recovery {}", i),
-            target_zone: Zone::Zone2,
-            duration_secs: 120, // 2 min
-            transition: TransitionCondition::TimeElapsed,
-        });
+/// Shut down background work before the app exits.
+///
+/// Shuts down the workout scheduler so no scheduled workout fires afterwards,
+/// disconnects from the HR device and writes any buffered session saves.
+/// Safe to call more than once.
+///
+/// # Errors
+///
+/// Returns an error if the scheduler fails to shut down, the device fails to
+/// disconnect, or a buffered session cannot be written.
+pub async fn shutdown() -> Result<()> {
+    tracing::info!("shutdown: Shutting down");
+
+    if let Some(executor) = get_session_executor().await?.lock().await.as_mut() {
+        executor.shutdown_scheduler().await?;
     }
 
-    interval_phases.push(TrainingPhase {
-        name: "Cooldown".to_string(),
-        target_zone: Zone::Zone1,
-        duration_secs: 300, // 5 min
-        transition: TransitionCondition::TimeElapsed,
-    });
+    disconnect().await?;
 
-    let interval_training = TrainingPlan {
-        name: "Interval Training".to_string(),
-        phases: interval_phases,
-        created_at: Utc::now(),
-        max_hr: 180,
-    };
-    save_plan(&interval_training).await?;
-    count += 1;
-
-    // 4. Long Slow Distance - 60 minutes
-    let lsd = TrainingPlan {
-        name: "Long Slow Distance".to_string(),
-        phases: vec![TrainingPhase {
-            name: "Steady Aerobic".to_string(),
-            target_zone: Zone::Zone2,
-            duration_secs: 3600, // 60 min
-            transition: TransitionCondition::TimeElapsed,
-        }],
-        created_at: Utc::now(),
-        max_hr: 180,
-    };
-    save_plan(&lsd).await?;
-    count += 1;
-
-    // 5. Recovery Run - 20 minutes
-    let recovery = TrainingPlan {
-        name: "Recovery Run".to_string(),
-        phases: vec![TrainingPhase {
-            name: "Easy Recovery".to_string(),
-            target_zone: Zone::Zone1,
-            duration_secs: 1200, // 20 min
-            transition: TransitionCondition::TimeElapsed,
-        }],
-        created_at: Utc::now(),
-        max_hr: 180,
-    };
-    save_plan(&recovery).await?;
-    count += 1;
+    if let Some(mutex) = SESSION_REPOSITORY.get() {
+        if let Some(repo) = mutex.lock().await.as_ref() {
+            repo.flush().await?;
+        }
+    }
 
-    tracing::info!("seed_default_plans: Created {} default plans", count);
-    Ok(count)
+    tracing::info!("shutdown: Shutdown complete");
+    Ok(())
 }
 
 /// Get or create the global session executor instance.
@@ -2394,6 +4866,42 @@ async fn get_session_executor() -> Result<&'static tokio::sync::Mutex<Option<Ses
     Ok(SESSION_EXECUTOR.get_or_init(|| tokio::sync::Mutex::new(None)))
 }
 
+/// Path of the checkpoint the workout executor saves its progress to.
+fn workout_checkpoint_path() -> Result<std::path::PathBuf> {
+    Ok(get_data_dir()?.join("session_checkpoint.json"))
+}
+
+/// Create the executor used by the workout API functions.
+async fn create_workout_executor() -> Result<SessionExecutor> {
+    // Notifications are dispatched by severity
+    let notification_port = notification_router();
+
+    // Get HR stream receiver
+    let hr_receiver = get_hr_stream_receiver();
+
+    // Get session repository
+    let session_repo = get_session_repository().await?;
+
+    // Create progress forwarder
+    let progress_sender = create_session_progress_forwarder();
+
+    // Create executor with HR stream, progress/transition/pace senders, session
+    // repository and checkpoints. A BLE drop keeps the workout timing; progress
+    // reports the link state.
+    Ok(
+        SessionExecutor::with_hr_stream(notification_port, hr_receiver)
+            .with_connection_status(subscribe_connection_status_stream())
+            .with_connection_health(subscribe_connection_health_stream())
+            .with_disconnect_policy(DisconnectPolicy::KeepTiming)
+            .with_progress_sender(progress_sender)
+            .with_phase_transition_sender(create_phase_transition_forwarder())
+            .with_pace_cue_sender(create_pace_cue_forwarder())
+            .with_session_repository(session_repo)
+            .with_checkpoint_path(workout_checkpoint_path()?)
+            .with_zone_smoothing(zone_min_dwell_secs()),
+    )
+}
+
 /// Start a workout session with the specified training plan.
 ///
 /// Loads the plan from ~/.heart-beat/plans/{plan_name}.json and starts
@@ -2410,15 +4918,29 @@ async fn get_session_executor() -> Result<&'static tokio::sync::Mutex<Option<Ses
 ///
 /// # Errors
 ///
-/// Returns an error if:
-/// - The plan file cannot be found or loaded
+/// Returns [`StartWorkoutError::Plan`] if the plan does not exist, cannot be
+/// parsed or fails validation, and [`StartWorkoutError::Other`] if:
 /// - A workout is already in progress
 /// - The executor cannot be initialized
-pub async fn start_workout(plan_name: String) -> Result<()> {
+pub async fn start_workout(plan_name: String) -> std::result::Result<(), StartWorkoutError> {
+    start_workout_for(plan_name, None).await
+}
+
+/// Start a workout session with a training plan of a profile.
+///
+/// Same as [`start_workout`], for the profile `namespace` (see
+/// [`set_data_dir_for`]): the plan is loaded from the profile's plans and the
+/// completed session is saved to its sessions. `None` is the default profile.
+pub async fn start_workout_for(
+    plan_name: String,
+    namespace: Option<String>,
+) -> std::result::Result<(), StartWorkoutError> {
     tracing::info!("start_workout: Starting workout with plan '{}'", plan_name);
 
     // Load the training plan
-    let plan = load_plan(&plan_name).await?;
+    let plan = load_plan_for(&plan_name, namespace.as_deref()).await?;
+    plan.validate()
+        .map_err(|e| StartWorkoutError::Plan(PlanError::ValidationFailed(e.to_string())))?;
 
     // Get the executor mutex
     let executor_mutex = get_session_executor().await?;
@@ -2427,33 +4949,23 @@ pub async fn start_workout(plan_name: String) -> Result<()> {
     // Initialize executor if needed
     if executor_guard.is_none() {
         tracing::info!("start_workout: Initializing session executor");
-
-        // Create notification port (stub for now)
-        let notification_port: Arc<dyn NotificationPort> = Arc::new(StubNotificationPort);
-
-        // Get HR stream receiver
-        let hr_receiver = get_hr_stream_receiver();
-
-        // Get session repository
-        let session_repo = get_session_repository().await?;
-
-        // Create progress forwarder
-        let progress_sender = create_session_progress_forwarder();
-
-        // Create executor with HR stream, progress sender, and session repository
-        let executor = SessionExecutor::with_hr_stream(notification_port, hr_receiver)
-            .with_progress_sender(progress_sender)
-            .with_session_repository(session_repo);
-
-        *executor_guard = Some(executor);
+        *executor_guard = Some(create_workout_executor().await?);
     }
 
     // Start the session
     if let Some(ref mut executor) = *executor_guard {
+        // The executor outlives workouts; pick up a changed smoothing setting
+        executor.set_zone_smoothing(zone_min_dwell_secs());
+        // Save the session to the profile the plan came from
+        let session_repo = get_session_repository_for(namespace.as_deref()).await?;
+        executor.set_session_repository(session_repo).await?;
         executor.start_session(plan).await?;
+        update_latest_values(LatestValues::clear_hr);
         tracing::info!("start_workout: Workout started successfully");
     } else {
-        return Err(anyhow!("Failed to initialize session executor"));
+        return Err(StartWorkoutError::Other(
+            "Failed to initialize session executor".to_string(),
+        ));
     }
 
     Ok(())
@@ -2506,6 +5018,100 @@ pub async fn resume_workout() -> Result<()> {
     }
 }
 
+/// Skip the rest of the current workout phase.
+///
+/// Moves straight to the next phase, or finishes the workout if this was the
+/// last phase. The time already spent in the skipped phase is kept.
+///
+/// # Errors
+///
+/// Returns an error if no phase is running or if the executor is not initialized.
+pub async fn skip_workout_phase() -> Result<()> {
+    tracing::info!("skip_workout_phase: Skipping current phase");
+
+    let executor_mutex = get_session_executor().await?;
+    let mut executor_guard = executor_mutex.lock().await;
+
+    if let Some(ref mut executor) = *executor_guard {
+        executor.skip_phase().await?;
+        tracing::info!("skip_workout_phase: Phase skipped successfully");
+        Ok(())
+    } else {
+        Err(anyhow!("No active workout session"))
+    }
+}
+
+/// Add time to the current workout phase.
+///
+/// Extends the active phase by `extra_secs` for this workout only; the saved
+/// plan is left unchanged. Can be used while the workout is paused.
+///
+/// # Errors
+///
+/// Returns an error if no workout is active or if the executor is not initialized.
+pub async fn extend_workout_phase(extra_secs: u32) -> Result<()> {
+    tracing::info!(
+        "extend_workout_phase: Extending current phase by {}s",
+        extra_secs
+    );
+
+    let executor_mutex = get_session_executor().await?;
+    let mut executor_guard = executor_mutex.lock().await;
+
+    if let Some(ref mut executor) = *executor_guard {
+        executor.extend_phase(extra_secs).await?;
+        tracing::info!("extend_workout_phase: Phase extended successfully");
+        Ok(())
+    } else {
+        Err(anyhow!("No active workout session"))
+    }
+}
+
+/// Get the max HR suggested after the last workout peaked above the plan's.
+///
+/// When a completed or stopped workout's highest BPM exceeds the plan's
+/// `max_hr` by a margin, a `MaxHrExceeded` notification is sent and the
+/// observed peak is suggested as the new max HR. Returns `None` if no workout
+/// has done so.
+///
+/// # Errors
+///
+/// Returns an error if the executor is not initialized.
+pub async fn suggest_max_hr_update() -> Result<Option<u16>> {
+    let executor_guard = get_session_executor().await?.lock().await;
+    match executor_guard.as_ref() {
+        Some(executor) => Ok(executor.suggested_max_hr().await),
+        None => Ok(None),
+    }
+}
+
+/// Get up to `count` phases that follow the current phase of the workout.
+///
+/// Works while the workout is running or paused. Returns fewer phases near
+/// the end of the plan, and an empty list on the last phase or when no
+/// workout is active.
+///
+/// # Errors
+///
+/// Returns an error if the executor is not initialized.
+pub async fn get_upcoming_phases(count: u32) -> Result<Vec<ApiUpcomingPhase>> {
+    let executor_guard = get_session_executor().await?.lock().await;
+    let Some(executor) = executor_guard.as_ref() else {
+        return Ok(Vec::new());
+    };
+    Ok(executor
+        .get_upcoming_phases(count as usize)
+        .await
+        .into_iter()
+        .map(|(index, phase)| ApiUpcomingPhase {
+            phase_index: index as u32,
+            name: phase.name,
+            target_zone: phase.target_zone,
+            duration_secs: phase.duration_secs,
+        })
+        .collect())
+}
+
 /// Stop the currently running workout.
 ///
 /// Ends the workout and saves the session to the repository. The session
@@ -2522,6 +5128,9 @@ pub async fn stop_workout() -> Result<()> {
 
     if let Some(ref mut executor) = *executor_guard {
         executor.stop_session().await?;
+        reset_progress_channel();
+        // A stopped workout must not be offered for resumption
+        executor.clear_checkpoint().await?;
         tracing::info!("stop_workout: Workout stopped successfully");
         Ok(())
     } else {
@@ -2529,6 +5138,109 @@ pub async fn stop_workout() -> Result<()> {
     }
 }
 
+/// Get a snapshot of the current app state.
+///
+/// Combines the latest connection status, workout progress, heart rate and
+/// battery level in one call, so the UI can restore its screens without
+/// subscribing to every stream first.
+pub async fn get_app_snapshot() -> ApiAppSnapshot {
+    let connected_device_id = match CONNECTION_STATE.get() {
+        Some(mutex) => mutex
+            .lock()
+            .await
+            .as_ref()
+            .map(|state| state.device_id.clone()),
+        None => None,
+    };
+
+    let session_progress = match SESSION_EXECUTOR.get() {
+        Some(mutex) => match mutex.lock().await.as_ref() {
+            Some(executor) => executor.current_progress().await,
+            None => None,
+        },
+        None => None,
+    };
+
+    let (connection_status, last_hr, battery) = LATEST_VALUES
+        .get()
+        .and_then(|m| m.lock().ok())
+        .map(|latest| {
+            (
+                latest.connection_status.clone(),
+                latest.hr.clone(),
+                latest.battery.clone(),
+            )
+        })
+        .unwrap_or_default();
+
+    ApiAppSnapshot {
+        connection_status: connection_status.unwrap_or(ApiConnectionStatus::Disconnected),
+        connected_device_id,
+        session_progress,
+        last_hr,
+        battery,
+    }
+}
+
+// Accessor functions for ApiAppSnapshot (opaque type)
+
+/// Get the connection status from an app snapshot
+pub fn app_snapshot_connection_status(snapshot: &ApiAppSnapshot) -> ApiConnectionStatus {
+    snapshot.connection_status.clone()
+}
+
+/// Get the connected device ID from an app snapshot
+pub fn app_snapshot_connected_device_id(snapshot: &ApiAppSnapshot) -> Option<String> {
+    snapshot.connected_device_id.clone()
+}
+
+/// Get the workout progress from an app snapshot
+pub fn app_snapshot_session_progress(snapshot: &ApiAppSnapshot) -> Option<ApiSessionProgress> {
+    snapshot.session_progress.clone()
+}
+
+/// Get the last heart rate measurement from an app snapshot
+pub fn app_snapshot_last_hr(snapshot: &ApiAppSnapshot) -> Option<ApiFilteredHeartRate> {
+    snapshot.last_hr.clone()
+}
+
+/// Get the battery level percentage from an app snapshot
+pub fn app_snapshot_battery_level(snapshot: &ApiAppSnapshot) -> Option<u8> {
+    snapshot.battery.as_ref().and_then(|battery| battery.level)
+}
+
+/// Resume the workout interrupted by a crash or app restart, if any.
+///
+/// Reads the checkpoint saved by the running workout and continues it from
+/// the saved phase and elapsed time. The plan is stored in the checkpoint, so
+/// the caller does not need to know its name.
+///
+/// Returns `true` if a workout was resumed, `false` if there was nothing to resume.
+///
+/// # Errors
+///
+/// Returns an error if the checkpoint cannot be read or the executor cannot
+/// be initialized.
+pub async fn resume_last_workout() -> Result<bool> {
+    tracing::info!("resume_last_workout: Looking for a saved workout");
+
+    let executor_mutex = get_session_executor().await?;
+    let mut executor_guard = executor_mutex.lock().await;
+
+    if executor_guard.is_none() {
+        *executor_guard = Some(create_workout_executor().await?);
+    }
+    let executor = executor_guard
+        .as_mut()
+        .ok_or_else(|| anyhow!("Failed to initialize session executor"))?;
+
+    let resumed = executor.resume_from_checkpoint().await?;
+    if resumed {
+        tracing::info!("resume_last_workout: Workout resumed from checkpoint");
+    }
+    Ok(resumed)
+}
+
 // SessionProgress accessor methods for opaque types
 
 /// Get the current session state from SessionProgress.
@@ -2566,6 +5278,44 @@ pub fn session_progress_phase_progress(progress: &ApiSessionProgress) -> ApiPhas
     progress.phase_progress.clone()
 }
 
+/// Get the overall completion percentage (0.0 to 100.0) from SessionProgress.
+pub fn session_progress_completion_pct(progress: &ApiSessionProgress) -> f64 {
+    progress.completion_pct
+}
+
+/// Get the projected session end time from SessionProgress, in milliseconds
+/// since the Unix epoch.
+pub fn session_progress_projected_end_ms(progress: &ApiSessionProgress) -> u64 {
+    progress.projected_end_ms
+}
+
+/// Get the sensor link state from SessionProgress.
+pub fn session_progress_connectivity(
+    progress: &ApiSessionProgress,
+) -> ApiConnectivityDuringSession {
+    progress.connectivity
+}
+
+/// Get whether the current BPM in SessionProgress comes from a recent HR sample.
+pub fn session_progress_bpm_is_fresh(progress: &ApiSessionProgress) -> bool {
+    progress.bpm_is_fresh
+}
+
+/// Get the epoch of the session a SessionProgress belongs to.
+pub fn session_progress_epoch(progress: &ApiSessionProgress) -> u64 {
+    progress.session_epoch
+}
+
+/// Get the TRIMP accumulated so far from SessionProgress.
+pub fn session_progress_trimp(progress: &ApiSessionProgress) -> f64 {
+    progress.trimp
+}
+
+/// Get the average TRIMP per running minute from SessionProgress.
+pub fn session_progress_trimp_per_min(progress: &ApiSessionProgress) -> f64 {
+    progress.trimp_per_min
+}
+
 // PhaseProgress accessor methods
 
 /// Get the phase index from PhaseProgress.
@@ -2583,6 +5333,24 @@ pub fn phase_progress_target_zone(progress: &ApiPhaseProgress) -> Zone {
     progress.target_zone
 }
 
+/// Get the lower bound of the phase's explicit BPM target from PhaseProgress.
+/// Returns `None` for phases that target a zone.
+pub fn phase_progress_target_low_bpm(progress: &ApiPhaseProgress) -> Option<u16> {
+    match progress.target {
+        Some(PhaseTarget::Bpm { low, .. }) => Some(low),
+        _ => None,
+    }
+}
+
+/// Get the upper bound of the phase's explicit BPM target from PhaseProgress.
+/// Returns `None` for phases that target a zone.
+pub fn phase_progress_target_high_bpm(progress: &ApiPhaseProgress) -> Option<u16> {
+    match progress.target {
+        Some(PhaseTarget::Bpm { high, .. }) => Some(high),
+        _ => None,
+    }
+}
+
 /// Get the elapsed seconds in the current phase from PhaseProgress.
 pub fn phase_progress_elapsed_secs(progress: &ApiPhaseProgress) -> u32 {
     progress.elapsed_secs
@@ -2593,6 +5361,40 @@ pub fn phase_progress_remaining_secs(progress: &ApiPhaseProgress) -> u32 {
     progress.remaining_secs
 }
 
+/// Get the consecutive seconds the heart rate target has been held.
+/// Always 0 for timed phases.
+pub fn phase_progress_hr_hold_elapsed_secs(progress: &ApiPhaseProgress) -> u32 {
+    progress.hr_hold_elapsed_secs
+}
+
+/// Get the seconds the heart rate target must be held to finish the phase.
+/// Returns 0 for timed phases.
+pub fn phase_progress_hr_hold_required_secs(progress: &ApiPhaseProgress) -> u32 {
+    progress.hr_hold_required_secs
+}
+
+// UpcomingPhase accessor methods
+
+/// Get the position of an upcoming phase in the plan (0-based).
+pub fn upcoming_phase_index(phase: &ApiUpcomingPhase) -> u32 {
+    phase.phase_index
+}
+
+/// Get the name of an upcoming phase.
+pub fn upcoming_phase_name(phase: &ApiUpcomingPhase) -> String {
+    phase.name.clone()
+}
+
+/// Get the target zone of an upcoming phase.
+pub fn upcoming_phase_target_zone(phase: &ApiUpcomingPhase) -> Zone {
+    phase.target_zone
+}
+
+/// Get the planned duration of an upcoming phase in seconds.
+pub fn upcoming_phase_duration_secs(phase: &ApiUpcomingPhase) -> u32 {
+    phase.duration_secs
+}
+
 // SessionState helper methods
 
 /// Check if the session state is Running.
@@ -2670,13 +5472,13 @@ pub async fn create_custom_plan(
     phase_zones: Vec<u8>,
     phase_durations: Vec<u32>,
     max_hr: u16,
-) -> Result<()> {
+) -> std::result::Result<(), ApiError> {
     use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
 
     tracing::info!("create_custom_plan: Creating plan '{}'", name);
 
     if phase_names.len() != phase_zones.len() || phase_names.len() != phase_durations.len() {
-        return Err(anyhow!("Phase arrays must have equal length"));
+        return Err(anyhow!("Phase arrays must have equal length").into());
     }
 
     let phases: Vec<TrainingPhase> = phase_names
@@ -2690,6 +5492,8 @@ pub async fn create_custom_plan(
                 target_zone: zone,
                 duration_secs: duration,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }
         })
         .collect();
@@ -2699,6 +5503,8 @@ pub async fn create_custom_plan(
         phases,
         created_at: chrono::Utc::now(),
         max_hr,
+        warmup_grace_secs: None,
+        resting_hr: None,
     };
 
     plan.validate()?;
@@ -2708,27 +5514,324 @@ pub async fn create_custom_plan(
     Ok(())
 }
 
-/// Delete a training plan by name.
-pub async fn delete_plan(name: String) -> Result<()> {
-    tracing::info!("delete_plan: Deleting plan '{}'", name);
+/// Parse, validate and save a training plan given as JSON.
+///
+/// Lets the app create or edit plans (not just the CLI). The JSON must match the
+/// `TrainingPlan` format used by the plan files; an existing plan with the same
+/// name is overwritten.
+///
+/// # Errors
+///
+/// Fails with [`ApiErrorCode::PlanInvalid`] if the JSON cannot be parsed, the
+/// name is not usable as a file name, or the plan fails validation, and
+/// [`ApiErrorCode::Io`] if the plan cannot be written.
+pub async fn save_training_plan(plan_json: String) -> std::result::Result<(), ApiError> {
+    save_training_plan_for(plan_json, None).await
+}
 
-    let data_dir = get_data_dir()?;
-    let plan_path = data_dir.join("plans").join(format!("{}.json", name));
+/// Parse, validate and save a training plan given as JSON for a profile.
+///
+/// Same as [`save_training_plan`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn save_training_plan_for(
+    plan_json: String,
+    namespace: Option<String>,
+) -> std::result::Result<(), ApiError> {
+    let plan: TrainingPlan =
+        serde_json::from_str(&plan_json).map_err(|e| PlanSaveError::InvalidJson(e.to_string()))?;
 
-    if !plan_path.exists() {
-        return Err(anyhow!("Plan '{}' not found", name));
-    }
+    tracing::info!("save_training_plan: Saving plan '{}'", plan.name);
 
-    tokio::fs::remove_file(&plan_path).await?;
-    tracing::info!("delete_plan: Plan '{}' deleted", name);
+    check_plan_name(&plan.name)?;
+
+    plan.validate()
+        .map_err(|e| PlanSaveError::InvalidPlan(e.to_string()))?;
+
+    save_plan_for(&plan, namespace.as_deref()).await?;
+
+    tracing::info!("save_training_plan: Plan '{}' saved", plan.name);
+    Ok(())
+}
+
+/// Reject plan names that are empty, padded, or unusable as a file name.
+fn check_plan_name(name: &str) -> Result<()> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() || trimmed != name || name.contains(['/', '\\']) || name.starts_with('.')
+    {
+        return Err(PlanSaveError::InvalidName(name.to_string()).into());
+    }
+    Ok(())
+}
+
+/// Chain saved plans into one and save the result as a new plan.
+///
+/// The phases of `names` run back to back in the given order (e.g. a bike
+/// plan then a run plan). All plans must use the same max HR. An existing plan
+/// named `new_name` is overwritten.
+///
+/// # Errors
+///
+/// Returns an error if a plan cannot be loaded, the plans use different max
+/// HRs, or the result fails validation ([`ApiErrorCode::PlanInvalid`] for an
+/// invalid name or plan).
+pub async fn compose_plans(
+    names: Vec<String>,
+    new_name: String,
+) -> std::result::Result<(), ApiError> {
+    compose_plans_for(names, new_name, None).await
+}
+
+/// Chain saved plans of a profile into one and save the result.
+///
+/// Same as [`compose_plans`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn compose_plans_for(
+    names: Vec<String>,
+    new_name: String,
+    namespace: Option<String>,
+) -> std::result::Result<(), ApiError> {
+    tracing::info!("compose_plans: Composing '{}' from {:?}", new_name, names);
+    check_plan_name(&new_name)?;
+
+    let mut plans = Vec::with_capacity(names.len());
+    for name in &names {
+        plans.push(load_plan_for(name, namespace.as_deref()).await?);
+    }
+
+    let plan = TrainingPlan::concat(&plans, &new_name)?;
+    plan.validate()
+        .map_err(|e| PlanSaveError::InvalidPlan(e.to_string()))?;
+    save_plan_for(&plan, namespace.as_deref()).await?;
+
+    tracing::info!(
+        "compose_plans: Plan '{}' saved with {} phases",
+        plan.name,
+        plan.phases.len()
+    );
+    Ok(())
+}
+
+/// Save the structure of a recorded session as a new plan.
+///
+/// Lets a freeform workout be repeated: the session's HR samples are
+/// segmented into timed zone phases (see
+/// [`crate::domain::session_history::derive_plan`]). Zones are computed
+/// against the max HR of the plan the session was run from. If that plan no
+/// longer exists, the default max HR of 180 BPM is used, raised to the
+/// session's peak HR if it was higher.
+///
+/// # Errors
+///
+/// Fails with [`ApiErrorCode::SessionNotFound`] if the session does not exist,
+/// [`ApiErrorCode::PlanInvalid`] for an invalid or already used name or a
+/// session that yields no valid plan, and [`ApiErrorCode::Io`] if the plan
+/// cannot be written.
+pub async fn create_plan_from_session(
+    session_id: String,
+    plan_name: String,
+) -> std::result::Result<(), ApiError> {
+    create_plan_from_session_for(session_id, plan_name, None).await
+}
+
+/// Save the structure of a profile's recorded session as a new plan.
+///
+/// Same as [`create_plan_from_session`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn create_plan_from_session_for(
+    session_id: String,
+    plan_name: String,
+    namespace: Option<String>,
+) -> std::result::Result<(), ApiError> {
+    tracing::info!(
+        "create_plan_from_session: Deriving '{}' from session {}",
+        plan_name,
+        session_id
+    );
+    check_plan_name(&plan_name)?;
+    let plan_path = get_data_dir_for(namespace.as_deref())?
+        .join("plans")
+        .join(format!("{}.json", plan_name));
+    if plan_path.exists() {
+        return Err(PlanSaveError::AlreadyExists(plan_name).into());
+    }
+
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let session = repo
+        .get(&session_id)
+        .await?
+        .ok_or_else(|| SessionError::NotFound(session_id.clone()))?;
+    // The peak HR only bounds max HR from below, so it can't stand in for it
+    let max_hr = match load_plan_for(&session.plan_name, namespace.as_deref()).await {
+        Ok(plan) => plan.max_hr,
+        Err(_) => session
+            .summary
+            .max_hr
+            .clamp(TrainingPlan::default().max_hr, 220),
+    };
+
+    let mut plan = crate::domain::session_history::derive_plan(&session, max_hr)
+        .map_err(|e| PlanSaveError::InvalidPlan(e.to_string()))?;
+    plan.name = plan_name;
+    plan.created_at = chrono::Utc::now();
+    save_plan_for(&plan, namespace.as_deref()).await?;
+
+    tracing::info!(
+        "create_plan_from_session: Plan '{}' saved with {} phases",
+        plan.name,
+        plan.phases.len()
+    );
+    Ok(())
+}
+
+/// Parse and save a plan template given as JSON.
+///
+/// Templates are stored at {data_dir}/templates/{name}.json and turned into
+/// plans with [`instantiate_template`]. An existing template with the same
+/// name is overwritten.
+///
+/// # Errors
+///
+/// Fails with [`ApiErrorCode::PlanInvalid`] if the JSON cannot be parsed or the
+/// name is not usable as a file name, and [`ApiErrorCode::Io`] if the template
+/// cannot be written.
+pub async fn save_plan_template(template_json: String) -> std::result::Result<(), ApiError> {
+    save_plan_template_for(template_json, None).await
+}
+
+/// Parse and save a plan template given as JSON for a profile.
+///
+/// Same as [`save_plan_template`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn save_plan_template_for(
+    template_json: String,
+    namespace: Option<String>,
+) -> std::result::Result<(), ApiError> {
+    let template: PlanTemplate = serde_json::from_str(&template_json)
+        .map_err(|e| PlanSaveError::InvalidJson(e.to_string()))?;
+    check_plan_name(&template.name)?;
+
+    let templates_dir = get_data_dir_for(namespace.as_deref())?.join("templates");
+    tokio::fs::create_dir_all(&templates_dir).await?;
+    let path = templates_dir.join(format!("{}.json", template.name));
+    tokio::fs::write(
+        &path,
+        serde_json::to_string_pretty(&template).map_err(anyhow::Error::from)?,
+    )
+    .await?;
+
+    tracing::info!(
+        "save_plan_template: Saved template '{}' to {:?}",
+        template.name,
+        path
+    );
+    Ok(())
+}
+
+/// Turn a stored template into a concrete plan and save it.
+///
+/// Phase durations are multiplied by `scale` and zones use `max_hr`. The plan
+/// is saved as "{template} ({minutes} min)", overwriting an existing plan of
+/// that name, and the name is returned.
+///
+/// # Errors
+///
+/// Returns an error if the template cannot be loaded, or
+/// [`ApiErrorCode::PlanInvalid`] if the scale is invalid or the resulting plan
+/// fails validation.
+pub async fn instantiate_template(
+    template_name: String,
+    max_hr: u16,
+    scale: f64,
+) -> std::result::Result<String, ApiError> {
+    instantiate_template_for(template_name, max_hr, scale, None).await
+}
+
+/// Turn a stored template of a profile into a concrete plan and save it.
+///
+/// Same as [`instantiate_template`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn instantiate_template_for(
+    template_name: String,
+    max_hr: u16,
+    scale: f64,
+    namespace: Option<String>,
+) -> std::result::Result<String, ApiError> {
+    tracing::info!(
+        "instantiate_template: '{}' for max HR {} at x{}",
+        template_name,
+        max_hr,
+        scale
+    );
+    check_plan_name(&template_name)?;
+
+    let path = get_data_dir_for(namespace.as_deref())?
+        .join("templates")
+        .join(format!("{}.json", template_name));
+    if !path.exists() {
+        return Err(anyhow!("Template '{}' not found", template_name).into());
+    }
+    let template: PlanTemplate = serde_json::from_str(&tokio::fs::read_to_string(&path).await?)
+        .map_err(anyhow::Error::from)?;
+
+    let base_secs: u32 = template.phases.iter().map(|p| p.duration_secs).sum();
+    let minutes = (base_secs as f64 * scale / 60.0).round();
+    let params = TemplateParams {
+        name: format!("{} ({} min)", template.name, minutes),
+        max_hr,
+        duration_scale: scale,
+    };
+    let plan = template
+        .instantiate(&params)
+        .map_err(|e| PlanSaveError::InvalidPlan(e.to_string()))?;
+    save_plan_for(&plan, namespace.as_deref()).await?;
+
+    tracing::info!("instantiate_template: Plan '{}' saved", plan.name);
+    Ok(plan.name)
+}
+
+/// Delete a training plan by name.
+pub async fn delete_plan(name: String) -> std::result::Result<(), ApiError> {
+    delete_plan_for(name, None).await
+}
+
+/// Delete a training plan of a profile by name.
+///
+/// Same as [`delete_plan`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn delete_plan_for(
+    name: String,
+    namespace: Option<String>,
+) -> std::result::Result<(), ApiError> {
+    tracing::info!("delete_plan: Deleting plan '{}'", name);
+
+    let data_dir = get_data_dir_for(namespace.as_deref())?;
+    let plan_path = data_dir.join("plans").join(format!("{}.json", name));
+
+    if !plan_path.exists() {
+        return Err(PlanError::NotFound(name).into());
+    }
+
+    tokio::fs::remove_file(&plan_path).await?;
+    tracing::info!("delete_plan: Plan '{}' deleted", name);
     Ok(())
 }
 
 /// Get details of a training plan by name.
 ///
 /// Returns parallel arrays for FRB compatibility.
-pub async fn get_plan_details(name: String) -> Result<ApiPlanDetails> {
-    let plan = load_plan(&name).await?;
+pub async fn get_plan_details(name: String) -> std::result::Result<ApiPlanDetails, ApiError> {
+    get_plan_details_for(name, None).await
+}
+
+/// Get details of a training plan of a profile by name.
+///
+/// Same as [`get_plan_details`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn get_plan_details_for(
+    name: String,
+    namespace: Option<String>,
+) -> std::result::Result<ApiPlanDetails, ApiError> {
+    let plan = load_plan_for(&name, namespace.as_deref()).await?;
 
     let phase_names: Vec<String> = plan.phases.iter().map(|p| p.name.clone()).collect();
     let phase_zones: Vec<u8> = plan
@@ -3204,7 +6307,7 @@ pub async fn start_template_workout(template_id: String, max_hr: u16) -> Result<
 
     // Save the plan and start the workout
     save_plan(&plan).await?;
-    start_workout(plan.name).await
+    Ok(start_workout(plan.name).await?)
 }
 
 // =============================================================================
@@ -3212,31 +6315,154 @@ pub async fn start_template_workout(template_id: String, max_hr: u16) -> Result<
 // =============================================================================
 
 /// Export a session as TCX format.
-pub async fn export_session_tcx(session_id: String) -> Result<String> {
+pub async fn export_session_tcx(session_id: String) -> std::result::Result<String, ApiError> {
+    export_session_tcx_for(session_id, None).await
+}
+
+/// Export a session of a profile as TCX format.
+///
+/// Same as [`export_session_tcx`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_session_tcx_for(
+    session_id: String,
+    namespace: Option<String>,
+) -> std::result::Result<String, ApiError> {
     use crate::domain::export_formats;
 
-    let repo = get_session_repository().await?;
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
     let session = repo
         .get(&session_id)
         .await?
-        .ok_or_else(|| anyhow!("Session not found: {}", session_id))?;
+        .ok_or_else(|| SessionError::NotFound(session_id.clone()))?;
 
     Ok(export_formats::export_to_tcx(&session))
 }
 
 /// Export a session as GPX format.
-pub async fn export_session_gpx(session_id: String) -> Result<String> {
+pub async fn export_session_gpx(session_id: String) -> std::result::Result<String, ApiError> {
+    export_session_gpx_for(session_id, None).await
+}
+
+/// Export a session of a profile as GPX format.
+///
+/// Same as [`export_session_gpx`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_session_gpx_for(
+    session_id: String,
+    namespace: Option<String>,
+) -> std::result::Result<String, ApiError> {
     use crate::domain::export_formats;
 
-    let repo = get_session_repository().await?;
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
     let session = repo
         .get(&session_id)
         .await?
-        .ok_or_else(|| anyhow!("Session not found: {}", session_id))?;
+        .ok_or_else(|| SessionError::NotFound(session_id.clone()))?;
 
     Ok(export_formats::export_to_gpx(&session))
 }
 
+/// Export a session as a replay timing file for post-workout playback.
+///
+/// The JSON holds the session's `start_ms` and its samples as
+/// `{offset_ms, bpm, zone}` relative to the start. Zones are computed against
+/// the max HR of the session's plan; if the plan no longer exists, the
+/// session's own max HR is used. See [`crate::domain::export_to_replay`].
+pub async fn export_session_replay(session_id: String) -> std::result::Result<String, ApiError> {
+    export_session_replay_for(session_id, None).await
+}
+
+/// Export a session of a profile as a replay timing file.
+///
+/// Same as [`export_session_replay`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_session_replay_for(
+    session_id: String,
+    namespace: Option<String>,
+) -> std::result::Result<String, ApiError> {
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let session = repo
+        .get(&session_id)
+        .await?
+        .ok_or_else(|| SessionError::NotFound(session_id.clone()))?;
+
+    let max_hr = match load_plan_for(&session.plan_name, namespace.as_deref()).await {
+        Ok(plan) => Some(plan.max_hr),
+        Err(e) => {
+            tracing::warn!(
+                "export_session_replay: Plan '{}' unavailable, using session max HR: {:#}",
+                session.plan_name,
+                e
+            );
+            None
+        }
+    };
+    Ok(crate::domain::export_to_replay(&session, max_hr))
+}
+
+/// Export a session as a zip bundle of all shareable formats.
+///
+/// The archive at `output_path` holds `session.csv`, `session.json`,
+/// `summary.txt` and `session.tcx`. It is written to `<output_path>.part` and
+/// renamed into place, so a failed export never leaves a truncated file.
+///
+/// # Returns
+///
+/// The size of the archive in bytes.
+///
+/// # Errors
+///
+/// Returns an error if the session cannot be found or the file cannot be written.
+pub async fn export_session_bundle(
+    id: String,
+    output_path: String,
+) -> std::result::Result<u64, ApiError> {
+    export_session_bundle_for(id, output_path, None).await
+}
+
+/// Export a session of a profile as a zip bundle of all shareable formats.
+///
+/// Same as [`export_session_bundle`], for the profile `namespace` (see
+/// [`set_data_dir_for`]). `None` is the default profile.
+pub async fn export_session_bundle_for(
+    id: String,
+    output_path: String,
+    namespace: Option<String>,
+) -> std::result::Result<u64, ApiError> {
+    tracing::info!(
+        "export_session_bundle: Exporting session {} to {}",
+        id,
+        output_path
+    );
+
+    let repo = get_session_repository_for(namespace.as_deref()).await?;
+    let session = repo
+        .get(&id)
+        .await?
+        .ok_or_else(|| SessionError::NotFound(id.clone()))?;
+    let bytes = crate::domain::export_to_bundle(&session)?;
+
+    let path = std::path::PathBuf::from(&output_path);
+    let mut part_path = path.as_os_str().to_owned();
+    part_path.push(".part");
+    let part_path = std::path::PathBuf::from(part_path);
+
+    if let Err(e) = tokio::fs::write(&part_path, &bytes).await {
+        let _ = tokio::fs::remove_file(&part_path).await;
+        return Err(anyhow!("Failed to write '{}': {}", part_path.display(), e).into());
+    }
+    tokio::fs::rename(&part_path, &path)
+        .await
+        .map_err(|e| anyhow!("Failed to move export to '{}': {}", path.display(), e))?;
+
+    tracing::info!(
+        "export_session_bundle: Successfully exported session {} ({} bytes)",
+        id,
+        bytes.len()
+    );
+    Ok(bytes.len() as u64)
+}
+
 // =============================================================================
 // Adaptive Plan API
 // =============================================================================
@@ -3263,7 +6489,7 @@ pub struct ApiAdaptedPlan {
 }
 
 /// Get an adapted version of a plan based on current readiness.
-pub async fn get_adapted_plan(plan_name: String) -> Result<ApiAdaptedPlan> {
+pub async fn get_adapted_plan(plan_name: String) -> std::result::Result<ApiAdaptedPlan, ApiError> {
     use crate::domain::{adaptive, readiness, training_load};
 
     let plan = load_plan(&plan_name).await?;
@@ -3443,11 +6669,16 @@ mod tests {
     };
     use chrono::Utc;
 
+    /// Serializes tests that drive the global connection state or the shared
+    /// HR broadcast stream, so they do not observe each other's data.
+    static GLOBALS_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
     fn create_test_hr_data(raw_bpm: u16, filtered_bpm: u16) -> ApiFilteredHeartRate {
         ApiFilteredHeartRate {
             raw_bpm,
             filtered_bpm,
             rmssd: Some(45.0),
+            rmssd_trend: None,
             filter_variance: Some(1.5),
             battery_level: Some(85),
             timestamp: std::time::SystemTime::now()
@@ -3456,6 +6687,8 @@ mod tests {
                 .as_millis() as u64,
             receive_timestamp_micros: None,
             stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
         }
     }
 
@@ -3487,9 +6720,296 @@ mod tests {
                 avg_hr: 130,
                 max_hr: 140,
                 min_hr: 120,
+                hr_p50: 130,
+                hr_p90: 140,
+                hr_p95: 140,
                 time_in_zone: [0, 900, 900, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_export_all_sessions_reports_corrupt_session() {
+        let data_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-export-all".to_string(),
+            data_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-export-all".to_string());
+
+        let repo = get_session_repository_for(ns.as_deref()).await.unwrap();
+        for id in ["export-all-1", "export-all-2", "export-all-3"] {
+            repo.save(&create_test_session(id, "Batch")).await.unwrap();
         }
+
+        let out = tempfile::tempdir().unwrap();
+        let out_dir = out.path().join("backup");
+        let result = export_all_sessions_for(
+            ExportFormat::Json,
+            out_dir.to_string_lossy().to_string(),
+            ns.clone(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.exported, 3);
+        assert!(result.errors.is_empty());
+        assert_eq!(std::fs::read_dir(&out_dir).unwrap().count(), 3);
+        assert!(out_dir.join("export-all-2.json").exists());
+
+        // A corrupt session is reported without stopping the others
+        std::fs::write(
+            data_dir
+                .path()
+                .join("sessions")
+                .join("20240101--Broken--export-all-bad.json"),
+            "{ truncated",
+        )
+        .unwrap();
+
+        let csv_dir = out.path().join("csv");
+        let result =
+            export_all_sessions_for(ExportFormat::Csv, csv_dir.to_string_lossy().to_string(), ns)
+                .await
+                .unwrap();
+
+        assert_eq!(result.exported, 3);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].session_id, "export-all-bad");
+        assert!(!result.errors[0].message.is_empty());
+        assert_eq!(std::fs::read_dir(&csv_dir).unwrap().count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_export_session_replay_uses_plan_max_hr() {
+        use crate::domain::heart_rate::Zone;
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let data_dir = tempfile::tempdir().unwrap();
+        set_data_dir(data_dir.path().to_string_lossy().to_string()).unwrap();
+
+        // The session peaked at 140 BPM; the plan's max HR is 200
+        let session = create_test_session("replay-plan", "Replay Plan");
+        let repo = get_session_repository().await.unwrap();
+        repo.save(&session).await.unwrap();
+        save_plan(&TrainingPlan::steady("Replay Plan", Zone::Zone2, 600, 200))
+            .await
+            .unwrap();
+
+        let replay: serde_json::Value = serde_json::from_str(
+            &export_session_replay("replay-plan".to_string())
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        let zones: Vec<_> = replay["samples"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["zone"].clone())
+            .collect();
+        let expected: Vec<_> = [Zone::Zone2, Zone::Zone3, Zone::Zone2]
+            .iter()
+            .map(|z| serde_json::to_value(z).unwrap())
+            .collect();
+        assert_eq!(zones, expected);
+
+        // Without the plan, zones fall back to the session's own max HR
+        let orphan = create_test_session("replay-orphan", "Deleted Plan");
+        repo.save(&orphan).await.unwrap();
+        let replay = export_session_replay("replay-orphan".to_string())
+            .await
+            .unwrap();
+        assert_eq!(replay, crate::domain::export_to_replay(&orphan, None));
+    }
+
+    #[tokio::test]
+    async fn test_export_session_to_file_matches_in_memory_export() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let data_dir = tempfile::tempdir().unwrap();
+        set_data_dir(data_dir.path().to_string_lossy().to_string()).unwrap();
+
+        let mut session = create_test_session("export-to-file-5k", "Long Ride");
+        let start = session.start_time;
+        session.hr_samples = (0..5000)
+            .map(|i| HrSample {
+                timestamp: start + chrono::Duration::seconds(i),
+                bpm: 110 + (i % 60) as u16,
+            })
+            .collect();
+        get_session_repository()
+            .await
+            .unwrap()
+            .save(&session)
+            .await
+            .unwrap();
+
+        let out = tempfile::tempdir().unwrap();
+        for format in [ExportFormat::Csv, ExportFormat::Json, ExportFormat::Summary] {
+            let mut rx = subscribe_export_progress_stream();
+            let path = out
+                .path()
+                .join(format!("session.{}", format.file_extension()));
+
+            let bytes = export_session_to_file(
+                "export-to-file-5k".to_string(),
+                format,
+                path.to_string_lossy().to_string(),
+            )
+            .await
+            .unwrap();
+
+            let written = std::fs::read(&path).unwrap();
+            let expected = export_session("export-to-file-5k".to_string(), format)
+                .await
+                .unwrap();
+            assert_eq!(written, expected.into_bytes(), "{:?}", format);
+            assert_eq!(bytes, written.len() as u64);
+            let part = format!("session.{}.part", format.file_extension());
+            assert!(!out.path().join(part).exists());
+
+            let mut updates = Vec::new();
+            while let Ok(progress) = rx.try_recv() {
+                if progress.session_id == "export-to-file-5k" {
+                    updates.push(progress);
+                }
+            }
+            assert!(
+                updates.len() > 1,
+                "Expected intermediate and final progress for {:?}",
+                format
+            );
+            assert!(!updates[0].done);
+            let last = updates.last().unwrap();
+            assert!(last.done);
+            assert_eq!(last.samples_written, 5000);
+            assert_eq!(last.total_samples, 5000);
+            assert_eq!(last.bytes_written, bytes);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_raw_logging_captures_samples_and_flushes_on_stop() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("raw.jsonl");
+
+        start_raw_logging(path.to_string_lossy().to_string())
+            .await
+            .unwrap();
+        for bpm in 60..70 {
+            log_raw_hr_sample(&create_test_hr_data(bpm, bpm - 1)).await;
+        }
+        stop_raw_logging().await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let samples: Vec<ApiFilteredHeartRate> = contents
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(samples.len(), 10);
+        assert_eq!(samples[0].raw_bpm, 60);
+        assert_eq!(samples[9].filtered_bpm, 68);
+
+        // Samples after stopping are not logged and stopping again is a no-op
+        log_raw_hr_sample(&create_test_hr_data(80, 80)).await;
+        stop_raw_logging().await.unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), contents);
+    }
+
+    #[tokio::test]
+    async fn test_notification_router_delivers_without_failures() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let before = get_notification_failures();
+
+        notification_router()
+            .notify(crate::ports::NotificationEvent::SessionStarted)
+            .await
+            .unwrap();
+
+        assert_eq!(get_notification_failures(), before);
+    }
+
+    #[tokio::test]
+    async fn test_notifications_reach_the_port_for_their_severity() {
+        use crate::adapters::MockNotificationAdapter;
+        use crate::ports::NotificationEvent;
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let haptic = Arc::new(MockNotificationAdapter::new());
+        let toast = Arc::new(MockNotificationAdapter::new());
+        set_notification_port(NotificationSeverity::Critical, haptic.clone());
+        set_notification_port(NotificationSeverity::Info, toast.clone());
+
+        let notifier = notification_router();
+        notifier
+            .notify(NotificationEvent::ConnectionLost)
+            .await
+            .unwrap();
+        notifier
+            .notify(NotificationEvent::SessionStarted)
+            .await
+            .unwrap();
+        // No Warning port registered: only logged
+        notifier
+            .notify(NotificationEvent::BatteryLow { percentage: 10 })
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            haptic.get_events().await[..],
+            [NotificationEvent::ConnectionLost]
+        ));
+        assert!(matches!(
+            toast.get_events().await[..],
+            [NotificationEvent::SessionStarted]
+        ));
+
+        // Resetting restores the logging stubs
+        reset_globals().await;
+        notifier
+            .notify(NotificationEvent::ConnectionLost)
+            .await
+            .unwrap();
+        assert_eq!(haptic.event_count().await, 1);
+    }
+
+    #[test]
+    fn test_check_data_dir_writable_temp_dir() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("nested").join("data");
+
+        check_data_dir_writable(dir.to_string_lossy().to_string()).unwrap();
+
+        assert!(dir.is_dir());
+        assert!(!dir.join(".write-probe").exists());
+    }
+
+    #[test]
+    fn test_check_data_dir_writable_read_only_path() {
+        // A path beneath a regular file can never be created or written,
+        // regardless of the privileges the test runs with.
+        let temp = tempfile::tempdir().unwrap();
+        let file = temp.path().join("not-a-dir");
+        std::fs::write(&file, b"x").unwrap();
+
+        let result = check_data_dir_writable(file.join("data").to_string_lossy().to_string());
+        assert!(result.is_err());
+
+        let result = check_data_dir_writable(file.to_string_lossy().to_string());
+        assert!(result.unwrap_err().to_string().contains("not a directory"));
     }
 
     #[tokio::test]
@@ -3500,8 +7020,23 @@ mod tests {
         // Test passes if no panic
     }
 
+    #[tokio::test]
+    async fn test_set_lthr_validates_and_clears() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        assert_eq!(current_lthr(), None);
+        assert!(set_lthr(0).is_err());
+
+        set_lthr(160).unwrap();
+        assert_eq!(current_lthr(), Some(160));
+        assert_eq!(intensity_factor(144, current_lthr()), Some(0.9));
+
+        clear_lthr();
+        assert_eq!(current_lthr(), None);
+    }
+
     #[tokio::test]
     async fn test_emit_and_receive_hr_data() {
+        let _guard = GLOBALS_LOCK.lock().await;
         use tokio::time::{timeout, Duration};
 
         // Create a receiver
@@ -3525,6 +7060,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_multiple_receivers_fan_out() {
+        let _guard = GLOBALS_LOCK.lock().await;
         use tokio::time::{timeout, Duration};
 
         // Create fresh receivers immediately before emitting so they have no
@@ -3572,6 +7108,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_stream_backpressure() {
+        let _guard = GLOBALS_LOCK.lock().await;
         let mut rx = get_hr_stream_receiver();
 
         // Emit more than buffer capacity (100 items)
@@ -3580,83 +7117,846 @@ mod tests {
             emit_hr_data(data);
         }
 
-        // Should be able to receive data, but may have missed some due to lagging
-        match rx.recv().await {
-            Ok(data) => {
-                // Successfully received data
-                assert!(data.raw_bpm >= 60 && data.raw_bpm < 210);
-            }
-            Err(broadcast::error::RecvError::Lagged(skipped)) => {
-                // Expected when buffer is exceeded
-                assert!(skipped > 0, "Should report skipped messages");
-            }
-            Err(e) => panic!("Unexpected error: {:?}", e),
+        // Should be able to receive data, but may have missed some due to lagging
+        match rx.recv().await {
+            Ok(data) => {
+                // Successfully received data
+                assert!(data.raw_bpm >= 60 && data.raw_bpm < 210);
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                // Expected when buffer is exceeded
+                assert!(skipped > 0, "Should report skipped messages");
+            }
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_recent_hr_returns_latest_samples_in_order() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+
+        for i in 0..50 {
+            emit_hr_data(create_test_hr_data(60 + i, 60 + i));
+        }
+
+        let recent = get_recent_hr(20);
+        let bpms: Vec<u16> = recent.iter().map(|d| d.raw_bpm).collect();
+        assert_eq!(bpms, (90..110).collect::<Vec<u16>>());
+
+        // Asking for more than was emitted returns everything
+        assert_eq!(get_recent_hr(100).len(), 50);
+
+        // A smaller buffer keeps only the newest samples
+        set_recent_hr_capacity(10);
+        assert_eq!(get_recent_hr(20).first().map(|d| d.raw_bpm), Some(100));
+        emit_hr_data(create_test_hr_data(200, 200));
+        let recent = get_recent_hr(20);
+        assert_eq!(recent.len(), 10);
+        assert_eq!(recent.last().map(|d| d.raw_bpm), Some(200));
+
+        set_recent_hr_capacity(DEFAULT_RECENT_HR_CAPACITY);
+        reset_globals().await;
+    }
+
+    #[tokio::test]
+    async fn test_hr_cache_cleared_on_session_start_and_disconnect() {
+        use crate::domain::heart_rate::Zone;
+        use crate::domain::training_plan::TrainingPhase;
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+
+        for i in 0..5 {
+            emit_hr_data(create_test_hr_data(100 + i, 100 + i));
+        }
+        let plan = TrainingPlan {
+            name: "Fresh".to_string(),
+            phases: vec![TrainingPhase::timed("Steady", Zone::Zone2, 600)],
+            max_hr: 180,
+            ..Default::default()
+        };
+        save_plan(&plan).await.unwrap();
+        start_workout(plan.name.clone()).await.unwrap();
+
+        // Samples from before the session are not backfilled into it
+        assert!(get_recent_hr(10).is_empty());
+        assert!(get_app_snapshot().await.last_hr.is_none());
+
+        emit_hr_data(create_test_hr_data(120, 120));
+        stop_workout().await.unwrap();
+        assert_eq!(get_recent_hr(10).len(), 1);
+
+        assert_eq!(
+            subscribe_hr_latest().borrow().as_ref().map(|d| d.raw_bpm),
+            Some(120)
+        );
+        disconnect().await.unwrap();
+        assert!(get_recent_hr(10).is_empty());
+        assert!(get_app_snapshot().await.last_hr.is_none());
+        assert!(subscribe_hr_latest().borrow().is_none());
+
+        reset_globals().await;
+    }
+
+    #[tokio::test]
+    async fn test_connect_clears_samples_of_previous_device() {
+        use crate::adapters::{MockAdapter, MockConfig};
+        use tokio::time::{timeout, Duration};
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+
+        for _ in 0..5 {
+            emit_hr_data(create_test_hr_data(50, 50));
+        }
+        let config = MockConfig {
+            baseline_bpm: 120,
+            noise_range: 0,
+            spike_probability: 0.0,
+            ..MockConfig::default()
+        };
+        set_ble_adapter(Arc::new(MockAdapter::with_config(config))).await;
+        let devices = scan_devices().await.unwrap();
+        let mut rx = get_hr_stream_receiver();
+        connect_device(devices[0].id.clone()).await.unwrap();
+        timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("Timed out waiting for HR data")
+            .unwrap();
+
+        let recent = get_recent_hr(10);
+        disconnect().await.unwrap();
+        reset_globals().await;
+
+        assert!(!recent.is_empty());
+        assert!(recent.iter().all(|d| d.raw_bpm == 120));
+    }
+
+    #[tokio::test]
+    async fn test_hr_latest_sees_last_value_without_lag() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        let mut slow = subscribe_hr_latest();
+
+        // Emit more than the broadcast buffer holds
+        for i in 0..150 {
+            let data = create_test_hr_data(60 + i as u16, 60 + i as u16);
+            emit_hr_data(data);
+        }
+
+        let fresh = subscribe_hr_latest();
+        assert_eq!(fresh.borrow().as_ref().map(|d| d.raw_bpm), Some(209));
+
+        // A consumer that fell behind skips straight to the latest value
+        assert!(slow.has_changed().unwrap());
+        let latest = slow.borrow_and_update().clone().unwrap();
+        assert_eq!(latest.raw_bpm, 209);
+        assert!(!slow.has_changed().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_session_api_integration() {
+        use std::env;
+        use tempfile::tempdir;
+
+        // Create a temporary directory for this test
+        let temp_dir = tempdir().unwrap();
+        let temp_path = temp_dir.path().to_str().unwrap();
+
+        // Set HOME environment variable to temp directory so FileSessionRepository
+        // will use a temporary .heart-beat/sessions directory
+        let original_home = env::var("HOME").ok();
+        env::set_var("HOME", temp_path);
+
+        // Clear the global repository to force re-initialization
+        // This is a bit hacky but necessary for testing with a temp directory
+        if let Some(mutex) = SESSION_REPOSITORY.get() {
+            *mutex.lock().await = None;
+        }
+
+        // Create a test session
+        let session = create_test_session("test-api-123", "Test Workout");
+
+        // Save the session directly using the repository
+        let repo = get_session_repository().await.unwrap();
+        repo.save(&session).await.unwrap();
+
+        // Test list_sessions
+        let sessions = list_sessions().await.unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].id, "test-api-123");
+        assert_eq!(sessions[0].plan_name, "Test Workout");
+        assert_eq!(sessions[0].avg_hr, 130);
+
+        // Test get_session
+        let retrieved = get_session("test-api-123".to_string()).await.unwrap();
+        assert!(retrieved.is_some());
+        let retrieved_session = retrieved.unwrap();
+        assert_eq!(retrieved_session.id, "test-api-123");
+        assert_eq!(retrieved_session.hr_samples.len(), 3);
+
+        // Test get_session with non-existent ID
+        let not_found = get_session("nonexistent".to_string()).await.unwrap();
+        assert!(not_found.is_none());
+
+        // Test delete_session
+        delete_session("test-api-123".to_string()).await.unwrap();
+        let sessions_after_delete = list_sessions().await.unwrap();
+        assert_eq!(sessions_after_delete.len(), 0);
+
+        // Restore original HOME
+        if let Some(home) = original_home {
+            env::set_var("HOME", home);
+        } else {
+            env::remove_var("HOME");
+        }
+
+        // Clean up temp directory
+        temp_dir.close().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_session_adherence_loads_plan_by_name() {
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+
+        let data_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-adherence".to_string(),
+            data_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let namespace = Some("ns-test-adherence".to_string());
+
+        let phase = |name: &str, target_zone| TrainingPhase {
+            name: name.to_string(),
+            target_zone,
+            duration_secs: 900,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "Steady Build".to_string(),
+            phases: vec![phase("Easy", Zone::Zone2), phase("Tempo", Zone::Zone3)],
+            created_at: Utc::now(),
+            max_hr: 190,
+            ..Default::default()
+        };
+        save_plan_for(&plan, namespace.as_deref()).await.unwrap();
+
+        let repo = get_session_repository_for(namespace.as_deref())
+            .await
+            .unwrap();
+        // 900s in Zone 2 and 900s in Zone 3, exactly as planned
+        repo.save(&create_test_session("on-plan", "Steady Build"))
+            .await
+            .unwrap();
+        repo.save(&create_test_session("no-plan", "Deleted Plan"))
+            .await
+            .unwrap();
+
+        let score = session_adherence_for("on-plan".to_string(), namespace.clone())
+            .await
+            .unwrap();
+        assert!((score - 100.0).abs() < 1e-9, "score was {}", score);
+
+        assert!(
+            session_adherence_for("no-plan".to_string(), namespace.clone())
+                .await
+                .is_err()
+        );
+        assert!(session_adherence_for("missing".to_string(), namespace)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_is_idempotent() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+
+        shutdown().await.unwrap();
+        shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_set_session_context_persists() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+
+        let repo = get_session_repository().await.unwrap();
+        repo.save(&create_test_session("ctx-1", "Hot Run"))
+            .await
+            .unwrap();
+
+        set_session_context(
+            "ctx-1".to_string(),
+            Some(31.5),
+            7,
+            Some("humid, headwind".to_string()),
+        )
+        .await
+        .unwrap();
+
+        // Read back through a fresh repository so nothing comes from memory
+        reset_globals().await;
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+        let session = get_session("ctx-1".to_string()).await.unwrap().unwrap();
+        assert_eq!(
+            session.context,
+            Some(SessionContextMeta {
+                temperature_c: Some(31.5),
+                rpe: 7,
+                conditions: Some("humid, headwind".to_string()),
+            })
+        );
+        assert_eq!(session_rpe(&session), Some(7));
+
+        assert!(set_session_context("ctx-1".to_string(), None, 11, None)
+            .await
+            .is_err());
+        assert!(set_session_context("missing".to_string(), None, 5, None)
+            .await
+            .is_err());
+
+        reset_globals().await;
+    }
+
+    #[tokio::test]
+    async fn test_namespaced_sessions_do_not_cross_contaminate() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        // Neither directory exists yet; they are created on first use
+        let alice_dir = temp_dir.path().join("alice");
+        let bob_dir = temp_dir.path().join("bob");
+        set_data_dir_for(
+            "ns-test-alice".to_string(),
+            alice_dir.to_string_lossy().to_string(),
+        )
+        .unwrap();
+        set_data_dir_for(
+            "ns-test-bob".to_string(),
+            bob_dir.to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let alice = Some("ns-test-alice".to_string());
+        let bob = Some("ns-test-bob".to_string());
+
+        get_session_repository_for(alice.as_deref())
+            .await
+            .unwrap()
+            .save(&create_test_session("alice-1", "Alice Run"))
+            .await
+            .unwrap();
+        let bob_repo = get_session_repository_for(bob.as_deref()).await.unwrap();
+        bob_repo
+            .save(&create_test_session("bob-1", "Bob Run"))
+            .await
+            .unwrap();
+        bob_repo
+            .save(&create_test_session("bob-2", "Bob Intervals"))
+            .await
+            .unwrap();
+
+        let alice_sessions = list_sessions_for(alice.clone()).await.unwrap();
+        assert_eq!(alice_sessions.len(), 1);
+        assert_eq!(alice_sessions[0].id, "alice-1");
+
+        let bob_sessions = list_sessions_for(bob.clone()).await.unwrap();
+        assert_eq!(bob_sessions.len(), 2);
+        assert!(bob_sessions.iter().all(|s| s.id.starts_with("bob-")));
+
+        assert!(get_session_for("bob-1".to_string(), alice.clone())
+            .await
+            .unwrap()
+            .is_none());
+        assert!(alice_dir.join("sessions").is_dir());
+        assert!(bob_dir.join("sessions").is_dir());
+
+        // Searches, checks and exports only see the profile's own sessions
+        let matches = search_sessions_for("intervals".to_string(), bob.clone())
+            .await
+            .unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].id, "bob-2");
+        assert!(verify_sessions_for(false, bob.clone())
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(
+            export_session_for("bob-1".to_string(), ExportFormat::Json, bob.clone())
+                .await
+                .unwrap()
+                .contains("bob-1")
+        );
+        let err = export_session_for("bob-1".to_string(), ExportFormat::Json, alice.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::SessionNotFound);
+        export_session_local_for("bob-1".to_string(), ExportFormat::Csv, 540, bob.clone())
+            .await
+            .unwrap();
+        export_session_bytes_for("bob-1".to_string(), ExportFormat::Binary, bob.clone())
+            .await
+            .unwrap();
+        export_session_tcx_for("bob-1".to_string(), bob.clone())
+            .await
+            .unwrap();
+        export_session_gpx_for("bob-1".to_string(), bob.clone())
+            .await
+            .unwrap();
+        export_session_replay_for("bob-1".to_string(), bob.clone())
+            .await
+            .unwrap();
+        let out_dir = tempfile::tempdir().unwrap();
+        let csv_path = out_dir.path().join("bob-1.csv");
+        export_session_to_file_for(
+            "bob-1".to_string(),
+            ExportFormat::Csv,
+            csv_path.to_string_lossy().to_string(),
+            bob.clone(),
+        )
+        .await
+        .unwrap();
+        assert!(csv_path.exists());
+        let zip_path = out_dir.path().join("bob-1.zip");
+        export_session_bundle_for(
+            "bob-1".to_string(),
+            zip_path.to_string_lossy().to_string(),
+            bob.clone(),
+        )
+        .await
+        .unwrap();
+        assert!(zip_path.exists());
+        assert!(export_session_tcx_for("bob-1".to_string(), alice.clone())
+            .await
+            .is_err());
+
+        // Session context is recorded in the profile's own session
+        set_session_context_for("bob-1".to_string(), Some(12.0), 4, None, bob.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            get_session_for("bob-1".to_string(), bob.clone())
+                .await
+                .unwrap()
+                .unwrap()
+                .context
+                .map(|c| c.rpe),
+            Some(4)
+        );
+        assert!(
+            set_session_context_for("bob-1".to_string(), None, 4, None, alice.clone())
+                .await
+                .is_err()
+        );
+
+        // Deleting in one profile leaves the other untouched
+        delete_session_for("alice-1".to_string(), alice.clone())
+            .await
+            .unwrap();
+        assert!(list_sessions_for(alice).await.unwrap().is_empty());
+        assert_eq!(list_sessions_for(bob).await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_start_workout_for_uses_the_profile_plans_and_sessions() {
+        use crate::domain::heart_rate::Zone;
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let default_dir = tempfile::tempdir().unwrap();
+        let carol_dir = tempfile::tempdir().unwrap();
+        set_data_dir(default_dir.path().to_string_lossy().to_string()).unwrap();
+        set_data_dir_for(
+            "ns-test-workout".to_string(),
+            carol_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let carol = Some("ns-test-workout".to_string());
+
+        let plan = TrainingPlan::steady("Carol Run", Zone::Zone2, 600, 180);
+        save_plan_for(&plan, carol.as_deref()).await.unwrap();
+
+        // The plan only exists in the profile
+        assert!(matches!(
+            start_workout(plan.name.clone()).await,
+            Err(StartWorkoutError::Plan(PlanError::NotFound(_)))
+        ));
+
+        start_workout_for(plan.name.clone(), carol.clone())
+            .await
+            .unwrap();
+        stop_workout().await.unwrap();
+
+        let sessions = list_sessions_for(carol).await.unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].plan_name, "Carol Run");
+        assert!(list_sessions().await.unwrap().is_empty());
+
+        reset_globals().await;
+    }
+
+    #[tokio::test]
+    async fn test_namespaced_plans_are_isolated() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-plans".to_string(),
+            temp_dir
+                .path()
+                .join("plans-profile")
+                .to_string_lossy()
+                .to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-plans".to_string());
+
+        let plan = TrainingPlan {
+            name: "Profile Plan".to_string(),
+            phases: vec![crate::domain::training_plan::TrainingPhase {
+                name: "Steady".to_string(),
+                target_zone: crate::domain::heart_rate::Zone::Zone2,
+                duration_secs: 600,
+                transition: crate::domain::training_plan::TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: chrono::Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+        save_plan_for(&plan, ns.as_deref()).await.unwrap();
+
+        assert_eq!(
+            list_plans_for(ns.clone()).await.unwrap(),
+            vec!["Profile Plan"]
+        );
+        let details = get_plan_details_for("Profile Plan".to_string(), ns.clone())
+            .await
+            .unwrap();
+        assert_eq!(details.phase_durations, vec![600]);
+
+        delete_plan_for("Profile Plan".to_string(), ns.clone())
+            .await
+            .unwrap();
+        assert!(list_plans_for(ns).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_seed_default_plans_keeps_phase_names() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let data_dir = tempfile::tempdir().unwrap();
+        set_data_dir(data_dir.path().to_string_lossy().to_string()).unwrap();
+
+        assert_eq!(seed_default_plans().await.unwrap(), 5);
+        assert_eq!(seed_default_plans().await.unwrap(), 0);
+
+        let phase_names = |plan: TrainingPlan| -> Vec<String> {
+            plan.phases.into_iter().map(|p| p.name).collect()
+        };
+        assert_eq!(
+            phase_names(load_plan("Easy Run").await.unwrap()),
+            vec!["Warmup", "Easy Pace", "Cooldown"]
+        );
+        assert_eq!(
+            phase_names(load_plan("Tempo Run").await.unwrap()),
+            vec!["Warmup", "Tempo", "Cooldown"]
+        );
+        assert_eq!(
+            phase_names(load_plan("Long Slow Distance").await.unwrap()),
+            vec!["Steady Aerobic"]
+        );
+        assert_eq!(
+            phase_names(load_plan("Recovery Run").await.unwrap()),
+            vec!["Easy Recovery"]
+        );
+
+        let intervals = load_plan("Interval Training").await.unwrap();
+        assert_eq!(intervals.phases.len(), 12);
+        assert_eq!(intervals.phases[1].name, "Interval 1");
+        assert_eq!(intervals.phases[10].name, "Recovery 5");
+    }
+
+    #[tokio::test]
+    async fn test_save_training_plan_then_delete() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-save-plan".to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-save-plan".to_string());
+
+        let plan_json = r#"{
+            "name": "Threshold Builder",
+            "phases": [
+                {"name": "Warmup", "target_zone": "Zone2", "duration_secs": 600, "transition": "TimeElapsed"},
+                {"name": "Work", "target_zone": "Zone4", "duration_secs": 1200, "transition": "TimeElapsed"}
+            ],
+            "created_at": "2024-01-01T00:00:00Z",
+            "max_hr": 185
+        }"#;
+
+        save_training_plan_for(plan_json.to_string(), ns.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            list_plans_for(ns.clone()).await.unwrap(),
+            vec!["Threshold Builder"]
+        );
+
+        delete_plan_for("Threshold Builder".to_string(), ns.clone())
+            .await
+            .unwrap();
+        assert!(list_plans_for(ns).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_diff_plans_against_stored_plan() {
+        use crate::domain::heart_rate::Zone;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-diff".to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-diff".to_string());
+
+        let plan = TrainingPlan::steady("Tempo", Zone::Zone3, 1200, 185);
+        save_plan_for(&plan, ns.as_deref()).await.unwrap();
+
+        let mut edited = plan.clone().with_cooldown(Zone::Zone1, 300);
+        edited.phases[0].duration_secs = 1500;
+        let edited_json = serde_json::to_string(&edited).unwrap();
+
+        let changes = diff_plans_for("Tempo".to_string(), edited_json.clone(), ns.clone())
+            .await
+            .unwrap();
+        let descriptions: Vec<String> = changes.iter().map(plan_change_description).collect();
+        assert_eq!(
+            descriptions,
+            vec!["Changed phase 1 'Tempo'", "Added phase 2 'Cooldown'"]
+        );
+
+        let err = diff_plans_for("Missing".to_string(), edited_json, ns.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanNotFound);
+        assert_eq!(
+            err.message,
+            PlanError::NotFound("Missing".to_string()).to_string()
+        );
+        let err = diff_plans_for("Tempo".to_string(), "{".to_string(), ns)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanInvalid);
+        assert!(err.message.starts_with("Invalid plan JSON"));
+    }
+
+    #[tokio::test]
+    async fn test_compose_plans_saves_composite() {
+        use crate::domain::heart_rate::Zone;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-compose".to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-compose".to_string());
+
+        for plan in [
+            TrainingPlan::steady("Bike", Zone::Zone2, 3600, 185),
+            TrainingPlan::steady("Run", Zone::Zone3, 1200, 185),
+        ] {
+            save_plan_for(&plan, ns.as_deref()).await.unwrap();
+        }
+
+        compose_plans_for(
+            vec!["Bike".to_string(), "Run".to_string()],
+            "Brick".to_string(),
+            ns.clone(),
+        )
+        .await
+        .unwrap();
+
+        let details = get_plan_details_for("Brick".to_string(), ns.clone())
+            .await
+            .unwrap();
+        assert_eq!(details.phase_names, vec!["Bike", "Run"]);
+        assert_eq!(details.phase_durations, vec![3600, 1200]);
+
+        // Missing plans and bad names are rejected without saving
+        assert!(compose_plans_for(
+            vec!["Bike".to_string(), "Swim".to_string()],
+            "Tri".to_string(),
+            ns.clone()
+        )
+        .await
+        .is_err());
+        let err = compose_plans_for(vec!["Bike".to_string()], "../Bike".to_string(), ns.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanInvalid);
+        assert_eq!(list_plans_for(ns).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_create_plan_from_session_saves_derived_plan() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-derive".to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-derive".to_string());
+
+        let repo = get_session_repository_for(ns.as_deref()).await.unwrap();
+        repo.save(&create_test_session("freeform", "Deleted Plan"))
+            .await
+            .unwrap();
+
+        create_plan_from_session_for("freeform".to_string(), "Repeat".to_string(), ns.clone())
+            .await
+            .unwrap();
+        let plan = load_plan_for("Repeat", ns.as_deref()).await.unwrap();
+        assert_eq!(plan.name, "Repeat");
+        assert!(!plan.phases.is_empty());
+        // Without its plan, a peak below the default max HR doesn't lower it
+        assert_eq!(plan.max_hr, 180);
+
+        // An existing plan is not overwritten
+        let err =
+            create_plan_from_session_for("freeform".to_string(), "Repeat".to_string(), ns.clone())
+                .await
+                .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanInvalid);
+        assert_eq!(
+            err.message,
+            PlanSaveError::AlreadyExists("Repeat".to_string()).to_string()
+        );
+
+        let err = create_plan_from_session_for("missing".to_string(), "Other".to_string(), ns)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::SessionNotFound);
+    }
+
+    #[tokio::test]
+    async fn test_list_plans_detailed_reports_difficulty() {
+        use crate::domain::heart_rate::Zone;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-detailed".to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-detailed".to_string());
+
+        for plan in [
+            TrainingPlan::steady("Easy", Zone::Zone1, 1800, 185),
+            TrainingPlan::steady("Hard", Zone::Zone5, 1800, 185),
+        ] {
+            save_plan_for(&plan, ns.as_deref()).await.unwrap();
         }
+        // Unreadable plans are skipped
+        std::fs::write(temp_dir.path().join("plans").join("Broken.json"), "{").unwrap();
+
+        let summaries = list_plans_detailed_for(ns).await.unwrap();
+        let names: Vec<String> = summaries.iter().map(plan_summary_name).collect();
+        assert_eq!(names, vec!["Easy", "Hard"]);
+        assert_eq!(plan_summary_duration_secs(&summaries[0]), 1800);
+        assert_eq!(plan_summary_phase_count(&summaries[0]), 1);
+        assert!(plan_summary_difficulty(&summaries[1]) > plan_summary_difficulty(&summaries[0]));
     }
 
     #[tokio::test]
-    async fn test_session_api_integration() {
-        use std::env;
-        use tempfile::tempdir;
+    async fn test_instantiate_template_saves_scaled_plan() {
+        use crate::domain::heart_rate::Zone;
 
-        // Create a temporary directory for this test
-        let temp_dir = tempdir().unwrap();
-        let temp_path = temp_dir.path().to_str().unwrap();
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-template".to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-template".to_string());
 
-        // Set HOME environment variable to temp directory so FileSessionRepository
-        // will use a temporary .heart-beat/sessions directory
-        let original_home = env::var("HOME").ok();
-        env::set_var("HOME", temp_path);
+        let template = PlanTemplate::from_plan(
+            &TrainingPlan::steady("Tempo", Zone::Zone3, 1200, 180).with_warmup(Zone::Zone2, 600),
+        );
+        save_plan_template_for(serde_json::to_string(&template).unwrap(), ns.clone())
+            .await
+            .unwrap();
 
-        // Clear the global repository to force re-initialization
-        // This is a bit hacky but necessary for testing with a temp directory
-        if let Some(mutex) = SESSION_REPOSITORY.get() {
-            *mutex.lock().await = None;
-        }
+        let name = instantiate_template_for("Tempo".to_string(), 190, 2.0, ns.clone())
+            .await
+            .unwrap();
+        assert_eq!(name, "Tempo (60 min)");
+        let details = get_plan_details_for(name, ns.clone()).await.unwrap();
+        assert_eq!(details.phase_durations, vec![1200, 2400]);
+        assert_eq!(details.max_hr, 190);
 
-        // Create a test session
-        let session = create_test_session("test-api-123", "Test Workout");
+        let err = instantiate_template_for("Tempo".to_string(), 190, -1.0, ns.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanInvalid);
+        assert!(
+            instantiate_template_for("Missing".to_string(), 190, 1.0, ns)
+                .await
+                .is_err()
+        );
+    }
 
-        // Save the session directly using the repository
-        let repo = get_session_repository().await.unwrap();
-        repo.save(&session).await.unwrap();
+    #[tokio::test]
+    async fn test_save_training_plan_rejects_invalid_plans() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir_for(
+            "ns-test-save-invalid".to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let ns = Some("ns-test-save-invalid".to_string());
 
-        // Test list_sessions
-        let sessions = list_sessions().await.unwrap();
-        assert_eq!(sessions.len(), 1);
-        assert_eq!(sessions[0].id, "test-api-123");
-        assert_eq!(sessions[0].plan_name, "Test Workout");
-        assert_eq!(sessions[0].avg_hr, 130);
+        let err = save_training_plan_for("not json".to_string(), ns.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanInvalid);
+        assert!(err.message.starts_with("Invalid plan JSON"));
 
-        // Test get_session
-        let retrieved = get_session("test-api-123".to_string()).await.unwrap();
-        assert!(retrieved.is_some());
-        let retrieved_session = retrieved.unwrap();
-        assert_eq!(retrieved_session.id, "test-api-123");
-        assert_eq!(retrieved_session.hr_samples.len(), 3);
+        let empty_phases = r#"{"name": "Empty", "phases": [], "created_at": "2024-01-01T00:00:00Z", "max_hr": 185}"#;
+        let err = save_training_plan_for(empty_phases.to_string(), ns.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanInvalid);
+        assert!(err.message.starts_with("Invalid plan:"));
 
-        // Test get_session with non-existent ID
-        let not_found = get_session("nonexistent".to_string()).await.unwrap();
-        assert!(not_found.is_none());
+        let bad_name = r#"{"name": "../escape", "phases": [{"name": "A", "target_zone": "Zone2", "duration_secs": 60, "transition": "TimeElapsed"}], "created_at": "2024-01-01T00:00:00Z", "max_hr": 185}"#;
+        let err = save_training_plan_for(bad_name.to_string(), ns.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanInvalid);
+        assert!(err.message.starts_with("Invalid plan name"));
 
-        // Test delete_session
-        delete_session("test-api-123".to_string()).await.unwrap();
-        let sessions_after_delete = list_sessions().await.unwrap();
-        assert_eq!(sessions_after_delete.len(), 0);
+        assert!(list_plans_for(ns).await.unwrap().is_empty());
+    }
 
-        // Restore original HOME
-        if let Some(home) = original_home {
-            env::set_var("HOME", home);
-        } else {
-            env::remove_var("HOME");
+    #[test]
+    fn test_set_data_dir_for_rejects_invalid_namespace() {
+        for ns in ["", ".", "..", "a/b", "a\\b"] {
+            assert!(set_data_dir_for(ns.to_string(), "/tmp".to_string()).is_err());
         }
-
-        // Clean up temp directory
-        temp_dir.close().unwrap();
     }
 
     #[tokio::test]
     async fn test_disconnect_when_connected() {
+        let _guard = GLOBALS_LOCK.lock().await;
         use tokio::time::{sleep, Duration};
 
         // Clear any existing connection state
@@ -3704,6 +8004,8 @@ mod tests {
             device_id: "test-device-123".to_string(),
             hr_task_handle,
             battery_task_handle,
+            health_task_handle: tokio::spawn(async {}),
+            cancel_token: CancellationToken::new(),
         };
 
         let state_mutex = CONNECTION_STATE.get_or_init(|| tokio::sync::Mutex::new(None));
@@ -3725,8 +8027,610 @@ mod tests {
         sleep(Duration::from_millis(50)).await;
     }
 
+    #[tokio::test]
+    async fn test_hr_task_uses_adapter_receive_time() {
+        use tokio::time::{timeout, Duration};
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+
+        let (hr_tx, hr_rx) = tokio::sync::mpsc::channel::<HrNotification>(10);
+        let cancel_token = CancellationToken::new();
+        let mut handle = spawn_hr_task(hr_rx, cancel_token.clone());
+        let mut rx = get_hr_stream_receiver();
+
+        // Arrived well before the task gets to it
+        let received_at = std::time::SystemTime::now() - Duration::from_secs(3);
+        let expected_ms = received_at
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        hr_tx
+            .send(HrNotification {
+                data: vec![0x06, 72],
+                received_at,
+            })
+            .await
+            .unwrap();
+
+        let sample = timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("sample should be emitted")
+            .unwrap();
+        assert_eq!(sample.raw_bpm, 72);
+        assert_eq!(sample.timestamp, expected_ms);
+        assert_eq!(
+            sample.receive_timestamp_micros.map(|us| us / 1000),
+            Some(expected_ms)
+        );
+
+        cancel_token.cancel();
+        assert!(stop_task("HR", &mut handle).await);
+    }
+
+    #[tokio::test]
+    async fn test_hr_liveness_ticks_without_hr_and_stalls_on_exit() {
+        use tokio::time::{sleep, timeout, Duration};
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        assert!(get_hr_liveness().is_none());
+
+        let mut rx = subscribe_hr_liveness_stream();
+        let (hr_tx, hr_rx) = tokio::sync::mpsc::channel::<HrNotification>(10);
+        let cancel_token = CancellationToken::new();
+        let mut handle = spawn_hr_task(hr_rx, cancel_token.clone());
+
+        // Ticks keep coming and advancing although no HR packet is sent
+        let first = timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("first tick")
+            .unwrap();
+        let second = timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("second tick")
+            .unwrap();
+        assert!(second.timestamp > first.timestamp);
+        assert_eq!(second.last_hr_timestamp, None);
+
+        // The adapter's notification stream ends, so the task exits
+        drop(hr_tx);
+        assert!(stop_task("HR", &mut handle).await);
+        let last = get_hr_liveness().unwrap().timestamp;
+        sleep(HR_LIVENESS_INTERVAL + Duration::from_millis(500)).await;
+        assert_eq!(get_hr_liveness().unwrap().timestamp, last);
+        while let Ok(tick) = rx.try_recv() {
+            assert!(tick.timestamp <= last);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_hr_task_cleans_up() {
+        let (hr_tx, hr_rx) = tokio::sync::mpsc::channel::<HrNotification>(10);
+        let cancel_token = CancellationToken::new();
+        let mut handle = spawn_hr_task(hr_rx, cancel_token.clone());
+
+        // The task is idle waiting for notifications until cancelled
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!handle.is_finished());
+        assert!(!hr_tx.is_closed());
+
+        cancel_token.cancel();
+
+        // It exits on its own (no abort) and drops its subscription on the way
+        assert!(stop_task("HR", &mut handle).await);
+        assert!(hr_tx.is_closed());
+    }
+
+    #[tokio::test]
+    async fn test_mock_pipeline_end_to_end() {
+        use crate::adapters::{MockAdapter, MockConfig};
+        use tokio::time::{timeout, Duration};
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+
+        // Steady 120 BPM with no noise or spikes
+        let config = MockConfig {
+            baseline_bpm: 120,
+            noise_range: 0,
+            spike_probability: 0.0,
+            ..MockConfig::default()
+        };
+        set_ble_adapter(Arc::new(MockAdapter::with_config(config))).await;
+
+        // scan -> connect -> HR stream -> filter -> emit
+        let devices = scan_devices().await.unwrap();
+        assert!(!devices.is_empty(), "Mock adapter should be discovered");
+
+        let mut rx = get_hr_stream_receiver();
+        connect_device(devices[0].id.clone()).await.unwrap();
+
+        let mut samples = Vec::new();
+        while samples.len() < 4 {
+            match timeout(Duration::from_secs(5), rx.recv()).await {
+                Ok(Ok(sample)) => samples.push(sample),
+                Ok(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                Ok(Err(e)) => panic!("HR stream closed: {:?}", e),
+                Err(_) => panic!("Timed out waiting for HR data"),
+            }
+        }
+
+        disconnect().await.unwrap();
+        reset_globals().await;
+
+        assert!(
+            samples.iter().all(|s| s.raw_bpm == 120),
+            "Raw BPM should match the injected value: {:?}",
+            samples.iter().map(|s| s.raw_bpm).collect::<Vec<_>>()
+        );
+
+        // The filtered value converges on the injected BPM
+        let errors: Vec<u16> = samples
+            .iter()
+            .map(|s| s.filtered_bpm.abs_diff(120))
+            .collect();
+        assert!(
+            errors.windows(2).all(|w| w[1] <= w[0]),
+            "Filtered BPM should approach 120: {:?}",
+            errors
+        );
+        assert!(
+            errors[errors.len() - 1] <= 5,
+            "Filtered BPM too far from 120: {:?}",
+            errors
+        );
+    }
+
+    #[tokio::test]
+    async fn test_auto_connect_targets_last_connected_device() {
+        use crate::adapters::MockAdapter;
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+        set_ble_adapter(Arc::new(MockAdapter::new())).await;
+
+        // Nothing to reconnect to yet
+        assert_eq!(auto_connect_last_device().await.unwrap(), None);
+
+        // Connect to the second of the mock devices
+        find_device("mock-device-002").await.unwrap();
+        connect_device("mock-device-002".to_string()).await.unwrap();
+        disconnect().await.unwrap();
+
+        let remembered = PersistedConnectionState::load(&last_device_path().unwrap())
+            .unwrap()
+            .expect("Connected device should be remembered");
+        assert_eq!(remembered.device_id, "mock-device-002");
+        assert_eq!(remembered.device_name.as_deref(), Some("Simulated HRM"));
+
+        // A fresh adapter (as on the next app start) has discovered nothing
+        set_ble_adapter(Arc::new(MockAdapter::new())).await;
+        let mut status_rx = subscribe_connection_status_stream();
+        let connected = auto_connect_last_device().await.unwrap();
+        let snapshot = get_app_snapshot().await;
+        disconnect().await.unwrap();
+        reset_globals().await;
+
+        assert_eq!(connected.as_deref(), Some("mock-device-002"));
+        assert_eq!(
+            snapshot.connection_status,
+            ApiConnectionStatus::Connected {
+                device_id: "mock-device-002".to_string()
+            }
+        );
+        let mut statuses = Vec::new();
+        while let Ok(status) = status_rx.try_recv() {
+            statuses.push(status);
+        }
+        assert!(statuses.contains(&ApiConnectionStatus::Connected {
+            device_id: "mock-device-002".to_string()
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_set_log_level_passes_previously_filtered_records() {
+        use tokio::time::{timeout, Duration};
+
+        let (subscriber, handle) = build_log_subscriber(EnvFilter::new("info"));
+        let mut rx = subscribe_log_stream();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("reload-test before");
+            apply_log_level(&handle, "DEBUG").unwrap();
+            tracing::debug!("reload-test after");
+        });
+
+        let mut messages = Vec::new();
+        while let Ok(Ok(msg)) = timeout(Duration::from_millis(100), rx.recv()).await {
+            if msg.message.starts_with("reload-test") {
+                messages.push(msg.message);
+            }
+        }
+        assert_eq!(messages, vec!["reload-test after".to_string()]);
+
+        assert!(apply_log_level(&handle, "verbose").is_err());
+        assert!(apply_log_level(&handle, "").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resume_last_workout() {
+        use crate::domain::heart_rate::Zone;
+        use crate::domain::training_plan::{TrainingPhase, TrainingPlan, TransitionCondition};
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+
+        // No checkpoint: nothing to resume
+        assert!(!resume_last_workout().await.unwrap());
+
+        let phase = |name: &str, target_zone| TrainingPhase {
+            name: name.to_string(),
+            target_zone,
+            duration_secs: 60,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "Interrupted".to_string(),
+            phases: vec![phase("Warmup", Zone::Zone1), phase("Work", Zone::Zone3)],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+        let checkpoint = serde_json::json!({
+            "plan": plan,
+            "current_phase": 1,
+            "elapsed_secs": 12,
+            "is_paused": false,
+        });
+        let checkpoint_path = temp_dir.path().join("session_checkpoint.json");
+        std::fs::write(&checkpoint_path, checkpoint.to_string()).unwrap();
+
+        assert!(resume_last_workout().await.unwrap());
+        let progress = {
+            let executor_guard = get_session_executor().await.unwrap().lock().await;
+            executor_guard.as_ref().unwrap().get_progress().await
+        };
+        let (phase, elapsed, _) = progress.expect("Workout should be in progress");
+        assert_eq!(phase, 1);
+        assert!(
+            elapsed >= 12,
+            "Should resume at the saved time, got {}",
+            elapsed
+        );
+
+        // A workout stopped by the user is not offered again
+        stop_workout().await.unwrap();
+        assert!(!checkpoint_path.exists());
+        assert!(!resume_last_workout().await.unwrap());
+
+        reset_globals().await;
+    }
+
+    #[tokio::test]
+    async fn test_start_workout_distinguishes_plan_errors() {
+        use crate::domain::heart_rate::Zone;
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+        let plans_dir = temp_dir.path().join("plans");
+        std::fs::create_dir_all(&plans_dir).unwrap();
+
+        let err = start_workout("Nope".to_string()).await.unwrap_err();
+        assert_eq!(
+            err,
+            StartWorkoutError::Plan(PlanError::NotFound("Nope".to_string()))
+        );
+
+        std::fs::write(plans_dir.join("Broken.json"), "{ not json").unwrap();
+        let err = start_workout("Broken".to_string()).await.unwrap_err();
+        assert!(matches!(
+            err,
+            StartWorkoutError::Plan(PlanError::InvalidJson(_))
+        ));
+
+        // Parses, but has no phases
+        let mut empty = TrainingPlan::steady("Empty", Zone::Zone2, 600, 180);
+        empty.phases.clear();
+        save_plan(&empty).await.unwrap();
+        let err = start_workout("Empty".to_string()).await.unwrap_err();
+        assert!(matches!(
+            err,
+            StartWorkoutError::Plan(PlanError::ValidationFailed(_))
+        ));
+
+        reset_globals().await;
+    }
+
+    #[tokio::test]
+    async fn test_api_error_codes_for_common_failures() {
+        use crate::adapters::MockAdapter;
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+        std::fs::create_dir_all(temp_dir.path().join("plans")).unwrap();
+        set_ble_adapter(Arc::new(MockAdapter::new())).await;
+
+        // No device with this id was discovered
+        let err = connect_device("no-such-device".to_string())
+            .await
+            .unwrap_err();
+        assert_eq!(api_error_code(&err), ApiErrorCode::DeviceNotFound);
+        assert!(api_error_message(&err).contains("no-such-device"));
+
+        // Missing and unparseable plans
+        assert!(matches!(
+            start_workout("Nope".to_string()).await,
+            Err(StartWorkoutError::Plan(PlanError::NotFound(_)))
+        ));
+        std::fs::write(temp_dir.path().join("plans/Broken.json"), "{ not json").unwrap();
+        assert!(matches!(
+            start_workout("Broken".to_string()).await,
+            Err(StartWorkoutError::Plan(PlanError::InvalidJson(_)))
+        ));
+        let err = delete_plan("Nope".to_string()).await.unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanNotFound);
+        let err = save_training_plan("{ not json".to_string())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::PlanInvalid);
+
+        // Missing session
+        let err = export_session("missing".to_string(), ExportFormat::Json)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code, ApiErrorCode::SessionNotFound);
+        assert_eq!(api_error_code_str(&err), "session.not_found");
+
+        // Errors without a typed cause
+        let err = ApiError::from(anyhow!("something else"));
+        assert_eq!(err.code, ApiErrorCode::Unknown);
+
+        reset_globals().await;
+    }
+
+    #[tokio::test]
+    async fn test_new_workout_does_not_receive_stale_progress() {
+        use crate::domain::heart_rate::Zone;
+        use tokio::time::{sleep, timeout, Duration};
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+
+        let plan = TrainingPlan::steady("Epochs", Zone::Zone2, 600, 180);
+        save_plan(&plan).await.unwrap();
+
+        // First workout: capture one of its progress updates
+        let mut first_rx = subscribe_session_progress_stream();
+        start_workout(plan.name.clone()).await.unwrap();
+        let first_epoch = current_session_epoch().await.unwrap().unwrap();
+        let leftover = timeout(Duration::from_secs(3), first_rx.recv())
+            .await
+            .expect("Timed out waiting for progress")
+            .unwrap();
+        assert_eq!(session_progress_epoch(&leftover), first_epoch);
+        stop_workout().await.unwrap();
+
+        // Second workout: a late update of the first one must not get through
+        let mut rx = subscribe_session_progress_stream();
+        start_workout(plan.name.clone()).await.unwrap();
+        let second_epoch = current_session_epoch().await.unwrap().unwrap();
+        assert!(second_epoch > first_epoch);
+        assert_eq!(emit_session_progress(leftover), 0);
+
+        sleep(Duration::from_millis(1500)).await;
+        stop_workout().await.unwrap();
+        reset_globals().await;
+
+        let mut received = Vec::new();
+        while let Ok(progress) = rx.try_recv() {
+            received.push(session_progress_epoch(&progress));
+        }
+        assert!(!received.is_empty(), "Second workout should emit progress");
+        assert!(
+            received.iter().all(|&epoch| epoch == second_epoch),
+            "Received progress from another session: {:?}",
+            received
+        );
+    }
+
+    /// Adapter that reports a different set of devices for each scan window
+    /// and fails to start a scan once its windows are used up.
+    struct WindowedScanAdapter {
+        windows: std::sync::Mutex<Vec<Vec<DiscoveredDevice>>>,
+        current: std::sync::Mutex<Vec<DiscoveredDevice>>,
+        stops: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait]
+    impl BleAdapter for WindowedScanAdapter {
+        async fn start_scan(&self) -> Result<()> {
+            let mut windows = self.windows.lock().unwrap();
+            if windows.is_empty() {
+                return Err(anyhow!("No scan windows left"));
+            }
+            *self.current.lock().unwrap() = windows.remove(0);
+            Ok(())
+        }
+
+        async fn stop_scan(&self) -> Result<()> {
+            self.stops.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn get_discovered_devices(&self) -> Vec<DiscoveredDevice> {
+            self.current.lock().unwrap().clone()
+        }
+
+        async fn connect(&self, _device_id: &str) -> Result<()> {
+            Ok(())
+        }
+
+        async fn disconnect(&self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn subscribe_hr(&self) -> Result<tokio::sync::mpsc::Receiver<Vec<u8>>> {
+            let (_tx, rx) = tokio::sync::mpsc::channel(1);
+            Ok(rx)
+        }
+
+        async fn read_battery(&self) -> Result<Option<u8>> {
+            Ok(None)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_scan_accumulate_merges_windows() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+
+        let device = |id: &str, name: Option<&str>, rssi| DiscoveredDevice {
+            id: id.to_string(),
+            name: name.map(str::to_string),
+            rssi,
+        };
+        set_ble_adapter(Arc::new(WindowedScanAdapter {
+            windows: std::sync::Mutex::new(vec![
+                vec![device("strap", None, -80)],
+                vec![
+                    device("strap", Some("HRM Strap"), -62),
+                    device("watch", Some("Watch"), -75),
+                ],
+                vec![device("strap", None, -90)],
+            ]),
+            current: std::sync::Mutex::new(Vec::new()),
+            stops: std::sync::atomic::AtomicU32::new(0),
+        }))
+        .await;
+
+        let devices = scan_devices_accumulate(3, 0).await.unwrap();
+
+        // The watch only advertised during the second window
+        assert_eq!(
+            devices,
+            vec![
+                device("strap", Some("HRM Strap"), -62),
+                device("watch", Some("Watch"), -75),
+            ]
+        );
+        reset_globals().await;
+    }
+
+    #[tokio::test]
+    async fn test_scan_accumulate_stops_scan_when_start_fails() {
+        let adapter = WindowedScanAdapter {
+            windows: std::sync::Mutex::new(vec![Vec::new()]),
+            current: std::sync::Mutex::new(Vec::new()),
+            stops: std::sync::atomic::AtomicU32::new(0),
+        };
+
+        // The second window fails to start
+        assert!(accumulate_scan_windows(&adapter, 2, Duration::ZERO)
+            .await
+            .is_err());
+        assert_eq!(adapter.stops.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_upcoming_phases_without_workout() {
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+
+        assert!(get_upcoming_phases(3).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_app_snapshot_after_connect_and_start() {
+        use crate::adapters::{MockAdapter, MockConfig};
+        use crate::domain::heart_rate::Zone;
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+        use tokio::time::{timeout, Duration};
+
+        let _guard = GLOBALS_LOCK.lock().await;
+        reset_globals().await;
+        let temp_dir = tempfile::tempdir().unwrap();
+        set_data_dir(temp_dir.path().to_string_lossy().to_string()).unwrap();
+
+        let snapshot = get_app_snapshot().await;
+        assert_eq!(
+            snapshot.connection_status,
+            ApiConnectionStatus::Disconnected
+        );
+        assert!(snapshot.session_progress.is_none());
+        assert!(snapshot.last_hr.is_none());
+
+        let config = MockConfig {
+            baseline_bpm: 120,
+            noise_range: 0,
+            spike_probability: 0.0,
+            ..MockConfig::default()
+        };
+        set_ble_adapter(Arc::new(MockAdapter::with_config(config))).await;
+        let devices = scan_devices().await.unwrap();
+        let device_id = devices[0].id.clone();
+        let mut rx = get_hr_stream_receiver();
+        connect_device(device_id.clone()).await.unwrap();
+        timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("Timed out waiting for HR data")
+            .unwrap();
+
+        let plan = TrainingPlan {
+            name: "Snapshot".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Steady".to_string(),
+                target_zone: Zone::Zone2,
+                duration_secs: 600,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+        save_plan(&plan).await.unwrap();
+        start_workout(plan.name.clone()).await.unwrap();
+        timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("Timed out waiting for HR data")
+            .unwrap();
+
+        let snapshot = get_app_snapshot().await;
+        stop_workout().await.unwrap();
+        disconnect().await.unwrap();
+        reset_globals().await;
+
+        assert_eq!(
+            app_snapshot_connection_status(&snapshot),
+            ApiConnectionStatus::Connected {
+                device_id: device_id.clone()
+            }
+        );
+        assert_eq!(app_snapshot_connected_device_id(&snapshot), Some(device_id));
+        let progress = app_snapshot_session_progress(&snapshot).expect("Workout should be running");
+        assert_eq!(progress.phase_progress.phase_name, "Steady");
+        assert_eq!(app_snapshot_last_hr(&snapshot).unwrap().raw_bpm, 120);
+    }
+
     #[tokio::test]
     async fn test_disconnect_when_already_disconnected() {
+        let _guard = GLOBALS_LOCK.lock().await;
         // Clear any existing connection state
         if let Some(mutex) = CONNECTION_STATE.get() {
             *mutex.lock().await = None;
@@ -3742,6 +8646,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_connect_after_disconnect() {
+        let _guard = GLOBALS_LOCK.lock().await;
         // Clear any existing connection state and BLE adapter
         if let Some(mutex) = CONNECTION_STATE.get() {
             *mutex.lock().await = None;
@@ -3905,4 +8810,26 @@ mod tests {
 
         assert_eq!(formatted, "2m / 5m / 10m / 15m / 30m");
     }
+
+    #[tokio::test]
+    async fn test_zero_bpm_run_notifies_contact_lost() {
+        use crate::adapters::MockNotificationAdapter;
+        use crate::ports::NotificationEvent;
+
+        let notifier = MockNotificationAdapter::new();
+        let mut monitor = ContactMonitor::default();
+
+        // 72 BPM, then a run of zero-BPM packets (UINT8 format)
+        let packets = [[0x00, 72], [0x00, 0], [0x00, 0], [0x00, 0], [0x00, 0]];
+        let mut sent = Vec::new();
+        for packet in packets {
+            let bpm = parse_heart_rate(&packet).unwrap().bpm;
+            sent.push(check_sensor_contact(&mut monitor, bpm, &notifier).await);
+        }
+
+        assert_eq!(sent, [false, false, false, true, false]);
+        let events = notifier.get_events().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(events[0], NotificationEvent::ContactLost));
+    }
 }
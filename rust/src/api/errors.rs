@@ -0,0 +1,324 @@
+//! Error types returned across the FFI boundary.
+//!
+//! [`ApiError`] pairs a message with a stable [`ApiErrorCode`] that the app
+//! can localize and act on (e.g. offer to turn Bluetooth on for
+//! [`ApiErrorCode::BlePoweredOff`]). It is returned by the device calls
+//! ([`scan_devices`](super::scan_devices),
+//! [`connect_device`](super::connect_device),
+//! [`auto_connect_last_device`](super::auto_connect_last_device)), and by the
+//! plan, session and storage calls such as [`get_plan_details`](super::get_plan_details),
+//! [`delete_session`](super::delete_session),
+//! [`export_session`](super::export_session) and
+//! [`set_data_dir`](super::set_data_dir).
+//! [`start_workout`](super::start_workout) returns [`StartWorkoutError`],
+//! which converts into an [`ApiError`].
+//!
+//! The remaining functions in [`crate::api`] report failures as
+//! `anyhow::Error`, which only reaches Flutter as a message. Internally the
+//! typed errors below are wrapped in `anyhow` errors where the cause matters;
+//! an `anyhow::Error` or a typed error converts into an [`ApiError`], and the
+//! code is taken from the first typed error found in the chain.
+
+use crate::ports::BleError;
+
+/// Stable identifier of an error category, for localized and actionable
+/// error handling in the app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiErrorCode {
+    /// The BLE stack or adapter is not available.
+    BleUnavailable,
+
+    /// The OS denied access to Bluetooth.
+    BlePermissionDenied,
+
+    /// The Bluetooth adapter is powered off.
+    BlePoweredOff,
+
+    /// The requested device was not found; it may be off or out of range.
+    DeviceNotFound,
+
+    /// Connecting to the device failed or timed out.
+    ConnectionFailed,
+
+    /// The requested plan does not exist.
+    PlanNotFound,
+
+    /// The plan could not be parsed, has an unusable name or failed validation.
+    PlanInvalid,
+
+    /// The requested session does not exist.
+    SessionNotFound,
+
+    /// Reading or writing local storage failed.
+    Io,
+
+    /// Any other failure.
+    Unknown,
+}
+
+impl ApiErrorCode {
+    /// Stable string form of the code, e.g. `"ble.powered_off"`, usable as a
+    /// localization key.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiErrorCode::BleUnavailable => "ble.unavailable",
+            ApiErrorCode::BlePermissionDenied => "ble.permission_denied",
+            ApiErrorCode::BlePoweredOff => "ble.powered_off",
+            ApiErrorCode::DeviceNotFound => "ble.device_not_found",
+            ApiErrorCode::ConnectionFailed => "ble.connection_failed",
+            ApiErrorCode::PlanNotFound => "plan.not_found",
+            ApiErrorCode::PlanInvalid => "plan.invalid",
+            ApiErrorCode::SessionNotFound => "session.not_found",
+            ApiErrorCode::Io => "io",
+            ApiErrorCode::Unknown => "unknown",
+        }
+    }
+}
+
+/// An error with a code the app can react to and a message to show or log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    /// Category of the failure.
+    pub code: ApiErrorCode,
+
+    /// Human-readable description, including the causes.
+    pub message: String,
+}
+
+impl ApiError {
+    /// Create an error with an explicit code.
+    pub fn new(code: ApiErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// Use `code` instead of [`ApiErrorCode::Unknown`] when no typed cause was found.
+    pub fn or_code(mut self, code: ApiErrorCode) -> Self {
+        if self.code == ApiErrorCode::Unknown {
+            self.code = code;
+        }
+        self
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(err: anyhow::Error) -> Self {
+        let code = err
+            .chain()
+            .find_map(code_of)
+            .unwrap_or(ApiErrorCode::Unknown);
+        Self::new(code, format!("{:#}", err))
+    }
+}
+
+impl From<StartWorkoutError> for ApiError {
+    fn from(err: StartWorkoutError) -> Self {
+        match err {
+            StartWorkoutError::Plan(plan_err) => anyhow::Error::new(plan_err).into(),
+            StartWorkoutError::Other(reason) => Self::new(ApiErrorCode::Unknown, reason),
+        }
+    }
+}
+
+impl From<PlanError> for ApiError {
+    fn from(err: PlanError) -> Self {
+        anyhow::Error::new(err).into()
+    }
+}
+
+impl From<PlanSaveError> for ApiError {
+    fn from(err: PlanSaveError) -> Self {
+        anyhow::Error::new(err).into()
+    }
+}
+
+impl From<SessionError> for ApiError {
+    fn from(err: SessionError) -> Self {
+        anyhow::Error::new(err).into()
+    }
+}
+
+impl From<std::io::Error> for ApiError {
+    fn from(err: std::io::Error) -> Self {
+        anyhow::Error::new(err).into()
+    }
+}
+
+/// Code of a typed error, or `None` for errors without one.
+fn code_of(err: &(dyn std::error::Error + 'static)) -> Option<ApiErrorCode> {
+    if let Some(err) = err.downcast_ref::<ApiError>() {
+        return Some(err.code);
+    }
+    if let Some(err) = err.downcast_ref::<BleError>() {
+        return Some(match err {
+            BleError::ManagerUnavailable(_) | BleError::NoAdapter => ApiErrorCode::BleUnavailable,
+            BleError::PermissionDenied => ApiErrorCode::BlePermissionDenied,
+            BleError::PoweredOff => ApiErrorCode::BlePoweredOff,
+            BleError::DeviceNotFound(_) => ApiErrorCode::DeviceNotFound,
+        });
+    }
+    if let Some(err) = err.downcast_ref::<PlanError>() {
+        return Some(match err {
+            PlanError::NotFound(_) => ApiErrorCode::PlanNotFound,
+            PlanError::InvalidJson(_) | PlanError::ValidationFailed(_) => ApiErrorCode::PlanInvalid,
+        });
+    }
+    if err.downcast_ref::<PlanSaveError>().is_some() {
+        return Some(ApiErrorCode::PlanInvalid);
+    }
+    if let Some(err) = err.downcast_ref::<StartWorkoutError>() {
+        return match err {
+            StartWorkoutError::Plan(plan_err) => code_of(plan_err),
+            StartWorkoutError::Other(_) => None,
+        };
+    }
+    if err.downcast_ref::<SessionError>().is_some() {
+        return Some(ApiErrorCode::SessionNotFound);
+    }
+    if err.downcast_ref::<std::io::Error>().is_some() {
+        return Some(ApiErrorCode::Io);
+    }
+    None
+}
+
+/// Get the code of an error
+pub fn api_error_code(err: &ApiError) -> ApiErrorCode {
+    err.code
+}
+
+/// Get the stable string form of an error's code (see [`ApiErrorCode::as_str`])
+pub fn api_error_code_str(err: &ApiError) -> String {
+    err.code.as_str().to_string()
+}
+
+/// Get the message of an error
+pub fn api_error_message(err: &ApiError) -> String {
+    err.message.clone()
+}
+
+/// Reason a stored session could not be used.
+///
+/// Raised by functions that load a session by ID; it reaches Flutter as an
+/// [`ApiError`] with [`ApiErrorCode::SessionNotFound`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionError {
+    /// No session with this ID exists.
+    NotFound(String),
+}
+
+impl std::fmt::Display for SessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionError::NotFound(id) => write!(f, "Session not found: {}", id),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+/// Reason a stored plan could not be used.
+///
+/// Raised by functions that load plans; it reaches Flutter as an [`ApiError`]
+/// with [`ApiErrorCode::PlanNotFound`] or [`ApiErrorCode::PlanInvalid`], or
+/// inside a [`StartWorkoutError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    /// No plan with this name exists.
+    NotFound(String),
+
+    /// The plan file could not be parsed as a training plan.
+    InvalidJson(String),
+
+    /// The plan parsed but failed validation.
+    ValidationFailed(String),
+}
+
+impl std::fmt::Display for PlanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanError::NotFound(name) => write!(
+                f,
+                "Plan '{}' not found. Use list_plans() to see available plans.",
+                name
+            ),
+            PlanError::InvalidJson(reason) => write!(f, "Invalid plan JSON: {}", reason),
+            PlanError::ValidationFailed(reason) => write!(f, "Invalid plan: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+/// Error returned by [`start_workout`](super::start_workout).
+///
+/// Separates problems with the plan, which the UI can resolve by offering to
+/// create or fix it, from every other failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StartWorkoutError {
+    /// The plan is missing or unusable.
+    Plan(PlanError),
+
+    /// Any other failure, e.g. a workout is already in progress.
+    Other(String),
+}
+
+impl std::fmt::Display for StartWorkoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartWorkoutError::Plan(err) => err.fmt(f),
+            StartWorkoutError::Other(reason) => f.write_str(reason),
+        }
+    }
+}
+
+impl std::error::Error for StartWorkoutError {}
+
+impl From<anyhow::Error> for StartWorkoutError {
+    fn from(err: anyhow::Error) -> Self {
+        match err.downcast::<PlanError>() {
+            Ok(plan_err) => StartWorkoutError::Plan(plan_err),
+            Err(err) => StartWorkoutError::Other(format!("{:#}", err)),
+        }
+    }
+}
+
+/// Reason a plan passed to [`save_training_plan`](super::save_training_plan) was rejected.
+///
+/// It reaches Flutter as an [`ApiError`] with [`ApiErrorCode::PlanInvalid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanSaveError {
+    /// The JSON could not be parsed as a training plan.
+    InvalidJson(String),
+
+    /// The plan name is empty or cannot be used as a file name.
+    InvalidName(String),
+
+    /// A plan with this name already exists and may not be overwritten.
+    AlreadyExists(String),
+
+    /// The plan parsed but failed validation.
+    InvalidPlan(String),
+}
+
+impl std::fmt::Display for PlanSaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanSaveError::InvalidJson(reason) => write!(f, "Invalid plan JSON: {}", reason),
+            PlanSaveError::InvalidName(name) => write!(f, "Invalid plan name: '{}'", name),
+            PlanSaveError::AlreadyExists(name) => write!(f, "Plan '{}' already exists", name),
+            PlanSaveError::InvalidPlan(reason) => write!(f, "Invalid plan: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PlanSaveError {}
@@ -4,14 +4,20 @@
 //! monitors via BLE, including device scanning, real-time monitoring, and mock
 //! data simulation.
 
-use clap::{Parser, Subcommand};
-use heart_beat::adapters::{BtleplugAdapter, MockAdapter, MockNotificationAdapter};
+use clap::{Args, Parser, Subcommand};
+use heart_beat::adapters::{
+    BtleplugAdapter, CliNotificationAdapter, FileSessionRepository, MockAdapter,
+    PersistedConnectionState,
+};
 use heart_beat::domain::filters::KalmanFilter;
-use heart_beat::domain::heart_rate::{parse_heart_rate, Zone};
-use heart_beat::domain::hrv::calculate_rmssd;
+use heart_beat::domain::heart_rate::{clamp_bpm, parse_heart_rate, BpmBounds, Zone};
+use heart_beat::domain::hrv::{calculate_rmssd, HrvWindow, DEFAULT_HRV_WINDOW};
 use heart_beat::domain::training_plan::{TrainingPhase, TrainingPlan, TransitionCondition};
 use heart_beat::ports::ble_adapter::BleAdapter;
-use heart_beat::scheduler::SessionExecutor;
+use heart_beat::ports::notification::{NotificationEvent, NotificationPort};
+use heart_beat::ports::session_repository::SessionRepository;
+use heart_beat::scheduler::{validate_cron_expression, SessionExecutor};
+use std::path::Path;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn, Level};
 use tracing_subscriber::FmtSubscriber;
@@ -25,10 +31,33 @@ struct Cli {
     #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Print machine-readable JSON instead of tables (scan, plan and session listings)
+    #[arg(long, global = true)]
+    json: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+/// How listing commands print their results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// Human-readable tables and text
+    Table,
+    /// Pretty-printed JSON on stdout, with logs on stderr
+    Json,
+}
+
+impl OutputFormat {
+    fn from_json_flag(json: bool) -> Self {
+        if json {
+            OutputFormat::Json
+        } else {
+            OutputFormat::Table
+        }
+    }
+}
+
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Manage BLE device connections
@@ -66,6 +95,9 @@ enum Commands {
         #[command(subcommand)]
         command: ExportCmd,
     },
+
+    /// Check BLE availability, permissions and data directory setup
+    Doctor,
 }
 
 #[derive(Subcommand, Debug)]
@@ -102,6 +134,42 @@ enum SessionCmd {
 
     /// Stop the session and show summary
     Stop,
+
+    /// Schedule a training session with a cron expression and wait for it in the foreground
+    Schedule {
+        /// Path to training plan JSON file
+        plan_path: String,
+
+        /// Six-field cron expression with seconds (e.g. "0 30 17 * * *" for 5:30 PM daily)
+        cron: String,
+    },
+
+    /// List completed sessions, most recent first
+    List,
+
+    /// Show a completed session
+    Show {
+        /// Session ID (from session list)
+        id: String,
+    },
+}
+
+/// Accepted BPM range for simulated heart rates.
+#[derive(Args, Debug)]
+struct BpmBoundsArgs {
+    /// Lowest simulated BPM
+    #[arg(long, default_value_t = BpmBounds::PHYSIOLOGICAL.min)]
+    min_bpm: u16,
+
+    /// Highest simulated BPM (raise for high-intensity simulations)
+    #[arg(long, default_value_t = BpmBounds::PHYSIOLOGICAL.max)]
+    max_bpm: u16,
+}
+
+impl BpmBoundsArgs {
+    fn bounds(&self) -> anyhow::Result<BpmBounds> {
+        BpmBounds::new(self.min_bpm, self.max_bpm)
+    }
 }
 
 #[derive(Subcommand, Debug)]
@@ -126,6 +194,9 @@ enum MockCmd {
         /// Duration in seconds
         #[arg(long)]
         duration: u32,
+
+        #[command(flatten)]
+        bounds: BpmBoundsArgs,
     },
 
     /// Generate interval pattern
@@ -145,6 +216,9 @@ enum MockCmd {
         /// Rest period in seconds
         #[arg(long)]
         rest_secs: u32,
+
+        #[command(flatten)]
+        bounds: BpmBoundsArgs,
     },
 
     /// Simulate packet dropout
@@ -201,12 +275,17 @@ enum ExportCmd {
         /// Export format
         #[arg(long, default_value = "csv")]
         format: String,
+
+        /// Write the export to this file instead of stdout (csv, json, summary)
+        #[arg(long)]
+        output: Option<String>,
     },
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    let output = OutputFormat::from_json_flag(cli.json);
 
     // Initialize tracing subscriber based on verbosity level
     let level = if cli.verbose {
@@ -220,6 +299,13 @@ async fn main() -> anyhow::Result<()> {
         .with_target(false)
         .with_thread_ids(false)
         .with_file(false)
+        // Keep stdout clean for JSON output
+        .with_writer(move || -> Box<dyn std::io::Write> {
+            match output {
+                OutputFormat::Json => Box::new(std::io::stderr()),
+                OutputFormat::Table => Box::new(std::io::stdout()),
+            }
+        })
         .finish();
 
     tracing::subscriber::set_global_default(subscriber).expect("Failed to set tracing subscriber");
@@ -229,7 +315,7 @@ async fn main() -> anyhow::Result<()> {
     match cli.command {
         Commands::Devices { command } => match command {
             DevicesCmd::Scan => {
-                handle_devices_scan().await?;
+                handle_devices_scan(output).await?;
             }
             DevicesCmd::Connect { device_id } => {
                 handle_devices_connect(&device_id).await?;
@@ -254,6 +340,15 @@ async fn main() -> anyhow::Result<()> {
             SessionCmd::Stop => {
                 handle_session_stop().await?;
             }
+            SessionCmd::Schedule { plan_path, cron } => {
+                handle_session_schedule(&plan_path, &cron).await?;
+            }
+            SessionCmd::List => {
+                handle_session_list(output).await?;
+            }
+            SessionCmd::Show { id } => {
+                handle_session_show(&id, output).await?;
+            }
         },
         Commands::Mock { command } => match command {
             MockCmd::Steady { bpm } => {
@@ -263,16 +358,18 @@ async fn main() -> anyhow::Result<()> {
                 start,
                 end,
                 duration,
+                bounds,
             } => {
-                handle_mock_ramp(start, end, duration).await?;
+                handle_mock_ramp(start, end, duration, bounds.bounds()?).await?;
             }
             MockCmd::Interval {
                 low,
                 high,
                 work_secs,
                 rest_secs,
+                bounds,
             } => {
-                handle_mock_interval(low, high, work_secs, rest_secs).await?;
+                handle_mock_interval(low, high, work_secs, rest_secs, bounds.bounds()?).await?;
             }
             MockCmd::Dropout { probability } => {
                 handle_mock_dropout(probability).await?;
@@ -280,10 +377,10 @@ async fn main() -> anyhow::Result<()> {
         },
         Commands::Plan { command } => match command {
             PlanCmd::List => {
-                handle_plan_list()?;
+                handle_plan_list(output)?;
             }
             PlanCmd::Show { name } => {
-                handle_plan_show(&name)?;
+                handle_plan_show(&name, output)?;
             }
             PlanCmd::Validate { path } => {
                 handle_plan_validate(&path)?;
@@ -307,17 +404,20 @@ async fn main() -> anyhow::Result<()> {
             }
         },
         Commands::Export { command } => match command {
-            ExportCmd::Session { id, format } => {
-                handle_export_session(&id, &format).await?;
+            ExportCmd::Session { id, format, output } => {
+                handle_export_session(&id, &format, output.as_deref()).await?;
             }
         },
+        Commands::Doctor => {
+            handle_doctor().await?;
+        }
     }
 
     Ok(())
 }
 
 /// Handle the devices scan subcommand.
-async fn handle_devices_scan() -> anyhow::Result<()> {
+async fn handle_devices_scan(output: OutputFormat) -> anyhow::Result<()> {
     use comfy_table::{presets::UTF8_FULL, Attribute, Cell, Color, ContentArrangement, Table};
     use indicatif::{ProgressBar, ProgressStyle};
     use std::time::Duration;
@@ -350,6 +450,11 @@ async fn handle_devices_scan() -> anyhow::Result<()> {
     // Get discovered devices
     let devices = adapter.get_discovered_devices().await;
 
+    if output == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&devices)?);
+        return Ok(());
+    }
+
     if devices.is_empty() {
         println!("No devices found.");
         println!("\nMake sure your heart rate monitor is:");
@@ -436,6 +541,13 @@ async fn handle_devices_connect(device_id: &str) -> anyhow::Result<()> {
     pb.finish_and_clear();
     println!("{} Connected to device", "✓".green().bold());
 
+    // Remember the connection for 'devices info' / 'devices disconnect'
+    if let Err(e) = PersistedConnectionState::default_path()
+        .and_then(|path| PersistedConnectionState::new(device_id, None).save(&path))
+    {
+        warn!("Failed to save connection state: {}", e);
+    }
+
     // Subscribe to heart rate notifications
     adapter.subscribe_hr().await?;
     let mut hr_receiver = adapter.subscribe_hr().await?;
@@ -466,15 +578,26 @@ async fn handle_devices_connect(device_id: &str) -> anyhow::Result<()> {
     // Initialize Kalman filter
     let mut filter = KalmanFilter::default();
 
+    // Recent filtered BPM values for the trend sparkline
+    let mut recent_bpm: std::collections::VecDeque<u16> =
+        std::collections::VecDeque::with_capacity(SPARKLINE_WIDTH);
+
+    // Recent RR-intervals for SDNN, plus its running mean over the session
+    let mut hrv_window = HrvWindow::new(DEFAULT_HRV_WINDOW);
+    let mut sdnn_sum = 0.0;
+    let mut sdnn_count = 0u32;
+
     // Print table header with colors
     println!(
-        "{:<20} {:>8} {:>12} {:>10}",
+        "{:<20} {:>8} {:>12} {:>10} {:>10}  {}",
         "Timestamp".cyan().bold(),
         "Raw BPM".cyan().bold(),
         "Filtered BPM".cyan().bold(),
-        "RMSSD (ms)".cyan().bold()
+        "RMSSD (ms)".cyan().bold(),
+        "SDNN (ms)".cyan().bold(),
+        "Trend".cyan().bold()
     );
-    println!("{}", "─".repeat(56).cyan());
+    println!("{}", "─".repeat(69 + SPARKLINE_WIDTH).cyan());
 
     // Set up Ctrl+C handler
     let ctrl_c = async {
@@ -510,16 +633,35 @@ async fn handle_devices_connect(device_id: &str) -> anyhow::Result<()> {
                             "     -    ".to_string()
                         };
 
+                        // Update SDNN, comparing against the mean of earlier readings
+                        hrv_window.extend(&measurement.rr_intervals);
+                        let session_mean =
+                            (sdnn_count > 0).then(|| sdnn_sum / sdnn_count as f64);
+                        let sdnn_str = sdnn_column(&hrv_window, session_mean);
+                        if let Some(sdnn) = hrv_window.sdnn() {
+                            sdnn_sum += sdnn;
+                            sdnn_count += 1;
+                        }
+
                         // Get current timestamp
                         let timestamp = chrono::Local::now().format("%H:%M:%S%.3f");
 
+                        // Update the trend window
+                        if recent_bpm.len() == SPARKLINE_WIDTH {
+                            recent_bpm.pop_front();
+                        }
+                        recent_bpm.push_back(filtered_bpm.round() as u16);
+                        let trend = sparkline(recent_bpm.make_contiguous());
+
                         // Print the data
                         println!(
-                            "{:<20} {:>8} {:>12.1} {}",
+                            "{:<20} {:>8} {:>12.1} {} {}  {}",
                             timestamp,
                             measurement.bpm,
                             filtered_bpm,
-                            rmssd_str
+                            rmssd_str,
+                            sdnn_str,
+                            trend.red()
                         );
                     }
                     Err(e) => {
@@ -539,6 +681,60 @@ async fn handle_devices_connect(device_id: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Number of recent BPM values shown in the connect view's trend sparkline.
+const SPARKLINE_WIDTH: usize = 30;
+
+/// SDNN change (ms) versus the session mean below which the trend shows as flat.
+const SDNN_TREND_DEADBAND_MS: f64 = 1.0;
+
+/// Format the connect view's SDNN column (10 characters wide).
+///
+/// Shows the window's SDNN followed by an arrow comparing it with the
+/// session's running mean: ↑ above, ↓ below, → within the deadband. The arrow is
+/// blank until a mean is available, and the value is "-" until the window holds
+/// enough intervals.
+fn sdnn_column(window: &HrvWindow, session_mean: Option<f64>) -> String {
+    let Some(sdnn) = window.sdnn() else {
+        return "    -     ".to_string();
+    };
+
+    let arrow = match session_mean {
+        Some(mean) if sdnn > mean + SDNN_TREND_DEADBAND_MS => '↑',
+        Some(mean) if sdnn < mean - SDNN_TREND_DEADBAND_MS => '↓',
+        Some(_) => '→',
+        None => ' ',
+    };
+    format!("{:8.2} {}", sdnn, arrow)
+}
+
+/// Render values as a unicode block sparkline, one character per value.
+///
+/// Values are scaled between the slice's min and max. A flat series renders as
+/// a mid-height line; an empty slice renders as an empty string.
+fn sparkline(values: &[u16]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+    if min == max {
+        return BLOCKS[BLOCKS.len() / 2 - 1]
+            .to_string()
+            .repeat(values.len());
+    }
+
+    let range = (max - min) as u32;
+    let top = (BLOCKS.len() - 1) as u32;
+    values
+        .iter()
+        .map(|&v| {
+            // Round to the nearest level
+            let level = ((v - min) as u32 * top + range / 2) / range;
+            BLOCKS[level as usize]
+        })
+        .collect()
+}
+
 /// Handle the devices info subcommand.
 async fn handle_devices_info() -> anyhow::Result<()> {
     use colored::Colorize;
@@ -557,62 +753,56 @@ async fn handle_devices_info() -> anyhow::Result<()> {
         "Use 'cli devices connect <id>' to establish a connection and view live data.\n".yellow()
     );
 
-    // Check for saved connection state (future enhancement)
-    let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
-    let state_file = home.join(".heart-beat").join("connection_state.json");
+    // Check for saved connection state
+    let state_file = PersistedConnectionState::default_path()?;
 
-    if state_file.exists() {
-        // Try to read connection state
-        match std::fs::read_to_string(&state_file) {
-            Ok(content) => {
-                if let Ok(state) = serde_json::from_str::<serde_json::Value>(&content) {
-                    let mut table = Table::new();
-                    table
-                        .load_preset(UTF8_FULL)
-                        .set_content_arrangement(ContentArrangement::Dynamic);
-
-                    table.set_header(vec![
-                        Cell::new("Property")
-                            .add_attribute(Attribute::Bold)
-                            .fg(Color::Cyan),
-                        Cell::new("Value")
-                            .add_attribute(Attribute::Bold)
-                            .fg(Color::Cyan),
-                    ]);
-
-                    if let Some(device_id) = state.get("device_id").and_then(|v| v.as_str()) {
-                        table.add_row(vec![
-                            Cell::new("Device ID"),
-                            Cell::new(device_id).fg(Color::Green),
-                        ]);
-                    }
+    let saved = PersistedConnectionState::load(&state_file).unwrap_or_else(|e| {
+        warn!("Ignoring unreadable connection state: {}", e);
+        None
+    });
 
-                    if let Some(device_name) = state.get("device_name").and_then(|v| v.as_str()) {
-                        table.add_row(vec![
-                            Cell::new("Device Name"),
-                            Cell::new(device_name).fg(Color::Green),
-                        ]);
-                    }
+    if let Some(state) = saved {
+        let mut table = Table::new();
+        table
+            .load_preset(UTF8_FULL)
+            .set_content_arrangement(ContentArrangement::Dynamic);
 
-                    if let Some(last_connected) =
-                        state.get("last_connected").and_then(|v| v.as_str())
-                    {
-                        table.add_row(vec![Cell::new("Last Connected"), Cell::new(last_connected)]);
-                    }
+        table.set_header(vec![
+            Cell::new("Property")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Value")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
 
-                    println!("{table}\n");
-                    println!(
-                        "{} The device is not currently active. Use 'cli devices connect <id>' to reconnect.",
-                        "⚠".yellow()
-                    );
-                } else {
-                    println!("{} No active device connection found.", "ⓘ".cyan());
-                }
-            }
-            Err(_) => {
-                println!("{} No active device connection found.", "ⓘ".cyan());
-            }
+        table.add_row(vec![
+            Cell::new("Device ID"),
+            Cell::new(&state.device_id).fg(Color::Green),
+        ]);
+
+        if let Some(ref device_name) = state.device_name {
+            table.add_row(vec![
+                Cell::new("Device Name"),
+                Cell::new(device_name).fg(Color::Green),
+            ]);
         }
+
+        table.add_row(vec![
+            Cell::new("Last Connected"),
+            Cell::new(
+                state
+                    .last_connected
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S"),
+            ),
+        ]);
+
+        println!("{table}\n");
+        println!(
+            "{} The device is not currently active. Use 'cli devices connect <id>' to reconnect.",
+            "⚠".yellow()
+        );
     } else {
         println!("{} No active device connection found.", "ⓘ".cyan());
         println!("\nTo connect to a device:");
@@ -640,24 +830,21 @@ async fn handle_devices_disconnect() -> anyhow::Result<()> {
         "To disconnect from an active connection, press Ctrl+C.\n".yellow()
     );
 
-    // Check for saved connection state
-    let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
-    let state_file = home.join(".heart-beat").join("connection_state.json");
+    // Clear saved connection state
+    let state_file = PersistedConnectionState::default_path()?;
 
-    if state_file.exists() {
-        // Remove the state file
-        match std::fs::remove_file(&state_file) {
-            Ok(_) => {
-                println!("{} Cleared saved connection state.", "✓".green().bold());
-            }
-            Err(e) => {
-                warn!("Failed to remove connection state file: {}", e);
-                println!("{} Failed to clear connection state: {}", "⚠".yellow(), e);
-            }
+    match PersistedConnectionState::clear(&state_file) {
+        Ok(true) => {
+            println!("{} Cleared saved connection state.", "✓".green().bold());
+        }
+        Ok(false) => {
+            println!("{} No active device connection found.", "ⓘ".cyan());
+            println!("\nThere is no saved connection state to clear.");
+        }
+        Err(e) => {
+            warn!("Failed to remove connection state file: {}", e);
+            println!("{} Failed to clear connection state: {}", "⚠".yellow(), e);
         }
-    } else {
-        println!("{} No active device connection found.", "ⓘ".cyan());
-        println!("\nThere is no saved connection state to clear.");
     }
 
     println!("\nTo manage device connections:");
@@ -788,14 +975,8 @@ async fn handle_mock_steady(bpm: u16) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Handle the session start subcommand.
-async fn handle_session_start(plan_path: &str) -> anyhow::Result<()> {
-    use session_display::SessionDisplay;
-    use tokio::signal;
-
-    info!("Starting training session from plan: {}", plan_path);
-
-    // Load the training plan from JSON file
+/// Load and validate a training plan from a JSON file, printing its header.
+fn load_training_plan(plan_path: &str) -> anyhow::Result<TrainingPlan> {
     let plan_data = std::fs::read_to_string(plan_path)
         .map_err(|e| anyhow::anyhow!("Failed to read plan file '{}': {}", plan_path, e))?;
     let plan: TrainingPlan = serde_json::from_str(&plan_data)
@@ -809,21 +990,124 @@ async fn handle_session_start(plan_path: &str) -> anyhow::Result<()> {
     println!("Phases: {}", plan.phases.len());
     println!("Max HR: {} BPM\n", plan.max_hr);
 
-    // Create notification adapter
-    let notifier = Arc::new(MockNotificationAdapter::new());
+    Ok(plan)
+}
+
+/// Handle the session start subcommand.
+async fn handle_session_start(plan_path: &str) -> anyhow::Result<()> {
+    info!("Starting training session from plan: {}", plan_path);
+
+    let plan = load_training_plan(plan_path)?;
+
+    // Queue notifications so they are printed below the display frame rather
+    // than interleaving with it
+    let notifier = CliNotificationAdapter::buffered();
 
     // Create session executor
-    let mut executor = SessionExecutor::new(notifier);
+    let mut executor = SessionExecutor::new(Arc::new(notifier.clone()));
 
     // Start the session
     executor.start_session(plan.clone()).await?;
     println!("✓ Session started\n");
 
+    run_session_display(&mut executor, &plan, &notifier).await?;
+    Ok(())
+}
+
+/// Notification port for scheduled sessions.
+///
+/// Forwards every event to the CLI adapter and signals when the scheduled
+/// workout fires, so the foreground loop can start it.
+struct ScheduledWorkoutNotifier {
+    inner: CliNotificationAdapter,
+    ready_tx: tokio::sync::mpsc::UnboundedSender<String>,
+}
+
+#[async_trait::async_trait]
+impl NotificationPort for ScheduledWorkoutNotifier {
+    async fn notify(&self, event: NotificationEvent) -> anyhow::Result<()> {
+        if let NotificationEvent::WorkoutReady { plan_name } = &event {
+            let _ = self.ready_tx.send(plan_name.clone());
+        }
+        self.inner.notify(event).await
+    }
+}
+
+/// Handle the session schedule subcommand.
+///
+/// Schedules the plan and blocks, starting the workout each time the cron
+/// expression fires, until Ctrl+C is pressed.
+async fn handle_session_schedule(plan_path: &str, cron: &str) -> anyhow::Result<()> {
+    use colored::Colorize;
+    use tokio::signal;
+
+    info!(
+        "Scheduling training session from plan: {} ({})",
+        plan_path, cron
+    );
+
+    let plan = load_training_plan(plan_path)?;
+    validate_cron_expression(cron)?;
+
+    let notifier = CliNotificationAdapter::buffered();
+    let (ready_tx, mut ready_rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut executor = SessionExecutor::new(Arc::new(ScheduledWorkoutNotifier {
+        inner: notifier.clone(),
+        ready_tx,
+    }));
+
+    executor.schedule_session(plan.clone(), cron).await?;
+    println!(
+        "{} '{}' scheduled with cron '{}'",
+        "✓".green().bold(),
+        plan.name,
+        cron
+    );
+
+    loop {
+        println!("Waiting for the scheduled workout (Ctrl+C to exit)...");
+
+        tokio::select! {
+            _ = signal::ctrl_c() => {
+                println!("\nScheduler stopped.");
+                break;
+            }
+            fired = ready_rx.recv() => {
+                if fired.is_none() {
+                    break;
+                }
+
+                // Print the WorkoutReady message, then start the workout
+                notifier.flush()?;
+                executor.start_session(plan.clone()).await?;
+                println!("✓ Session started\n");
+
+                if run_session_display(&mut executor, &plan, &notifier).await? {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Show the live display for a started session until it ends, then stop it.
+///
+/// Returns `true` if the session was interrupted with Ctrl+C.
+async fn run_session_display(
+    executor: &mut SessionExecutor,
+    plan: &TrainingPlan,
+    notifier: &CliNotificationAdapter,
+) -> anyhow::Result<bool> {
+    use session_display::SessionDisplay;
+    use tokio::signal;
+
     // Give user a moment to read the message before clearing screen
     tokio::time::sleep(std::time::Duration::from_secs(1)).await;
 
     // Initialize session display
-    let mut display = SessionDisplay::new(&plan);
+    let mut display = SessionDisplay::new(plan);
 
     // Set up Ctrl+C handler
     let ctrl_c = async {
@@ -833,9 +1117,10 @@ async fn handle_session_start(plan_path: &str) -> anyhow::Result<()> {
     };
 
     // Main display loop
-    tokio::select! {
+    let interrupted = tokio::select! {
         _ = ctrl_c => {
             info!("Ctrl+C received, stopping session...");
+            true
         }
         _ = async {
             loop {
@@ -845,12 +1130,17 @@ async fn handle_session_start(plan_path: &str) -> anyhow::Result<()> {
                 if let Some((phase_idx, elapsed_secs, phase_duration)) = progress {
                     if phase_idx < plan.phases.len() {
                         // Update the display with current progress
-                        display.update(phase_idx, elapsed_secs, phase_duration, &plan);
+                        display.update(phase_idx, elapsed_secs, phase_duration, plan);
 
                         // Render the display
                         if let Err(e) = display.render() {
                             error!("Failed to render display: {}", e);
                         }
+
+                        // Print queued notifications below the frame
+                        if let Err(e) = notifier.flush() {
+                            error!("Failed to flush notifications: {}", e);
+                        }
                     } else {
                         // Session complete
                         SessionDisplay::clear().ok();
@@ -869,17 +1159,19 @@ async fn handle_session_start(plan_path: &str) -> anyhow::Result<()> {
             }
         } => {
             info!("Session display loop ended");
+            false
         }
-    }
+    };
 
     // Clean up display
     SessionDisplay::clear().ok();
+    notifier.flush().ok();
 
     // Stop the session
     executor.stop_session().await?;
     println!("\n✓ Session stopped");
 
-    Ok(())
+    Ok(interrupted)
 }
 
 /// Handle the session pause subcommand.
@@ -1121,7 +1413,11 @@ async fn handle_session_stop() -> anyhow::Result<()> {
                             let zone = phase
                                 .get("target_zone")
                                 .and_then(|z| z.as_str())
-                                .unwrap_or("Unknown");
+                                .map(|z| match z.parse::<Zone>() {
+                                    Ok(zone) => zone.display_name(),
+                                    Err(_) => z.to_string(),
+                                })
+                                .unwrap_or_else(|| "Unknown".to_string());
 
                             let status = if idx < current_phase as usize {
                                 Cell::new("✓ Completed").fg(Color::Green)
@@ -1182,18 +1478,92 @@ async fn handle_session_stop() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Handle the session list subcommand.
+async fn handle_session_list(output: OutputFormat) -> anyhow::Result<()> {
+    use comfy_table::{presets::UTF8_FULL, Attribute, Cell, Color, ContentArrangement, Table};
+
+    let repository = FileSessionRepository::new().await?;
+    let sessions = repository.list().await?;
+
+    if output == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&sessions)?);
+        return Ok(());
+    }
+
+    if sessions.is_empty() {
+        println!("No completed sessions found.");
+        return Ok(());
+    }
+
+    println!("Found {} session(s):\n", sessions.len());
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic);
+
+    table.set_header(
+        ["Started", "Plan", "Duration", "Avg HR", "Status", "ID"]
+            .into_iter()
+            .map(|title| {
+                Cell::new(title)
+                    .add_attribute(Attribute::Bold)
+                    .fg(Color::Cyan)
+            }),
+    );
+
+    for session in &sessions {
+        table.add_row(vec![
+            Cell::new(session.start_time.format("%Y-%m-%d %H:%M")),
+            Cell::new(&session.plan_name),
+            Cell::new(format!(
+                "{}:{:02}",
+                session.duration_secs / 60,
+                session.duration_secs % 60
+            )),
+            Cell::new(format!("{} BPM", session.avg_hr)),
+            Cell::new(&session.status),
+            Cell::new(&session.id),
+        ]);
+    }
+
+    println!("{table}");
+    println!("\nUse 'cli session show <id>' to view a session.");
+
+    Ok(())
+}
+
+/// Handle the session show subcommand.
+async fn handle_session_show(id: &str, output: OutputFormat) -> anyhow::Result<()> {
+    let repository = FileSessionRepository::new().await?;
+    let session = repository.get(id).await?.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Session '{}' not found. Run 'cli session list' to see available sessions.",
+            id
+        )
+    })?;
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&session)?),
+        OutputFormat::Table => print!("{}", heart_beat::domain::export_to_summary(&session)),
+    }
+
+    Ok(())
+}
+
 /// Handle the mock ramp subcommand.
-async fn handle_mock_ramp(start: u16, end: u16, duration: u32) -> anyhow::Result<()> {
+async fn handle_mock_ramp(
+    start: u16,
+    end: u16,
+    duration: u32,
+    bounds: BpmBounds,
+) -> anyhow::Result<()> {
     use rand::Rng;
     use tokio::signal;
 
     // Validate inputs
-    if !(30..=220).contains(&start) {
-        return Err(anyhow::anyhow!("Start BPM must be between 30-220"));
-    }
-    if !(30..=220).contains(&end) {
-        return Err(anyhow::anyhow!("End BPM must be between 30-220"));
-    }
+    bounds.check("Start", start)?;
+    bounds.check("End", end)?;
     if duration == 0 {
         return Err(anyhow::anyhow!("Duration must be greater than 0"));
     }
@@ -1208,7 +1578,7 @@ async fn handle_mock_ramp(start: u16, end: u16, duration: u32) -> anyhow::Result
     );
 
     // Create mock adapter
-    let adapter = MockAdapter::new();
+    let adapter = MockAdapter::new().with_bpm_bounds(bounds);
 
     // Start scan to populate devices
     adapter.start_scan().await?;
@@ -1263,10 +1633,10 @@ async fn handle_mock_ramp(start: u16, end: u16, duration: u32) -> anyhow::Result
 
                 // Add realistic noise (±3 BPM)
                 let noise = rng.gen_range(-3.0..=3.0);
-                let simulated_bpm = (target_bpm + noise).clamp(30.0, 220.0);
+                let simulated_bpm = clamp_bpm(target_bpm + noise, bounds);
 
                 // Filter the BPM value
-                let filtered_bpm = filter.filter_if_valid(simulated_bpm);
+                let filtered_bpm = filter.filter_if_within(simulated_bpm, bounds);
 
                 // Calculate progress
                 let progress_pct = ((i + 1) as f64 / duration as f64) * 100.0;
@@ -1306,17 +1676,14 @@ async fn handle_mock_interval(
     high: u16,
     work_secs: u32,
     rest_secs: u32,
+    bounds: BpmBounds,
 ) -> anyhow::Result<()> {
     use rand::Rng;
     use tokio::signal;
 
     // Validate inputs
-    if !(30..=220).contains(&low) {
-        return Err(anyhow::anyhow!("Low BPM must be between 30-220"));
-    }
-    if !(30..=220).contains(&high) {
-        return Err(anyhow::anyhow!("High BPM must be between 30-220"));
-    }
+    bounds.check("Low", low)?;
+    bounds.check("High", high)?;
     if low >= high {
         return Err(anyhow::anyhow!("Low BPM must be less than high BPM"));
     }
@@ -1336,7 +1703,7 @@ async fn handle_mock_interval(
     );
 
     // Create mock adapter
-    let adapter = MockAdapter::new();
+    let adapter = MockAdapter::new().with_bpm_bounds(bounds);
 
     // Start scan to populate devices
     adapter.start_scan().await?;
@@ -1395,10 +1762,10 @@ async fn handle_mock_interval(
 
                     // Add realistic noise (±3 BPM)
                     let noise = rng.gen_range(-3.0..=3.0);
-                    let simulated_bpm = (target_bpm + noise).clamp(30.0, 220.0);
+                    let simulated_bpm = clamp_bpm(target_bpm + noise, bounds);
 
                     // Filter the BPM value
-                    let filtered_bpm = filter.filter_if_valid(simulated_bpm);
+                    let filtered_bpm = filter.filter_if_within(simulated_bpm, bounds);
 
                     let remaining = work_secs - i - 1;
 
@@ -1426,10 +1793,10 @@ async fn handle_mock_interval(
 
                     // Add realistic noise (±3 BPM)
                     let noise = rng.gen_range(-3.0..=3.0);
-                    let simulated_bpm = (target_bpm + noise).clamp(30.0, 220.0);
+                    let simulated_bpm = clamp_bpm(target_bpm + noise, bounds);
 
                     // Filter the BPM value
-                    let filtered_bpm = filter.filter_if_valid(simulated_bpm);
+                    let filtered_bpm = filter.filter_if_within(simulated_bpm, bounds);
 
                     let remaining = rest_secs - i - 1;
 
@@ -1615,30 +1982,15 @@ async fn handle_mock_dropout(probability: f64) -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Handle the plan list subcommand.
-fn handle_plan_list() -> anyhow::Result<()> {
-    use comfy_table::{presets::UTF8_FULL, Attribute, Cell, Color, ContentArrangement, Table};
+/// Load every parseable plan in `plans_dir` as (file stem, plan), sorted by file.
+///
+/// Files that cannot be read or parsed are skipped with a warning.
+fn load_plans(plans_dir: &Path) -> anyhow::Result<Vec<(String, TrainingPlan)>> {
     use std::fs;
 
-    info!("Listing training plans");
-
-    // Get plans directory
-    let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
-    let plans_dir = home.join(".heart-beat").join("plans");
-
-    // Create directory if it doesn't exist
-    if !plans_dir.exists() {
-        fs::create_dir_all(&plans_dir)?;
-        println!("No training plans found.");
-        println!("\nPlans directory: {}", plans_dir.display());
-        println!("Use 'cli plan create' to create a new plan.");
-        return Ok(());
-    }
-
-    // Read all .json files from the plans directory
     let mut plans: Vec<(String, TrainingPlan)> = Vec::new();
 
-    for entry in fs::read_dir(&plans_dir)? {
+    for entry in fs::read_dir(plans_dir)? {
         let entry = entry?;
         let path = entry.path();
 
@@ -1664,6 +2016,50 @@ fn handle_plan_list() -> anyhow::Result<()> {
         }
     }
 
+    plans.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(plans)
+}
+
+/// Render plans as a JSON array of plan objects, each with its `file` stem.
+fn plans_to_json(plans: &[(String, TrainingPlan)]) -> anyhow::Result<String> {
+    #[derive(serde::Serialize)]
+    struct PlanEntry<'a> {
+        file: &'a str,
+        #[serde(flatten)]
+        plan: &'a TrainingPlan,
+    }
+
+    let entries: Vec<PlanEntry> = plans
+        .iter()
+        .map(|(file, plan)| PlanEntry { file, plan })
+        .collect();
+    Ok(serde_json::to_string_pretty(&entries)?)
+}
+
+/// Handle the plan list subcommand.
+fn handle_plan_list(output: OutputFormat) -> anyhow::Result<()> {
+    use comfy_table::{presets::UTF8_FULL, Attribute, Cell, Color, ContentArrangement, Table};
+    use std::fs;
+
+    info!("Listing training plans");
+
+    // Get plans directory
+    let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("Could not find home directory"))?;
+    let plans_dir = home.join(".heart-beat").join("plans");
+
+    // Create directory if it doesn't exist
+    if !plans_dir.exists() {
+        fs::create_dir_all(&plans_dir)?;
+    }
+
+    // Read all .json files from the plans directory
+    let plans = load_plans(&plans_dir)?;
+
+    if output == OutputFormat::Json {
+        println!("{}", plans_to_json(&plans)?);
+        return Ok(());
+    }
+
     if plans.is_empty() {
         println!("No training plans found.");
         println!("\nPlans directory: {}", plans_dir.display());
@@ -1727,7 +2123,7 @@ fn handle_plan_list() -> anyhow::Result<()> {
 }
 
 /// Handle the plan show subcommand.
-fn handle_plan_show(name: &str) -> anyhow::Result<()> {
+fn handle_plan_show(name: &str, output: OutputFormat) -> anyhow::Result<()> {
     use comfy_table::{presets::UTF8_FULL, Attribute, Cell, Color, ContentArrangement, Table};
     use std::fs;
 
@@ -1751,6 +2147,11 @@ fn handle_plan_show(name: &str) -> anyhow::Result<()> {
     let content = fs::read_to_string(&plan_path)?;
     let plan: TrainingPlan = serde_json::from_str(&content)?;
 
+    if output == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+        return Ok(());
+    }
+
     // Display plan header
     println!("\n{}", "═".repeat(60));
     println!("Plan: {}", plan.name);
@@ -1806,13 +2207,8 @@ fn handle_plan_show(name: &str) -> anyhow::Result<()> {
 
         // Color code the zone
         let zone_str = format!("{:?}", phase.target_zone);
-        let zone_cell = match phase.target_zone {
-            Zone::Zone1 => Cell::new(&zone_str).fg(Color::Blue),
-            Zone::Zone2 => Cell::new(&zone_str).fg(Color::Green),
-            Zone::Zone3 => Cell::new(&zone_str).fg(Color::Yellow),
-            Zone::Zone4 => Cell::new(&zone_str).fg(Color::DarkYellow),
-            Zone::Zone5 => Cell::new(&zone_str).fg(Color::Red),
-        };
+        let (r, g, b) = phase.target_zone.rgb();
+        let zone_cell = Cell::new(&zone_str).fg(Color::Rgb { r, g, b });
 
         let transition_str = match &phase.transition {
             TransitionCondition::TimeElapsed => "Time".to_string(),
@@ -1861,9 +2257,12 @@ fn handle_plan_validate(path: &str) -> anyhow::Result<()> {
     println!("File: {}\n", path);
 
     // Validate the plan
-    match plan.validate() {
-        Ok(()) => {
+    match plan.validate_with_warnings() {
+        Ok(warnings) => {
             println!("{} Plan is valid!", "✓".green().bold());
+            for warning in &warnings {
+                println!("{} {}", "Warning:".yellow().bold(), warning);
+            }
             println!("\nPlan Summary:");
             println!("  Name: {}", plan.name);
             println!("  Max HR: {} BPM", plan.max_hr);
@@ -2016,6 +2415,8 @@ fn handle_plan_create() -> anyhow::Result<()> {
             target_zone,
             duration_secs,
             transition,
+            alerts_enabled: true,
+            target: None,
         });
 
         // Ask if user wants to add another phase
@@ -2037,6 +2438,8 @@ fn handle_plan_create() -> anyhow::Result<()> {
         phases,
         created_at: chrono::Utc::now(),
         max_hr,
+        warmup_grace_secs: None,
+        resting_hr: None,
     };
 
     // Validate the plan
@@ -2320,13 +2723,37 @@ async fn handle_analytics_resting_hr() -> anyhow::Result<()> {
 // Export handler
 // =============================================================================
 
-async fn handle_export_session(id: &str, format: &str) -> anyhow::Result<()> {
+async fn handle_export_session(id: &str, format: &str, output: Option<&str>) -> anyhow::Result<()> {
     use colored::Colorize;
 
     let home = dirs::home_dir().ok_or_else(|| anyhow::anyhow!("No home directory"))?;
     let data_dir = home.join(".heart-beat");
     heart_beat::api::set_data_dir(data_dir.to_string_lossy().to_string())?;
 
+    if let Some(path) = output {
+        let export_format = match format {
+            "json" => heart_beat::api::ExportFormat::Json,
+            "summary" => heart_beat::api::ExportFormat::Summary,
+            "csv" => heart_beat::api::ExportFormat::Csv,
+            other => anyhow::bail!("--output is not supported for format '{}'", other),
+        };
+        let bytes = heart_beat::api::export_session_to_file(
+            id.to_string(),
+            export_format,
+            path.to_string(),
+        )
+        .await?;
+        println!(
+            "{} Exported session {} as {} to {} ({} bytes)",
+            "✓".green().bold(),
+            id,
+            format,
+            path,
+            bytes
+        );
+        return Ok(());
+    }
+
     let output = match format {
         "json" => {
             heart_beat::api::export_session(id.to_string(), heart_beat::api::ExportFormat::Json)
@@ -2354,6 +2781,97 @@ async fn handle_export_session(id: &str, format: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+// =============================================================================
+// Doctor handler
+// =============================================================================
+
+/// Handle the doctor command.
+///
+/// Runs environment checks and prints remediation for anything that fails.
+/// Returns an error, so the process exits non-zero, if any check failed.
+async fn handle_doctor() -> anyhow::Result<()> {
+    use colored::Colorize;
+    use heart_beat::ports::BleError;
+
+    println!("{}", "Heart Beat Doctor".bold());
+    println!("{}", "═".repeat(50));
+
+    let mut problems = 0;
+
+    // BLE adapter presence and state
+    match BtleplugAdapter::new().await {
+        Ok(adapter) => {
+            let info = adapter
+                .adapter_info()
+                .await
+                .unwrap_or_else(|_| "unknown".to_string());
+            println!("{} BLE adapter found ({})", "✓".green().bold(), info);
+
+            match adapter.check_powered().await {
+                Ok(()) => println!("{} BLE adapter is powered on", "✓".green().bold()),
+                Err(e) => {
+                    problems += 1;
+                    println!("{} {}", "✗".red().bold(), e);
+                    if let Some(ble_err) = e.downcast_ref::<BleError>() {
+                        println!("  {} {}", "→".yellow(), ble_err.remediation());
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            problems += 1;
+            println!("{} {}", "✗".red().bold(), e);
+            match e.downcast_ref::<BleError>() {
+                Some(ble_err) => println!("  {} {}", "→".yellow(), ble_err.remediation()),
+                None => println!(
+                    "  {} Check that Bluetooth is supported and enabled on this system.",
+                    "→".yellow()
+                ),
+            }
+        }
+    }
+
+    // Data directory writability
+    match dirs::home_dir() {
+        Some(home) => {
+            let data_dir = home.join(".heart-beat");
+            let path = data_dir.to_string_lossy().to_string();
+            match heart_beat::api::check_data_dir_writable(path.clone()) {
+                Ok(()) => println!(
+                    "{} Data directory is writable ({})",
+                    "✓".green().bold(),
+                    path
+                ),
+                Err(e) => {
+                    problems += 1;
+                    println!("{} {}", "✗".red().bold(), e);
+                    println!(
+                        "  {} Fix the permissions of {} or free up disk space.",
+                        "→".yellow(),
+                        path
+                    );
+                }
+            }
+        }
+        None => {
+            problems += 1;
+            println!("{} No home directory found", "✗".red().bold());
+            println!(
+                "  {} Ensure the HOME environment variable is set.",
+                "→".yellow()
+            );
+        }
+    }
+
+    println!();
+    if problems > 0 {
+        anyhow::bail!("{} problem(s) found", problems);
+    }
+    println!("{} All checks passed", "✓".green().bold());
+
+    Ok(())
+}
+
 /// Session display module for real-time terminal UI during training sessions.
 mod session_display {
     use crossterm::{
@@ -2528,7 +3046,7 @@ mod session_display {
                 stdout,
                 Print("║  "),
                 SetForegroundColor(zone_color),
-                Print(format!("{:^40}", format!("{:?}", self.target_zone))),
+                Print(format!("{:^40}", self.target_zone.label())),
                 ResetColor,
                 Print("  ║\n")
             )?;
@@ -2617,13 +3135,8 @@ mod session_display {
         }
 
         fn get_zone_color(&self) -> Color {
-            match self.target_zone {
-                Zone::Zone1 => Color::Blue,
-                Zone::Zone2 => Color::Green,
-                Zone::Zone3 => Color::Yellow,
-                Zone::Zone4 => Color::DarkYellow,
-                Zone::Zone5 => Color::Red,
-            }
+            let (r, g, b) = self.target_zone.rgb();
+            Color::Rgb { r, g, b }
         }
 
         fn get_bpm_color(&self, bpm: u16) -> Color {
@@ -2655,3 +3168,70 @@ mod session_display {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sdnn_column_value_and_trend_arrow() {
+        let mut window = HrvWindow::new(DEFAULT_HRV_WINDOW);
+        assert_eq!(sdnn_column(&window, Some(40.0)), "    -     ");
+
+        // 1000 ms and 2000 ms intervals: SDNN is exactly 500 ms
+        window.extend(&[1024, 2048]);
+        assert_eq!(sdnn_column(&window, None), "  500.00  ");
+        assert_eq!(sdnn_column(&window, Some(400.0)), "  500.00 ↑");
+        assert_eq!(sdnn_column(&window, Some(600.0)), "  500.00 ↓");
+        assert_eq!(sdnn_column(&window, Some(499.5)), "  500.00 →");
+    }
+
+    #[test]
+    fn test_sparkline_maps_range_to_blocks() {
+        assert_eq!(sparkline(&[60, 70, 80, 90, 100, 110, 120, 130]), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&[100, 170, 100]), "▁█▁");
+        assert_eq!(sparkline(&[0, 50, 100]), "▁▅█");
+    }
+
+    #[test]
+    fn test_sparkline_edge_cases() {
+        assert_eq!(sparkline(&[]), "");
+        assert_eq!(sparkline(&[72, 72, 72]), "▄▄▄");
+        assert_eq!(sparkline(&[72]), "▄");
+    }
+
+    #[test]
+    fn test_plan_list_json_is_array_of_plans() {
+        let temp = tempfile::tempdir().unwrap();
+        for (file, name) in [("tempo", "Tempo Run"), ("easy", "Easy Run")] {
+            let plan = format!(
+                r#"{{"name": "{}", "phases": [{{"name": "Work", "target_zone": "Zone3", "duration_secs": 600, "transition": "TimeElapsed"}}], "created_at": "2024-01-01T00:00:00Z", "max_hr": 185}}"#,
+                name
+            );
+            std::fs::write(temp.path().join(format!("{}.json", file)), plan).unwrap();
+        }
+        std::fs::write(temp.path().join("broken.json"), "{ not json").unwrap();
+        std::fs::write(temp.path().join("notes.txt"), "ignored").unwrap();
+
+        let json = plans_to_json(&load_plans(temp.path()).unwrap()).unwrap();
+
+        let plans: Vec<TrainingPlan> = serde_json::from_str(&json).unwrap();
+        let names: Vec<&str> = plans.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Easy Run", "Tempo Run"]);
+        assert_eq!(plans[1].phases[0].duration_secs, 600);
+
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        assert_eq!(entries[0]["file"], "easy");
+        assert_eq!(entries[1]["file"], "tempo");
+    }
+
+    #[test]
+    fn test_json_flag_selects_output_format() {
+        let cli = Cli::try_parse_from(["heart-beat-cli", "plan", "list", "--json"]).unwrap();
+        assert!(cli.json);
+        assert_eq!(OutputFormat::from_json_flag(cli.json), OutputFormat::Json);
+
+        let cli = Cli::try_parse_from(["heart-beat-cli", "session", "list"]).unwrap();
+        assert_eq!(OutputFormat::from_json_flag(cli.json), OutputFormat::Table);
+    }
+}
@@ -118,7 +118,10 @@ pub fn map_err(e: anyhow::Error) -> (StatusCode, Json<ApiErr>) {
 // ---------------------------------------------------------------------------
 
 async fn devices_scan() -> ApiResult<Vec<api::ApiDiscoveredDevice>> {
-    api::scan_devices().await.map(ok_json).map_err(map_err)
+    api::scan_devices()
+        .await
+        .map(ok_json)
+        .map_err(|e| map_err(e.into()))
 }
 
 #[derive(Deserialize)]
@@ -130,7 +133,7 @@ async fn devices_connect(Json(body): Json<ConnectBody>) -> ApiResult<&'static st
     api::connect_device(body.device_id)
         .await
         .map(|_| ok_json("connected"))
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn devices_disconnect() -> ApiResult<&'static str> {
@@ -153,7 +156,7 @@ async fn workout_start(Json(body): Json<WorkoutStartBody>) -> ApiResult<&'static
     api::start_workout(body.plan_name)
         .await
         .map(|_| ok_json("started"))
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn workout_pause() -> ApiResult<&'static str> {
@@ -195,18 +198,24 @@ async fn workout_start_template(Json(body): Json<TemplateStartBody>) -> ApiResul
 // ---------------------------------------------------------------------------
 
 async fn sessions_list() -> ApiResult<Vec<api::ApiSessionSummaryPreview>> {
-    api::list_sessions().await.map(ok_json).map_err(map_err)
+    api::list_sessions()
+        .await
+        .map(ok_json)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn session_get(Path(id): Path<String>) -> ApiResult<Option<api::ApiCompletedSession>> {
-    api::get_session(id).await.map(ok_json).map_err(map_err)
+    api::get_session(id)
+        .await
+        .map(ok_json)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn session_delete(Path(id): Path<String>) -> ApiResult<&'static str> {
     api::delete_session(id)
         .await
         .map(|_| ok_json("deleted"))
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 #[derive(Deserialize)]
@@ -223,21 +232,21 @@ async fn session_export(Path(id): Path<String>, Query(q): Query<ExportQuery>) ->
     api::export_session(id, format)
         .await
         .map(ok_json)
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn session_export_tcx(Path(id): Path<String>) -> ApiResult<String> {
     api::export_session_tcx(id)
         .await
         .map(ok_json)
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn session_export_gpx(Path(id): Path<String>) -> ApiResult<String> {
     api::export_session_gpx(id)
         .await
         .map(ok_json)
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 // ---------------------------------------------------------------------------
@@ -245,14 +254,17 @@ async fn session_export_gpx(Path(id): Path<String>) -> ApiResult<String> {
 // ---------------------------------------------------------------------------
 
 async fn plans_list() -> ApiResult<Vec<String>> {
-    api::list_plans().await.map(ok_json).map_err(map_err)
+    api::list_plans()
+        .await
+        .map(ok_json)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn plan_details(Path(name): Path<String>) -> ApiResult<api::ApiPlanDetails> {
     api::get_plan_details(name)
         .await
         .map(ok_json)
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 #[derive(Deserialize)]
@@ -274,28 +286,28 @@ async fn plan_create(Json(body): Json<CreatePlanBody>) -> ApiResult<&'static str
     )
     .await
     .map(|_| ok_json("created"))
-    .map_err(map_err)
+    .map_err(|e| map_err(e.into()))
 }
 
 async fn plan_delete(Path(name): Path<String>) -> ApiResult<&'static str> {
     api::delete_plan(name)
         .await
         .map(|_| ok_json("deleted"))
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn plans_seed() -> ApiResult<u32> {
     api::seed_default_plans()
         .await
         .map(ok_json)
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 async fn plan_adapted(Path(name): Path<String>) -> ApiResult<api::ApiAdaptedPlan> {
     api::get_adapted_plan(name)
         .await
         .map(ok_json)
-        .map_err(map_err)
+        .map_err(|e| map_err(e.into()))
 }
 
 // ---------------------------------------------------------------------------
@@ -392,8 +404,14 @@ pub struct StateResponse {
 }
 
 async fn debug_state() -> ApiResult<StateResponse> {
-    let sessions = api::list_sessions().await.map_err(map_err)?.len();
-    let plans = api::list_plans().await.map_err(map_err)?.len();
+    let sessions = api::list_sessions()
+        .await
+        .map_err(|e| map_err(e.into()))?
+        .len();
+    let plans = api::list_plans()
+        .await
+        .map_err(|e| map_err(e.into()))?
+        .len();
     Ok(ok_json(StateResponse {
         sessions_count: sessions,
         plans_count: plans,
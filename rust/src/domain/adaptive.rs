@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::domain::heart_rate::Zone;
 #[allow(unused_imports)] // TransitionCondition used in tests
-use crate::domain::training_plan::{TrainingPhase, TrainingPlan, TransitionCondition};
+use crate::domain::training_plan::{PhaseTarget, TrainingPhase, TrainingPlan, TransitionCondition};
 
 /// Reason why a training plan was adjusted.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -111,6 +111,30 @@ pub fn shift_zone(zone: Zone, delta: i8) -> Zone {
     }
 }
 
+/// Midpoint of a zone as a fraction of max HR (Zone 1 is 50-60%).
+fn zone_midpoint(zone: Zone) -> f64 {
+    match zone {
+        Zone::Zone1 => 0.55,
+        Zone::Zone2 => 0.65,
+        Zone::Zone3 => 0.75,
+        Zone::Zone4 => 0.85,
+        Zone::Zone5 => 0.95,
+    }
+}
+
+/// Shift a phase's explicit target along with its zone.
+///
+/// Zone targets shift by `delta` like the phase zone; BPM bands are scaled by
+/// the ratio of the shifted zone's intensity to the original's.
+fn shift_target(target: PhaseTarget, phase_zone: Zone, delta: i8) -> PhaseTarget {
+    match target {
+        PhaseTarget::Zone(zone) => PhaseTarget::Zone(shift_zone(zone, delta)),
+        band => {
+            band.scaled(zone_midpoint(shift_zone(phase_zone, delta)) / zone_midpoint(phase_zone))
+        }
+    }
+}
+
 /// Apply duration factor to a phase duration, enforcing a minimum of 60 seconds.
 fn apply_duration_factor(duration_secs: u32, factor: f64) -> u32 {
     let adjusted = (duration_secs as f64 * factor).round() as u32;
@@ -131,6 +155,10 @@ pub fn adapt_plan(plan: &TrainingPlan, readiness_score: u8, tsb: Option<f64>) ->
             target_zone: shift_zone(phase.target_zone, adjustment.zone_delta),
             duration_secs: apply_duration_factor(phase.duration_secs, adjustment.duration_factor),
             transition: phase.transition.clone(),
+            alerts_enabled: phase.alerts_enabled,
+            target: phase
+                .target
+                .map(|target| shift_target(target, phase.target_zone, adjustment.zone_delta)),
         })
         .collect();
 
@@ -157,10 +185,13 @@ mod tests {
                     target_zone: zone,
                     duration_secs: dur,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 })
                 .collect(),
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         }
     }
 
@@ -311,6 +342,45 @@ mod tests {
         assert_eq!(adapted.adjusted_phases[2].duration_secs, 180);
     }
 
+    #[test]
+    fn test_adapt_plan_shifts_explicit_targets() {
+        let mut plan = make_plan(vec![(Zone::Zone3, 1200), (Zone::Zone3, 600)]);
+        plan.phases[0].target = Some(PhaseTarget::Bpm {
+            low: 148,
+            high: 152,
+        });
+        plan.phases[1].target = Some(PhaseTarget::Zone(Zone::Zone2));
+
+        let easier = adapt_plan(&plan, 30, None);
+        assert_eq!(
+            easier.adjusted_phases[0].target,
+            Some(PhaseTarget::Bpm {
+                low: 109,
+                high: 111
+            })
+        );
+        assert_eq!(
+            easier.adjusted_phases[1].target,
+            Some(PhaseTarget::Zone(Zone::Zone1))
+        );
+
+        let harder = adapt_plan(&plan, 90, None);
+        assert_eq!(
+            harder.adjusted_phases[0].target,
+            Some(PhaseTarget::Bpm {
+                low: 168,
+                high: 172
+            })
+        );
+        assert_eq!(
+            harder.adjusted_phases[1].target,
+            Some(PhaseTarget::Zone(Zone::Zone3))
+        );
+
+        let unchanged = adapt_plan(&plan, 70, None);
+        assert_eq!(unchanged.adjusted_phases[0].target, plan.phases[0].target);
+    }
+
     #[test]
     fn test_adapt_plan_well_recovered() {
         let plan = make_plan(vec![(Zone::Zone3, 1200), (Zone::Zone5, 600)]);
@@ -365,9 +435,12 @@ mod tests {
                     target_bpm: 160,
                     hold_secs: 10,
                 },
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
         let adapted = adapt_plan(&plan, 50, None);
 
@@ -388,6 +461,7 @@ mod tests {
             phases: vec![],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
         let adapted = adapt_plan(&plan, 70, None);
 
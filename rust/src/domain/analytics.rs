@@ -211,8 +211,19 @@ mod tests {
                 avg_hr,
                 max_hr: avg_hr + 20,
                 min_hr: avg_hr.saturating_sub(20),
+                hr_p50: avg_hr,
+                hr_p90: avg_hr + 20,
+                hr_p95: avg_hr + 20,
                 time_in_zone: [60, 120, 180, 60, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         }
     }
 
@@ -0,0 +1,213 @@
+//! Monotonic-aware wall-clock timestamps.
+//!
+//! Reading `SystemTime::now()` for every timestamp breaks ordering when the
+//! wall clock is adjusted backwards (NTP corrections, manual changes), and
+//! `duration_since(UNIX_EPOCH)` fails outright for times before the epoch.
+//! [`MonotonicClock`] instead captures the wall clock once and advances it by
+//! a monotonic [`Instant`] delta, so timestamps taken from it never decrease
+//! during a run.
+//!
+//! The process-wide clock is rebased at the start of each session
+//! ([`rebase_clock`]) and HR timestamps use a clock based per connection, so
+//! a long-running process does not keep reporting the wall clock as it was at
+//! first use.
+
+use chrono::{DateTime, Utc};
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Wall-clock base advanced by a monotonic delta.
+#[derive(Debug, Clone, Copy)]
+pub struct MonotonicClock {
+    /// Wall-clock time of `mono_base` since the UNIX epoch
+    wall_base: Duration,
+    /// Monotonic instant the wall clock was captured at
+    mono_base: Instant,
+}
+
+impl MonotonicClock {
+    /// Capture the current wall clock as the base.
+    pub fn new() -> Self {
+        Self::with_base(SystemTime::now(), Instant::now())
+    }
+
+    /// Create a clock whose `mono_base` corresponds to `wall_base`.
+    ///
+    /// A `wall_base` before the UNIX epoch is treated as the epoch.
+    pub fn with_base(wall_base: SystemTime, mono_base: Instant) -> Self {
+        let wall_base = wall_base.duration_since(UNIX_EPOCH).unwrap_or_default();
+        Self {
+            wall_base,
+            mono_base,
+        }
+    }
+
+    /// UNIX epoch microseconds at `instant`.
+    ///
+    /// Instants before the base count back from it, saturating at the epoch.
+    pub fn micros_at(&self, instant: Instant) -> u64 {
+        let wall = match instant.checked_duration_since(self.mono_base) {
+            Some(elapsed) => self.wall_base.saturating_add(elapsed),
+            None => self
+                .wall_base
+                .saturating_sub(self.mono_base.duration_since(instant)),
+        };
+        wall.as_micros() as u64
+    }
+
+    /// UNIX epoch milliseconds at `instant`.
+    pub fn millis_at(&self, instant: Instant) -> u64 {
+        self.micros_at(instant) / 1_000
+    }
+
+    /// Current time in UNIX epoch milliseconds.
+    pub fn now_millis(&self) -> u64 {
+        self.millis_at(Instant::now())
+    }
+
+    /// Current time in UNIX epoch microseconds.
+    pub fn now_micros(&self) -> u64 {
+        self.micros_at(Instant::now())
+    }
+
+    /// Current time as a UTC date-time.
+    pub fn now_utc(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_micros(self.now_micros() as i64).unwrap_or_default()
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn process_clock() -> &'static RwLock<MonotonicClock> {
+    static CLOCK: OnceLock<RwLock<MonotonicClock>> = OnceLock::new();
+    CLOCK.get_or_init(|| RwLock::new(MonotonicClock::new()))
+}
+
+/// Process-wide clock, based on the wall clock at first use or last rebase.
+pub fn clock() -> MonotonicClock {
+    *process_clock().read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Re-capture the process-wide clock's base from the current wall clock.
+///
+/// Timestamps only stay non-decreasing between rebases, so this is called at
+/// session boundaries rather than per reading.
+pub fn rebase_clock() {
+    *process_clock().write().unwrap_or_else(|e| e.into_inner()) = MonotonicClock::new();
+}
+
+/// Current time in UNIX epoch milliseconds from the process-wide clock.
+pub fn unix_millis() -> u64 {
+    clock().now_millis()
+}
+
+/// Current time as a UTC date-time from the process-wide clock.
+pub fn utc_now() -> DateTime<Utc> {
+    clock().now_utc()
+}
+
+/// Map a wall-clock reading onto the monotonic clock.
+///
+/// `wall_at` was read from the wall clock `wall_now - wall_at` ago; the
+/// result is `now` minus that age. If the wall clock has since moved
+/// backwards past `wall_at`, the reading is treated as taken at `now`.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::clock::arrival_instant;
+/// use std::time::{Duration, Instant, SystemTime};
+///
+/// let now = Instant::now();
+/// let wall_now = SystemTime::now();
+///
+/// let earlier = arrival_instant(wall_now - Duration::from_millis(20), wall_now, now);
+/// assert!(earlier <= now);
+///
+/// // The wall clock was set back after the reading
+/// let skewed = arrival_instant(wall_now + Duration::from_secs(3600), wall_now, now);
+/// assert_eq!(skewed, now);
+/// ```
+pub fn arrival_instant(wall_at: SystemTime, wall_now: SystemTime, now: Instant) -> Instant {
+    let age = wall_now.duration_since(wall_at).unwrap_or_default();
+    now.checked_sub(age).unwrap_or(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_timestamps_advance_with_instant() {
+        let base = Instant::now();
+        let clock = MonotonicClock::with_base(UNIX_EPOCH + Duration::from_secs(1_000), base);
+
+        assert_eq!(clock.millis_at(base), 1_000_000);
+        assert_eq!(
+            clock.millis_at(base + Duration::from_millis(250)),
+            1_000_250
+        );
+        // Instants before the base count back from it
+        assert_eq!(clock.millis_at(base - Duration::from_secs(1)), 999_000);
+    }
+
+    #[test]
+    fn test_sub_microsecond_base_is_not_truncated() {
+        let base = Instant::now();
+        let clock = MonotonicClock::with_base(UNIX_EPOCH + Duration::from_nanos(1_999_600), base);
+
+        // 1_999.6us + 0.4us lands on 2ms exactly
+        assert_eq!(clock.micros_at(base + Duration::from_nanos(400)), 2_000);
+        assert_eq!(clock.millis_at(base + Duration::from_nanos(400)), 2);
+    }
+
+    #[test]
+    fn test_wall_before_epoch_is_zero_based() {
+        let base = Instant::now();
+        let clock = MonotonicClock::with_base(UNIX_EPOCH - Duration::from_secs(5), base);
+        assert_eq!(clock.millis_at(base + Duration::from_millis(10)), 10);
+        assert_eq!(clock.millis_at(base - Duration::from_millis(10)), 0);
+    }
+
+    #[test]
+    fn test_backward_wall_clock_never_decreases_timestamps() {
+        let base = Instant::now();
+        let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let clock = MonotonicClock::with_base(start, base);
+
+        // One reading per second; the wall clock is set back an hour at the
+        // fourth reading and 10s at the seventh
+        let mut wall = start;
+        let mut timestamps = Vec::new();
+        for i in 1..=10u64 {
+            wall += Duration::from_secs(1);
+            if i == 4 {
+                wall -= Duration::from_secs(3_600);
+            }
+            if i == 7 {
+                wall -= Duration::from_secs(10);
+            }
+            let now = base + Duration::from_secs(i);
+            // Adapters stamp arrival from the same (skewed) wall clock
+            let received_at = wall - Duration::from_millis(5);
+            timestamps.push(clock.millis_at(arrival_instant(received_at, wall, now)));
+        }
+
+        assert!(timestamps.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(timestamps[0], 1_700_000_000_995);
+        assert_eq!(timestamps[9], 1_700_000_009_995);
+    }
+
+    #[test]
+    fn test_process_clock_is_non_decreasing() {
+        let a = unix_millis();
+        let b = unix_millis();
+        assert!(b >= a);
+        assert!(utc_now().timestamp_millis() as u64 >= b);
+    }
+}
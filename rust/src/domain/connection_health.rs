@@ -0,0 +1,308 @@
+//! Composite connection health indicator.
+//!
+//! Combines signal strength (RSSI), the rate of missed HR samples and the
+//! strap's battery level into a single glanceable [`HealthLevel`]. Each input
+//! is graded on its own and the composite is the worst of the three, so a
+//! single critical input makes the connection Poor regardless of the others.
+//! Unknown inputs (no RSSI or battery reading) do not downgrade the result.
+
+use crate::domain::session_progress::ConnectivityDuringSession;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// RSSI (dBm) at or above which the signal counts as good.
+pub const RSSI_GOOD_DBM: i16 = -70;
+
+/// RSSI (dBm) below which the signal counts as poor.
+pub const RSSI_POOR_DBM: i16 = -85;
+
+/// Dropout rate at or below which the connection counts as good.
+pub const DROPOUT_GOOD_RATE: f64 = 0.05;
+
+/// Dropout rate above which the connection counts as poor.
+pub const DROPOUT_POOR_RATE: f64 = 0.20;
+
+/// Battery percentage below which the strap counts as fair (matches `BatteryLevel::is_low`).
+pub const BATTERY_LOW_PERCENT: u8 = 15;
+
+/// Battery percentage below which the strap counts as poor.
+pub const BATTERY_CRITICAL_PERCENT: u8 = 5;
+
+/// Overall connection health, ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HealthLevel {
+    /// All inputs are healthy.
+    Good,
+    /// At least one input is degraded.
+    Fair,
+    /// At least one input is critical.
+    Poor,
+}
+
+/// Connection health together with the inputs it was computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConnectionHealth {
+    /// Composite health level.
+    pub level: HealthLevel,
+    /// Latest signal strength in dBm, if known.
+    pub rssi: Option<i16>,
+    /// Fraction of expected HR samples that were missed (0.0 to 1.0).
+    pub dropout_rate: f64,
+    /// Latest battery percentage, if known.
+    pub battery: Option<u8>,
+}
+
+/// Grade a signal strength reading.
+pub fn rssi_level(rssi: i16) -> HealthLevel {
+    if rssi >= RSSI_GOOD_DBM {
+        HealthLevel::Good
+    } else if rssi >= RSSI_POOR_DBM {
+        HealthLevel::Fair
+    } else {
+        HealthLevel::Poor
+    }
+}
+
+/// Grade a dropout rate (fraction of missed samples).
+pub fn dropout_level(dropout_rate: f64) -> HealthLevel {
+    if dropout_rate <= DROPOUT_GOOD_RATE {
+        HealthLevel::Good
+    } else if dropout_rate <= DROPOUT_POOR_RATE {
+        HealthLevel::Fair
+    } else {
+        HealthLevel::Poor
+    }
+}
+
+/// Grade a battery percentage.
+pub fn battery_level(battery: u8) -> HealthLevel {
+    if battery >= BATTERY_LOW_PERCENT {
+        HealthLevel::Good
+    } else if battery >= BATTERY_CRITICAL_PERCENT {
+        HealthLevel::Fair
+    } else {
+        HealthLevel::Poor
+    }
+}
+
+/// Combine the individual inputs into a [`ConnectionHealth`].
+///
+/// The composite level is the worst of the individual grades; `None` inputs
+/// are treated as healthy.
+pub fn assess_connection_health(
+    rssi: Option<i16>,
+    dropout_rate: f64,
+    battery: Option<u8>,
+) -> ConnectionHealth {
+    let dropout_rate = dropout_rate.clamp(0.0, 1.0);
+    let level = [
+        rssi.map(rssi_level).unwrap_or(HealthLevel::Good),
+        dropout_level(dropout_rate),
+        battery.map(battery_level).unwrap_or(HealthLevel::Good),
+    ]
+    .into_iter()
+    .max()
+    .unwrap_or(HealthLevel::Good);
+
+    ConnectionHealth {
+        level,
+        rssi,
+        dropout_rate,
+        battery,
+    }
+}
+
+/// Sliding-window counter of expected vs. missed HR samples.
+///
+/// Call [`DropoutCounter::record`] once per expected sample interval (e.g.
+/// every second for a 1 Hz strap) with whether a sample arrived.
+#[derive(Debug, Clone)]
+pub struct DropoutCounter {
+    /// Most recent intervals; `true` means the sample was missed.
+    window: VecDeque<bool>,
+    /// Maximum number of intervals kept.
+    capacity: usize,
+}
+
+impl DropoutCounter {
+    /// Create a counter over the last `capacity` intervals (at least 1).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            window: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Record one interval, noting whether a sample was received in it.
+    pub fn record(&mut self, received: bool) {
+        if self.window.len() == self.capacity {
+            self.window.pop_front();
+        }
+        self.window.push_back(!received);
+    }
+
+    /// Fraction of recorded intervals that were missed (0.0 when empty).
+    pub fn rate(&self) -> f64 {
+        if self.window.is_empty() {
+            return 0.0;
+        }
+        let missed = self.window.iter().filter(|&&missed| missed).count();
+        missed as f64 / self.window.len() as f64
+    }
+}
+
+/// How reliable the sensor connection was over a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionSummary {
+    /// Times the link came back after dropping.
+    pub reconnect_count: u32,
+    /// Seconds the link was down in total, including a drop still ongoing at
+    /// the end of the session.
+    pub total_dropout_secs: u32,
+    /// Weakest signal strength seen in dBm, if any was read.
+    pub worst_rssi: Option<i16>,
+}
+
+/// Connectivity events captured during a session.
+///
+/// Record every link state change with [`record`](Self::record) and RSSI
+/// readings with [`record_rssi`](Self::record_rssi); the log starts out
+/// connected. [`summary`](Self::summary) condenses it into a
+/// [`ConnectionSummary`].
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionLog {
+    /// Link state changes, in order of arrival.
+    events: Vec<(DateTime<Utc>, ConnectivityDuringSession)>,
+    /// Weakest RSSI recorded so far.
+    worst_rssi: Option<i16>,
+}
+
+impl ConnectionLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the link state reported at `at`.
+    pub fn record(&mut self, at: DateTime<Utc>, connectivity: ConnectivityDuringSession) {
+        self.events.push((at, connectivity));
+    }
+
+    /// Record a signal strength reading in dBm.
+    pub fn record_rssi(&mut self, rssi: i16) {
+        self.worst_rssi = Some(self.worst_rssi.map_or(rssi, |worst| worst.min(rssi)));
+    }
+
+    /// Summarize the log for a session that ended at `end`.
+    ///
+    /// A drop starts when the link leaves Connected and ends, counting as a
+    /// reconnect, when it is Connected again; repeated reports of the same
+    /// state (e.g. successive reconnection attempts) belong to one drop.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::connection_health::ConnectionLog;
+    /// use heart_beat::domain::session_progress::ConnectivityDuringSession;
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let start = Utc::now();
+    /// let mut log = ConnectionLog::new();
+    /// log.record(start, ConnectivityDuringSession::Reconnecting);
+    /// log.record(start + Duration::seconds(8), ConnectivityDuringSession::Connected);
+    /// log.record_rssi(-82);
+    ///
+    /// let summary = log.summary(start + Duration::seconds(60));
+    /// assert_eq!(summary.reconnect_count, 1);
+    /// assert_eq!(summary.total_dropout_secs, 8);
+    /// assert_eq!(summary.worst_rssi, Some(-82));
+    /// ```
+    pub fn summary(&self, end: DateTime<Utc>) -> ConnectionSummary {
+        let mut reconnect_count = 0;
+        let mut dropout_ms = 0i64;
+        let mut dropped_at: Option<DateTime<Utc>> = None;
+
+        for (at, connectivity) in &self.events {
+            match (dropped_at, connectivity.hr_available()) {
+                (None, false) => dropped_at = Some(*at),
+                (Some(since), true) => {
+                    reconnect_count += 1;
+                    dropout_ms += at.signed_duration_since(since).num_milliseconds().max(0);
+                    dropped_at = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(since) = dropped_at {
+            dropout_ms += end.signed_duration_since(since).num_milliseconds().max(0);
+        }
+
+        ConnectionSummary {
+            reconnect_count,
+            total_dropout_secs: ((dropout_ms + 500) / 1000) as u32,
+            worst_rssi: self.worst_rssi,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composite_logic_table() {
+        use HealthLevel::*;
+
+        let cases = [
+            // (rssi, dropout_rate, battery, expected)
+            (Some(-55), 0.0, Some(90), Good),
+            (None, 0.0, None, Good),
+            (Some(-75), 0.0, Some(90), Fair),
+            (Some(-90), 0.0, Some(90), Poor),
+            (Some(-55), 0.10, Some(90), Fair),
+            (Some(-55), 0.0, Some(10), Fair),
+            (Some(-55), 0.0, Some(3), Poor),
+            (Some(-75), 0.10, Some(10), Fair),
+            (Some(-75), 0.10, Some(3), Poor),
+        ];
+
+        for (rssi, dropout, battery, expected) in cases {
+            assert_eq!(
+                assess_connection_health(rssi, dropout, battery).level,
+                expected,
+                "rssi={:?} dropout={} battery={:?}",
+                rssi,
+                dropout,
+                battery
+            );
+        }
+    }
+
+    #[test]
+    fn test_strong_rssi_with_heavy_dropout_is_downgraded() {
+        let health = assess_connection_health(Some(-50), 0.30, Some(100));
+        assert!(health.level >= HealthLevel::Fair);
+        assert_eq!(health.level, HealthLevel::Poor);
+        assert_eq!(health.rssi, Some(-50));
+        assert_eq!(health.battery, Some(100));
+    }
+
+    #[test]
+    fn test_dropout_counter_sliding_window() {
+        let mut counter = DropoutCounter::new(10);
+        assert_eq!(counter.rate(), 0.0);
+
+        for i in 0..10 {
+            counter.record(i % 5 != 0); // 2 of 10 missed
+        }
+        assert!((counter.rate() - 0.2).abs() < 1e-9);
+
+        // Older misses slide out of the window
+        for _ in 0..10 {
+            counter.record(true);
+        }
+        assert_eq!(counter.rate(), 0.0);
+    }
+}
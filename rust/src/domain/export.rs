@@ -1,12 +1,80 @@
 //! Export functionality for training session data.
 //!
 //! This module provides functions to export completed training sessions in various
-//! formats (CSV, JSON, text summary) for sharing and analysis. All functions are
-//! pure with no I/O dependencies.
+//! formats (CSV, JSON, text summary) for sharing and analysis. The `export_to_*`
+//! functions build an in-memory `String`; the `write_*` variants stream the same
+//! bytes into any `std::io::Write` so large sessions can go straight to disk.
+//!
+//! Exports depend only on the session unless a generation time is set: the
+//! `_with` variants take an [`ExportOptions`] whose `generated_at` is then
+//! recorded in the JSON and TCX output. The options also carry the UTC
+//! offset the human-facing formats (CSV, summary) render timestamps in; JSON
+//! always stays in UTC for interchange.
 
 use super::heart_rate::Zone;
 use super::session_history::CompletedSession;
 use super::training_plan::calculate_zone;
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// Options shared by the session exporters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportOptions {
+    /// Generation time recorded in JSON and TCX exports. `None` leaves it
+    /// out, so the output depends on the session alone.
+    pub generated_at: Option<DateTime<Utc>>,
+    /// Offset CSV and summary timestamps are rendered in. `None` uses UTC.
+    pub timezone: Option<FixedOffset>,
+}
+
+impl ExportOptions {
+    /// Record `generated_at` as the generation time.
+    pub fn with_generated_at(mut self, generated_at: DateTime<Utc>) -> Self {
+        self.generated_at = Some(generated_at);
+        self
+    }
+
+    /// Render CSV and summary timestamps at `timezone` instead of UTC.
+    pub fn with_timezone(mut self, timezone: FixedOffset) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// The offset to render local timestamps in (UTC unless set).
+    pub fn timezone(&self) -> FixedOffset {
+        self.timezone
+            .unwrap_or_else(|| FixedOffset::east_opt(0).expect("zero offset"))
+    }
+
+    /// `time` converted to the export timezone.
+    pub fn local_time(&self, time: DateTime<Utc>) -> DateTime<FixedOffset> {
+        time.with_timezone(&self.timezone())
+    }
+
+    /// The generation time to record: the pinned one, or now.
+    pub fn generated_at(&self) -> DateTime<Utc> {
+        self.generated_at.unwrap_or_else(Utc::now)
+    }
+
+    /// Options with the generation time resolved, so several exports made
+    /// from them record the same time.
+    pub fn pinned(&self) -> Self {
+        Self {
+            generated_at: Some(self.generated_at()),
+            ..self.clone()
+        }
+    }
+}
+
+/// JSON export document: the session plus the time it was exported, if set.
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    #[serde(flatten)]
+    session: &'a CompletedSession,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generated_at: Option<DateTime<Utc>>,
+}
 
 /// Export a completed session to CSV format.
 ///
@@ -33,23 +101,68 @@ use super::training_plan::calculate_zone;
 ///         avg_hr: 140,
 ///         max_hr: 160,
 ///         min_hr: 120,
+///         hr_p50: 140,
+///         hr_p90: 160,
+///         hr_p95: 160,
 ///         time_in_zone: [0, 300, 0, 0, 0],
+///         zone_hr_stats: [None; 5],
+///         data_completeness_pct: None,
+///         elapsed_wall_secs: None,
+///         active_duration_secs: None,
+///         trimp: None,
 ///     },
+///     context: None,
+///     phase_results: vec![],
+///     connection_summary: None,
 /// };
 ///
 /// let csv = export_to_csv(&session);
 /// assert!(csv.contains("timestamp,bpm,zone"));
 /// ```
 pub fn export_to_csv(session: &CompletedSession) -> String {
-    let mut csv = String::from("timestamp,bpm,zone\n");
+    export_to_csv_with(session, &ExportOptions::default())
+}
 
-    let max_hr = if session.summary.max_hr > 0 {
-        session.summary.max_hr
-    } else {
-        180 // Default fallback
-    };
+/// Export a completed session to CSV with timestamps in the options' timezone.
+pub fn export_to_csv_with(session: &CompletedSession, options: &ExportOptions) -> String {
+    let mut buf = Vec::new();
+    write_csv_with(session, options, &mut buf, |_| {}).expect("writing to a Vec cannot fail");
+    String::from_utf8(buf).expect("CSV export is valid UTF-8")
+}
 
-    for sample in &session.hr_samples {
+/// Stream a completed session as CSV into a writer.
+///
+/// Produces exactly the same bytes as [`export_to_csv`]. `on_sample` is called
+/// with the number of samples written so far after each row, which callers can
+/// use to report progress on very long sessions.
+///
+/// # Errors
+///
+/// Returns any I/O error raised by the writer.
+pub fn write_csv<W: Write>(
+    session: &CompletedSession,
+    writer: &mut W,
+    on_sample: impl FnMut(usize),
+) -> io::Result<()> {
+    write_csv_with(session, &ExportOptions::default(), writer, on_sample)
+}
+
+/// Stream a completed session as CSV with the given [`ExportOptions`].
+///
+/// # Errors
+///
+/// Returns any I/O error raised by the writer.
+pub fn write_csv_with<W: Write>(
+    session: &CompletedSession,
+    options: &ExportOptions,
+    writer: &mut W,
+    mut on_sample: impl FnMut(usize),
+) -> io::Result<()> {
+    writer.write_all(b"timestamp,bpm,zone\n")?;
+
+    let max_hr = zone_max_hr(session);
+
+    for (i, sample) in session.hr_samples.iter().enumerate() {
         let zone = calculate_zone(sample.bpm, max_hr).ok().flatten();
         let zone_str = match zone {
             Some(Zone::Zone1) => "Zone1",
@@ -60,21 +173,24 @@ pub fn export_to_csv(session: &CompletedSession) -> String {
             None => "Unknown",
         };
 
-        csv.push_str(&format!(
-            "{},{},{}\n",
-            sample.timestamp.to_rfc3339(),
+        writeln!(
+            writer,
+            "{},{},{}",
+            options.local_time(sample.timestamp).to_rfc3339(),
             sample.bpm,
             zone_str
-        ));
+        )?;
+        on_sample(i + 1);
     }
 
-    csv
+    Ok(())
 }
 
 /// Export a completed session to JSON format.
 ///
 /// Uses serde_json to serialize the CompletedSession structure with
-/// pretty printing for readability.
+/// pretty printing for readability. See [`export_to_json_with`] to also
+/// record the export time.
 ///
 /// # Examples
 ///
@@ -96,15 +212,163 @@ pub fn export_to_csv(session: &CompletedSession) -> String {
 ///         avg_hr: 140,
 ///         max_hr: 160,
 ///         min_hr: 120,
+///         hr_p50: 140,
+///         hr_p90: 160,
+///         hr_p95: 160,
 ///         time_in_zone: [0, 300, 0, 0, 0],
+///         zone_hr_stats: [None; 5],
+///         data_completeness_pct: None,
+///         elapsed_wall_secs: None,
+///         active_duration_secs: None,
+///         trimp: None,
 ///     },
+///     context: None,
+///     phase_results: vec![],
+///     connection_summary: None,
 /// };
 ///
 /// let json = export_to_json(&session);
 /// assert!(json.contains("\"plan_name\": \"Test Plan\""));
 /// ```
 pub fn export_to_json(session: &CompletedSession) -> String {
-    serde_json::to_string_pretty(session).unwrap_or_else(|_| "{}".to_string())
+    export_to_json_with(session, &ExportOptions::default())
+}
+
+/// Export a completed session to JSON with the given [`ExportOptions`].
+pub fn export_to_json_with(session: &CompletedSession, options: &ExportOptions) -> String {
+    let document = JsonExport {
+        session,
+        generated_at: options.generated_at,
+    };
+    serde_json::to_string_pretty(&document).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Stream a completed session as pretty-printed JSON into a writer.
+///
+/// Produces the same bytes as [`export_to_json`] without building the whole
+/// document in memory first.
+///
+/// # Errors
+///
+/// Returns any I/O error raised by the writer.
+pub fn write_json<W: Write>(session: &CompletedSession, writer: &mut W) -> io::Result<()> {
+    write_json_with(session, &ExportOptions::default(), writer)
+}
+
+/// Stream a completed session as JSON with the given [`ExportOptions`].
+///
+/// # Errors
+///
+/// Returns any I/O error raised by the writer.
+pub fn write_json_with<W: Write>(
+    session: &CompletedSession,
+    options: &ExportOptions,
+    writer: &mut W,
+) -> io::Result<()> {
+    let document = JsonExport {
+        session,
+        generated_at: options.generated_at,
+    };
+    serde_json::to_writer_pretty(writer, &document).map_err(io::Error::from)
+}
+
+/// Max HR the exporters classify a session's samples against.
+fn zone_max_hr(session: &CompletedSession) -> u16 {
+    if session.summary.max_hr > 0 {
+        session.summary.max_hr
+    } else {
+        180 // Default fallback
+    }
+}
+
+/// Replay export document: sample offsets for a playback animation.
+#[derive(Serialize)]
+struct ReplayExport {
+    start_ms: i64,
+    samples: Vec<ReplaySample>,
+}
+
+/// One replay frame, relative to the session start.
+#[derive(Serialize)]
+struct ReplaySample {
+    offset_ms: u64,
+    bpm: u16,
+    zone: Option<Zone>,
+}
+
+/// Export a completed session as a replay timing file for playback animation.
+///
+/// Emits JSON with `start_ms` (session start in UNIX epoch milliseconds) and a
+/// `samples` array of `{offset_ms, bpm, zone}`. Unlike [`export_to_json`],
+/// samples carry offsets from the start instead of absolute timestamps, and
+/// their zones are precomputed against `max_hr` (`null` when the BPM can't be
+/// classified). Pass the athlete's max HR, e.g. from the session's plan; `None`
+/// falls back to the same max HR as the CSV export. Offsets start at 0 and
+/// never decrease, even if samples were recorded slightly out of order.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::export::export_to_replay;
+/// use heart_beat::domain::session_history::{CompletedSession, SessionStatus, SessionSummary, HrSample};
+/// use chrono::Utc;
+///
+/// let start = Utc::now();
+/// let session = CompletedSession {
+///     id: "test".to_string(),
+///     plan_name: "Test Plan".to_string(),
+///     start_time: start,
+///     end_time: start,
+///     status: SessionStatus::Completed,
+///     hr_samples: vec![HrSample { timestamp: start, bpm: 140 }],
+///     phases_completed: 1,
+///     summary: SessionSummary {
+///         duration_secs: 300,
+///         avg_hr: 140,
+///         max_hr: 160,
+///         min_hr: 120,
+///         hr_p50: 140,
+///         hr_p90: 160,
+///         hr_p95: 160,
+///         time_in_zone: [0, 300, 0, 0, 0],
+///         zone_hr_stats: [None; 5],
+///         data_completeness_pct: None,
+///         elapsed_wall_secs: None,
+///         active_duration_secs: None,
+///         trimp: None,
+///     },
+///     context: None,
+///     phase_results: vec![],
+///     connection_summary: None,
+/// };
+///
+/// let replay = export_to_replay(&session, Some(190));
+/// assert!(replay.contains("\"offset_ms\": 0"));
+/// ```
+pub fn export_to_replay(session: &CompletedSession, max_hr: Option<u16>) -> String {
+    let max_hr = max_hr.unwrap_or_else(|| zone_max_hr(session));
+    let mut offset_ms = 0;
+    let samples = session
+        .hr_samples
+        .iter()
+        .map(|sample| {
+            let since_start = (sample.timestamp - session.start_time)
+                .num_milliseconds()
+                .max(0) as u64;
+            offset_ms = offset_ms.max(since_start);
+            ReplaySample {
+                offset_ms,
+                bpm: sample.bpm,
+                zone: calculate_zone(sample.bpm, max_hr).ok().flatten(),
+            }
+        })
+        .collect();
+
+    let document = ReplayExport {
+        start_ms: session.start_time.timestamp_millis(),
+        samples,
+    };
+    serde_json::to_string_pretty(&document).unwrap_or_else(|_| "{}".to_string())
 }
 
 /// Export a completed session to a human-readable text summary.
@@ -132,8 +396,19 @@ pub fn export_to_json(session: &CompletedSession) -> String {
 ///         avg_hr: 140,
 ///         max_hr: 160,
 ///         min_hr: 120,
+///         hr_p50: 140,
+///         hr_p90: 160,
+///         hr_p95: 160,
 ///         time_in_zone: [0, 300, 0, 0, 0],
+///         zone_hr_stats: [None; 5],
+///         data_completeness_pct: None,
+///         elapsed_wall_secs: None,
+///         active_duration_secs: None,
+///         trimp: None,
 ///     },
+///     context: None,
+///     phase_results: vec![],
+///     connection_summary: None,
 /// };
 ///
 /// let summary = export_to_summary(&session);
@@ -141,6 +416,11 @@ pub fn export_to_json(session: &CompletedSession) -> String {
 /// assert!(summary.contains("Test Plan"));
 /// ```
 pub fn export_to_summary(session: &CompletedSession) -> String {
+    export_to_summary_with(session, &ExportOptions::default())
+}
+
+/// Export a text summary with the session date in the options' timezone.
+pub fn export_to_summary_with(session: &CompletedSession, options: &ExportOptions) -> String {
     let mut summary = String::new();
 
     // Header
@@ -151,7 +431,9 @@ pub fn export_to_summary(session: &CompletedSession) -> String {
     summary.push_str(&format!("Plan: {}\n", session.plan_name));
     summary.push_str(&format!(
         "Date: {}\n",
-        session.start_time.format("%B %d, %Y at %H:%M")
+        options
+            .local_time(session.start_time)
+            .format("%B %d, %Y at %H:%M")
     ));
     summary.push_str(&format!(
         "Duration: {}:{:02}\n",
@@ -199,11 +481,36 @@ pub fn export_to_summary(session: &CompletedSession) -> String {
     summary
 }
 
+/// Write the human-readable text summary of a session into a writer.
+///
+/// The summary is small and independent of the sample count, so this simply
+/// writes the output of [`export_to_summary`].
+///
+/// # Errors
+///
+/// Returns any I/O error raised by the writer.
+pub fn write_summary<W: Write>(session: &CompletedSession, writer: &mut W) -> io::Result<()> {
+    write_summary_with(session, &ExportOptions::default(), writer)
+}
+
+/// Write the text summary of a session with the given [`ExportOptions`].
+///
+/// # Errors
+///
+/// Returns any I/O error raised by the writer.
+pub fn write_summary_with<W: Write>(
+    session: &CompletedSession,
+    options: &ExportOptions,
+    writer: &mut W,
+) -> io::Result<()> {
+    writer.write_all(export_to_summary_with(session, options).as_bytes())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::session_history::{HrSample, SessionStatus, SessionSummary};
-    use chrono::Utc;
+    use chrono::{TimeZone, Utc};
 
     fn create_test_session() -> CompletedSession {
         let start = Utc::now();
@@ -235,8 +542,19 @@ mod tests {
                 avg_hr: 140,
                 max_hr: 160,
                 min_hr: 120,
+                hr_p50: 140,
+                hr_p90: 160,
+                hr_p95: 160,
                 time_in_zone: [0, 100, 150, 50, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         }
     }
 
@@ -360,8 +678,19 @@ mod tests {
                 avg_hr: 0,
                 max_hr: 160,
                 min_hr: 0,
+                hr_p50: 0,
+                hr_p90: 160,
+                hr_p95: 160,
                 time_in_zone: [0, 0, 0, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         };
 
         let csv = export_to_csv(&session);
@@ -407,8 +736,19 @@ mod tests {
                 avg_hr: 126,
                 max_hr: 180,
                 min_hr: 90,
+                hr_p50: 126,
+                hr_p90: 180,
+                hr_p95: 180,
                 time_in_zone: [60, 60, 60, 60, 60],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         };
 
         let csv = export_to_csv(&session);
@@ -420,4 +760,120 @@ mod tests {
         assert!(csv.contains("Zone4"));
         assert!(csv.contains("Zone5"));
     }
+
+    #[test]
+    fn test_write_csv_matches_export_and_reports_progress() {
+        let session = create_test_session();
+        let mut buf = Vec::new();
+        let mut progress = Vec::new();
+
+        write_csv(&session, &mut buf, |n| progress.push(n)).unwrap();
+
+        assert_eq!(String::from_utf8(buf).unwrap(), export_to_csv(&session));
+        assert_eq!(progress, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_write_json_matches_export() {
+        let session = create_test_session();
+        let mut buf = Vec::new();
+
+        write_json(&session, &mut buf).unwrap();
+
+        assert_eq!(String::from_utf8(buf).unwrap(), export_to_json(&session));
+    }
+
+    #[test]
+    fn test_json_export_with_fixed_generated_at_is_reproducible() {
+        let session = create_test_session();
+        let generated_at = Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap();
+        let options = ExportOptions::default().with_generated_at(generated_at);
+
+        let first = export_to_json_with(&session, &options);
+        let second = export_to_json_with(&session, &options);
+        assert_eq!(first.as_bytes(), second.as_bytes());
+
+        let parsed: serde_json::Value = serde_json::from_str(&first).unwrap();
+        assert_eq!(parsed["generated_at"], "2025-01-02T03:04:05Z");
+        // The session itself still round-trips
+        let restored: CompletedSession = serde_json::from_str(&first).unwrap();
+        assert_eq!(restored, session);
+    }
+
+    #[test]
+    fn test_replay_offsets_start_at_zero_and_zones_match_max_hr() {
+        let mut session = create_test_session();
+        let start = session.start_time;
+        // A sample recorded slightly out of order must not move backwards
+        session.hr_samples.push(HrSample {
+            timestamp: start + chrono::Duration::milliseconds(119_500),
+            bpm: 150,
+        });
+
+        let replay: serde_json::Value =
+            serde_json::from_str(&export_to_replay(&session, None)).unwrap();
+
+        assert_eq!(replay["start_ms"], start.timestamp_millis());
+        let samples = replay["samples"].as_array().unwrap();
+        assert_eq!(samples.len(), 4);
+
+        let offsets: Vec<u64> = samples
+            .iter()
+            .map(|s| s["offset_ms"].as_u64().unwrap())
+            .collect();
+        assert_eq!(offsets, vec![0, 60_000, 120_000, 120_000]);
+
+        for (sample, expected) in samples.iter().zip(&session.hr_samples) {
+            assert_eq!(sample["bpm"], expected.bpm);
+            let zone = calculate_zone(expected.bpm, session.summary.max_hr)
+                .unwrap()
+                .unwrap();
+            assert_eq!(sample["zone"], serde_json::to_value(zone).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_replay_of_empty_session() {
+        let mut session = create_test_session();
+        session.hr_samples.clear();
+
+        let replay: serde_json::Value =
+            serde_json::from_str(&export_to_replay(&session, None)).unwrap();
+        assert_eq!(replay["samples"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_local_timezone_shifts_csv_and_summary_only() {
+        let mut session = create_test_session();
+        let start = Utc.with_ymd_and_hms(2025, 3, 1, 23, 30, 0).unwrap();
+        session.start_time = start;
+        session.hr_samples.truncate(1);
+        session.hr_samples[0].timestamp = start;
+
+        let tokyo = ExportOptions::default()
+            .pinned()
+            .with_timezone(FixedOffset::east_opt(9 * 3600).unwrap());
+        let new_york = tokyo
+            .clone()
+            .with_timezone(FixedOffset::west_opt(5 * 3600).unwrap());
+
+        let csv_tokyo = export_to_csv_with(&session, &tokyo);
+        let csv_new_york = export_to_csv_with(&session, &new_york);
+        assert!(csv_tokyo.contains("2025-03-02T08:30:00+09:00,120,"));
+        assert!(csv_new_york.contains("2025-03-01T18:30:00-05:00,120,"));
+
+        let summary_tokyo = export_to_summary_with(&session, &tokyo);
+        let summary_new_york = export_to_summary_with(&session, &new_york);
+        assert!(summary_tokyo.contains("Date: March 02, 2025 at 08:30"));
+        assert!(summary_new_york.contains("Date: March 01, 2025 at 18:30"));
+
+        // JSON stays in UTC regardless of the export timezone
+        assert_eq!(
+            export_to_json_with(&session, &tokyo),
+            export_to_json_with(&session, &new_york)
+        );
+
+        // Without a timezone the CSV is unchanged UTC output
+        assert!(export_to_csv(&session).contains("2025-03-01T23:30:00+00:00,120,"));
+    }
 }
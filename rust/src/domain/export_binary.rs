@@ -0,0 +1,616 @@
+//! Compact binary export of completed sessions for syncing.
+//!
+//! The layout is hand-written rather than derived from the serde types, so it
+//! stays stable when fields are added to [`CompletedSession`]. Every export
+//! starts with the [`BINARY_MAGIC`] bytes and a [`BINARY_FORMAT_VERSION`] byte;
+//! the layout of version 1 is (all integers little-endian):
+//!
+//! | Field            | Encoding                                                 |
+//! |------------------|----------------------------------------------------------|
+//! | id, plan_name    | `u32` byte length + UTF-8                                |
+//! | start/end time   | `i64` microseconds since the Unix epoch                  |
+//! | status           | `u8` (0 = Completed, 1 = Interrupted, 2 = Stopped)       |
+//! | phases_completed | `u32`                                                    |
+//! | summary          | `u32` duration, six `u16` HR stats, five `u32` zone times |
+//! | zone HR stats    | per zone: `u8` presence flag, then `u16` min/avg/max     |
+//! | hr_samples       | `u32` count, then per sample a zigzag varint of the      |
+//! |                  | microseconds since the previous sample (the first is     |
+//! |                  | relative to the start time) and a `u16` BPM              |
+//!
+//! Version 2 appends the fields added to sessions since, each optional value
+//! as a `u8` presence flag followed by the value:
+//!
+//! | Field            | Encoding                                                 |
+//! |------------------|----------------------------------------------------------|
+//! | context          | optional: optional `f64` temperature, `u8` RPE,          |
+//! |                  | optional string conditions                               |
+//! | data completeness| optional `f64` percentage                                |
+//! | elapsed/active   | optional `u32` wall-clock and active durations           |
+//! | trimp            | optional `f64` live TRIMP                                |
+//! | phase_results    | `u32` count, then per phase the name, `i64` start/end    |
+//! |                  | microseconds, `u16` avg/max/min HR and `u8` target met   |
+//! | connection       | optional: `u32` reconnects, `u32` dropout seconds,       |
+//! |                  | optional `i16` worst RSSI                                |
+//!
+//! Version 1 exports are still read; the fields they lack are left unset.
+//! Timestamps are kept to the microsecond; a 1 Hz sample takes 5 bytes.
+
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+
+use super::connection_health::ConnectionSummary;
+use super::session_history::{
+    CompletedSession, HrSample, PhaseResult, SessionContextMeta, SessionStatus, SessionSummary,
+    ZoneHrStat,
+};
+
+/// Bytes every binary export starts with.
+pub const BINARY_MAGIC: [u8; 3] = *b"HBS";
+
+/// Version of the layout written by [`export_to_binary`].
+pub const BINARY_FORMAT_VERSION: u8 = 2;
+
+/// Encode a completed session in the compact binary layout.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::export_binary::{export_to_binary, import_from_binary};
+/// use heart_beat::domain::session_history::{CompletedSession, SessionStatus, SessionSummary};
+/// use chrono::{TimeZone, Utc};
+///
+/// let start = Utc.with_ymd_and_hms(2024, 1, 1, 7, 0, 0).unwrap();
+/// let session = CompletedSession {
+///     id: "test".to_string(),
+///     plan_name: "Test Plan".to_string(),
+///     start_time: start,
+///     end_time: start,
+///     status: SessionStatus::Completed,
+///     hr_samples: vec![],
+///     phases_completed: 1,
+///     summary: SessionSummary::from_samples(&[], 0, [0; 5]),
+///     context: None,
+///     phase_results: vec![],
+///     connection_summary: None,
+/// };
+///
+/// let bytes = export_to_binary(&session);
+/// assert_eq!(import_from_binary(&bytes).unwrap(), session);
+/// ```
+pub fn export_to_binary(session: &CompletedSession) -> Vec<u8> {
+    encode(session, BINARY_FORMAT_VERSION)
+}
+
+/// Encode `session` in layout `version` (1 or 2).
+fn encode(session: &CompletedSession, version: u8) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(128 + session.hr_samples.len() * 5);
+    buf.extend_from_slice(&BINARY_MAGIC);
+    buf.push(version);
+
+    put_str(&mut buf, &session.id);
+    put_str(&mut buf, &session.plan_name);
+    buf.extend_from_slice(&session.start_time.timestamp_micros().to_le_bytes());
+    buf.extend_from_slice(&session.end_time.timestamp_micros().to_le_bytes());
+    buf.push(match session.status {
+        SessionStatus::Completed => 0,
+        SessionStatus::Interrupted => 1,
+        SessionStatus::Stopped => 2,
+    });
+    buf.extend_from_slice(&session.phases_completed.to_le_bytes());
+
+    let summary = &session.summary;
+    buf.extend_from_slice(&summary.duration_secs.to_le_bytes());
+    for value in [
+        summary.avg_hr,
+        summary.max_hr,
+        summary.min_hr,
+        summary.hr_p50,
+        summary.hr_p90,
+        summary.hr_p95,
+    ] {
+        buf.extend_from_slice(&value.to_le_bytes());
+    }
+    for secs in summary.time_in_zone {
+        buf.extend_from_slice(&secs.to_le_bytes());
+    }
+    for stat in summary.zone_hr_stats {
+        match stat {
+            Some(stat) => {
+                buf.push(1);
+                for value in [stat.min_bpm, stat.avg_bpm, stat.max_bpm] {
+                    buf.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            None => buf.push(0),
+        }
+    }
+
+    buf.extend_from_slice(&(session.hr_samples.len() as u32).to_le_bytes());
+    let mut previous = session.start_time.timestamp_micros();
+    for sample in &session.hr_samples {
+        let micros = sample.timestamp.timestamp_micros();
+        put_varint(&mut buf, zigzag(micros.wrapping_sub(previous)));
+        buf.extend_from_slice(&sample.bpm.to_le_bytes());
+        previous = micros;
+    }
+
+    if version >= 2 {
+        put_option(&mut buf, session.context.as_ref(), |buf, context| {
+            put_option(buf, context.temperature_c, |buf, temperature_c| {
+                buf.extend_from_slice(&temperature_c.to_le_bytes())
+            });
+            buf.push(context.rpe);
+            put_option(buf, context.conditions.as_deref(), put_str);
+        });
+        put_option(&mut buf, summary.data_completeness_pct, |buf, pct| {
+            buf.extend_from_slice(&pct.to_le_bytes())
+        });
+        for secs in [summary.elapsed_wall_secs, summary.active_duration_secs] {
+            put_option(&mut buf, secs, |buf, secs| {
+                buf.extend_from_slice(&secs.to_le_bytes())
+            });
+        }
+        put_option(&mut buf, summary.trimp, |buf, trimp| {
+            buf.extend_from_slice(&trimp.to_le_bytes())
+        });
+
+        buf.extend_from_slice(&(session.phase_results.len() as u32).to_le_bytes());
+        for result in &session.phase_results {
+            put_str(&mut buf, &result.phase_name);
+            buf.extend_from_slice(&result.start_time.timestamp_micros().to_le_bytes());
+            buf.extend_from_slice(&result.end_time.timestamp_micros().to_le_bytes());
+            for value in [result.avg_hr, result.max_hr, result.min_hr] {
+                buf.extend_from_slice(&value.to_le_bytes());
+            }
+            buf.push(result.target_met as u8);
+        }
+
+        put_option(&mut buf, session.connection_summary, |buf, connection| {
+            buf.extend_from_slice(&connection.reconnect_count.to_le_bytes());
+            buf.extend_from_slice(&connection.total_dropout_secs.to_le_bytes());
+            put_option(buf, connection.worst_rssi, |buf, rssi| {
+                buf.extend_from_slice(&rssi.to_le_bytes())
+            });
+        });
+    }
+
+    buf
+}
+
+/// Decode a session written by [`export_to_binary`].
+///
+/// # Errors
+///
+/// Returns an error if the bytes are not a binary export, were written with
+/// an unsupported layout version, or are truncated or malformed.
+pub fn import_from_binary(bytes: &[u8]) -> Result<CompletedSession> {
+    let mut reader = Reader { bytes, pos: 0 };
+
+    if reader.take(BINARY_MAGIC.len())? != BINARY_MAGIC {
+        bail!("Not a binary session export (missing header)");
+    }
+    let version = reader.u8()?;
+    if !(1..=BINARY_FORMAT_VERSION).contains(&version) {
+        bail!(
+            "Unsupported binary export version {} (expected 1 to {})",
+            version,
+            BINARY_FORMAT_VERSION
+        );
+    }
+
+    let id = reader.string()?;
+    let plan_name = reader.string()?;
+    let start_time = reader.timestamp()?;
+    let end_time = reader.timestamp()?;
+    let status = match reader.u8()? {
+        0 => SessionStatus::Completed,
+        1 => SessionStatus::Interrupted,
+        2 => SessionStatus::Stopped,
+        other => bail!("Invalid session status {} in binary export", other),
+    };
+    let phases_completed = reader.u32()?;
+
+    let duration_secs = reader.u32()?;
+    let mut hr = [0u16; 6];
+    for value in &mut hr {
+        *value = reader.u16()?;
+    }
+    let mut time_in_zone = [0u32; 5];
+    for secs in &mut time_in_zone {
+        *secs = reader.u32()?;
+    }
+    let mut zone_hr_stats = [None; 5];
+    for stat in &mut zone_hr_stats {
+        *stat = match reader.u8()? {
+            0 => None,
+            1 => Some(ZoneHrStat {
+                min_bpm: reader.u16()?,
+                avg_bpm: reader.u16()?,
+                max_bpm: reader.u16()?,
+            }),
+            other => bail!("Invalid zone statistics flag {} in binary export", other),
+        };
+    }
+    let [avg_hr, max_hr, min_hr, hr_p50, hr_p90, hr_p95] = hr;
+
+    let count = reader.u32()? as usize;
+    // Every sample takes at least 3 bytes, so a corrupt count cannot over-allocate
+    let mut hr_samples = Vec::with_capacity(count.min(reader.remaining() / 3));
+    let mut previous = start_time.timestamp_micros();
+    for _ in 0..count {
+        let micros = previous.wrapping_add(unzigzag(reader.varint()?));
+        let bpm = reader.u16()?;
+        hr_samples.push(HrSample {
+            timestamp: micros_to_datetime(micros)?,
+            bpm,
+        });
+        previous = micros;
+    }
+
+    let mut context = None;
+    let mut data_completeness_pct = None;
+    let mut elapsed_wall_secs = None;
+    let mut active_duration_secs = None;
+    let mut trimp = None;
+    let mut phase_results = Vec::new();
+    let mut connection_summary = None;
+    if version >= 2 {
+        context = reader.option(|reader| {
+            Ok(SessionContextMeta {
+                temperature_c: reader.option(Reader::f64)?,
+                rpe: reader.u8()?,
+                conditions: reader.option(Reader::string)?,
+            })
+        })?;
+        data_completeness_pct = reader.option(Reader::f64)?;
+        elapsed_wall_secs = reader.option(Reader::u32)?;
+        active_duration_secs = reader.option(Reader::u32)?;
+        trimp = reader.option(Reader::f64)?;
+
+        let count = reader.u32()? as usize;
+        // Every result takes at least 27 bytes
+        phase_results.reserve(count.min(reader.remaining() / 27));
+        for _ in 0..count {
+            phase_results.push(PhaseResult {
+                phase_name: reader.string()?,
+                start_time: reader.timestamp()?,
+                end_time: reader.timestamp()?,
+                avg_hr: reader.u16()?,
+                max_hr: reader.u16()?,
+                min_hr: reader.u16()?,
+                target_met: reader.u8()? != 0,
+            });
+        }
+
+        connection_summary = reader.option(|reader| {
+            Ok(ConnectionSummary {
+                reconnect_count: reader.u32()?,
+                total_dropout_secs: reader.u32()?,
+                worst_rssi: reader.option(Reader::i16)?,
+            })
+        })?;
+    }
+
+    if reader.remaining() > 0 {
+        bail!(
+            "Binary export has {} unexpected trailing bytes",
+            reader.remaining()
+        );
+    }
+
+    Ok(CompletedSession {
+        id,
+        plan_name,
+        start_time,
+        end_time,
+        status,
+        hr_samples,
+        phases_completed,
+        summary: SessionSummary {
+            duration_secs,
+            avg_hr,
+            max_hr,
+            min_hr,
+            hr_p50,
+            hr_p90,
+            hr_p95,
+            time_in_zone,
+            zone_hr_stats,
+            data_completeness_pct,
+            elapsed_wall_secs,
+            active_duration_secs,
+            trimp,
+        },
+        context,
+        phase_results,
+        connection_summary,
+    })
+}
+
+/// Append a length-prefixed UTF-8 string.
+fn put_str(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buf.extend_from_slice(value.as_bytes());
+}
+
+/// Append a `u8` presence flag, followed by the value if present.
+fn put_option<T>(buf: &mut Vec<u8>, value: Option<T>, put: impl FnOnce(&mut Vec<u8>, T)) {
+    buf.push(value.is_some() as u8);
+    if let Some(value) = value {
+        put(buf, value);
+    }
+}
+
+/// Append an unsigned LEB128 varint.
+fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+/// Map a signed value to an unsigned one so small magnitudes stay small.
+fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// Inverse of [`zigzag`].
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// Convert microseconds since the Unix epoch to a timestamp.
+fn micros_to_datetime(micros: i64) -> Result<DateTime<Utc>> {
+    DateTime::from_timestamp_micros(micros)
+        .ok_or_else(|| anyhow!("Timestamp out of range in binary export"))
+}
+
+/// Cursor over the bytes of a binary export.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.pos
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.remaining() {
+            bail!("Binary export is truncated");
+        }
+        let slice = &self.bytes[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        Ok(i16::from_le_bytes(self.array()?))
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_le_bytes(self.array()?))
+    }
+
+    /// Read a value written by [`put_option`].
+    fn option<T>(&mut self, read: impl FnOnce(&mut Self) -> Result<T>) -> Result<Option<T>> {
+        match self.u8()? {
+            0 => Ok(None),
+            1 => read(self).map(Some),
+            other => bail!("Invalid presence flag {} in binary export", other),
+        }
+    }
+
+    fn timestamp(&mut self) -> Result<DateTime<Utc>> {
+        micros_to_datetime(i64::from_le_bytes(self.array()?))
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let len = self.u32()? as usize;
+        String::from_utf8(self.take(len)?.to_vec())
+            .map_err(|_| anyhow!("Invalid UTF-8 string in binary export"))
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        bail!("Invalid varint in binary export")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::export::export_to_json;
+    use chrono::{Duration, TimeZone};
+
+    fn create_test_session() -> CompletedSession {
+        let start =
+            Utc.with_ymd_and_hms(2024, 3, 10, 6, 30, 0).unwrap() + Duration::microseconds(123_456);
+        // Irregular spacing, including a backwards step
+        let offsets_ms = [0i64, 1000, 1980, 3015, 2990, 9000];
+        let hr_samples = offsets_ms
+            .iter()
+            .enumerate()
+            .map(|(i, &ms)| HrSample {
+                timestamp: start + Duration::milliseconds(ms),
+                bpm: 118 + i as u16 * 7,
+            })
+            .collect();
+
+        CompletedSession {
+            id: "0190f0b2-session".to_string(),
+            plan_name: "Tempo – 30 min".to_string(),
+            start_time: start,
+            end_time: start + Duration::seconds(1800),
+            status: SessionStatus::Stopped,
+            hr_samples,
+            phases_completed: 2,
+            summary: SessionSummary {
+                duration_secs: 1800,
+                avg_hr: 135,
+                max_hr: 153,
+                min_hr: 118,
+                hr_p50: 132,
+                hr_p90: 146,
+                hr_p95: 153,
+                time_in_zone: [0, 600, 1200, 0, 0],
+                zone_hr_stats: [
+                    None,
+                    Some(ZoneHrStat {
+                        min_bpm: 118,
+                        avg_bpm: 128,
+                        max_bpm: 139,
+                    }),
+                    Some(ZoneHrStat {
+                        min_bpm: 146,
+                        avg_bpm: 150,
+                        max_bpm: 153,
+                    }),
+                    None,
+                    None,
+                ],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
+            },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
+        }
+    }
+
+    /// [`create_test_session`] with every optional field set.
+    fn create_full_session() -> CompletedSession {
+        let mut session = create_test_session();
+        session.context = Some(SessionContextMeta {
+            temperature_c: Some(28.5),
+            rpe: 6,
+            conditions: Some("humid".to_string()),
+        });
+        session.summary.data_completeness_pct = Some(92.5);
+        session.summary.elapsed_wall_secs = Some(1900);
+        session.summary.active_duration_secs = Some(1800);
+        session.summary.trimp = Some(64.25);
+        session.phase_results = vec![
+            PhaseResult {
+                phase_name: "Warmup".to_string(),
+                start_time: session.start_time,
+                end_time: session.start_time + Duration::seconds(600),
+                avg_hr: 128,
+                max_hr: 139,
+                min_hr: 118,
+                target_met: true,
+            },
+            PhaseResult {
+                phase_name: "Tempo".to_string(),
+                start_time: session.start_time + Duration::seconds(600),
+                end_time: session.start_time + Duration::seconds(1800),
+                avg_hr: 150,
+                max_hr: 153,
+                min_hr: 146,
+                target_met: false,
+            },
+        ];
+        session.connection_summary = Some(ConnectionSummary {
+            reconnect_count: 2,
+            total_dropout_secs: 14,
+            worst_rssi: Some(-88),
+        });
+        session
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let session = create_test_session();
+        let bytes = export_to_binary(&session);
+
+        assert_eq!(&bytes[..3], &BINARY_MAGIC);
+        assert_eq!(bytes[3], BINARY_FORMAT_VERSION);
+        assert_eq!(import_from_binary(&bytes).unwrap(), session);
+        assert!(bytes.len() < export_to_json(&session).len() / 4);
+    }
+
+    #[test]
+    fn test_binary_version_mismatch_is_rejected() {
+        let mut bytes = export_to_binary(&create_test_session());
+        bytes[3] = BINARY_FORMAT_VERSION + 1;
+
+        let err = import_from_binary(&bytes).unwrap_err().to_string();
+        assert_eq!(err, "Unsupported binary export version 3 (expected 1 to 2)");
+    }
+
+    #[test]
+    fn test_binary_round_trip_keeps_every_field() {
+        let session = create_full_session();
+        let bytes = export_to_binary(&session);
+
+        assert_eq!(import_from_binary(&bytes).unwrap(), session);
+    }
+
+    #[test]
+    fn test_binary_v1_is_still_read() {
+        let session = create_full_session();
+        let v1 = encode(&session, 1);
+        assert_eq!(v1[3], 1);
+
+        // Fields added in layout v2 are left unset
+        let imported = import_from_binary(&v1).unwrap();
+        assert_eq!(imported.hr_samples, session.hr_samples);
+        assert_eq!(imported.summary.time_in_zone, session.summary.time_in_zone);
+        assert_eq!(imported.context, None);
+        assert_eq!(imported.summary.data_completeness_pct, None);
+        assert_eq!(imported.summary.elapsed_wall_secs, None);
+        assert_eq!(imported.summary.active_duration_secs, None);
+        assert!(imported.phase_results.is_empty());
+        assert_eq!(imported.connection_summary, None);
+    }
+
+    #[test]
+    fn test_binary_malformed_input_is_rejected() {
+        let bytes = export_to_binary(&create_test_session());
+
+        assert!(import_from_binary(b"{\"id\":1}")
+            .unwrap_err()
+            .to_string()
+            .contains("header"));
+        assert!(import_from_binary(&bytes[..bytes.len() - 1])
+            .unwrap_err()
+            .to_string()
+            .contains("truncated"));
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(import_from_binary(&trailing)
+            .unwrap_err()
+            .to_string()
+            .contains("trailing"));
+    }
+}
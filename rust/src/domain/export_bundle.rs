@@ -0,0 +1,181 @@
+//! Zip bundle holding a session in every shareable export format.
+//!
+//! Sharing a workout as one file is easier than sending several, so the
+//! bundle packs the CSV, JSON, text summary and TCX exports into a single zip
+//! archive. Entry names are fixed (see [`BUNDLE_ENTRIES`]) so other tools can
+//! pick out the format they need. The archive is built in memory; no I/O is
+//! performed.
+//!
+//! Every entry records the same generation time, which is also used as the
+//! entries' modification time, so a pinned [`ExportOptions::generated_at`]
+//! yields a byte-identical archive.
+
+use std::io::{Cursor, Write};
+
+use anyhow::{Context, Result};
+use chrono::{Datelike, Timelike};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use super::export::{
+    export_to_csv_with, export_to_json_with, export_to_summary_with, ExportOptions,
+};
+use super::export_formats::export_to_tcx_with;
+use super::session_history::CompletedSession;
+
+/// Names of the entries in a session bundle, in archive order.
+pub const BUNDLE_ENTRIES: [&str; 4] = ["session.csv", "session.json", "summary.txt", "session.tcx"];
+
+/// Build a zip archive with the session as CSV, JSON, text summary and TCX.
+///
+/// # Errors
+///
+/// Returns an error if the archive cannot be encoded.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::export_bundle::export_to_bundle;
+/// use heart_beat::domain::session_history::{CompletedSession, SessionStatus, SessionSummary};
+/// use chrono::Utc;
+///
+/// let now = Utc::now();
+/// let session = CompletedSession {
+///     id: "test".to_string(),
+///     plan_name: "Test Plan".to_string(),
+///     start_time: now,
+///     end_time: now,
+///     status: SessionStatus::Completed,
+///     hr_samples: vec![],
+///     phases_completed: 1,
+///     summary: SessionSummary::from_samples(&[], 0, [0; 5]),
+///     context: None,
+///     phase_results: vec![],
+///     connection_summary: None,
+/// };
+///
+/// let zip = export_to_bundle(&session).unwrap();
+/// assert!(zip.starts_with(b"PK"));
+/// ```
+pub fn export_to_bundle(session: &CompletedSession) -> Result<Vec<u8>> {
+    export_to_bundle_with(session, &ExportOptions::default())
+}
+
+/// Build a session bundle with the given [`ExportOptions`].
+///
+/// # Errors
+///
+/// Returns an error if the archive cannot be encoded.
+pub fn export_to_bundle_with(
+    session: &CompletedSession,
+    options: &ExportOptions,
+) -> Result<Vec<u8>> {
+    let options = options.pinned();
+    let contents = [
+        export_to_csv_with(session, &options),
+        export_to_json_with(session, &options),
+        export_to_summary_with(session, &options),
+        export_to_tcx_with(session, &options),
+    ];
+
+    let generated_at = options.generated_at();
+    let mut file_options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    // Zip timestamps only cover 1980-2107; outside that the default is kept
+    if let Ok(modified) = zip::DateTime::from_date_and_time(
+        generated_at.year() as u16,
+        generated_at.month() as u8,
+        generated_at.day() as u8,
+        generated_at.hour() as u8,
+        generated_at.minute() as u8,
+        generated_at.second() as u8,
+    ) {
+        file_options = file_options.last_modified_time(modified);
+    }
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, content) in BUNDLE_ENTRIES.iter().zip(contents) {
+        zip.start_file(*name, file_options)
+            .with_context(|| format!("Failed to add {} to bundle", name))?;
+        zip.write_all(content.as_bytes())
+            .with_context(|| format!("Failed to write {} to bundle", name))?;
+    }
+
+    let cursor = zip.finish().context("Failed to finish session bundle")?;
+    Ok(cursor.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::export::export_to_csv;
+    use crate::domain::session_history::{HrSample, SessionStatus, SessionSummary};
+    use chrono::{TimeZone, Utc};
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    fn make_session() -> CompletedSession {
+        let start = Utc.with_ymd_and_hms(2025, 6, 15, 10, 0, 0).unwrap();
+        let samples: Vec<HrSample> = (0..60)
+            .map(|i| HrSample {
+                timestamp: start + chrono::Duration::seconds(i),
+                bpm: 120 + (i % 20) as u16,
+            })
+            .collect();
+        let summary = SessionSummary::from_samples(&samples, 60, [0, 60, 0, 0, 0]);
+
+        CompletedSession {
+            id: "bundle-001".into(),
+            plan_name: "Easy Run".into(),
+            start_time: start,
+            end_time: start + chrono::Duration::seconds(60),
+            status: SessionStatus::Completed,
+            hr_samples: samples,
+            phases_completed: 1,
+            summary,
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
+        }
+    }
+
+    #[test]
+    fn test_bundle_contains_all_formats() {
+        let session = make_session();
+        let options = ExportOptions::default().pinned();
+        let bytes = export_to_bundle_with(&session, &options).unwrap();
+        let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+
+        let names: Vec<&str> = archive.file_names().collect();
+        assert_eq!(names.len(), 4);
+        for name in BUNDLE_ENTRIES {
+            assert!(names.contains(&name), "missing {}", name);
+        }
+
+        let mut csv = String::new();
+        archive
+            .by_name("session.csv")
+            .unwrap()
+            .read_to_string(&mut csv)
+            .unwrap();
+        assert_eq!(csv, export_to_csv(&session));
+
+        let mut tcx = String::new();
+        archive
+            .by_name("session.tcx")
+            .unwrap()
+            .read_to_string(&mut tcx)
+            .unwrap();
+        assert_eq!(tcx, export_to_tcx_with(&session, &options));
+    }
+
+    #[test]
+    fn test_bundle_with_fixed_generated_at_is_reproducible() {
+        let session = make_session();
+        let generated_at = Utc.with_ymd_and_hms(2025, 6, 16, 8, 0, 0).unwrap();
+        let options = ExportOptions::default().with_generated_at(generated_at);
+
+        let first = export_to_bundle_with(&session, &options).unwrap();
+        let second = export_to_bundle_with(&session, &options).unwrap();
+        assert_eq!(first, second);
+    }
+}
@@ -6,6 +6,7 @@
 
 use chrono::SecondsFormat;
 
+use crate::domain::export::ExportOptions;
 use crate::domain::session_history::CompletedSession;
 
 // ---------------------------------------------------------------------------
@@ -84,8 +85,14 @@ fn write_tcx_lap(session: &CompletedSession) -> String {
 /// Export a completed session as a Garmin-compatible TCX XML string.
 ///
 /// The output conforms to the TrainingCenterDatabase v2 schema and can be
-/// imported into Garmin Connect, Strava, and other fitness platforms.
+/// imported into Garmin Connect, Strava, and other fitness platforms. See
+/// [`export_to_tcx_with`] to also record the export time in a comment.
 pub fn export_to_tcx(session: &CompletedSession) -> String {
+    export_to_tcx_with(session, &ExportOptions::default())
+}
+
+/// Export a completed session as TCX with the given [`ExportOptions`].
+pub fn export_to_tcx_with(session: &CompletedSession, options: &ExportOptions) -> String {
     let start_iso = session
         .start_time
         .to_rfc3339_opts(SecondsFormat::Secs, true);
@@ -93,6 +100,12 @@ pub fn export_to_tcx(session: &CompletedSession) -> String {
 
     let mut xml = String::new();
     xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    if let Some(generated_at) = options.generated_at {
+        let generated_iso = generated_at.to_rfc3339_opts(SecondsFormat::Secs, true);
+        xml.push_str(&format!(
+            "<!-- Generated by HeartBeat at {generated_iso} -->\n"
+        ));
+    }
     xml.push_str("<TrainingCenterDatabase xmlns=\"http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2\">\n");
     xml.push_str("  <Activities>\n");
     xml.push_str("    <Activity Sport=\"Other\">\n");
@@ -194,8 +207,19 @@ mod tests {
                 avg_hr: avg,
                 max_hr: max,
                 min_hr: min,
+                hr_p50: avg,
+                hr_p90: max,
+                hr_p95: max,
                 time_in_zone: [60, 60, 60, 60, 60],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         }
     }
 
@@ -300,6 +324,20 @@ mod tests {
         assert!(tcx.contains("<Notes>Tempo</Notes>"));
     }
 
+    #[test]
+    fn tcx_with_fixed_generated_at_is_reproducible() {
+        let session = make_session("Tempo", normal_samples());
+        let generated_at = Utc.with_ymd_and_hms(2025, 6, 16, 8, 0, 0).unwrap();
+        let options = ExportOptions::default().with_generated_at(generated_at);
+
+        let first = export_to_tcx_with(&session, &options);
+        assert_eq!(
+            first.as_bytes(),
+            export_to_tcx_with(&session, &options).as_bytes()
+        );
+        assert!(first.contains("<!-- Generated by HeartBeat at 2025-06-16T08:00:00Z -->"));
+    }
+
     // -----------------------------------------------------------------------
     // TCX – empty samples
     // -----------------------------------------------------------------------
@@ -3,13 +3,32 @@
 //! This module provides filtering capabilities to reduce noise in heart rate measurements
 //! using Kalman filtering techniques.
 
+use crate::domain::heart_rate::BpmBounds;
 use kalman_filters::{KalmanFilter as KF, KalmanFilterBuilder};
 
-/// Minimum physiologically plausible heart rate in BPM.
-const MIN_VALID_BPM: u16 = 30;
+/// Initial state estimate: a typical resting heart rate in BPM.
+const INITIAL_ESTIMATE_BPM: f64 = 70.0;
 
-/// Maximum physiologically plausible heart rate in BPM.
-const MAX_VALID_BPM: u16 = 220;
+/// Initial variance of the state estimate in BPM².
+const INITIAL_VARIANCE: f64 = 10.0;
+
+/// Measurement noise multiplier for a quality 0 reading.
+///
+/// A reading of quality `q` uses `1 + (LOW_QUALITY_NOISE_FACTOR - 1) * (1 - q)`
+/// times the filter's measurement noise, so quality 1 keeps the configured noise.
+const LOW_QUALITY_NOISE_FACTOR: f64 = 4.0;
+
+/// Quality assigned to a reading that carries no RR-intervals.
+pub const NO_RR_QUALITY: f64 = 0.5;
+
+/// Coefficient of variation of RR-intervals treated as fully erratic (quality 0).
+const ERRATIC_RR_CV: f64 = 0.2;
+
+/// Fastest plausible heart rate change in BPM per second.
+///
+/// Even at the onset of a sprint HR rises by a few BPM per second; larger
+/// jumps between consecutive readings are sensor artifacts.
+pub const DEFAULT_MAX_SLOPE_BPM_PER_SEC: f64 = 25.0;
 
 /// Checks if a heart rate value is physiologically plausible.
 ///
@@ -40,7 +59,44 @@ const MAX_VALID_BPM: u16 = 220;
 /// assert!(!is_valid_bpm(20));  // Too low
 /// ```
 pub fn is_valid_bpm(bpm: u16) -> bool {
-    (MIN_VALID_BPM..=MAX_VALID_BPM).contains(&bpm)
+    BpmBounds::PHYSIOLOGICAL.contains(bpm)
+}
+
+/// Estimates how trustworthy a heart rate reading is from its RR-intervals.
+///
+/// Returns a quality between 0 (noisy) and 1 (trustworthy) for use with
+/// [`KalmanFilter::update_with_quality`]. Readings without RR-intervals get
+/// [`NO_RR_QUALITY`]. When intervals are present, quality falls linearly with
+/// their coefficient of variation, reaching 0 at 20% (erratic beats or
+/// artifacts); a single interval counts as consistent.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::filters::{rr_quality, NO_RR_QUALITY};
+///
+/// assert_eq!(rr_quality(&[]), NO_RR_QUALITY);
+/// assert_eq!(rr_quality(&[800, 800, 800]), 1.0);
+/// assert!(rr_quality(&[500, 1100]) < NO_RR_QUALITY);
+/// ```
+pub fn rr_quality(rr_intervals: &[u16]) -> f64 {
+    if rr_intervals.is_empty() {
+        return NO_RR_QUALITY;
+    }
+
+    let n = rr_intervals.len() as f64;
+    let mean = rr_intervals.iter().map(|&rr| rr as f64).sum::<f64>() / n;
+    if mean <= 0.0 {
+        return 0.0;
+    }
+    let variance = rr_intervals
+        .iter()
+        .map(|&rr| (rr as f64 - mean).powi(2))
+        .sum::<f64>()
+        / n;
+    let cv = variance.sqrt() / mean;
+
+    (1.0 - cv / ERRATIC_RR_CV).clamp(0.0, 1.0)
 }
 
 /// A Kalman filter wrapper configured for heart rate tracking.
@@ -60,6 +116,15 @@ pub fn is_valid_bpm(bpm: u16) -> bool {
 /// ```
 pub struct KalmanFilter {
     kalman: KF<f64>,
+    /// Kalman gain applied by the last update.
+    last_gain: Option<f64>,
+    /// Measurement minus predicted estimate at the last update.
+    last_innovation: Option<f64>,
+    /// Largest accepted change in BPM per second; `None` accepts any change.
+    max_slope: Option<f64>,
+    /// Last measurement accepted by [`update_at`](Self::update_at) and its
+    /// timestamp in milliseconds.
+    last_accepted: Option<(f64, u64)>,
 }
 
 impl KalmanFilter {
@@ -78,8 +143,8 @@ impl KalmanFilter {
     ///   This reflects the expected measurement uncertainty.
     pub fn new(process_noise: f64, measurement_noise: f64) -> Self {
         let kalman = KalmanFilterBuilder::new(1, 1)
-            .initial_state(vec![70.0]) // Initial guess: typical resting HR
-            .initial_covariance(vec![10.0]) // Initial uncertainty
+            .initial_state(vec![INITIAL_ESTIMATE_BPM]) // Initial guess: typical resting HR
+            .initial_covariance(vec![INITIAL_VARIANCE]) // Initial uncertainty
             .transition_matrix(vec![1.0]) // State doesn't change without input
             .process_noise(vec![process_noise])
             .observation_matrix(vec![1.0]) // Directly observe the state
@@ -87,7 +152,24 @@ impl KalmanFilter {
             .build()
             .expect("Failed to build Kalman filter with valid 1D parameters");
 
-        Self { kalman }
+        Self {
+            kalman,
+            last_gain: None,
+            last_innovation: None,
+            max_slope: None,
+            last_accepted: None,
+        }
+    }
+
+    /// Rejects measurements in [`update_at`](Self::update_at) that differ
+    /// from the last accepted one by more than `max_bpm_per_sec` per second
+    /// elapsed (e.g. [`DEFAULT_MAX_SLOPE_BPM_PER_SEC`]).
+    ///
+    /// The allowed change grows with the time since the last accepted
+    /// measurement, so the filter recovers after a dropout or a real jump.
+    pub fn with_max_slope(mut self, max_bpm_per_sec: f64) -> Self {
+        self.max_slope = Some(max_bpm_per_sec.max(0.0));
+        self
     }
 
     /// Updates the filter with a new heart rate measurement and returns the filtered value.
@@ -114,6 +196,11 @@ impl KalmanFilter {
         // Predict step (no control input)
         self.kalman.predict();
 
+        // Record diagnostics from the predicted state (1D: H = 1)
+        let predicted_variance = self.kalman.covariance()[0];
+        self.last_gain = Some(predicted_variance / (predicted_variance + self.kalman.R[0]));
+        self.last_innovation = Some(measurement - self.kalman.state()[0]);
+
         // Update step with measurement
         self.kalman
             .update(&[measurement])
@@ -123,6 +210,79 @@ impl KalmanFilter {
         self.kalman.state()[0]
     }
 
+    /// Updates the filter with a measurement weighted by its quality.
+    ///
+    /// `quality` ranges from 0 (noisy) to 1 (trustworthy), e.g. from
+    /// [`rr_quality`]; values outside that range are clamped. Lower quality
+    /// raises the measurement noise for this update only (up to 4x at quality
+    /// 0), so the reading moves the estimate less and the variance shrinks
+    /// less. Quality 1 behaves like [`update`](Self::update).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::filters::KalmanFilter;
+    ///
+    /// let mut trusted = KalmanFilter::default();
+    /// let mut noisy = KalmanFilter::default();
+    /// trusted.update_with_quality(120.0, 1.0);
+    /// noisy.update_with_quality(120.0, 0.0);
+    /// assert!(trusted.variance() < noisy.variance());
+    /// ```
+    pub fn update_with_quality(&mut self, measurement: f64, quality: f64) -> f64 {
+        let quality = if quality.is_nan() {
+            0.0
+        } else {
+            quality.clamp(0.0, 1.0)
+        };
+        let base_noise = self.kalman.R[0];
+        self.kalman.R[0] = base_noise * (1.0 + (LOW_QUALITY_NOISE_FACTOR - 1.0) * (1.0 - quality));
+        let estimate = self.update(measurement);
+        self.kalman.R[0] = base_noise;
+        estimate
+    }
+
+    /// Updates the filter with a measurement taken at `timestamp_ms`, unless
+    /// it changes faster than the max slope (see [`with_max_slope`](Self::with_max_slope)).
+    ///
+    /// Accepted measurements are weighted by `quality` as in
+    /// [`update_with_quality`](Self::update_with_quality). A rejected
+    /// measurement leaves the filter unchanged and returns the current
+    /// estimate. Gaps under a second count as one second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::filters::KalmanFilter;
+    ///
+    /// let mut filter = KalmanFilter::default().with_max_slope(25.0);
+    /// filter.update_at(60.0, 1.0, 0);
+    /// let before = filter.estimate();
+    ///
+    /// // 60 -> 200 BPM within a second is an artifact
+    /// assert_eq!(filter.update_at(200.0, 1.0, 1_000), before);
+    /// ```
+    pub fn update_at(&mut self, measurement: f64, quality: f64, timestamp_ms: u64) -> f64 {
+        if !self.is_plausible_change(measurement, timestamp_ms) {
+            return self.estimate();
+        }
+        self.last_accepted = Some((measurement, timestamp_ms));
+        self.update_with_quality(measurement, quality)
+    }
+
+    /// Whether `measurement` at `timestamp_ms` is within the max slope of the
+    /// last measurement accepted by [`update_at`](Self::update_at).
+    ///
+    /// Always `true` without a max slope or before the first accepted
+    /// measurement.
+    pub fn is_plausible_change(&self, measurement: f64, timestamp_ms: u64) -> bool {
+        let (Some(max_slope), Some((last, last_ms))) = (self.max_slope, self.last_accepted) else {
+            return true;
+        };
+        let elapsed_secs = (timestamp_ms.saturating_sub(last_ms) as f64 / 1000.0).max(1.0);
+        (measurement - last).abs() <= max_slope * elapsed_secs
+    }
+
     /// Updates the filter only if the measurement is physiologically valid.
     ///
     /// If the measurement is invalid (outside 30-220 BPM range), the filter state
@@ -155,9 +315,15 @@ impl KalmanFilter {
     /// assert_eq!(filtered2, filtered3); // State unchanged
     /// ```
     pub fn filter_if_valid(&mut self, measurement: f64) -> f64 {
+        self.filter_if_within(measurement, BpmBounds::PHYSIOLOGICAL)
+    }
+
+    /// Like [`filter_if_valid`](Self::filter_if_valid), but accepts any
+    /// measurement within `bounds` instead of the physiological range.
+    pub fn filter_if_within(&mut self, measurement: f64, bounds: BpmBounds) -> f64 {
         let bpm = measurement.round() as u16;
 
-        if is_valid_bpm(bpm) {
+        if bounds.contains(bpm) {
             // Measurement is valid, update the filter
             self.update(measurement)
         } else {
@@ -204,6 +370,46 @@ impl KalmanFilter {
         // Variance is the first (and only) element of the covariance matrix
         self.kalman.covariance()[0]
     }
+
+    /// Returns the current filtered heart rate estimate in BPM.
+    ///
+    /// Before the first update this is the initial guess of 70 BPM.
+    pub fn estimate(&self) -> f64 {
+        self.kalman.state()[0]
+    }
+
+    /// Returns the Kalman gain applied by the most recent update.
+    ///
+    /// The gain (0-1) is the weight given to the new measurement versus the
+    /// prediction. It starts high while the filter is uncertain and falls as
+    /// the filter converges. Before the first update, this is the gain the
+    /// first measurement will receive.
+    pub fn gain(&self) -> f64 {
+        self.last_gain.unwrap_or_else(|| {
+            let predicted_variance = self.kalman.P[0] + self.kalman.Q[0];
+            predicted_variance / (predicted_variance + self.kalman.R[0])
+        })
+    }
+
+    /// Returns the innovation of the most recent update, in BPM.
+    ///
+    /// The innovation is the measurement minus the predicted estimate, i.e.
+    /// how surprising the reading was. `None` until the first update.
+    pub fn innovation(&self) -> Option<f64> {
+        self.last_innovation
+    }
+
+    /// Resets the filter to its initial estimate and variance.
+    ///
+    /// Keeps the noise parameters, so one filter can be reused across
+    /// sessions instead of creating a new one per connection.
+    pub fn reset(&mut self) {
+        self.kalman.x[0] = INITIAL_ESTIMATE_BPM;
+        self.kalman.P[0] = INITIAL_VARIANCE;
+        self.last_gain = None;
+        self.last_innovation = None;
+        self.last_accepted = None;
+    }
 }
 
 impl Default for KalmanFilter {
@@ -241,6 +447,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reset_restores_initial_state() {
+        let mut filter = KalmanFilter::default();
+        let initial_gain = filter.gain();
+        assert_eq!(filter.estimate(), 70.0);
+        assert_eq!(filter.innovation(), None);
+
+        for _ in 0..20 {
+            filter.update(150.0);
+        }
+        assert!(filter.estimate() > 140.0);
+        assert!(filter.variance() < 10.0);
+        assert!(filter.innovation().is_some());
+
+        filter.reset();
+        assert_eq!(filter.estimate(), 70.0);
+        assert_eq!(filter.variance(), 10.0);
+        assert_eq!(filter.innovation(), None);
+        assert_eq!(filter.gain(), initial_gain);
+
+        // The reset filter behaves like a fresh one
+        let mut fresh = KalmanFilter::default();
+        assert_eq!(filter.update(80.0), fresh.update(80.0));
+    }
+
+    #[test]
+    fn test_gain_decreases_while_converging() {
+        let mut filter = KalmanFilter::default();
+
+        let first_gain = filter.gain();
+        filter.update(75.0);
+        assert_eq!(filter.gain(), first_gain);
+
+        let mut gains = vec![first_gain];
+        for _ in 0..10 {
+            filter.update(75.0);
+            gains.push(filter.gain());
+        }
+
+        assert!(
+            gains.windows(2).all(|w| w[1] < w[0]),
+            "Gain should fall on a steady signal: {:?}",
+            gains
+        );
+        // The first update sees the full gap between the guess and the signal
+        assert_eq!(
+            {
+                let mut f = KalmanFilter::default();
+                f.update(75.0);
+                f.innovation()
+            },
+            Some(5.0)
+        );
+        assert!(filter.innovation().unwrap().abs() < 0.5);
+    }
+
     #[test]
     fn test_filter_tracks_step_change() {
         let mut filter = KalmanFilter::default();
@@ -502,4 +764,62 @@ mod tests {
         );
         assert!(low_variance > 0.0, "Converged variance should be positive");
     }
+
+    #[test]
+    fn test_quality_scales_variance_and_tracking() {
+        let mut trusted = KalmanFilter::default();
+        let mut noisy = KalmanFilter::default();
+
+        for bpm in [72.0, 75.0, 90.0, 110.0, 120.0, 118.0] {
+            let trusted_bpm = trusted.update_with_quality(bpm, 1.0);
+            let noisy_bpm = noisy.update_with_quality(bpm, 0.1);
+            assert!(trusted.variance() < noisy.variance());
+            // Trusted readings pull the estimate closer to the measurement
+            assert!((bpm - trusted_bpm).abs() <= (bpm - noisy_bpm).abs());
+        }
+
+        // Quality 1 matches a plain update; out-of-range qualities are clamped
+        let mut plain = KalmanFilter::default();
+        let mut clamped = KalmanFilter::default();
+        assert_eq!(plain.update(80.0), clamped.update_with_quality(80.0, 3.0));
+        assert_eq!(plain.variance(), clamped.variance());
+    }
+
+    #[test]
+    fn test_max_slope_rejects_impossible_jumps() {
+        let mut filter = KalmanFilter::default().with_max_slope(DEFAULT_MAX_SLOPE_BPM_PER_SEC);
+        for second in 0..10 {
+            filter.update_at(60.0, 1.0, second * 1000);
+        }
+        let settled = filter.estimate();
+
+        // +140 BPM in one second is rejected and leaves the filter unchanged
+        assert!(!filter.is_plausible_change(200.0, 10_000));
+        assert_eq!(filter.update_at(200.0, 1.0, 10_000), settled);
+
+        // +15 BPM in one second is accepted and moves the estimate
+        assert!(filter.is_plausible_change(75.0, 10_000));
+        assert!(filter.update_at(75.0, 1.0, 10_000) > settled);
+
+        // After a long enough gap a large change is plausible again
+        assert!(filter.is_plausible_change(200.0, 20_000));
+
+        // Without a max slope every change is accepted
+        let mut unbounded = KalmanFilter::default();
+        unbounded.update_at(60.0, 1.0, 0);
+        assert!(unbounded.is_plausible_change(200.0, 1_000));
+    }
+
+    #[test]
+    fn test_rr_quality() {
+        assert_eq!(rr_quality(&[]), NO_RR_QUALITY);
+        assert_eq!(rr_quality(&[820]), 1.0);
+        assert_eq!(rr_quality(&[0, 0]), 0.0);
+
+        let steady = rr_quality(&[800, 810, 795, 805]);
+        let erratic = rr_quality(&[600, 1000, 700, 1100]);
+        assert!(steady > 0.9);
+        assert!(erratic < steady);
+        assert_eq!(rr_quality(&[300, 2000]), 0.0);
+    }
 }
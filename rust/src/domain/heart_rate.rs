@@ -4,36 +4,124 @@
 //! measurements, zones, and related utilities. All types are designed to be
 //! pure data structures with no I/O dependencies.
 
+use crate::domain::hrv::Trend;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::fmt;
+use std::str::FromStr;
 use std::time::Instant;
 
 /// Heart rate training zones based on percentage of max heart rate.
 ///
 /// These zones are commonly used in exercise physiology to categorize
 /// training intensity levels.
+///
+/// The serde representation is pinned to "Zone1".."Zone5" so stored plans and
+/// sessions keep loading if the variants are renamed; the canonical strings
+/// "zone1".."zone5" (see [`Zone::as_str`]) are accepted as well.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Zone {
     /// Zone 1: 50-60% of max HR (very light, recovery)
+    #[serde(rename = "Zone1", alias = "zone1")]
     Zone1,
     /// Zone 2: 60-70% of max HR (light, fat burning)
+    #[serde(rename = "Zone2", alias = "zone2")]
     Zone2,
     /// Zone 3: 70-80% of max HR (moderate, aerobic)
+    #[serde(rename = "Zone3", alias = "zone3")]
     Zone3,
     /// Zone 4: 80-90% of max HR (hard, anaerobic threshold)
+    #[serde(rename = "Zone4", alias = "zone4")]
     Zone4,
     /// Zone 5: 90-100% of max HR (maximum effort)
+    #[serde(rename = "Zone5", alias = "zone5")]
     Zone5,
 }
 
+impl Zone {
+    /// Canonical machine-readable name of the zone: "zone1".."zone5".
+    ///
+    /// This is also the [`Display`](fmt::Display) form, and round-trips
+    /// through [`str::parse`]; use [`Zone::display_name`] for text shown to
+    /// users.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Zone::Zone1 => "zone1",
+            Zone::Zone2 => "zone2",
+            Zone::Zone3 => "zone3",
+            Zone::Zone4 => "zone4",
+            Zone::Zone5 => "zone5",
+        }
+    }
+
+    /// Name of the zone shown to users, e.g. "Zone 1 (Recovery)".
+    pub fn display_name(&self) -> String {
+        let number = match self {
+            Zone::Zone1 => 1,
+            Zone::Zone2 => 2,
+            Zone::Zone3 => 3,
+            Zone::Zone4 => 4,
+            Zone::Zone5 => 5,
+        };
+        format!("Zone {} ({})", number, self.label())
+    }
+
+    /// Short human-readable name of the zone (e.g. "Recovery").
+    pub fn label(&self) -> &'static str {
+        match self {
+            Zone::Zone1 => "Recovery",
+            Zone::Zone2 => "Fat Burning",
+            Zone::Zone3 => "Aerobic",
+            Zone::Zone4 => "Threshold",
+            Zone::Zone5 => "Maximum",
+        }
+    }
+
+    /// One-line description of the zone's intensity and HR range.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Zone::Zone1 => "Very light effort for recovery (50-60% of max HR)",
+            Zone::Zone2 => "Light effort that builds aerobic base (60-70% of max HR)",
+            Zone::Zone3 => "Moderate aerobic effort (70-80% of max HR)",
+            Zone::Zone4 => "Hard effort around the anaerobic threshold (80-90% of max HR)",
+            Zone::Zone5 => "Maximum effort (90-100% of max HR)",
+        }
+    }
+
+    /// Display color of the zone as an RGB triple, shared by all front-ends.
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Zone::Zone1 => (33, 150, 243), // blue
+            Zone::Zone2 => (76, 175, 80),  // green
+            Zone::Zone3 => (255, 235, 59), // yellow
+            Zone::Zone4 => (255, 152, 0),  // orange
+            Zone::Zone5 => (244, 67, 54),  // red
+        }
+    }
+}
+
 impl fmt::Display for Zone {
+    /// Writes the canonical name (see [`Zone::as_str`]).
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Zone::Zone1 => write!(f, "Zone 1 (Recovery)"),
-            Zone::Zone2 => write!(f, "Zone 2 (Fat Burning)"),
-            Zone::Zone3 => write!(f, "Zone 3 (Aerobic)"),
-            Zone::Zone4 => write!(f, "Zone 4 (Threshold)"),
-            Zone::Zone5 => write!(f, "Zone 5 (Maximum)"),
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Zone {
+    type Err = anyhow::Error;
+
+    /// Parse a zone from its canonical name ("zone1".."zone5").
+    ///
+    /// Matching is case-insensitive, so the serialized form "Zone1" used by
+    /// older plans and sessions is accepted too.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "zone1" => Ok(Zone::Zone1),
+            "zone2" => Ok(Zone::Zone2),
+            "zone3" => Ok(Zone::Zone3),
+            "zone4" => Ok(Zone::Zone4),
+            "zone5" => Ok(Zone::Zone5),
+            _ => anyhow::bail!("Unknown zone '{}', expected zone1..zone5", s),
         }
     }
 }
@@ -51,7 +139,9 @@ pub struct HeartRateMeasurement {
     ///
     /// RR-intervals represent the time between successive heartbeats and are
     /// used for heart rate variability (HRV) analysis. The values are stored
-    /// in units of 1/1024 seconds as specified by the Bluetooth SIG.
+    /// in units of 1/1024 seconds as specified by the Bluetooth SIG
+    /// ([`RrUnit::Raw1024`]); use [`HeartRateMeasurement::rr_intervals_in`]
+    /// for other units.
     pub rr_intervals: Vec<u16>,
 
     /// Whether the sensor has detected skin contact.
@@ -72,6 +162,84 @@ pub struct HeartRateMeasurement {
     pub receive_timestamp: Option<Instant>,
 }
 
+/// RR-interval resolution defined by the Heart Rate Service (1/1024 second).
+pub const RR_INTERVAL_UNITS_PER_SEC: u32 = 1024;
+
+/// Convert an RR-interval from 1/1024 second units to milliseconds.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::rr_to_ms;
+///
+/// assert_eq!(rr_to_ms(1024), 1000.0);
+/// assert_eq!(rr_to_ms(512), 500.0);
+/// ```
+pub fn rr_to_ms(rr_interval: u16) -> f64 {
+    rr_interval as f64 * 1000.0 / RR_INTERVAL_UNITS_PER_SEC as f64
+}
+
+/// Unit of RR-interval values.
+///
+/// Sensors report RR-intervals in 1/1024 second, while most HRV tools expect
+/// milliseconds. Functions taking RR values as `f64` take the unit alongside
+/// them so the two cannot be confused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RrUnit {
+    /// 1/1024 second, as sent by the Heart Rate Service (the canonical unit).
+    #[default]
+    Raw1024,
+    /// Milliseconds.
+    Milliseconds,
+}
+
+impl RrUnit {
+    /// Express a raw 1/1024 second interval in this unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::heart_rate::RrUnit;
+    ///
+    /// assert_eq!(RrUnit::Raw1024.convert_raw(819), 819.0);
+    /// assert_eq!(RrUnit::Milliseconds.convert_raw(1024), 1000.0);
+    /// ```
+    pub fn convert_raw(self, raw: u16) -> f64 {
+        match self {
+            RrUnit::Raw1024 => raw as f64,
+            RrUnit::Milliseconds => rr_to_ms(raw),
+        }
+    }
+
+    /// Convert a value in this unit to milliseconds.
+    pub fn to_ms(self, value: f64) -> f64 {
+        match self {
+            RrUnit::Raw1024 => value * 1000.0 / RR_INTERVAL_UNITS_PER_SEC as f64,
+            RrUnit::Milliseconds => value,
+        }
+    }
+}
+
+impl HeartRateMeasurement {
+    /// RR-intervals in the canonical 1/1024 second unit, in arrival order.
+    pub fn rr_intervals_raw(&self) -> &[u16] {
+        &self.rr_intervals
+    }
+
+    /// RR-intervals converted to milliseconds, in arrival order.
+    pub fn rr_intervals_ms(&self) -> Vec<f64> {
+        self.rr_intervals_in(RrUnit::Milliseconds)
+    }
+
+    /// RR-intervals expressed in `unit`, in arrival order.
+    pub fn rr_intervals_in(&self, unit: RrUnit) -> Vec<f64> {
+        self.rr_intervals
+            .iter()
+            .map(|&raw| unit.convert_raw(raw))
+            .collect()
+    }
+}
+
 impl fmt::Display for HeartRateMeasurement {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -84,6 +252,82 @@ impl fmt::Display for HeartRateMeasurement {
     }
 }
 
+/// Inclusive range of heart rates accepted as plausible.
+///
+/// [`BpmBounds::PHYSIOLOGICAL`] (30-220 BPM) is used for real sensor data.
+/// Simulations may widen it, e.g. to model efforts above 220 BPM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BpmBounds {
+    /// Lowest accepted BPM.
+    pub min: u16,
+    /// Highest accepted BPM.
+    pub max: u16,
+}
+
+impl BpmBounds {
+    /// Physiologically plausible heart rates (30-220 BPM).
+    pub const PHYSIOLOGICAL: BpmBounds = BpmBounds { min: 30, max: 220 };
+
+    /// Create bounds from `min` to `max` inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `min` is 0 or not below `max`.
+    pub fn new(min: u16, max: u16) -> anyhow::Result<Self> {
+        if min == 0 || min >= max {
+            return Err(anyhow::anyhow!(
+                "Invalid BPM bounds {}-{}: minimum must be above 0 and below maximum",
+                min,
+                max
+            ));
+        }
+        Ok(Self { min, max })
+    }
+
+    /// Whether `bpm` lies within the bounds.
+    pub fn contains(&self, bpm: u16) -> bool {
+        (self.min..=self.max).contains(&bpm)
+    }
+
+    /// Check a user-supplied BPM, naming it in the error if out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error like "Start BPM must be between 30-220".
+    pub fn check(&self, label: &str, bpm: u16) -> anyhow::Result<()> {
+        if self.contains(bpm) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "{} BPM must be between {}-{}",
+                label,
+                self.min,
+                self.max
+            ))
+        }
+    }
+}
+
+impl Default for BpmBounds {
+    fn default() -> Self {
+        Self::PHYSIOLOGICAL
+    }
+}
+
+/// Clamp a (possibly noisy, fractional) BPM value into `bounds`.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::{clamp_bpm, BpmBounds};
+///
+/// assert_eq!(clamp_bpm(231.5, BpmBounds::PHYSIOLOGICAL), 220.0);
+/// assert_eq!(clamp_bpm(231.5, BpmBounds::new(30, 240).unwrap()), 231.5);
+/// ```
+pub fn clamp_bpm(bpm: f64, bounds: BpmBounds) -> f64 {
+    bpm.clamp(bounds.min as f64, bounds.max as f64)
+}
+
 /// A discovered BLE device during scanning.
 ///
 /// This struct represents a device found during BLE scanning operations,
@@ -107,6 +351,36 @@ pub struct DiscoveredDevice {
     pub rssi: i16,
 }
 
+/// Union devices found in one scan window into `merged`.
+///
+/// Devices are matched by id. A device seen again keeps the strongest RSSI
+/// of all windows and picks up a name if it had none; new devices are
+/// appended in the order they were found.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::{merge_discovered_devices, DiscoveredDevice};
+///
+/// let device = |id: &str, rssi| DiscoveredDevice { id: id.to_string(), name: None, rssi };
+/// let mut merged = vec![device("a", -80)];
+/// merge_discovered_devices(&mut merged, &[device("a", -60), device("b", -70)]);
+/// assert_eq!(merged, vec![device("a", -60), device("b", -70)]);
+/// ```
+pub fn merge_discovered_devices(merged: &mut Vec<DiscoveredDevice>, found: &[DiscoveredDevice]) {
+    for device in found {
+        match merged.iter_mut().find(|known| known.id == device.id) {
+            Some(known) => {
+                known.rssi = known.rssi.max(device.rssi);
+                if known.name.is_none() {
+                    known.name = device.name.clone();
+                }
+            }
+            None => merged.push(device.clone()),
+        }
+    }
+}
+
 /// Heart rate data after processing through filtering and HRV calculation.
 ///
 /// This struct represents the final output after raw BLE measurements have been
@@ -130,6 +404,14 @@ pub struct FilteredHeartRate {
     /// HRV metric used for stress and recovery assessment.
     pub rmssd: Option<f64>,
 
+    /// Direction of the windowed RMSSD, for live stress biofeedback.
+    ///
+    /// `Rising` suggests recovery and `Falling` increasing stress or effort.
+    /// `None` until enough RR-intervals have been received, or if the sensor
+    /// does not report them.
+    #[serde(default)]
+    pub rmssd_trend: Option<Trend>,
+
     /// Filter confidence/variance in BPM².
     ///
     /// Represents the Kalman filter's estimated uncertainty in the filtered value.
@@ -180,6 +462,237 @@ pub struct FilteredHeartRate {
     /// Defaults to `false` (data is fresh).
     #[serde(default)]
     pub stale: bool,
+
+    /// Filtered BPM as a fraction of the lactate threshold heart rate (LTHR).
+    ///
+    /// 1.0 means the athlete is exactly at threshold. `None` when no LTHR is
+    /// configured. See [`intensity_factor`].
+    #[serde(default)]
+    pub intensity_factor: Option<f64>,
+
+    /// Average filtered BPM over the last 10 seconds.
+    ///
+    /// During the first seconds of a connection this averages whatever data
+    /// is available. See [`RollingAverage`].
+    #[serde(default)]
+    pub avg_bpm_10s: Option<u16>,
+}
+
+/// Window of the rolling average reported in [`FilteredHeartRate::avg_bpm_10s`].
+pub const ROLLING_AVG_WINDOW_MS: u64 = 10_000;
+
+/// Time-based rolling average of BPM samples.
+///
+/// Samples older than the window (relative to the newest sample) are
+/// dropped, so the average covers a fixed time span regardless of how often
+/// the sensor reports.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::RollingAverage;
+///
+/// let mut avg = RollingAverage::new(10_000);
+/// avg.push(0, 120);
+/// assert_eq!(avg.push(1_000, 130), Some(125));
+/// assert_eq!(avg.push(11_000, 140), Some(140));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingAverage {
+    /// (timestamp in ms, bpm) pairs, oldest first
+    samples: VecDeque<(u64, u16)>,
+    window_ms: u64,
+}
+
+impl RollingAverage {
+    /// Create an empty average over `window_ms` milliseconds (at least 1).
+    pub fn new(window_ms: u64) -> Self {
+        Self {
+            samples: VecDeque::new(),
+            window_ms: window_ms.max(1),
+        }
+    }
+
+    /// Add a sample taken at `timestamp_ms` and return the updated average.
+    pub fn push(&mut self, timestamp_ms: u64, bpm: u16) -> Option<u16> {
+        self.samples.push_back((timestamp_ms, bpm));
+        while self
+            .samples
+            .front()
+            .is_some_and(|&(ts, _)| timestamp_ms.saturating_sub(ts) >= self.window_ms)
+        {
+            self.samples.pop_front();
+        }
+        self.average()
+    }
+
+    /// Average BPM of the samples in the window, or `None` if empty.
+    pub fn average(&self) -> Option<u16> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let sum: u64 = self.samples.iter().map(|&(_, bpm)| bpm as u64).sum();
+        Some((sum as f64 / self.samples.len() as f64).round() as u16)
+    }
+}
+
+/// Time constant of the simulated HR response to a new target, in seconds.
+///
+/// After one time constant HR has covered about 63% of the way to the target.
+pub const PHYSIO_TIME_CONSTANT_SECS: f64 = 30.0;
+
+/// Simulated heart rate that follows a sequence of timed targets with a realistic lag.
+///
+/// HR approaches the current target BPM as a first-order lag instead of
+/// jumping to it, and returns toward the starting BPM once every target has
+/// run. The simulation is deterministic; callers add measurement noise on top
+/// (see `MockHrMode::FollowPlan`). A training plan's simulation comes from
+/// `TrainingPlan::hr_simulator`.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::PhysioSimulator;
+///
+/// // Aim for 125 BPM for 10 minutes
+/// let mut sim = PhysioSimulator::new(&[(600, 125.0)], 70);
+/// let after_10s = (0..10).map(|_| sim.step(1.0)).last().unwrap();
+/// assert!(after_10s > 70.0 && after_10s < sim.target_bpm());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PhysioSimulator {
+    /// (target end in seconds since start, target BPM) for each target
+    targets: Vec<(f64, f64)>,
+    /// BPM the simulation started at and returns to after the last target
+    rest_bpm: f64,
+    bpm: f64,
+    elapsed_secs: f64,
+    time_constant_secs: f64,
+}
+
+impl PhysioSimulator {
+    /// Start a simulation from `start_bpm` through `targets`.
+    ///
+    /// Each target is a `(duration_secs, target_bpm)` pair; the targets run
+    /// back to back in order.
+    pub fn new(targets: &[(u32, f64)], start_bpm: u16) -> Self {
+        let mut end_secs = 0.0;
+        let targets = targets
+            .iter()
+            .map(|&(duration_secs, target_bpm)| {
+                end_secs += duration_secs as f64;
+                (end_secs, target_bpm)
+            })
+            .collect();
+        Self {
+            targets,
+            rest_bpm: start_bpm as f64,
+            bpm: start_bpm as f64,
+            elapsed_secs: 0.0,
+            time_constant_secs: PHYSIO_TIME_CONSTANT_SECS,
+        }
+    }
+
+    /// Set how quickly HR responds to a new target (at least 1 second).
+    ///
+    /// Defaults to [`PHYSIO_TIME_CONSTANT_SECS`].
+    pub fn with_time_constant(mut self, secs: f64) -> Self {
+        self.time_constant_secs = secs.max(1.0);
+        self
+    }
+
+    /// BPM the simulation is currently moving toward.
+    pub fn target_bpm(&self) -> f64 {
+        self.targets
+            .iter()
+            .find(|&&(end, _)| self.elapsed_secs < end)
+            .map_or(self.rest_bpm, |&(_, target)| target)
+    }
+
+    /// Current simulated BPM.
+    pub fn bpm(&self) -> f64 {
+        self.bpm
+    }
+
+    /// Advance the simulation by `dt_secs` and return the new BPM.
+    pub fn step(&mut self, dt_secs: f64) -> f64 {
+        let dt_secs = dt_secs.max(0.0);
+        let target = self.target_bpm();
+        self.bpm += (target - self.bpm) * (1.0 - (-dt_secs / self.time_constant_secs).exp());
+        self.elapsed_secs += dt_secs;
+        self.bpm
+    }
+}
+
+/// Slowest rise (BPM per second) still treated as a ramp by [`predict_time_to_zone`].
+///
+/// Slower trends are indistinguishable from noise on a flat HR and would give
+/// meaninglessly long predictions.
+pub const MIN_RAMP_BPM_PER_SEC: f64 = 0.05;
+
+/// Predict how long until HR reaches `target_low_bpm` during a ramp.
+///
+/// Fits a least-squares line through `recent` (timestamp in ms, bpm) samples
+/// and extrapolates from the fitted value at the newest sample. Returns the
+/// seconds until the target is reached, rounded up, `Some(0)` if the fit is
+/// already at or above it, or `None` if there are fewer than two samples at
+/// different times or HR is not rising by at least [`MIN_RAMP_BPM_PER_SEC`].
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::predict_time_to_zone;
+///
+/// // Rising 1 BPM per second, 20 BPM below the target
+/// let ramp: Vec<(u64, u16)> = (0..10).map(|i| (i * 1000, 100 + i as u16)).collect();
+/// assert_eq!(predict_time_to_zone(&ramp, 129), Some(20));
+/// ```
+pub fn predict_time_to_zone(recent: &[(u64, u16)], target_low_bpm: u16) -> Option<u32> {
+    let (&(t0, _), &(t_last, _)) = (recent.first()?, recent.last()?);
+    let n = recent.len() as f64;
+    // Seconds relative to the first sample keep the sums small
+    let points = recent
+        .iter()
+        .map(|&(ts, bpm)| (ts.saturating_sub(t0) as f64 / 1000.0, bpm as f64));
+    let (sum_x, sum_y, sum_xx, sum_xy) = points.fold((0.0, 0.0, 0.0, 0.0), |acc, (x, y)| {
+        (acc.0 + x, acc.1 + y, acc.2 + x * x, acc.3 + x * y)
+    });
+    let denominator = n * sum_xx - sum_x * sum_x;
+    if recent.len() < 2 || denominator <= f64::EPSILON {
+        return None;
+    }
+
+    let slope = (n * sum_xy - sum_x * sum_y) / denominator;
+    let intercept = (sum_y - slope * sum_x) / n;
+    let fitted_now = intercept + slope * (t_last.saturating_sub(t0) as f64 / 1000.0);
+    let gap = target_low_bpm as f64 - fitted_now;
+    if gap <= 0.0 {
+        return Some(0);
+    }
+    if slope < MIN_RAMP_BPM_PER_SEC {
+        return None;
+    }
+    Some((gap / slope).ceil().min(u32::MAX as f64) as u32)
+}
+
+/// Compute the intensity factor of a heart rate relative to threshold.
+///
+/// Returns `filtered_bpm / lthr`, or `None` if no LTHR is configured (or it
+/// is zero).
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::intensity_factor;
+///
+/// assert_eq!(intensity_factor(144, Some(160)), Some(0.9));
+/// assert_eq!(intensity_factor(144, None), None);
+/// ```
+pub fn intensity_factor(filtered_bpm: u16, lthr: Option<u16>) -> Option<f64> {
+    match lthr {
+        Some(lthr) if lthr > 0 => Some(filtered_bpm as f64 / lthr as f64),
+        _ => None,
+    }
 }
 
 /// Parse a BLE Heart Rate Measurement characteristic value.
@@ -197,6 +710,16 @@ pub struct FilteredHeartRate {
 /// - Optional: Energy Expended (UINT16) if bit 3 is set
 /// - Optional: RR-Intervals (one or more UINT16 values) if bit 4 is set
 ///
+/// All multi-byte fields are little-endian. The fields are packed, so the RR
+/// section starts at byte 2, 3, 4 or 5 depending on the BPM format and whether
+/// energy expended is present. RR-intervals are kept in their raw 1/1024 second
+/// units; use [`HeartRateMeasurement::rr_intervals_ms`] for milliseconds.
+///
+/// Packets too short for the BPM or energy expended fields their flags
+/// announce are rejected. A trailing odd byte in the RR section cannot be a
+/// complete interval and is ignored. Arbitrary byte slices never panic; the
+/// tests check this against every short packet and random input.
+///
 /// # Arguments
 ///
 /// * `data` - Raw byte array from BLE Heart Rate Measurement characteristic
@@ -266,16 +789,13 @@ pub fn parse_heart_rate(data: &[u8]) -> anyhow::Result<HeartRateMeasurement> {
     let mut rr_intervals = Vec::new();
 
     if has_rr_intervals {
-        // RR-intervals are UINT16 values in 1/1024 second resolution
-        while offset + 1 < data.len() {
-            if data.len() < offset + 2 {
-                // Incomplete RR-interval at end of packet - ignore it
-                break;
-            }
-            let rr = u16::from_le_bytes([data[offset], data[offset + 1]]);
-            rr_intervals.push(rr);
-            offset += 2;
-        }
+        // RR-intervals are UINT16 values in 1/1024 second resolution.
+        // chunks_exact drops an incomplete interval at the end of the packet.
+        rr_intervals.extend(
+            data[offset..]
+                .chunks_exact(2)
+                .map(|rr| u16::from_le_bytes([rr[0], rr[1]])),
+        );
     }
 
     Ok(HeartRateMeasurement {
@@ -291,13 +811,151 @@ pub fn parse_heart_rate(data: &[u8]) -> anyhow::Result<HeartRateMeasurement> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_zone_metadata_is_distinct() {
+        let zones = [
+            Zone::Zone1,
+            Zone::Zone2,
+            Zone::Zone3,
+            Zone::Zone4,
+            Zone::Zone5,
+        ];
+
+        for (i, a) in zones.iter().enumerate() {
+            assert!(!a.label().is_empty());
+            assert!(!a.description().is_empty());
+            for b in &zones[i + 1..] {
+                assert_ne!(a.label(), b.label());
+                assert_ne!(a.description(), b.description());
+                assert_ne!(a.rgb(), b.rgb());
+            }
+        }
+    }
+
+    #[test]
+    fn test_zone_parses_canonical_and_legacy_forms() {
+        for zone in [
+            Zone::Zone1,
+            Zone::Zone2,
+            Zone::Zone3,
+            Zone::Zone4,
+            Zone::Zone5,
+        ] {
+            assert_eq!(zone.as_str().parse::<Zone>().unwrap(), zone);
+            assert_eq!(zone.to_string().parse::<Zone>().unwrap(), zone);
+        }
+        assert_eq!("zone3".parse::<Zone>().unwrap(), Zone::Zone3);
+        assert_eq!("Zone3".parse::<Zone>().unwrap(), Zone::Zone3);
+        assert!("zone6".parse::<Zone>().is_err());
+        assert!("".parse::<Zone>().is_err());
+
+        let legacy: Zone = serde_json::from_str("\"Zone4\"").unwrap();
+        let canonical: Zone = serde_json::from_str("\"zone4\"").unwrap();
+        assert_eq!(legacy, Zone::Zone4);
+        assert_eq!(canonical, Zone::Zone4);
+    }
+
+    #[test]
+    fn test_zone_serialization_is_stable() {
+        assert_eq!(serde_json::to_string(&Zone::Zone1).unwrap(), "\"Zone1\"");
+        assert_eq!(serde_json::to_string(&Zone::Zone5).unwrap(), "\"Zone5\"");
+    }
+
+    #[test]
+    fn test_intensity_factor_relative_to_lthr() {
+        assert_eq!(intensity_factor(160, Some(160)), Some(1.0));
+        assert_eq!(intensity_factor(144, Some(160)), Some(0.9));
+        assert_eq!(intensity_factor(160, None), None);
+        assert_eq!(intensity_factor(160, Some(0)), None);
+    }
+
+    #[test]
+    fn test_rolling_average_steady_and_step() {
+        let mut avg = RollingAverage::new(ROLLING_AVG_WINDOW_MS);
+        assert_eq!(avg.average(), None);
+
+        // Warmup: averages what is available
+        assert_eq!(avg.push(0, 120), Some(120));
+        for i in 1..30 {
+            assert_eq!(avg.push(i * 1000, 120), Some(120));
+        }
+
+        // Step to 160: the average lags and reaches it after the window
+        let mut previous = 120;
+        for i in 30..40 {
+            let current = avg.push(i * 1000, 160).unwrap();
+            assert!(current > previous && current <= 160);
+            previous = current;
+        }
+        assert_eq!(previous, 160);
+    }
+
+    #[test]
+    fn test_rr_unit_conversions_are_exact() {
+        let measurement = HeartRateMeasurement {
+            bpm: 70,
+            rr_intervals: vec![0, 512, 819, 1024, u16::MAX],
+            sensor_contact: true,
+            receive_timestamp: None,
+        };
+
+        assert_eq!(
+            measurement.rr_intervals_raw(),
+            &[0, 512, 819, 1024, u16::MAX]
+        );
+        assert_eq!(
+            measurement.rr_intervals_in(RrUnit::Raw1024),
+            vec![0.0, 512.0, 819.0, 1024.0, 65535.0]
+        );
+        // Every 1/1024 s value is exactly representable in milliseconds
+        assert_eq!(
+            measurement.rr_intervals_ms(),
+            vec![0.0, 500.0, 799.8046875, 1000.0, 63999.0234375]
+        );
+        assert_eq!(
+            measurement.rr_intervals_in(RrUnit::Milliseconds),
+            measurement.rr_intervals_ms()
+        );
+
+        // Round trip back to milliseconds from either unit
+        for &raw in measurement.rr_intervals_raw() {
+            let ms = rr_to_ms(raw);
+            assert_eq!(RrUnit::Raw1024.to_ms(raw as f64), ms);
+            assert_eq!(RrUnit::Milliseconds.to_ms(ms), ms);
+        }
+    }
+
+    #[test]
+    fn test_predict_time_to_zone() {
+        // Warmup rising 0.5 BPM/s from 100 over 20 s; fitted now = 110
+        let ramp: Vec<(u64, u16)> = (0..=20u64)
+            .map(|i| (i * 1000, 100 + (i / 2) as u16))
+            .collect();
+        let eta = predict_time_to_zone(&ramp, 130).unwrap();
+        assert!((38..=42).contains(&eta), "eta {}", eta);
+
+        // Already in the target zone
+        assert_eq!(predict_time_to_zone(&ramp, 105), Some(0));
+
+        // Flat, falling or too little data: no prediction
+        let flat: Vec<(u64, u16)> = (0..20u64).map(|i| (i * 1000, 110)).collect();
+        assert_eq!(predict_time_to_zone(&flat, 130), None);
+        let falling: Vec<(u64, u16)> = (0..20u64).map(|i| (i * 1000, 130 - i as u16)).collect();
+        assert_eq!(predict_time_to_zone(&falling, 140), None);
+        assert_eq!(predict_time_to_zone(&[], 130), None);
+        assert_eq!(predict_time_to_zone(&[(0, 100)], 130), None);
+        assert_eq!(predict_time_to_zone(&[(5, 100), (5, 110)], 130), None);
+    }
+
     #[test]
     fn test_zone_display() {
-        assert_eq!(Zone::Zone1.to_string(), "Zone 1 (Recovery)");
-        assert_eq!(Zone::Zone2.to_string(), "Zone 2 (Fat Burning)");
-        assert_eq!(Zone::Zone3.to_string(), "Zone 3 (Aerobic)");
-        assert_eq!(Zone::Zone4.to_string(), "Zone 4 (Threshold)");
-        assert_eq!(Zone::Zone5.to_string(), "Zone 5 (Maximum)");
+        assert_eq!(Zone::Zone1.to_string(), "zone1");
+        assert_eq!(Zone::Zone5.to_string(), "zone5");
+        assert_eq!(Zone::Zone1.display_name(), "Zone 1 (Recovery)");
+        assert_eq!(Zone::Zone2.display_name(), "Zone 2 (Fat Burning)");
+        assert_eq!(Zone::Zone3.display_name(), "Zone 3 (Aerobic)");
+        assert_eq!(Zone::Zone4.display_name(), "Zone 4 (Threshold)");
+        assert_eq!(Zone::Zone5.display_name(), "Zone 5 (Maximum)");
     }
 
     #[test]
@@ -487,6 +1145,48 @@ mod tests {
         assert_eq!(result.rr_intervals, vec![768, 784, 800, 816, 832]);
     }
 
+    #[test]
+    fn test_parse_heart_rate_uint16_energy_and_rr_to_ms() {
+        // Flags: 0x1F (UINT16, sensor contact, energy expended, RR-intervals)
+        let data = &[
+            0x1F, 0x2C, 0x01, // BPM: 300 (0x012C, high byte set)
+            0x34, 0x12, // Energy: 0x1234 - skipped
+            0x00, 0x04, // RR: 1024 -> 1000 ms
+            0x00, 0x02, // RR: 512 -> 500 ms
+            0x33, 0x03, // RR: 819 -> 799.8 ms
+        ];
+        let result = parse_heart_rate(data).unwrap();
+
+        assert_eq!(result.bpm, 300);
+        assert_eq!(result.rr_intervals, vec![1024, 512, 819]);
+        let ms = result.rr_intervals_ms();
+        assert_eq!(ms[..2], [1000.0, 500.0]);
+        assert!((ms[2] - 799.8046875).abs() < 1e-9);
+
+        // Without the energy flag the same bytes shift into the RR section
+        let mut no_energy = data.to_vec();
+        no_energy[0] = 0x17;
+        let result = parse_heart_rate(&no_energy).unwrap();
+        assert_eq!(result.rr_intervals, vec![0x1234, 1024, 512, 819]);
+    }
+
+    #[test]
+    fn test_parse_heart_rate_uint16_energy_truncated() {
+        // UINT16 BPM present but energy expended cut short
+        let data = &[0x19, 0x48, 0x00, 0x34];
+        let result = parse_heart_rate(data);
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Insufficient data for energy"));
+
+        // RR flag with only BPM and energy present yields no intervals
+        let data = &[0x19, 0x48, 0x00, 0x34, 0x12];
+        assert!(parse_heart_rate(data).unwrap().rr_intervals.is_empty());
+    }
+
     #[test]
     fn test_parse_heart_rate_sensor_contact_bit_patterns() {
         // Test all sensor contact bit patterns
@@ -668,5 +1368,114 @@ mod tests {
                 let _ = result;
             }
         }
+
+        /// Bytes the flags require before the RR section, per the HR Measurement spec.
+        fn required_len(flags: u8) -> usize {
+            let bpm_len = if flags & 0x01 != 0 { 2 } else { 1 };
+            let energy_len = if flags & 0x08 != 0 { 2 } else { 0 };
+            1 + bpm_len + energy_len
+        }
+
+        // Property 8: Err exactly when the flags claim fields the packet does not
+        // contain; otherwise the result matches a reference decoding
+        proptest! {
+            #[test]
+            fn result_matches_reference_decoding(data in prop::collection::vec(any::<u8>(), 0..64)) {
+                let result = parse_heart_rate(&data);
+                if data.len() < 2 || data.len() < required_len(data[0]) {
+                    prop_assert!(result.is_err());
+                    return Ok(());
+                }
+
+                let flags = data[0];
+                let measurement = result.unwrap();
+                let expected_bpm = if flags & 0x01 != 0 {
+                    u16::from_le_bytes([data[1], data[2]])
+                } else {
+                    data[1] as u16
+                };
+                prop_assert_eq!(measurement.bpm, expected_bpm);
+                prop_assert_eq!(measurement.sensor_contact, flags & 0x04 != 0);
+
+                let expected_rr = if flags & 0x10 != 0 {
+                    (data.len() - required_len(flags)) / 2
+                } else {
+                    0
+                };
+                prop_assert_eq!(measurement.rr_intervals.len(), expected_rr);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_heart_rate_all_flags_short_packets() {
+        // Every flag combination against every packet length up to the largest
+        // header plus one RR interval: no panics, and Err only for missing fields
+        for flags in 0..=u8::MAX {
+            for len in 0..=7usize {
+                let mut data = vec![0xAB; len];
+                if let Some(first) = data.first_mut() {
+                    *first = flags;
+                }
+                let result = parse_heart_rate(&data);
+
+                let bpm_len = if flags & 0x01 != 0 { 2 } else { 1 };
+                let energy_len = if flags & 0x08 != 0 { 2 } else { 0 };
+                let truncated = len < 2 || len < 1 + bpm_len + energy_len;
+                assert_eq!(
+                    result.is_err(),
+                    truncated,
+                    "flags {:#04x}, len {}: {:?}",
+                    flags,
+                    len,
+                    result
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_heart_rate_regressions() {
+        // RR flag set with nothing after the BPM
+        let result = parse_heart_rate(&[0x10, 72]).unwrap();
+        assert!(result.rr_intervals.is_empty());
+
+        // UINT16 + energy + RR flags with exactly the header present
+        let result = parse_heart_rate(&[0x19, 0x48, 0x00, 0x34, 0x12]).unwrap();
+        assert_eq!(result.bpm, 72);
+        assert!(result.rr_intervals.is_empty());
+
+        // UINT16 + energy + RR flags one byte short of the header
+        assert!(parse_heart_rate(&[0x19, 0x48, 0x00, 0x34]).is_err());
+
+        // UINT16 flag with a single byte after the flags
+        assert!(parse_heart_rate(&[0xFF, 0x48]).is_err());
+
+        // Reserved bits 5-7 are ignored
+        let result = parse_heart_rate(&[0xE0, 65]).unwrap();
+        assert_eq!(result.bpm, 65);
+        assert!(!result.sensor_contact);
+    }
+
+    #[test]
+    fn test_bpm_bounds_clamp_and_check() {
+        let default = BpmBounds::default();
+        assert_eq!(default, BpmBounds::PHYSIOLOGICAL);
+        assert_eq!(clamp_bpm(12.0, default), 30.0);
+        assert_eq!(clamp_bpm(235.0, default), 220.0);
+        assert_eq!(clamp_bpm(142.3, default), 142.3);
+
+        // Widened for a high-intensity simulation
+        let wide = BpmBounds::new(25, 240).unwrap();
+        assert_eq!(clamp_bpm(235.0, wide), 235.0);
+        assert_eq!(clamp_bpm(250.0, wide), 240.0);
+        assert_eq!(clamp_bpm(20.0, wide), 25.0);
+        assert!(wide.check("High", 240).is_ok());
+
+        let err = default.check("High", 235).unwrap_err();
+        assert_eq!(err.to_string(), "High BPM must be between 30-220");
+
+        assert!(BpmBounds::new(0, 200).is_err());
+        assert!(BpmBounds::new(200, 200).is_err());
     }
 }
@@ -4,6 +4,10 @@
 //! extracted from Bluetooth heart rate monitors. HRV metrics are useful for
 //! assessing stress, recovery, and autonomic nervous system function.
 
+use crate::domain::heart_rate::RrUnit;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
 /// Calculates RMSSD (Root Mean Square of Successive Differences) from RR-intervals.
 ///
 /// RMSSD is a time-domain HRV metric that measures short-term heart rate variability.
@@ -14,7 +18,8 @@
 /// # Arguments
 ///
 /// * `rr_intervals` - Slice of RR-intervals in 1/1024 second resolution, as provided
-///   by Bluetooth heart rate monitors following the Heart Rate Service specification.
+///   by Bluetooth heart rate monitors following the Heart Rate Service specification
+///   ([`RrUnit::Raw1024`]). See [`calculate_rmssd_in`] for other units.
 ///
 /// # Returns
 ///
@@ -43,17 +48,32 @@
 /// Intervals outside this range are likely sensor artifacts and cause the function
 /// to return `None`.
 pub fn calculate_rmssd(rr_intervals: &[u16]) -> Option<f64> {
+    let rr: Vec<f64> = rr_intervals.iter().map(|&rr| rr as f64).collect();
+    calculate_rmssd_in(&rr, RrUnit::Raw1024)
+}
+
+/// Calculates RMSSD from RR-intervals given in an explicit unit.
+///
+/// Same as [`calculate_rmssd`], for RR values that are already in
+/// milliseconds (or any other [`RrUnit`]). The result is always in
+/// milliseconds.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::RrUnit;
+/// use heart_beat::domain::hrv::calculate_rmssd_in;
+///
+/// let rmssd = calculate_rmssd_in(&[800.0, 820.0, 810.0], RrUnit::Milliseconds).unwrap();
+/// assert!((rmssd - 250.0_f64.sqrt()).abs() < 1e-9);
+/// ```
+pub fn calculate_rmssd_in(rr_intervals: &[f64], unit: RrUnit) -> Option<f64> {
     // Need at least 2 intervals to calculate successive differences
     if rr_intervals.len() < 2 {
         return None;
     }
 
-    // Convert from 1/1024 second units to milliseconds
-    // Formula: ms = (value * 1000) / 1024
-    let rr_ms: Vec<f64> = rr_intervals
-        .iter()
-        .map(|&rr| (rr as f64 * 1000.0) / 1024.0)
-        .collect();
+    let rr_ms: Vec<f64> = rr_intervals.iter().map(|&rr| unit.to_ms(rr)).collect();
 
     // Validate that all intervals are physiologically plausible
     // Valid range: 300ms (200 BPM) to 2000ms (30 BPM)
@@ -91,6 +111,7 @@ pub fn calculate_rmssd(rr_intervals: &[u16]) -> Option<f64> {
 /// # Arguments
 ///
 /// * `rr_intervals` - Slice of RR-intervals in 1/1024 second resolution
+///   ([`RrUnit::Raw1024`]); see [`calculate_sdnn_in`] for other units
 ///
 /// # Returns
 ///
@@ -108,16 +129,21 @@ pub fn calculate_rmssd(rr_intervals: &[u16]) -> Option<f64> {
 /// assert!(sdnn.is_some());
 /// ```
 pub fn calculate_sdnn(rr_intervals: &[u16]) -> Option<f64> {
+    let rr: Vec<f64> = rr_intervals.iter().map(|&rr| rr as f64).collect();
+    calculate_sdnn_in(&rr, RrUnit::Raw1024)
+}
+
+/// Calculates SDNN from RR-intervals given in an explicit unit.
+///
+/// Same as [`calculate_sdnn`], for RR values in any [`RrUnit`]. The result
+/// is always in milliseconds.
+pub fn calculate_sdnn_in(rr_intervals: &[f64], unit: RrUnit) -> Option<f64> {
     // Need at least 2 intervals for meaningful standard deviation
     if rr_intervals.len() < 2 {
         return None;
     }
 
-    // Convert from 1/1024 second units to milliseconds
-    let rr_ms: Vec<f64> = rr_intervals
-        .iter()
-        .map(|&rr| (rr as f64 * 1000.0) / 1024.0)
-        .collect();
+    let rr_ms: Vec<f64> = rr_intervals.iter().map(|&rr| unit.to_ms(rr)).collect();
 
     // Validate physiological range
     const MIN_RR_MS: f64 = 300.0;
@@ -139,6 +165,151 @@ pub fn calculate_sdnn(rr_intervals: &[u16]) -> Option<f64> {
     Some(sdnn)
 }
 
+/// Default number of RR-intervals kept by an [`HrvWindow`] (about a minute at rest).
+pub const DEFAULT_HRV_WINDOW: usize = 64;
+
+/// Number of recent window RMSSD values used to classify the trend.
+pub const RMSSD_TREND_POINTS: usize = 10;
+
+/// Relative change of RMSSD across the trend points treated as a trend (5%).
+pub const RMSSD_TREND_THRESHOLD: f64 = 0.05;
+
+/// Direction of a live HRV metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Trend {
+    /// Variability is increasing (recovering, relaxing).
+    Rising,
+    /// Variability is decreasing (stress or increasing effort).
+    Falling,
+    /// Variability is roughly constant.
+    Stable,
+}
+
+/// Sliding window of recent RR-intervals for continuously updated HRV metrics.
+///
+/// Intervals are added as they arrive with [`push`](Self::push); once the window
+/// is full the oldest interval is dropped. Intervals outside the physiologically
+/// valid range (300-2000 ms) are rejected on entry, so a single artifact does not
+/// invalidate the metrics for the whole window.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::hrv::HrvWindow;
+///
+/// let mut window = HrvWindow::new(4);
+/// window.extend(&[1024, 2048]); // 1000 ms, 2000 ms
+/// assert_eq!(window.sdnn(), Some(500.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HrvWindow {
+    /// Most recent valid intervals in 1/1024 second units, oldest first
+    intervals: VecDeque<u16>,
+    /// Maximum number of intervals kept
+    capacity: usize,
+    /// Window RMSSD after each of the last accepted intervals, oldest first
+    rmssd_history: VecDeque<f64>,
+}
+
+impl HrvWindow {
+    /// Create a window holding up to `capacity` intervals (at least 2).
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(2);
+        Self {
+            intervals: VecDeque::with_capacity(capacity),
+            capacity,
+            rmssd_history: VecDeque::with_capacity(RMSSD_TREND_POINTS),
+        }
+    }
+
+    /// Add an RR-interval (1/1024 second units).
+    ///
+    /// Returns `false` if the interval was rejected as an artifact.
+    pub fn push(&mut self, rr_interval: u16) -> bool {
+        let rr_ms = (rr_interval as f64 * 1000.0) / 1024.0;
+        if !(300.0..=2000.0).contains(&rr_ms) {
+            return false;
+        }
+        if self.intervals.len() == self.capacity {
+            self.intervals.pop_front();
+        }
+        self.intervals.push_back(rr_interval);
+
+        if let Some(rmssd) = self.rmssd() {
+            if self.rmssd_history.len() == RMSSD_TREND_POINTS {
+                self.rmssd_history.pop_front();
+            }
+            self.rmssd_history.push_back(rmssd);
+        }
+        true
+    }
+
+    /// Add several RR-intervals in arrival order.
+    pub fn extend(&mut self, rr_intervals: &[u16]) {
+        for &rr in rr_intervals {
+            self.push(rr);
+        }
+    }
+
+    /// Number of intervals currently in the window.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Whether the window holds no intervals.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// RMSSD over the window, or `None` with fewer than 2 intervals.
+    pub fn rmssd(&self) -> Option<f64> {
+        calculate_rmssd(&self.to_vec())
+    }
+
+    /// SDNN over the window, or `None` with fewer than 2 intervals.
+    pub fn sdnn(&self) -> Option<f64> {
+        calculate_sdnn(&self.to_vec())
+    }
+
+    /// Direction of the window RMSSD over the last [`RMSSD_TREND_POINTS`] intervals.
+    ///
+    /// The least-squares slope of the recent RMSSD values, extended over the
+    /// trend points and relative to their mean, is compared against
+    /// [`RMSSD_TREND_THRESHOLD`]. Returns `None` until enough intervals have
+    /// been recorded.
+    pub fn rmssd_trend(&self) -> Option<Trend> {
+        let n = self.rmssd_history.len();
+        if n < RMSSD_TREND_POINTS {
+            return None;
+        }
+
+        let mean_x = (n - 1) as f64 / 2.0;
+        let mean_y = self.rmssd_history.iter().sum::<f64>() / n as f64;
+        if mean_y <= 0.0 {
+            return Some(Trend::Stable);
+        }
+        let (mut cov, mut var) = (0.0, 0.0);
+        for (i, &y) in self.rmssd_history.iter().enumerate() {
+            let dx = i as f64 - mean_x;
+            cov += dx * (y - mean_y);
+            var += dx * dx;
+        }
+
+        let relative_change = cov / var * (n - 1) as f64 / mean_y;
+        Some(if relative_change > RMSSD_TREND_THRESHOLD {
+            Trend::Rising
+        } else if relative_change < -RMSSD_TREND_THRESHOLD {
+            Trend::Falling
+        } else {
+            Trend::Stable
+        })
+    }
+
+    fn to_vec(&self) -> Vec<u16> {
+        self.intervals.iter().copied().collect()
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::useless_vec)]
 mod tests {
@@ -247,6 +418,27 @@ mod tests {
         // RMSSD focuses on successive differences, SDNN on overall variation
     }
 
+    #[test]
+    fn test_hrv_window_slides_and_rejects_artifacts() {
+        let mut window = HrvWindow::new(3);
+        assert!(window.is_empty());
+        assert_eq!(window.sdnn(), None);
+
+        window.extend(&[1024, 2048]);
+        assert_eq!(window.sdnn(), Some(500.0));
+
+        // Artifact is ignored rather than poisoning the window
+        assert!(!window.push(100));
+        assert_eq!(window.len(), 2);
+
+        // Oldest interval drops out once full
+        window.extend(&[1024, 1024]);
+        assert_eq!(window.len(), 3);
+        let sdnn = window.sdnn().unwrap();
+        assert!((sdnn - calculate_sdnn(&[2048, 1024, 1024]).unwrap()).abs() < 1e-9);
+        assert!(window.rmssd().is_some());
+    }
+
     #[test]
     fn test_conversion_accuracy() {
         // Test unit conversion: 1024 units = 1000ms
@@ -259,10 +451,68 @@ mod tests {
         assert!((rmssd.unwrap()).abs() < 0.01);
     }
 
+    #[test]
+    fn test_metrics_agree_across_rr_units() {
+        let raw = [819u16, 839, 829, 815, 825];
+        let ms: Vec<f64> = raw
+            .iter()
+            .map(|&rr| RrUnit::Milliseconds.convert_raw(rr))
+            .collect();
+        let raw_f64: Vec<f64> = raw.iter().map(|&rr| rr as f64).collect();
+
+        let rmssd = calculate_rmssd(&raw).unwrap();
+        assert_eq!(calculate_rmssd_in(&ms, RrUnit::Milliseconds), Some(rmssd));
+        assert_eq!(calculate_rmssd_in(&raw_f64, RrUnit::Raw1024), Some(rmssd));
+
+        let sdnn = calculate_sdnn(&raw).unwrap();
+        let sdnn_ms = calculate_sdnn_in(&ms, RrUnit::Milliseconds).unwrap();
+        assert!((sdnn_ms - sdnn).abs() < 1e-9);
+
+        // Raw values mistaken for milliseconds land outside the valid range
+        assert_eq!(
+            calculate_rmssd_in(&[2000.0, 2048.0], RrUnit::Milliseconds),
+            None
+        );
+        assert!(calculate_rmssd_in(&[2000.0, 2048.0], RrUnit::Raw1024).is_some());
+    }
+
     #[test]
     fn test_mixed_valid_and_invalid() {
         // One invalid interval should fail the whole calculation
         let rr_intervals = vec![819, 839, 2200, 829]; // 2200 is out of range
         assert_eq!(calculate_rmssd(&rr_intervals), None);
     }
+
+    /// Intervals alternating around ~800 ms with the given amplitudes (1/1024 s units).
+    fn alternating(amplitudes: impl Iterator<Item = u16>) -> Vec<u16> {
+        amplitudes
+            .enumerate()
+            .map(|(i, a)| if i % 2 == 0 { 820 + a } else { 820 - a })
+            .collect()
+    }
+
+    #[test]
+    fn test_rmssd_trend_follows_variability() {
+        let mut rising = HrvWindow::new(8);
+        rising.extend(&alternating(2..32));
+        assert_eq!(rising.rmssd_trend(), Some(Trend::Rising));
+
+        let mut falling = HrvWindow::new(8);
+        falling.extend(&alternating((2..32).rev()));
+        assert_eq!(falling.rmssd_trend(), Some(Trend::Falling));
+
+        let mut steady = HrvWindow::new(8);
+        steady.extend(&alternating(std::iter::repeat(15).take(30)));
+        assert_eq!(steady.rmssd_trend(), Some(Trend::Stable));
+    }
+
+    #[test]
+    fn test_rmssd_trend_needs_history() {
+        let mut window = HrvWindow::new(8);
+        window.extend(&alternating(2..(RMSSD_TREND_POINTS as u16 + 2)));
+        // The first interval yields no RMSSD, so one point short
+        assert_eq!(window.rmssd_trend(), None);
+        window.push(900);
+        assert!(window.rmssd_trend().is_some());
+    }
 }
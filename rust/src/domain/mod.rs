@@ -6,15 +6,21 @@
 pub mod adaptive;
 pub mod analytics;
 pub mod battery;
+pub mod clock;
+pub mod connection_health;
 pub mod export;
+pub mod export_binary;
+pub mod export_bundle;
 pub mod export_formats;
 pub mod filters;
 pub mod heart_rate;
 pub mod hrv;
+pub mod pacer;
 pub mod periodization;
 pub mod readiness;
 pub mod reconnection;
 pub mod resting_hr;
+pub mod sensor;
 pub mod session_history;
 pub mod session_progress;
 pub mod training_load;
@@ -26,13 +32,28 @@ pub use adaptive::{
     adapt_plan, compute_adjustment, shift_zone, AdaptedPlan, Adjustment, AdjustmentReason,
 };
 pub use battery::BatteryLevel;
-pub use export::{export_to_csv, export_to_json, export_to_summary};
-pub use export_formats::{export_to_gpx, export_to_tcx};
-pub use filters::{is_valid_bpm, KalmanFilter};
+pub use clock::MonotonicClock;
+pub use connection_health::{
+    assess_connection_health, ConnectionHealth, ConnectionLog, ConnectionSummary, DropoutCounter,
+    HealthLevel,
+};
+pub use export::{
+    export_to_csv, export_to_csv_with, export_to_json, export_to_json_with, export_to_replay,
+    export_to_summary, export_to_summary_with, write_csv, write_csv_with, write_json,
+    write_json_with, write_summary, write_summary_with, ExportOptions,
+};
+pub use export_binary::{export_to_binary, import_from_binary};
+pub use export_bundle::{export_to_bundle, export_to_bundle_with, BUNDLE_ENTRIES};
+pub use export_formats::{export_to_gpx, export_to_tcx, export_to_tcx_with};
+pub use filters::{is_valid_bpm, rr_quality, KalmanFilter};
 pub use heart_rate::{
-    parse_heart_rate, DiscoveredDevice, FilteredHeartRate, HeartRateMeasurement, Zone,
+    clamp_bpm, merge_discovered_devices, parse_heart_rate, predict_time_to_zone, BpmBounds,
+    DiscoveredDevice, FilteredHeartRate, HeartRateMeasurement, RollingAverage, RrUnit, Zone,
+};
+pub use hrv::{
+    calculate_rmssd, calculate_rmssd_in, calculate_sdnn, calculate_sdnn_in, HrvWindow, Trend,
 };
-pub use hrv::{calculate_rmssd, calculate_sdnn};
+pub use pacer::{compute_pace_cue, Direction, PaceCue, Severity};
 pub use periodization::{
     compute_compliance, create_5k_plan, create_general_fitness_plan, generate_week_schedule,
     BlockType, PeriodizationPlan, ScheduledSession, TrainingBlock,
@@ -41,18 +62,32 @@ pub use readiness::{
     compute_hrv_baseline, compute_readiness, compute_rhr_baseline, HrvReading, ReadinessLevel,
     ReadinessScore, RestingHrReading,
 };
-pub use reconnection::{ConnectionStatus, ReconnectionPolicy};
+pub use reconnection::{CircuitBreaker, ConnectionStatus, ReconnectionPolicy};
 pub use resting_hr::{
     compute_resting_hr_stats, compute_resting_hr_trend, detect_resting_hr_from_session,
     MeasurementSource, RestingHrMeasurement, RestingHrStats, TrendDirection,
 };
-pub use session_history::{CompletedSession, HrSample, PhaseResult, SessionStatus, SessionSummary};
-pub use session_progress::{PhaseProgress, SessionProgress, SessionState, ZoneStatus};
+pub use sensor::{ContactMonitor, SensorLocation};
+pub use session_history::{
+    adherence_score, data_completeness_pct, derive_plan, new_session_id, recovery_hr,
+    time_in_zone_from_samples, time_in_zone_smoothed, time_in_zone_with_max_gap,
+    zone_hr_stats_from_samples, CompletedSession, HrSample, PhaseResult, RunningStats,
+    SampleDecimator, SessionContextMeta, SessionStatus, SessionSummary, ZoneHrStat,
+    DEFAULT_RECOVERY_WINDOW_SECS, HIGH_QUALITY_COMPLETENESS_PCT, MAX_RPE, MAX_SAMPLE_GAP_SECS,
+    MIN_DERIVED_PHASE_SECS, PHASE_TARGET_MET_FRACTION,
+};
+pub use session_progress::{
+    ConnectivityDuringSession, PhaseProgress, PhaseTransition, SessionProgress, SessionState,
+    ZoneStatus,
+};
 pub use training_load::{
-    compute_daily_trimp, compute_session_trimp, compute_training_load, current_training_load,
-    DailyTrimp, TrainingLoadMetrics,
+    banister_trimp_per_minute, compute_daily_trimp, compute_session_trimp, compute_training_load,
+    current_training_load, DailyTrimp, LiveTrimp, TrainingLoadMetrics, DEFAULT_RESTING_HR,
+};
+pub use training_plan::{
+    calculate_zone, suggest_max_hr, zone_bpm_bounds, PhaseTarget, PlanChange, PlanSummary,
+    PlanWarning, TrainingPhase, TrainingPlan, TransitionCondition, MAX_HR_EXCEEDED_MARGIN_BPM,
 };
-pub use training_plan::{calculate_zone, TrainingPhase, TrainingPlan, TransitionCondition};
 pub use workout_library::{
     get_default_templates, get_templates_by_difficulty, get_templates_by_sport, Difficulty, Sport,
     WorkoutTemplate,
@@ -0,0 +1,157 @@
+//! Target HR pacer.
+//!
+//! Turns the distance between the current BPM and the target band into a
+//! graded cue ("+8 BPM, ease off") instead of a binary too-high/too-low flag.
+//! The band is the inclusive BPM range of the target zone (see
+//! [`zone_bpm_bounds`](crate::domain::training_plan::zone_bpm_bounds)) or an
+//! explicit BPM band, optionally widened by a tolerance.
+
+use crate::domain::training_plan::PhaseTarget;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Which way the athlete should adjust their effort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Direction {
+    /// BPM is below the band: increase effort.
+    SpeedUp,
+    /// BPM is within the band: keep the current effort.
+    Hold,
+    /// BPM is above the band: ease off.
+    SlowDown,
+}
+
+/// How far outside the band the current BPM is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    /// Within the band.
+    None,
+    /// 1-5 BPM outside the band.
+    Mild,
+    /// 6-10 BPM outside the band.
+    Moderate,
+    /// More than 10 BPM outside the band.
+    Strong,
+}
+
+impl Severity {
+    /// Severity tier for a distance in BPM from the band edge.
+    pub fn from_delta(delta_bpm: u16) -> Self {
+        match delta_bpm {
+            0 => Severity::None,
+            1..=5 => Severity::Mild,
+            6..=10 => Severity::Moderate,
+            _ => Severity::Strong,
+        }
+    }
+}
+
+/// A pacing hint relative to the target zone band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaceCue {
+    /// Which way to adjust effort.
+    pub direction: Direction,
+    /// Distance in BPM to the nearest edge of the band (0 when inside it).
+    pub delta_bpm: u16,
+    /// How urgent the adjustment is.
+    pub severity: Severity,
+}
+
+/// Compute a pace cue for `bpm` against the band of `target`, a zone or an
+/// explicit [`PhaseTarget::Bpm`] band.
+///
+/// The band is widened by `tolerance_bpm` on both sides, matching the in-zone
+/// check used by the session state machine.
+///
+/// # Errors
+///
+/// Returns an error if `max_hr` is invalid for zone calculation of a zone target.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::heart_rate::Zone;
+/// use heart_beat::domain::pacer::{compute_pace_cue, Direction, Severity};
+///
+/// // Zone 3 at 200 max_hr is 140-159 BPM
+/// let cue = compute_pace_cue(167, Zone::Zone3, 200, 0).unwrap();
+/// assert_eq!(cue.direction, Direction::SlowDown);
+/// assert_eq!(cue.delta_bpm, 8);
+/// assert_eq!(cue.severity, Severity::Moderate);
+/// ```
+pub fn compute_pace_cue(
+    bpm: u16,
+    target: impl Into<PhaseTarget>,
+    max_hr: u16,
+    tolerance_bpm: u16,
+) -> Result<PaceCue> {
+    let (low, high) = target.into().bpm_bounds(max_hr)?;
+    let low = low.saturating_sub(tolerance_bpm);
+    let high = high.saturating_add(tolerance_bpm);
+
+    let (direction, delta_bpm) = if bpm < low {
+        (Direction::SpeedUp, low - bpm)
+    } else if bpm > high {
+        (Direction::SlowDown, bpm - high)
+    } else {
+        (Direction::Hold, 0)
+    };
+
+    Ok(PaceCue {
+        direction,
+        delta_bpm,
+        severity: Severity::from_delta(delta_bpm),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::heart_rate::Zone;
+
+    #[test]
+    fn test_above_band_slows_down() {
+        // Zone 3 at 200 max_hr is 140-159 BPM
+        let cue = compute_pace_cue(167, Zone::Zone3, 200, 0).unwrap();
+        assert_eq!(cue.direction, Direction::SlowDown);
+        assert_eq!(cue.delta_bpm, 8);
+        assert_eq!(cue.severity, Severity::Moderate);
+    }
+
+    #[test]
+    fn test_below_band_speeds_up() {
+        let cue = compute_pace_cue(137, Zone::Zone3, 200, 0).unwrap();
+        assert_eq!(cue.direction, Direction::SpeedUp);
+        assert_eq!(cue.delta_bpm, 3);
+        assert_eq!(cue.severity, Severity::Mild);
+
+        let cue = compute_pace_cue(120, Zone::Zone3, 200, 0).unwrap();
+        assert_eq!(cue.delta_bpm, 20);
+        assert_eq!(cue.severity, Severity::Strong);
+    }
+
+    #[test]
+    fn test_band_edges_hold() {
+        for bpm in [140, 150, 159] {
+            let cue = compute_pace_cue(bpm, Zone::Zone3, 200, 0).unwrap();
+            assert_eq!(cue.direction, Direction::Hold);
+            assert_eq!(cue.delta_bpm, 0);
+            assert_eq!(cue.severity, Severity::None);
+        }
+    }
+
+    #[test]
+    fn test_tolerance_widens_band() {
+        let cue = compute_pace_cue(167, Zone::Zone3, 200, 5).unwrap();
+        assert_eq!(cue.direction, Direction::SlowDown);
+        assert_eq!(cue.delta_bpm, 3);
+
+        let cue = compute_pace_cue(162, Zone::Zone3, 200, 5).unwrap();
+        assert_eq!(cue.direction, Direction::Hold);
+    }
+
+    #[test]
+    fn test_invalid_max_hr_errors() {
+        assert!(compute_pace_cue(150, Zone::Zone3, 50, 0).is_err());
+    }
+}
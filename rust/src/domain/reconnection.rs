@@ -4,7 +4,7 @@
 //! to BLE heart rate monitors. All types are pure domain logic with no I/O.
 
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Sentinel value indicating unlimited reconnection attempts.
 ///
@@ -201,6 +201,143 @@ pub enum ConnectionStatus {
         /// Reason for the failure.
         reason: String,
     },
+
+    /// Reconnection is suspended after repeated failed cycles.
+    ///
+    /// The device is probably out of range; no attempts are made until the
+    /// cooldown ends or the breaker is reset (see [`CircuitBreaker`]).
+    CircuitOpen {
+        /// Seconds until a single probe attempt is allowed again.
+        retry_after_secs: u64,
+    },
+}
+
+/// Default number of consecutive failed reconnection cycles that open the breaker.
+pub const DEFAULT_BREAKER_THRESHOLD: u32 = 3;
+
+/// Default time the breaker stays open before allowing a probe.
+pub const DEFAULT_BREAKER_COOLDOWN: Duration = Duration::from_secs(300);
+
+/// Stops reconnection after repeated failed cycles, to save battery.
+///
+/// Each full reconnection cycle (all attempts of a [`ReconnectionPolicy`]) is
+/// reported with [`record_failure`](Self::record_failure) or
+/// [`record_success`](Self::record_success). After `threshold` consecutive
+/// failed cycles the breaker opens and [`try_acquire`](Self::try_acquire)
+/// refuses new cycles. Once the cooldown has passed, a single probe cycle is
+/// allowed: success closes the breaker, failure opens it for another cooldown.
+///
+/// Time is passed in explicitly so the breaker stays free of I/O.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::reconnection::CircuitBreaker;
+/// use std::time::{Duration, Instant};
+///
+/// let mut breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+/// let now = Instant::now();
+///
+/// for _ in 0..2 {
+///     assert!(breaker.try_acquire(now));
+///     breaker.record_failure(now);
+/// }
+/// assert!(!breaker.try_acquire(now));
+/// assert!(breaker.try_acquire(now + Duration::from_secs(60))); // probe
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    /// Consecutive failed cycles that open the breaker (at least 1).
+    threshold: u32,
+    /// How long the breaker stays open.
+    cooldown: Duration,
+    /// Failed cycles since the last success or reset.
+    consecutive_failures: u32,
+    /// When the breaker last opened, if it is open.
+    opened_at: Option<Instant>,
+    /// Whether the post-cooldown probe cycle is in progress.
+    probing: bool,
+}
+
+impl CircuitBreaker {
+    /// Create a breaker that opens after `threshold` failed cycles for `cooldown`.
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            cooldown,
+            consecutive_failures: 0,
+            opened_at: None,
+            probing: false,
+        }
+    }
+
+    /// Whether a reconnection cycle may start at `now`.
+    ///
+    /// Returns `true` while closed. While open, returns `false` until the
+    /// cooldown has passed, then `true` exactly once for the probe cycle.
+    pub fn try_acquire(&mut self, now: Instant) -> bool {
+        match self.opened_at {
+            None => true,
+            Some(_) if self.probing => false,
+            Some(opened_at) if now.duration_since(opened_at) >= self.cooldown => {
+                self.probing = true;
+                true
+            }
+            Some(_) => false,
+        }
+    }
+
+    /// Record a reconnection cycle that ended connected; closes the breaker.
+    pub fn record_success(&mut self) {
+        self.reset();
+    }
+
+    /// Record a reconnection cycle that exhausted its attempts.
+    ///
+    /// Returns `true` if the breaker is open afterwards.
+    pub fn record_failure(&mut self, now: Instant) -> bool {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        if self.probing || self.consecutive_failures >= self.threshold {
+            self.opened_at = Some(now);
+            self.probing = false;
+        }
+        self.opened_at.is_some()
+    }
+
+    /// Give back a probe that ended without an outcome (e.g. it was cancelled).
+    ///
+    /// The breaker stays open but the next [`try_acquire`](Self::try_acquire)
+    /// may start a new probe.
+    pub fn cancel_probe(&mut self) {
+        self.probing = false;
+    }
+
+    /// Close the breaker and forget past failures.
+    pub fn reset(&mut self) {
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+        self.probing = false;
+    }
+
+    /// Time left in the cooldown at `now`, or `None` if the breaker is closed.
+    pub fn retry_after(&self, now: Instant) -> Option<Duration> {
+        self.opened_at
+            .map(|opened_at| self.cooldown.saturating_sub(now.duration_since(opened_at)))
+    }
+
+    /// The status to report while the breaker is open, or `None` if closed.
+    pub fn status(&self, now: Instant) -> Option<ConnectionStatus> {
+        self.retry_after(now)
+            .map(|remaining| ConnectionStatus::CircuitOpen {
+                retry_after_secs: remaining.as_secs_f64().ceil() as u64,
+            })
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(DEFAULT_BREAKER_THRESHOLD, DEFAULT_BREAKER_COOLDOWN)
+    }
 }
 
 #[cfg(test)]
@@ -328,6 +465,69 @@ mod tests {
         let json = serde_json::to_string(&status).unwrap();
         let deserialized: ConnectionStatus = serde_json::from_str(&json).unwrap();
         assert_eq!(status, deserialized);
+
+        // Test CircuitOpen
+        let status = ConnectionStatus::CircuitOpen {
+            retry_after_secs: 300,
+        };
+        let json = serde_json::to_string(&status).unwrap();
+        assert!(json.contains("\"type\":\"circuit_open\""));
+        let deserialized: ConnectionStatus = serde_json::from_str(&json).unwrap();
+        assert_eq!(status, deserialized);
+    }
+
+    #[test]
+    fn test_breaker_opens_after_threshold_cycles() {
+        let mut breaker = CircuitBreaker::new(3, Duration::from_secs(300));
+        let start = Instant::now();
+
+        for _ in 0..2 {
+            assert!(breaker.try_acquire(start));
+            assert!(!breaker.record_failure(start));
+        }
+        assert!(breaker.try_acquire(start));
+        assert!(breaker.record_failure(start));
+
+        // Open: further cycles are blocked during the cooldown
+        let later = start + Duration::from_secs(120);
+        assert!(!breaker.try_acquire(later));
+        assert_eq!(
+            breaker.status(later),
+            Some(ConnectionStatus::CircuitOpen {
+                retry_after_secs: 180
+            })
+        );
+
+        // Reset closes it immediately
+        breaker.reset();
+        assert!(breaker.try_acquire(later));
+        assert_eq!(breaker.status(later), None);
+    }
+
+    #[test]
+    fn test_breaker_allows_single_probe_after_cooldown() {
+        let cooldown = Duration::from_secs(60);
+        let mut breaker = CircuitBreaker::new(1, cooldown);
+        let start = Instant::now();
+
+        assert!(breaker.record_failure(start));
+        assert!(!breaker.try_acquire(start + Duration::from_secs(59)));
+
+        // One probe after the cooldown, nothing else while it runs
+        let probe = start + cooldown;
+        assert!(breaker.try_acquire(probe));
+        assert!(!breaker.try_acquire(probe));
+
+        // A failed probe reopens for a full cooldown
+        assert!(breaker.record_failure(probe));
+        assert!(!breaker.try_acquire(probe + Duration::from_secs(30)));
+
+        // A successful probe closes the breaker
+        let probe = probe + cooldown;
+        assert!(breaker.try_acquire(probe));
+        breaker.record_success();
+        assert!(breaker.try_acquire(probe));
+        assert!(breaker.try_acquire(probe));
     }
 
     #[test]
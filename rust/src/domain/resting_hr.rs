@@ -227,8 +227,19 @@ mod tests {
                 avg_hr: 140,
                 max_hr: 160,
                 min_hr: 60,
+                hr_p50: 140,
+                hr_p90: 160,
+                hr_p95: 160,
                 time_in_zone: [60, 120, 60, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         }
     }
 
@@ -0,0 +1,163 @@
+//! Sensor placement and skin-contact domain types.
+//!
+//! This module maps the Body Sensor Location characteristic (0x2A38) of the
+//! Heart Rate Service and tracks skin contact from the BPM stream. Many straps
+//! report contact loss only by sending a heart rate of 0, so contact is
+//! derived from runs of zero-BPM packets.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Number of consecutive zero-BPM packets treated as lost skin contact.
+pub const DEFAULT_CONTACT_LOSS_PACKETS: u32 = 3;
+
+/// Where the heart rate sensor is worn, as reported by Body Sensor Location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SensorLocation {
+    /// Other or unspecified location (also used for reserved values)
+    Other,
+    /// Chest strap
+    Chest,
+    /// Wrist
+    Wrist,
+    /// Finger
+    Finger,
+    /// Hand
+    Hand,
+    /// Ear lobe
+    EarLobe,
+    /// Foot
+    Foot,
+}
+
+impl SensorLocation {
+    /// Map a Body Sensor Location byte to a location.
+    ///
+    /// Values reserved by the specification (7-255) map to [`SensorLocation::Other`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::sensor::SensorLocation;
+    ///
+    /// assert_eq!(SensorLocation::from_byte(1), SensorLocation::Chest);
+    /// assert_eq!(SensorLocation::from_byte(42), SensorLocation::Other);
+    /// ```
+    pub fn from_byte(value: u8) -> Self {
+        match value {
+            1 => SensorLocation::Chest,
+            2 => SensorLocation::Wrist,
+            3 => SensorLocation::Finger,
+            4 => SensorLocation::Hand,
+            5 => SensorLocation::EarLobe,
+            6 => SensorLocation::Foot,
+            _ => SensorLocation::Other,
+        }
+    }
+}
+
+impl fmt::Display for SensorLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SensorLocation::Other => "Other",
+            SensorLocation::Chest => "Chest",
+            SensorLocation::Wrist => "Wrist",
+            SensorLocation::Finger => "Finger",
+            SensorLocation::Hand => "Hand",
+            SensorLocation::EarLobe => "Ear lobe",
+            SensorLocation::Foot => "Foot",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Tracks skin contact from consecutive zero-BPM packets.
+///
+/// Call [`ContactMonitor::record`] with the BPM of every received packet.
+#[derive(Debug, Clone)]
+pub struct ContactMonitor {
+    /// Zero-BPM packets needed before contact counts as lost (at least 1).
+    threshold: u32,
+    /// Length of the current run of zero-BPM packets.
+    zero_run: u32,
+}
+
+impl ContactMonitor {
+    /// Create a monitor that reports loss after `threshold` zero-BPM packets.
+    pub fn new(threshold: u32) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            zero_run: 0,
+        }
+    }
+
+    /// Record one packet's BPM.
+    ///
+    /// Returns `true` only for the packet that completes a run of zero-BPM
+    /// packets, so each loss is reported once. A non-zero BPM restores contact.
+    pub fn record(&mut self, bpm: u16) -> bool {
+        if bpm != 0 {
+            self.zero_run = 0;
+            return false;
+        }
+        self.zero_run = self.zero_run.saturating_add(1);
+        self.zero_run == self.threshold
+    }
+
+    /// Whether contact is currently considered lost.
+    pub fn is_lost(&self) -> bool {
+        self.zero_run >= self.threshold
+    }
+}
+
+impl Default for ContactMonitor {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONTACT_LOSS_PACKETS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::heart_rate::parse_heart_rate;
+
+    #[test]
+    fn test_sensor_location_from_spec_bytes() {
+        let expected = [
+            (0, SensorLocation::Other),
+            (1, SensorLocation::Chest),
+            (2, SensorLocation::Wrist),
+            (3, SensorLocation::Finger),
+            (4, SensorLocation::Hand),
+            (5, SensorLocation::EarLobe),
+            (6, SensorLocation::Foot),
+            (7, SensorLocation::Other),
+            (255, SensorLocation::Other),
+        ];
+        for (byte, location) in expected {
+            assert_eq!(SensorLocation::from_byte(byte), location, "byte {}", byte);
+        }
+    }
+
+    #[test]
+    fn test_zero_bpm_run_reports_contact_lost_once() {
+        let mut monitor = ContactMonitor::default();
+        let zero_packet = parse_heart_rate(&[0x00, 0x00]).unwrap();
+
+        assert!(!monitor.record(72));
+        assert!(!monitor.record(zero_packet.bpm));
+        assert!(!monitor.record(zero_packet.bpm));
+        assert!(monitor.record(zero_packet.bpm));
+        assert!(monitor.is_lost());
+        // Further zeros do not report again
+        assert!(!monitor.record(zero_packet.bpm));
+
+        // Contact restored, then lost again
+        assert!(!monitor.record(70));
+        assert!(!monitor.is_lost());
+        for _ in 0..2 {
+            assert!(!monitor.record(0));
+        }
+        assert!(monitor.record(0));
+    }
+}
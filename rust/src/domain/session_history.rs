@@ -4,8 +4,25 @@
 //! heart rate samples, phase completion, and summary statistics. All types are
 //! pure data structures with no I/O dependencies.
 
+use crate::domain::connection_health::ConnectionSummary;
+use crate::domain::heart_rate::Zone;
+use crate::domain::training_plan::{
+    calculate_zone, PhaseTarget, TrainingPhase, TrainingPlan, TransitionCondition,
+};
+use anyhow::{bail, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Longest gap between consecutive samples that counts towards time in zone.
+///
+/// Straps report roughly once per second; a longer gap is a dropout, and only
+/// this much of it is attributed to the zone of the sample before the gap.
+pub const MAX_SAMPLE_GAP_SECS: u32 = 5;
+
+/// Data completeness (in percent) at or above which a session counts as a
+/// clean recording; see [`CompletedSession::is_high_quality`].
+pub const HIGH_QUALITY_COMPLETENESS_PCT: f64 = 80.0;
 
 /// A completed training session with full history and statistics.
 ///
@@ -37,6 +54,43 @@ pub struct CompletedSession {
 
     /// Statistical summary of the session.
     pub summary: SessionSummary,
+
+    /// Conditions the user recorded for the session, if any.
+    ///
+    /// `None` until set, and for sessions saved before context was recorded.
+    #[serde(default)]
+    pub context: Option<SessionContextMeta>,
+
+    /// Results of the phases that were completed, in order.
+    ///
+    /// Empty for sessions saved before phase results were recorded.
+    #[serde(default)]
+    pub phase_results: Vec<PhaseResult>,
+
+    /// How reliable the sensor connection was during the session.
+    ///
+    /// `None` for sessions saved before it was recorded.
+    #[serde(default)]
+    pub connection_summary: Option<ConnectionSummary>,
+}
+
+/// Highest rating of perceived exertion on the CR10 scale.
+pub const MAX_RPE: u8 = 10;
+
+/// Environmental and subjective context recorded with a session.
+///
+/// Entered by the user after the session so it can later be correlated with
+/// heart rate data, e.g. higher average HR on hot days.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionContextMeta {
+    /// Ambient temperature in degrees Celsius, if known.
+    pub temperature_c: Option<f64>,
+
+    /// Rating of perceived exertion on the CR10 scale (0-[`MAX_RPE`]).
+    pub rpe: u8,
+
+    /// Free-form description of the conditions, e.g. "humid, headwind".
+    pub conditions: Option<String>,
 }
 
 /// Status of a completed session.
@@ -55,7 +109,10 @@ pub enum SessionStatus {
 /// Summary statistics for a training session.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SessionSummary {
-    /// Total duration of the session in seconds.
+    /// Duration of the session in seconds.
+    ///
+    /// Excludes time spent paused for sessions that record
+    /// `active_duration_secs`.
     pub duration_secs: u32,
 
     /// Average heart rate during the session.
@@ -67,10 +124,70 @@ pub struct SessionSummary {
     /// Minimum heart rate recorded during the session.
     pub min_hr: u16,
 
+    /// Median (50th percentile) heart rate, by nearest rank.
+    ///
+    /// 0 for sessions without samples or saved before percentiles were recorded.
+    #[serde(default)]
+    pub hr_p50: u16,
+
+    /// 90th percentile heart rate, by nearest rank.
+    #[serde(default)]
+    pub hr_p90: u16,
+
+    /// 95th percentile heart rate, by nearest rank.
+    #[serde(default)]
+    pub hr_p95: u16,
+
     /// Time spent in each heart rate zone in seconds.
     ///
     /// Indexed by zone number (0-4 for Zone1-Zone5).
     pub time_in_zone: [u32; 5],
+
+    /// Heart rate distribution within each zone, indexed like `time_in_zone`.
+    ///
+    /// `None` for zones the session never reached, and for all zones in
+    /// sessions saved before zone statistics were recorded.
+    #[serde(default)]
+    pub zone_hr_stats: [Option<ZoneHrStat>; 5],
+
+    /// Share of the expected HR samples that were recorded, in percent (0-100).
+    ///
+    /// See [`data_completeness_pct`]. `None` for sessions saved before
+    /// completeness was recorded.
+    #[serde(default)]
+    pub data_completeness_pct: Option<f64>,
+
+    /// Wall-clock time from session start to end in seconds, pauses included.
+    ///
+    /// `None` for sessions saved before pause time was tracked.
+    #[serde(default)]
+    pub elapsed_wall_secs: Option<u32>,
+
+    /// Time spent training in seconds, i.e. wall-clock time minus pauses.
+    ///
+    /// `None` for sessions saved before pause time was tracked.
+    #[serde(default)]
+    pub active_duration_secs: Option<u32>,
+
+    /// Banister TRIMP accumulated while the session ran (see
+    /// [`LiveTrimp`](crate::domain::training_load::LiveTrimp)).
+    ///
+    /// `None` for sessions saved before live TRIMP was recorded.
+    #[serde(default)]
+    pub trimp: Option<f64>,
+}
+
+/// Minimum, average and maximum heart rate of the samples within one zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZoneHrStat {
+    /// Lowest BPM recorded in the zone.
+    pub min_bpm: u16,
+
+    /// Mean BPM of the samples in the zone, rounded to the nearest beat.
+    pub avg_bpm: u16,
+
+    /// Highest BPM recorded in the zone.
+    pub max_bpm: u16,
 }
 
 /// A single heart rate sample at a specific point in time.
@@ -103,6 +220,70 @@ pub struct PhaseResult {
 
     /// Minimum heart rate during this phase.
     pub min_hr: u16,
+
+    /// Whether HR stayed in the phase's target zone for at least
+    /// [`PHASE_TARGET_MET_FRACTION`] of the phase.
+    #[serde(default)]
+    pub target_met: bool,
+}
+
+/// Share of a phase's time that must be spent in its target zone for
+/// [`PhaseResult::target_met`].
+pub const PHASE_TARGET_MET_FRACTION: f64 = 0.7;
+
+impl PhaseResult {
+    /// Compute the result of `phase`, run from `start_time` to `end_time`.
+    ///
+    /// Uses the samples taken within the phase. Time on target (in the target
+    /// zone, or within the phase's explicit BPM band) is measured as in
+    /// [`time_in_zone_from_samples`], with the last sample counting until the
+    /// phase ends, and compared with the phase's active time (elapsed time
+    /// minus the `paused_secs` spent paused), so dropouts count as time off
+    /// target but pauses don't. A phase without samples has zero heart rates
+    /// and does not meet its target.
+    pub fn from_samples(
+        phase: &TrainingPhase,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        samples: &[HrSample],
+        max_hr: u16,
+        paused_secs: u32,
+    ) -> Self {
+        let mut in_phase: Vec<HrSample> = samples
+            .iter()
+            .filter(|s| s.timestamp >= start_time && s.timestamp < end_time)
+            .copied()
+            .collect();
+        let stats = RunningStats::from_samples(&in_phase, None);
+
+        // The last sample holds until the phase ends
+        if let Some(last) = in_phase.last().copied() {
+            in_phase.push(HrSample {
+                timestamp: end_time,
+                bpm: last.bpm,
+            });
+        }
+        let active_secs =
+            end_time.signed_duration_since(start_time).num_seconds() - paused_secs as i64;
+        let on_target_secs = match phase.effective_target() {
+            PhaseTarget::Zone(zone) => {
+                time_in_zone_from_samples(&in_phase, max_hr)[zone_index(zone)]
+            }
+            PhaseTarget::Bpm { low, high } => time_in_bpm_band(&in_phase, low, high),
+        };
+        let target_met = active_secs > 0
+            && on_target_secs as f64 >= PHASE_TARGET_MET_FRACTION * active_secs as f64;
+
+        Self {
+            phase_name: phase.name.clone(),
+            start_time,
+            end_time,
+            avg_hr: stats.avg_bpm(),
+            max_hr: stats.max().unwrap_or(0),
+            min_hr: stats.min().unwrap_or(0),
+            target_met,
+        }
+    }
 }
 
 impl CompletedSession {
@@ -117,37 +298,664 @@ impl CompletedSession {
     pub fn is_completed(&self) -> bool {
         self.status == SessionStatus::Completed
     }
+
+    /// Share of the expected HR samples that were recorded, in percent.
+    ///
+    /// Uses the value stored in the summary, or for older sessions computes it
+    /// from the stored samples at the nominal 1 Hz rate.
+    pub fn data_completeness_pct(&self) -> f64 {
+        self.summary.data_completeness_pct.unwrap_or_else(|| {
+            data_completeness_pct(self.hr_samples.len(), self.summary.duration_secs, 1)
+        })
+    }
+
+    /// Whether the recording is complete enough to trust its statistics
+    /// (at least [`HIGH_QUALITY_COMPLETENESS_PCT`] of the expected samples).
+    pub fn is_high_quality(&self) -> bool {
+        self.data_completeness_pct() >= HIGH_QUALITY_COMPLETENESS_PCT
+    }
 }
 
 impl SessionSummary {
     /// Create a summary from a list of heart rate samples.
     ///
-    /// Calculates average, min, max heart rates from the samples.
+    /// Calculates average, min, max and percentile heart rates from the samples,
+    /// and data completeness assuming one sample per second (override it with
+    /// [`with_data_completeness`](Self::with_data_completeness) for coarser samples).
     /// The caller should provide time_in_zone separately as it requires
-    /// zone calculation based on max_hr.
+    /// zone calculation based on max_hr (see [`time_in_zone_from_samples`]).
     pub fn from_samples(samples: &[HrSample], duration_secs: u32, time_in_zone: [u32; 5]) -> Self {
-        if samples.is_empty() {
-            return Self {
+        Self::from_running_stats(
+            &RunningStats::from_samples(samples, None),
+            duration_secs,
+            time_in_zone,
+        )
+    }
+
+    /// Create a summary from statistics accumulated during the session.
+    ///
+    /// Same as [`from_samples`](Self::from_samples) for the samples fed to
+    /// `stats`, without revisiting them. Per-zone statistics are included if
+    /// `stats` tracks zones.
+    pub fn from_running_stats(
+        stats: &RunningStats,
+        duration_secs: u32,
+        time_in_zone: [u32; 5],
+    ) -> Self {
+        Self {
+            duration_secs,
+            avg_hr: stats.avg_bpm(),
+            max_hr: stats.max().unwrap_or(0),
+            min_hr: stats.min().unwrap_or(0),
+            hr_p50: stats.percentile(50),
+            hr_p90: stats.percentile(90),
+            hr_p95: stats.percentile(95),
+            time_in_zone,
+            zone_hr_stats: stats.zone_hr_stats(),
+            data_completeness_pct: Some(data_completeness_pct(
+                stats.count() as usize,
                 duration_secs,
-                avg_hr: 0,
-                max_hr: 0,
-                min_hr: 0,
-                time_in_zone,
-            };
+                1,
+            )),
+            elapsed_wall_secs: None,
+            active_duration_secs: None,
+            trimp: None,
         }
+    }
+
+    /// Set the data completeness in percent (see [`data_completeness_pct`]).
+    pub fn with_data_completeness(mut self, pct: f64) -> Self {
+        self.data_completeness_pct = Some(pct);
+        self
+    }
+
+    /// Record the wall-clock and active (pause-excluded) durations.
+    ///
+    /// `duration_secs` is set to the active duration.
+    pub fn with_durations(mut self, elapsed_wall_secs: u32, active_duration_secs: u32) -> Self {
+        self.duration_secs = active_duration_secs;
+        self.elapsed_wall_secs = Some(elapsed_wall_secs);
+        self.active_duration_secs = Some(active_duration_secs);
+        self
+    }
+
+    /// Record the TRIMP accumulated during the session.
+    pub fn with_trimp(mut self, trimp: f64) -> Self {
+        self.trimp = Some(trimp);
+        self
+    }
 
-        let sum: u32 = samples.iter().map(|s| s.bpm as u32).sum();
-        let avg_hr = (sum / samples.len() as u32) as u16;
-        let max_hr = samples.iter().map(|s| s.bpm).max().unwrap_or(0);
-        let min_hr = samples.iter().map(|s| s.bpm).min().unwrap_or(0);
+    /// Set the per-zone heart rate statistics (see [`zone_hr_stats_from_samples`]).
+    pub fn with_zone_hr_stats(mut self, zone_hr_stats: [Option<ZoneHrStat>; 5]) -> Self {
+        self.zone_hr_stats = zone_hr_stats;
+        self
+    }
+}
 
+/// Generate a new unique session ID.
+///
+/// IDs are UUID v7: 74 random bits make collisions practically impossible
+/// even for sessions ending in the same millisecond, and the leading timestamp
+/// (with a per-process counter for IDs created within the same millisecond)
+/// makes the string form sort by creation order.
+pub fn new_session_id() -> String {
+    uuid::Uuid::now_v7().to_string()
+}
+
+/// Compute seconds spent in each zone (index 0 = Zone 1) from HR samples.
+///
+/// Each sample is weighted by the actual time until the next sample rather
+/// than assuming uniform spacing, so jittery or irregular sampling still sums
+/// to the real elapsed time. Gaps longer than [`MAX_SAMPLE_GAP_SECS`] are capped
+/// so dropouts are not attributed to a zone. The last sample has no successor
+/// and contributes nothing; samples below Zone 1 are not counted.
+///
+/// Returns all zeros if `max_hr` is invalid for zone calculation.
+pub fn time_in_zone_from_samples(samples: &[HrSample], max_hr: u16) -> [u32; 5] {
+    time_in_zone_with_max_gap(samples, max_hr, MAX_SAMPLE_GAP_SECS)
+}
+
+/// Like [`time_in_zone_from_samples`], but with a custom gap cap.
+///
+/// Use this for samples recorded at a coarser interval than 1 Hz, where the
+/// regular spacing between samples may exceed [`MAX_SAMPLE_GAP_SECS`].
+pub fn time_in_zone_with_max_gap(samples: &[HrSample], max_hr: u16, max_gap_secs: u32) -> [u32; 5] {
+    time_in_zone_smoothed(samples, max_hr, max_gap_secs, 0)
+}
+
+/// Like [`time_in_zone_with_max_gap`], but ignoring brief zone excursions.
+///
+/// A zone change only counts once HR stays in the new zone for at least
+/// `min_dwell_secs`; shorter excursions are attributed to the zone HR was in
+/// before them. `0` disables smoothing.
+pub fn time_in_zone_smoothed(
+    samples: &[HrSample],
+    max_hr: u16,
+    max_gap_secs: u32,
+    min_dwell_secs: u32,
+) -> [u32; 5] {
+    let max_gap_ms = max_gap_secs as i64 * 1000;
+    let mut zones = Vec::with_capacity(samples.len());
+    for sample in samples {
+        match calculate_zone(sample.bpm, max_hr) {
+            Ok(zone) => zones.push(zone),
+            Err(_) => return [0; 5],
+        }
+    }
+
+    // Time each sample accounts for, until the next one
+    let deltas_ms: Vec<i64> = samples
+        .windows(2)
+        .map(|pair| {
+            pair[1]
+                .timestamp
+                .signed_duration_since(pair[0].timestamp)
+                .num_milliseconds()
+                .clamp(0, max_gap_ms)
+        })
+        .collect();
+
+    if min_dwell_secs > 0 {
+        smooth_zone_runs(&mut zones, &deltas_ms, min_dwell_secs as i64 * 1000);
+    }
+
+    let mut zone_ms = [0i64; 5];
+    for (zone, delta_ms) in zones.iter().zip(&deltas_ms) {
+        if let Some(zone) = zone {
+            zone_ms[zone_index(*zone)] += delta_ms;
+        }
+    }
+
+    // Round once at the end so sub-second deltas are not truncated per sample
+    zone_ms.map(|ms| ((ms + 500) / 1000) as u32)
+}
+
+/// Seconds spent within `low..=high` BPM, timed as in [`time_in_zone_from_samples`].
+fn time_in_bpm_band(samples: &[HrSample], low: u16, high: u16) -> u32 {
+    let max_gap_ms = MAX_SAMPLE_GAP_SECS as i64 * 1000;
+    let band_ms: i64 = samples
+        .windows(2)
+        .filter(|pair| (low..=high).contains(&pair[0].bpm))
+        .map(|pair| {
+            pair[1]
+                .timestamp
+                .signed_duration_since(pair[0].timestamp)
+                .num_milliseconds()
+                .clamp(0, max_gap_ms)
+        })
+        .sum();
+    ((band_ms + 500) / 1000) as u32
+}
+
+/// Reassign runs of samples shorter than `min_dwell_ms` to the zone before them.
+///
+/// `deltas_ms[i]` is the time sample `i` accounts for; the last sample has
+/// none. A short run at the very start has no earlier zone and is kept.
+fn smooth_zone_runs(zones: &mut [Option<Zone>], deltas_ms: &[i64], min_dwell_ms: i64) {
+    let mut settled: Option<Option<Zone>> = None;
+    let mut start = 0;
+    while start < zones.len() {
+        let zone = zones[start];
+        let end = zones[start..]
+            .iter()
+            .position(|z| *z != zone)
+            .map_or(zones.len(), |len| start + len);
+        let dwell_ms: i64 = deltas_ms[start..end.min(deltas_ms.len())].iter().sum();
+
+        match settled {
+            Some(previous) if dwell_ms < min_dwell_ms => zones[start..end].fill(previous),
+            _ => settled = Some(zone),
+        }
+        start = end;
+    }
+}
+
+/// Compute min/avg/max BPM per zone (index 0 = Zone 1) from HR samples.
+///
+/// Each sample counts once towards the zone its BPM falls in; samples below
+/// Zone 1 are ignored. Zones without samples are `None`, as are all zones if
+/// `max_hr` is invalid for zone calculation.
+pub fn zone_hr_stats_from_samples(samples: &[HrSample], max_hr: u16) -> [Option<ZoneHrStat>; 5] {
+    RunningStats::from_samples(samples, Some(max_hr)).zone_hr_stats()
+}
+
+/// Heart rate statistics updated one sample at a time.
+///
+/// Tracks the mean and variance (Welford's algorithm), min and max, a BPM
+/// histogram for percentiles and, given a max HR, per-zone min/avg/max. The
+/// histogram has at most one entry per distinct BPM, so both updating and
+/// reading the statistics take constant time however long the session runs.
+/// The results equal the batch computations over the same samples, see
+/// [`SessionSummary::from_running_stats`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunningStats {
+    /// Max HR for zone tracking; `None` if zones are not tracked.
+    max_hr: Option<u16>,
+    count: u32,
+    sum: u64,
+    mean: f64,
+    /// Sum of squared deviations from the mean.
+    m2: f64,
+    min: Option<u16>,
+    max: Option<u16>,
+    /// Number of samples per BPM.
+    bpm_counts: BTreeMap<u16, u32>,
+    /// (min, max, sum, count) per zone.
+    zones: [Option<(u16, u16, u32, u32)>; 5],
+}
+
+impl RunningStats {
+    /// Create empty statistics, tracking zones for `max_hr` if given.
+    pub fn new(max_hr: Option<u16>) -> Self {
         Self {
-            duration_secs,
-            avg_hr,
             max_hr,
-            min_hr,
-            time_in_zone,
+            ..Self::default()
+        }
+    }
+
+    /// Statistics over `samples`.
+    pub fn from_samples(samples: &[HrSample], max_hr: Option<u16>) -> Self {
+        let mut stats = Self::new(max_hr);
+        for sample in samples {
+            stats.push(sample.bpm);
+        }
+        stats
+    }
+
+    /// Add a sample.
+    pub fn push(&mut self, bpm: u16) {
+        self.count += 1;
+        self.sum += bpm as u64;
+        let delta = bpm as f64 - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (bpm as f64 - self.mean);
+        self.min = Some(self.min.map_or(bpm, |min| min.min(bpm)));
+        self.max = Some(self.max.map_or(bpm, |max| max.max(bpm)));
+        *self.bpm_counts.entry(bpm).or_insert(0) += 1;
+
+        if let Some(max_hr) = self.max_hr {
+            match calculate_zone(bpm, max_hr) {
+                Ok(Some(zone)) => {
+                    let (min, max, sum, count) =
+                        self.zones[zone_index(zone)].get_or_insert((bpm, bpm, 0, 0));
+                    *min = (*min).min(bpm);
+                    *max = (*max).max(bpm);
+                    *sum += bpm as u32;
+                    *count += 1;
+                }
+                Ok(None) => {}
+                // An invalid max HR yields no zone statistics at all
+                Err(_) => self.max_hr = None,
+            }
+        }
+    }
+
+    /// Number of samples added.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Mean BPM, or `None` without samples.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Population variance of the BPMs, or `None` without samples.
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Population standard deviation of the BPMs, or `None` without samples.
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Lowest BPM, or `None` without samples.
+    pub fn min(&self) -> Option<u16> {
+        self.min
+    }
+
+    /// Highest BPM, or `None` without samples.
+    pub fn max(&self) -> Option<u16> {
+        self.max
+    }
+
+    /// Mean BPM rounded down, as stored in [`SessionSummary::avg_hr`] (0 without samples).
+    pub fn avg_bpm(&self) -> u16 {
+        if self.count == 0 {
+            return 0;
+        }
+        (self.sum / self.count as u64) as u16
+    }
+
+    /// Nearest-rank percentile of the BPMs (0 without samples).
+    ///
+    /// The result is the smallest BPM such that at least `percentile`% of the
+    /// samples are less than or equal to it, i.e. the value at rank
+    /// `ceil(percentile / 100 * n)`. It is always one of the samples.
+    pub fn percentile(&self, percentile: u32) -> u16 {
+        let rank = (percentile.min(100) as u64 * self.count as u64)
+            .div_ceil(100)
+            .max(1);
+        let mut seen = 0u64;
+        for (&bpm, &count) in &self.bpm_counts {
+            seen += count as u64;
+            if seen >= rank {
+                return bpm;
+            }
+        }
+        0
+    }
+
+    /// Min/avg/max BPM per zone (index 0 = Zone 1).
+    ///
+    /// All `None` if zones are not tracked or the max HR is invalid; see
+    /// [`zone_hr_stats_from_samples`].
+    pub fn zone_hr_stats(&self) -> [Option<ZoneHrStat>; 5] {
+        if self.max_hr.is_none() {
+            return [None; 5];
+        }
+        self.zones.map(|zone| {
+            zone.map(|(min_bpm, max_bpm, sum, count)| ZoneHrStat {
+                min_bpm,
+                avg_bpm: ((sum + count / 2) / count) as u16,
+                max_bpm,
+            })
+        })
+    }
+}
+
+/// Index of `zone` in `time_in_zone` arrays (0 = Zone 1).
+fn zone_index(zone: Zone) -> usize {
+    match zone {
+        Zone::Zone1 => 0,
+        Zone::Zone2 => 1,
+        Zone::Zone3 => 2,
+        Zone::Zone4 => 3,
+        Zone::Zone5 => 4,
+    }
+}
+
+/// Score (0-100) of how well a session hit the zone distribution of its plan.
+///
+/// The plan's target time per zone is the sum of the durations of the phases
+/// targeting that zone. Time the session spent in a zone counts up to that
+/// zone's target, so the score is the share of planned zone time achieved:
+/// time in untargeted zones, or beyond a zone's target, earns nothing.
+///
+/// Phases with an explicit BPM band ([`PhaseTarget::Bpm`]) are scored the
+/// same way per band, against the time the session's samples spent within
+/// it (timed as in [`time_in_zone_from_samples`]).
+///
+/// Returns 0.0 if the plan has no duration.
+pub fn adherence_score(session: &CompletedSession, plan: &TrainingPlan) -> f64 {
+    let mut target = [0u64; 5];
+    let mut band_target: BTreeMap<(u16, u16), u64> = BTreeMap::new();
+    for phase in &plan.phases {
+        match phase.effective_target() {
+            PhaseTarget::Zone(zone) => target[zone_index(zone)] += phase.duration_secs as u64,
+            PhaseTarget::Bpm { low, high } => {
+                *band_target.entry((low, high)).or_default() += phase.duration_secs as u64
+            }
+        }
+    }
+
+    let total: u64 = target.iter().chain(band_target.values()).sum();
+    if total == 0 {
+        return 0.0;
+    }
+
+    let zone_achieved: u64 = target
+        .iter()
+        .zip(session.summary.time_in_zone.iter())
+        .map(|(&target, &actual)| target.min(actual as u64))
+        .sum();
+    let band_achieved: u64 = band_target
+        .iter()
+        .map(|(&(low, high), &target)| {
+            target.min(time_in_bpm_band(&session.hr_samples, low, high) as u64)
+        })
+        .sum();
+
+    (zone_achieved + band_achieved) as f64 / total as f64 * 100.0
+}
+
+/// Shortest phase [`derive_plan`] produces; shorter zone segments are merged
+/// into a neighbouring phase.
+pub const MIN_DERIVED_PHASE_SECS: u32 = 60;
+
+/// Derive a training plan that approximates the structure of a session.
+///
+/// Segments the session's zone timeline (the zone of each HR sample against
+/// `max_hr`, timed as in [`time_in_zone_from_samples`]) into time-based
+/// phases, one per run of samples in the same zone. Segments shorter than
+/// [`MIN_DERIVED_PHASE_SECS`] are merged into the phase before them (the
+/// first into the phase after it). Samples below Zone 1 count as Zone 1.
+///
+/// The plan is named after the session's plan and dated at the session's end.
+///
+/// # Errors
+///
+/// Returns an error if `max_hr` is invalid for zone calculation or the
+/// session has too few samples to derive a valid plan.
+pub fn derive_plan(session: &CompletedSession, max_hr: u16) -> Result<TrainingPlan> {
+    let samples = &session.hr_samples;
+    let mut zones = Vec::with_capacity(samples.len());
+    for sample in samples {
+        zones.push(Some(
+            calculate_zone(sample.bpm, max_hr)?.unwrap_or(Zone::Zone1),
+        ));
+    }
+
+    let max_gap_ms = MAX_SAMPLE_GAP_SECS as i64 * 1000;
+    let deltas_ms: Vec<i64> = samples
+        .windows(2)
+        .map(|pair| {
+            pair[1]
+                .timestamp
+                .signed_duration_since(pair[0].timestamp)
+                .num_milliseconds()
+                .clamp(0, max_gap_ms)
+        })
+        .collect();
+
+    let min_phase_ms = MIN_DERIVED_PHASE_SECS as i64 * 1000;
+    smooth_zone_runs(&mut zones, &deltas_ms, min_phase_ms);
+
+    // Runs of the same zone, with the time they account for
+    let mut segments: Vec<(Zone, i64)> = Vec::new();
+    for (zone, delta_ms) in zones.iter().flatten().zip(&deltas_ms) {
+        match segments.last_mut() {
+            Some((last, ms)) if last == zone => *ms += delta_ms,
+            _ => segments.push((*zone, *delta_ms)),
+        }
+    }
+
+    // Smoothing keeps a short run at the very start; fold it into the next
+    if segments.len() > 1 && segments[0].1 < min_phase_ms {
+        let (_, ms) = segments.remove(0);
+        segments[0].1 += ms;
+    }
+
+    let phases: Vec<TrainingPhase> = segments
+        .into_iter()
+        .map(|(zone, ms)| TrainingPhase {
+            name: zone.label().to_string(),
+            target_zone: zone,
+            duration_secs: ((ms + 500) / 1000) as u32,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        })
+        .collect();
+
+    if phases.is_empty() {
+        bail!(
+            "Session '{}' has too few samples to derive a plan",
+            session.id
+        );
+    }
+
+    let plan = TrainingPlan {
+        name: session.plan_name.clone(),
+        phases,
+        created_at: session.end_time,
+        max_hr,
+        warmup_grace_secs: None,
+        resting_hr: None,
+    };
+    plan.validate()?;
+    Ok(plan)
+}
+
+/// Default window after the peak over which recovery heart rate is measured.
+pub const DEFAULT_RECOVERY_WINDOW_SECS: u32 = 60;
+
+/// Recovery heart rate: how far BPM dropped `window_secs` after the session's peak.
+///
+/// The peak is the first sample with the session's highest BPM; the drop is
+/// measured to the first sample at least `window_secs` later. A larger drop
+/// indicates better fitness.
+///
+/// Returns `None` if the session has no samples, or if the peak came less
+/// than `window_secs` before the last sample, so the recovery wasn't recorded.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::session_history::{recovery_hr, CompletedSession, HrSample, SessionStatus, SessionSummary};
+/// use chrono::{Duration, Utc};
+///
+/// let start = Utc::now();
+/// let hr_samples = vec![
+///     HrSample { timestamp: start, bpm: 175 },
+///     HrSample { timestamp: start + Duration::seconds(60), bpm: 150 },
+/// ];
+/// let session = CompletedSession {
+///     id: "test".to_string(),
+///     plan_name: "Test Plan".to_string(),
+///     start_time: start,
+///     end_time: start + Duration::seconds(60),
+///     status: SessionStatus::Completed,
+///     summary: SessionSummary::from_samples(&hr_samples, 60, [0; 5]),
+///     hr_samples,
+///     phases_completed: 1,
+///     context: None,
+///     phase_results: vec![],
+///     connection_summary: None,
+/// };
+///
+/// assert_eq!(recovery_hr(&session, 60), Some(25));
+/// assert_eq!(recovery_hr(&session, 120), None);
+/// ```
+pub fn recovery_hr(session: &CompletedSession, window_secs: u32) -> Option<u16> {
+    let samples = &session.hr_samples;
+    let peak_idx = samples
+        .iter()
+        .enumerate()
+        .fold(None::<(usize, u16)>, |best, (i, s)| match best {
+            Some((_, bpm)) if bpm >= s.bpm => best,
+            _ => Some((i, s.bpm)),
+        })?
+        .0;
+    let peak = samples[peak_idx];
+    let target = peak.timestamp + chrono::Duration::seconds(window_secs as i64);
+
+    samples[peak_idx..]
+        .iter()
+        .find(|s| s.timestamp >= target)
+        .map(|after| peak.bpm.saturating_sub(after.bpm))
+}
+
+/// Percentage (0-100) of the samples expected over `duration_secs` that were
+/// recorded, when one sample is expected every `sample_interval_secs`.
+///
+/// Dropped packets and sensor disconnects lower the value; a session without
+/// expected samples (zero duration) reports 0. Capped at 100, since straps may
+/// report slightly faster than nominal.
+pub fn data_completeness_pct(
+    sample_count: usize,
+    duration_secs: u32,
+    sample_interval_secs: u32,
+) -> f64 {
+    let expected = duration_secs / sample_interval_secs.max(1);
+    if expected == 0 {
+        return 0.0;
+    }
+    (sample_count as f64 * 100.0 / expected as f64).min(100.0)
+}
+
+/// Reduces a stream of BPM readings to one [`HrSample`] per interval.
+///
+/// Readings are grouped into buckets of `interval_secs`, starting at the first
+/// reading of each bucket. When a reading falls past the end of the current
+/// bucket, the bucket is emitted as a single sample carrying its start time and
+/// the rounded mean BPM. With an interval of 1 every reading is passed through
+/// unchanged.
+#[derive(Debug, Clone)]
+pub struct SampleDecimator {
+    /// Bucket length in seconds (at least 1).
+    interval_secs: u32,
+    /// Timestamp of the first reading in the current bucket.
+    bucket_start: Option<DateTime<Utc>>,
+    /// Sum of the BPMs in the current bucket.
+    bpm_sum: u32,
+    /// Number of readings in the current bucket.
+    count: u32,
+}
+
+impl SampleDecimator {
+    /// Create a decimator emitting one sample per `interval_secs` (values below 1 are treated as 1).
+    pub fn new(interval_secs: u32) -> Self {
+        Self {
+            interval_secs: interval_secs.max(1),
+            bucket_start: None,
+            bpm_sum: 0,
+            count: 0,
+        }
+    }
+
+    /// Bucket length in seconds.
+    pub fn interval_secs(&self) -> u32 {
+        self.interval_secs
+    }
+
+    /// Add a reading, returning the previous bucket's sample if this reading closed it.
+    pub fn push(&mut self, timestamp: DateTime<Utc>, bpm: u16) -> Option<HrSample> {
+        if self.interval_secs == 1 {
+            return Some(HrSample { timestamp, bpm });
         }
+
+        let mut completed = None;
+        if let Some(start) = self.bucket_start {
+            let elapsed_ms = timestamp.signed_duration_since(start).num_milliseconds();
+            if elapsed_ms >= self.interval_secs as i64 * 1000 {
+                completed = self.flush();
+            }
+        }
+
+        self.bucket_start.get_or_insert(timestamp);
+        self.bpm_sum += bpm as u32;
+        self.count += 1;
+        completed
+    }
+
+    /// Emit the partially filled bucket, if any, and start afresh.
+    pub fn flush(&mut self) -> Option<HrSample> {
+        let start = self.bucket_start.take()?;
+        let bpm = (self.bpm_sum + self.count / 2) / self.count;
+        self.bpm_sum = 0;
+        self.count = 0;
+        Some(HrSample {
+            timestamp: start,
+            bpm: bpm as u16,
+        })
+    }
+}
+
+impl Default for SampleDecimator {
+    fn default() -> Self {
+        Self::new(1)
     }
 }
 
@@ -173,8 +981,19 @@ mod tests {
                 avg_hr: 140,
                 max_hr: 160,
                 min_hr: 120,
+                hr_p50: 140,
+                hr_p90: 160,
+                hr_p95: 160,
                 time_in_zone: [0, 300, 0, 0, 0],
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         };
 
         assert_eq!(session.duration_secs(), 300);
@@ -215,4 +1034,587 @@ mod tests {
         assert_eq!(summary.max_hr, 0);
         assert_eq!(summary.min_hr, 0);
     }
+
+    #[test]
+    fn test_summary_percentiles_nearest_rank() {
+        let now = Utc::now();
+        // 20 samples, deliberately unsorted: 100, 105, ..., 195
+        let samples: Vec<HrSample> = (0..20u16)
+            .rev()
+            .map(|i| HrSample {
+                timestamp: now,
+                bpm: 100 + i * 5,
+            })
+            .collect();
+
+        let summary = SessionSummary::from_samples(&samples, 20, [0; 5]);
+
+        // p50: rank ceil(0.50 * 20) = 10 -> 145
+        // p90: rank ceil(0.90 * 20) = 18 -> 185
+        // p95: rank ceil(0.95 * 20) = 19 -> 190
+        assert_eq!(summary.hr_p50, 145);
+        assert_eq!(summary.hr_p90, 185);
+        assert_eq!(summary.hr_p95, 190);
+
+        // Ranks that are not whole numbers round up: ceil(0.9 * 7) = 7
+        let mut stats = RunningStats::new(None);
+        for bpm in [60, 61, 62, 63, 64, 65, 66] {
+            stats.push(bpm);
+        }
+        assert_eq!(stats.percentile(90), 66);
+        assert_eq!(stats.percentile(50), 63);
+    }
+
+    #[test]
+    fn test_summary_percentiles_single_and_empty() {
+        let single = [HrSample {
+            timestamp: Utc::now(),
+            bpm: 128,
+        }];
+        let summary = SessionSummary::from_samples(&single, 1, [0; 5]);
+        assert_eq!(
+            (summary.hr_p50, summary.hr_p90, summary.hr_p95),
+            (128, 128, 128)
+        );
+
+        let summary = SessionSummary::from_samples(&[], 0, [0; 5]);
+        assert_eq!((summary.hr_p50, summary.hr_p90, summary.hr_p95), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_zone_hr_stats_only_for_visited_zones() {
+        let now = Utc::now();
+        // max_hr 200: Zone 2 is 120-139, Zone 3 is 140-159
+        let samples: Vec<HrSample> = [122, 130, 135, 142, 150, 157, 90]
+            .into_iter()
+            .enumerate()
+            .map(|(i, bpm)| HrSample {
+                timestamp: now + chrono::Duration::seconds(i as i64),
+                bpm,
+            })
+            .collect();
+
+        let stats = zone_hr_stats_from_samples(&samples, 200);
+        assert_eq!(
+            stats,
+            [
+                None,
+                Some(ZoneHrStat {
+                    min_bpm: 122,
+                    avg_bpm: 129,
+                    max_bpm: 135
+                }),
+                Some(ZoneHrStat {
+                    min_bpm: 142,
+                    avg_bpm: 150,
+                    max_bpm: 157
+                }),
+                None,
+                None,
+            ]
+        );
+
+        let summary = SessionSummary::from_samples(&samples, 7, [0; 5]).with_zone_hr_stats(stats);
+        assert_eq!(summary.zone_hr_stats, stats);
+
+        // Invalid max_hr yields no statistics
+        assert_eq!(zone_hr_stats_from_samples(&samples, 50), [None; 5]);
+    }
+
+    #[test]
+    fn test_phase_result_target_met() {
+        let start = Utc::now();
+        let phase = TrainingPhase::timed("Steady", Zone::Zone2, 60);
+        // max_hr 200: Zone 2 is 120-139
+        let samples = |in_zone_secs: i64| -> Vec<HrSample> {
+            (0..=60)
+                .map(|i| HrSample {
+                    timestamp: start + chrono::Duration::seconds(i),
+                    bpm: if i < in_zone_secs { 130 } else { 150 },
+                })
+                .collect()
+        };
+        let end = start + chrono::Duration::seconds(60);
+
+        // 50 of 60 seconds in zone
+        let mostly_in = PhaseResult::from_samples(&phase, start, end, &samples(50), 200, 0);
+        assert!(mostly_in.target_met);
+        assert_eq!(mostly_in.phase_name, "Steady");
+        assert_eq!(mostly_in.min_hr, 130);
+        assert_eq!(mostly_in.max_hr, 150);
+
+        // 20 of 60 seconds in zone
+        let mostly_out = PhaseResult::from_samples(&phase, start, end, &samples(20), 200, 0);
+        assert!(!mostly_out.target_met);
+
+        // 40 of 60 seconds in zone falls short, unless 20 of them were paused
+        let partly = PhaseResult::from_samples(&phase, start, end, &samples(40), 200, 0);
+        assert!(!partly.target_met);
+        let paused = PhaseResult::from_samples(&phase, start, end, &samples(40), 200, 20);
+        assert!(paused.target_met);
+
+        // No samples within the phase
+        let empty = PhaseResult::from_samples(
+            &phase,
+            end,
+            end + chrono::Duration::seconds(60),
+            &[],
+            200,
+            0,
+        );
+        assert!(!empty.target_met);
+        assert_eq!(empty.avg_hr, 0);
+    }
+
+    #[test]
+    fn test_running_stats_match_batch_computation() {
+        // An hour of 1 Hz samples drifting through several zones
+        let bpms: Vec<u16> = (0..3600u32)
+            .map(|i| (110 + (i * 7919) % 73 + i / 60) as u16)
+            .collect();
+
+        let mut stats = RunningStats::new(Some(190));
+        for &bpm in &bpms {
+            stats.push(bpm);
+        }
+
+        let n = bpms.len() as f64;
+        let mean = bpms.iter().map(|&b| b as f64).sum::<f64>() / n;
+        let variance = bpms.iter().map(|&b| (b as f64 - mean).powi(2)).sum::<f64>() / n;
+        let mut sorted = bpms.clone();
+        sorted.sort_unstable();
+
+        assert_eq!(stats.count(), 3600);
+        assert!((stats.mean().unwrap() - mean).abs() < 1e-9);
+        assert!((stats.variance().unwrap() - variance).abs() < 1e-6);
+        assert_eq!(stats.min(), sorted.first().copied());
+        assert_eq!(stats.max(), sorted.last().copied());
+        assert_eq!(
+            stats.avg_bpm() as u32,
+            bpms.iter().map(|&b| b as u32).sum::<u32>() / 3600
+        );
+        assert_eq!(stats.percentile(90), sorted[3240 - 1]);
+
+        // Per-zone accumulators match a pass over the samples grouped by zone
+        for (index, zone_stat) in stats.zone_hr_stats().iter().enumerate() {
+            let in_zone: Vec<u16> = bpms
+                .iter()
+                .copied()
+                .filter(|&b| calculate_zone(b, 190).unwrap().map(zone_index) == Some(index))
+                .collect();
+            let expected = (!in_zone.is_empty()).then(|| {
+                let sum: u32 = in_zone.iter().map(|&b| b as u32).sum();
+                let count = in_zone.len() as u32;
+                ZoneHrStat {
+                    min_bpm: *in_zone.iter().min().unwrap(),
+                    avg_bpm: ((sum + count / 2) / count) as u16,
+                    max_bpm: *in_zone.iter().max().unwrap(),
+                }
+            });
+            assert_eq!(*zone_stat, expected, "zone index {}", index);
+        }
+        assert!(stats.zone_hr_stats().iter().filter(|z| z.is_some()).count() >= 3);
+
+        assert_eq!(RunningStats::new(None).mean(), None);
+        assert_eq!(RunningStats::new(None).percentile(50), 0);
+    }
+
+    #[test]
+    fn test_data_completeness_of_half_recorded_session() {
+        let start = Utc::now();
+        // 600s session, but only every other second made it through
+        let samples: Vec<HrSample> = (0..300)
+            .map(|i| HrSample {
+                timestamp: start + chrono::Duration::seconds(i * 2),
+                bpm: 140,
+            })
+            .collect();
+        let summary = SessionSummary::from_samples(&samples, 600, [0; 5]);
+        assert_eq!(summary.data_completeness_pct, Some(50.0));
+
+        let mut session = CompletedSession {
+            id: "gappy".to_string(),
+            plan_name: "Test Plan".to_string(),
+            start_time: start,
+            end_time: start + chrono::Duration::seconds(600),
+            status: SessionStatus::Completed,
+            hr_samples: samples,
+            phases_completed: 1,
+            summary,
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
+        };
+        assert!((session.data_completeness_pct() - 50.0).abs() < 0.01);
+        assert!(!session.is_high_quality());
+
+        // Older sessions without the stored value are computed from their samples
+        session.summary.data_completeness_pct = None;
+        assert!((session.data_completeness_pct() - 50.0).abs() < 0.01);
+
+        // Samples stored at a coarse interval are measured against that interval
+        assert_eq!(data_completeness_pct(120, 600, 5), 100.0);
+        assert_eq!(data_completeness_pct(0, 0, 1), 0.0);
+    }
+
+    #[test]
+    fn test_summary_without_percentiles_deserializes() {
+        let json = r#"{"duration_secs": 60, "avg_hr": 140, "max_hr": 150, "min_hr": 130,
+            "time_in_zone": [0, 0, 60, 0, 0]}"#;
+        let summary: SessionSummary = serde_json::from_str(json).unwrap();
+        assert_eq!(summary.avg_hr, 140);
+        assert_eq!(summary.hr_p90, 0);
+    }
+
+    #[test]
+    fn test_session_context_round_trips_and_is_optional() {
+        let mut session = session_with_time_in_zone([0; 5]);
+
+        // Sessions saved before context existed have no "context" key
+        let mut legacy = serde_json::to_value(&session).unwrap();
+        legacy.as_object_mut().unwrap().remove("context");
+        let restored: CompletedSession = serde_json::from_value(legacy).unwrap();
+        assert_eq!(restored.context, None);
+        assert_eq!(restored, session);
+
+        session.context = Some(SessionContextMeta {
+            temperature_c: Some(-2.5),
+            rpe: 4,
+            conditions: None,
+        });
+        let json = serde_json::to_string(&session).unwrap();
+        let restored: CompletedSession = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, session);
+    }
+
+    #[test]
+    fn test_recovery_hr_after_peak() {
+        let start = Utc::now();
+        // Build up to 180 BPM at 120s, then decline 0.5 BPM per second
+        let samples: Vec<HrSample> = (0..=240)
+            .map(|i| HrSample {
+                timestamp: start + chrono::Duration::seconds(i),
+                bpm: if i <= 120 {
+                    120 + (i / 2) as u16
+                } else {
+                    180 - ((i - 120) / 2) as u16
+                },
+            })
+            .collect();
+        let mut session = session_with_time_in_zone([0; 5]);
+        session.hr_samples = samples;
+
+        // 60s after the peak: 180 -> 150
+        assert_eq!(
+            recovery_hr(&session, DEFAULT_RECOVERY_WINDOW_SECS),
+            Some(30)
+        );
+        assert_eq!(recovery_hr(&session, 120), Some(60));
+        // The session ends 120s after the peak
+        assert_eq!(recovery_hr(&session, 121), None);
+
+        session.hr_samples.clear();
+        assert_eq!(recovery_hr(&session, 60), None);
+    }
+
+    #[test]
+    fn test_recovery_hr_peak_too_close_to_end() {
+        let start = Utc::now();
+        let mut session = session_with_time_in_zone([0; 5]);
+        session.hr_samples = vec![
+            HrSample {
+                timestamp: start,
+                bpm: 140,
+            },
+            HrSample {
+                timestamp: start + chrono::Duration::seconds(50),
+                bpm: 185,
+            },
+            HrSample {
+                timestamp: start + chrono::Duration::seconds(80),
+                bpm: 170,
+            },
+        ];
+        assert_eq!(recovery_hr(&session, 60), None);
+        assert_eq!(recovery_hr(&session, 30), Some(15));
+    }
+
+    fn adherence_test_plan() -> TrainingPlan {
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+
+        let phase = |name: &str, target_zone, duration_secs| TrainingPhase {
+            name: name.to_string(),
+            target_zone,
+            duration_secs,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        TrainingPlan {
+            name: "Intervals".to_string(),
+            phases: vec![
+                phase("Warmup", Zone::Zone2, 300),
+                phase("Work", Zone::Zone4, 600),
+                phase("Cooldown", Zone::Zone2, 300),
+            ],
+            created_at: Utc::now(),
+            max_hr: 190,
+            ..Default::default()
+        }
+    }
+
+    fn session_with_time_in_zone(time_in_zone: [u32; 5]) -> CompletedSession {
+        let start = Utc::now();
+        CompletedSession {
+            id: "adherence".to_string(),
+            plan_name: "Intervals".to_string(),
+            start_time: start,
+            end_time: start + chrono::Duration::seconds(1200),
+            status: SessionStatus::Completed,
+            hr_samples: vec![],
+            phases_completed: 3,
+            summary: SessionSummary::from_samples(&[], 1200, time_in_zone),
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
+        }
+    }
+
+    #[test]
+    fn test_adherence_score_perfect_and_wrong_zones() {
+        let plan = adherence_test_plan();
+
+        // Targets: 600s in Zone 2, 600s in Zone 4
+        let perfect = session_with_time_in_zone([0, 600, 0, 600, 0]);
+        assert!((adherence_score(&perfect, &plan) - 100.0).abs() < 1e-9);
+
+        // Almost perfect: a few seconds drifted into Zone 3
+        let close = session_with_time_in_zone([0, 590, 10, 600, 0]);
+        assert!(adherence_score(&close, &plan) > 99.0);
+
+        // The whole session in zones the plan never targets
+        let wrong = session_with_time_in_zone([600, 0, 0, 0, 600]);
+        assert_eq!(adherence_score(&wrong, &plan), 0.0);
+
+        // Half the work done, and extra Zone 2 time earns nothing beyond target
+        let partial = session_with_time_in_zone([0, 900, 0, 300, 0]);
+        assert!((adherence_score(&partial, &plan) - 75.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_adherence_score_uses_bpm_targets() {
+        // The work phase holds 160-165 BPM instead of Zone 4
+        let mut plan = adherence_test_plan();
+        plan.phases[1].target = Some(PhaseTarget::Bpm {
+            low: 160,
+            high: 165,
+        });
+
+        let start = Utc::now();
+        let work_at = |bpm: u16| -> Vec<HrSample> {
+            (0..=600)
+                .map(|secs| sample_at(start, secs * 1000, bpm))
+                .collect()
+        };
+
+        let mut on_band = session_with_time_in_zone([0, 600, 0, 600, 0]);
+        on_band.hr_samples = work_at(162);
+        assert!((adherence_score(&on_band, &plan) - 100.0).abs() < 1e-9);
+
+        // In Zone 4, but above the band: the work phase earns nothing
+        let mut above_band = session_with_time_in_zone([0, 600, 0, 600, 0]);
+        above_band.hr_samples = work_at(172);
+        assert!((adherence_score(&above_band, &plan) - 50.0).abs() < 1e-9);
+    }
+
+    fn sample_at(start: DateTime<Utc>, offset_ms: i64, bpm: u16) -> HrSample {
+        HrSample {
+            timestamp: start + chrono::Duration::milliseconds(offset_ms),
+            bpm,
+        }
+    }
+
+    #[test]
+    fn test_derive_plan_segments_zone_structure() {
+        // max_hr 200: Zone1 = 100-119, Zone2 = 120-139, Zone4 = 160-179
+        let start = Utc::now();
+        let mut session = session_with_time_in_zone([0; 5]);
+        session.hr_samples = (0..=900)
+            .map(|secs| {
+                let bpm = match secs {
+                    0..=299 => 130,
+                    // A brief spike into Zone 3 is merged into the warmup
+                    300..=309 => 150,
+                    310..=599 => 130,
+                    600..=779 => 170,
+                    _ => 110,
+                };
+                sample_at(start, secs * 1000, bpm)
+            })
+            .collect();
+
+        let plan = derive_plan(&session, 200).unwrap();
+        let zones: Vec<Zone> = plan.phases.iter().map(|p| p.target_zone).collect();
+        assert_eq!(zones, vec![Zone::Zone2, Zone::Zone4, Zone::Zone1]);
+        let durations: Vec<u32> = plan.phases.iter().map(|p| p.duration_secs).collect();
+        assert_eq!(durations, vec![600, 180, 120]);
+        assert_eq!(plan.max_hr, 200);
+        assert!(plan
+            .phases
+            .iter()
+            .all(|p| p.transition == TransitionCondition::TimeElapsed));
+
+        // Nothing to derive from an empty session or against an invalid max HR
+        assert!(derive_plan(&session_with_time_in_zone([0; 5]), 200).is_err());
+        assert!(derive_plan(&session, 50).is_err());
+    }
+
+    #[test]
+    fn test_time_in_zone_weights_by_actual_deltas() {
+        let start = Utc::now();
+        // max_hr 200: Zone2 = 120-139, Zone3 = 140-159, Zone4 = 160-179
+        let samples = vec![
+            sample_at(start, 0, 130),      // Zone2 for 1.5s
+            sample_at(start, 1_500, 130),  // Zone2 for 0.5s
+            sample_at(start, 2_000, 150),  // Zone3 for 2.0s
+            sample_at(start, 4_000, 150),  // Zone3, 16s dropout capped at 5s
+            sample_at(start, 20_000, 170), // Zone4 for 1.0s
+            sample_at(start, 21_000, 170), // last sample, no successor
+        ];
+
+        assert_eq!(time_in_zone_from_samples(&samples, 200), [0, 2, 7, 1, 0]);
+    }
+
+    #[test]
+    fn test_time_in_zone_jitter_sums_to_elapsed_time() {
+        let start = Utc::now();
+        let samples = vec![
+            sample_at(start, 0, 130),
+            sample_at(start, 800, 131),
+            sample_at(start, 2_100, 129),
+            sample_at(start, 2_900, 130),
+            sample_at(start, 4_000, 132),
+            sample_at(start, 6_000, 130),
+        ];
+
+        let tiz = time_in_zone_from_samples(&samples, 200);
+        assert_eq!(tiz, [0, 6, 0, 0, 0]);
+        assert_eq!(tiz.iter().sum::<u32>(), 6);
+    }
+
+    #[test]
+    fn test_time_in_zone_invalid_max_hr_or_too_few_samples() {
+        let start = Utc::now();
+        let samples = vec![sample_at(start, 0, 130), sample_at(start, 1_000, 130)];
+        assert_eq!(time_in_zone_from_samples(&samples, 50), [0; 5]);
+        assert_eq!(time_in_zone_from_samples(&samples[..1], 200), [0; 5]);
+    }
+
+    #[test]
+    fn test_new_session_id_unique_and_sortable() {
+        let ids: Vec<String> = (0..10_000).map(|_| new_session_id()).collect();
+
+        let unique: std::collections::HashSet<&String> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+
+        let mut sorted = ids.clone();
+        sorted.sort();
+        assert_eq!(sorted, ids, "IDs should sort in creation order");
+    }
+
+    #[test]
+    fn test_decimator_averages_each_bucket() {
+        let start = Utc::now();
+        let mut decimator = SampleDecimator::new(5);
+
+        // 20 seconds at 1 Hz with BPM rising by one each second
+        let mut recorded: Vec<HrSample> = (0..20)
+            .filter_map(|i| decimator.push(start + chrono::Duration::seconds(i), 100 + i as u16))
+            .collect();
+        recorded.extend(decimator.flush());
+
+        let bpms: Vec<u16> = recorded.iter().map(|s| s.bpm).collect();
+        assert_eq!(bpms, vec![102, 107, 112, 117]);
+        for (i, sample) in recorded.iter().enumerate() {
+            assert_eq!(
+                sample.timestamp,
+                start + chrono::Duration::seconds(i as i64 * 5)
+            );
+        }
+        assert!(decimator.flush().is_none());
+    }
+
+    #[test]
+    fn test_decimator_interval_one_passes_through() {
+        let start = Utc::now();
+        let mut decimator = SampleDecimator::new(0);
+        assert_eq!(decimator.interval_secs(), 1);
+
+        let sample = decimator.push(start, 131).unwrap();
+        assert_eq!(sample, sample_at(start, 0, 131));
+        // Two readings within the same second are both kept
+        assert!(decimator
+            .push(start + chrono::Duration::milliseconds(200), 132)
+            .is_some());
+        assert!(decimator.flush().is_none());
+    }
+
+    #[test]
+    fn test_time_in_zone_with_max_gap_counts_coarse_samples() {
+        let start = Utc::now();
+        let samples = vec![
+            sample_at(start, 0, 130),
+            sample_at(start, 10_000, 130),
+            sample_at(start, 20_000, 130),
+        ];
+        // Default cap treats 10s spacing as dropouts
+        assert_eq!(time_in_zone_from_samples(&samples, 200), [0, 10, 0, 0, 0]);
+        assert_eq!(
+            time_in_zone_with_max_gap(&samples, 200, 10),
+            [0, 20, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_time_in_zone_smoothing_ignores_brief_excursion() {
+        let start = Utc::now();
+        // 1 Hz: 5s in Zone3, a 1s blip into Zone4, then 4s more in Zone3
+        let bpms = [150, 150, 150, 150, 150, 170, 150, 150, 150, 150, 150];
+        let samples: Vec<HrSample> = bpms
+            .iter()
+            .enumerate()
+            .map(|(i, &bpm)| sample_at(start, i as i64 * 1000, bpm))
+            .collect();
+
+        assert_eq!(
+            time_in_zone_smoothed(&samples, 200, MAX_SAMPLE_GAP_SECS, 0),
+            [0, 0, 9, 1, 0]
+        );
+        assert_eq!(
+            time_in_zone_smoothed(&samples, 200, MAX_SAMPLE_GAP_SECS, 3),
+            [0, 0, 10, 0, 0]
+        );
+        // Smoothing off matches the unsmoothed accounting
+        assert_eq!(
+            time_in_zone_from_samples(&samples, 200),
+            time_in_zone_smoothed(&samples, 200, MAX_SAMPLE_GAP_SECS, 0)
+        );
+    }
+
+    #[test]
+    fn test_time_in_zone_smoothing_counts_sustained_change() {
+        let start = Utc::now();
+        // 3s in Zone3, then 4s in Zone4: long enough to count as a change
+        let bpms = [150, 150, 150, 170, 170, 170, 170, 170];
+        let samples: Vec<HrSample> = bpms
+            .iter()
+            .enumerate()
+            .map(|(i, &bpm)| sample_at(start, i as i64 * 1000, bpm))
+            .collect();
+
+        assert_eq!(
+            time_in_zone_smoothed(&samples, 200, MAX_SAMPLE_GAP_SECS, 3),
+            [0, 0, 3, 4, 0]
+        );
+    }
 }
@@ -5,6 +5,7 @@
 //! to be lightweight and FRB-compatible for efficient cross-language serialization.
 
 use crate::domain::heart_rate::Zone;
+use crate::domain::training_plan::PhaseTarget;
 use serde::{Deserialize, Serialize};
 
 /// Current state of a workout session for UI updates.
@@ -34,6 +35,54 @@ pub struct SessionProgress {
 
     /// Details about the current phase progress.
     pub phase_progress: PhaseProgress,
+
+    /// Overall plan completion as a percentage (0.0 to 100.0).
+    ///
+    /// Time-based for plans made of timed phases. When the plan contains
+    /// HR-based transitions its total duration is indeterminate, so this is
+    /// estimated from the phase index instead.
+    #[serde(default)]
+    pub completion_pct: f64,
+
+    /// Projected wall-clock end of the session, in milliseconds since the Unix epoch.
+    ///
+    /// Computed as the snapshot time plus `total_remaining_secs`. Time already
+    /// spent paused is not added on top, but while the session is paused the
+    /// remaining time is frozen, so the projection moves later in real time.
+    #[serde(default)]
+    pub projected_end_ms: u64,
+
+    /// State of the sensor link during the session.
+    ///
+    /// While this is not [`ConnectivityDuringSession::Connected`] the session
+    /// keeps timing but no HR is available, so `current_bpm` is 0.
+    #[serde(default)]
+    pub connectivity: ConnectivityDuringSession,
+
+    /// Whether `current_bpm` comes from a recently received HR sample.
+    ///
+    /// `false` once no new sample has arrived within the executor's freshness
+    /// window, so the UI can gray out a BPM that is no longer live.
+    #[serde(default)]
+    pub bpm_is_fresh: bool,
+
+    /// Epoch of the session this update belongs to.
+    ///
+    /// Every started session gets a new, higher epoch, so subscribers can
+    /// drop updates left over from an earlier session. 0 if unknown.
+    #[serde(default)]
+    pub session_epoch: u64,
+
+    /// Banister TRIMP accumulated so far in this session.
+    ///
+    /// Integrated once per running second from the current BPM using the
+    /// plan's resting and max HR. Paused time does not count.
+    #[serde(default)]
+    pub trimp: f64,
+
+    /// Average TRIMP per running minute, a live effort gauge.
+    #[serde(default)]
+    pub trimp_per_min: f64,
 }
 
 /// Execution state of a workout session.
@@ -52,6 +101,27 @@ pub enum SessionState {
     Stopped,
 }
 
+/// State of the sensor link while a session runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectivityDuringSession {
+    /// Sensor connected, HR data is flowing.
+    #[default]
+    Connected,
+
+    /// Link dropped and a reconnection is in progress.
+    Reconnecting,
+
+    /// Link dropped and no reconnection is in progress.
+    Disconnected,
+}
+
+impl ConnectivityDuringSession {
+    /// Whether HR data is available for zone logic.
+    pub fn hr_available(&self) -> bool {
+        matches!(self, ConnectivityDuringSession::Connected)
+    }
+}
+
 /// Progress information for the currently executing phase.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhaseProgress {
@@ -64,11 +134,27 @@ pub struct PhaseProgress {
     /// Target heart rate zone for this phase.
     pub target_zone: Zone,
 
+    /// Explicit target of the phase, if it has one (see
+    /// [`TrainingPhase::target`](crate::domain::training_plan::TrainingPhase::target)).
+    /// Zone status is then judged against it rather than `target_zone`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<PhaseTarget>,
+
     /// Time elapsed in this phase in seconds.
     pub elapsed_secs: u32,
 
     /// Time remaining in this phase in seconds.
     pub remaining_secs: u32,
+
+    /// Consecutive seconds the heart rate has held the phase's target, for
+    /// phases that end on `HeartRateReached`; 0 for timed phases.
+    #[serde(default)]
+    pub hr_hold_elapsed_secs: u32,
+
+    /// Seconds the target must be held to finish a `HeartRateReached` phase;
+    /// 0 for timed phases.
+    #[serde(default)]
+    pub hr_hold_required_secs: u32,
 }
 
 /// Status of current heart rate relative to the target zone.
@@ -87,6 +173,34 @@ pub enum ZoneStatus {
     TooHigh,
 }
 
+/// A change of the active phase during session execution.
+///
+/// Emitted once per transition so the UI can react to phase changes (animate,
+/// announce) without diffing consecutive [`SessionProgress`] snapshots. The
+/// final transition, when the last phase finishes, has `to_index` equal to the
+/// number of phases and no target phase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseTransition {
+    /// Index of the phase that just ended (0-based).
+    pub from_index: u32,
+
+    /// Index of the phase that just started, or the phase count on completion.
+    pub to_index: u32,
+
+    /// Name of the phase that just started (`None` on completion).
+    pub to_phase_name: Option<String>,
+
+    /// Target zone of the phase that just started (`None` on completion).
+    pub to_zone: Option<Zone>,
+}
+
+impl PhaseTransition {
+    /// Whether this transition marks the completion of the session.
+    pub fn is_completion(&self) -> bool {
+        self.to_phase_name.is_none()
+    }
+}
+
 impl SessionProgress {
     /// Calculate the total duration of the session in seconds.
     pub fn total_duration_secs(&self) -> u32 {
@@ -103,6 +217,12 @@ impl SessionProgress {
     }
 }
 
+/// Projected end time (Unix epoch milliseconds) of a session with
+/// `remaining_secs` left, as seen at `now_ms`.
+pub fn projected_end_ms(now_ms: u64, remaining_secs: u32) -> u64 {
+    now_ms.saturating_add(remaining_secs as u64 * 1000)
+}
+
 impl PhaseProgress {
     /// Calculate the total duration of this phase in seconds.
     pub fn duration_secs(&self) -> u32 {
@@ -136,9 +256,19 @@ mod tests {
                 phase_index: 0,
                 phase_name: "Warmup".to_string(),
                 target_zone: Zone::Zone2,
+                target: None,
                 elapsed_secs: 300,
                 remaining_secs: 300,
+                hr_hold_elapsed_secs: 0,
+                hr_hold_required_secs: 0,
             },
+            completion_pct: 33.3,
+            projected_end_ms: 0,
+            connectivity: ConnectivityDuringSession::Connected,
+            bpm_is_fresh: true,
+            session_epoch: 1,
+            trimp: 0.0,
+            trimp_per_min: 0.0,
         };
 
         assert_eq!(progress.total_duration_secs(), 900);
@@ -151,14 +281,36 @@ mod tests {
             phase_index: 1,
             phase_name: "Work".to_string(),
             target_zone: Zone::Zone4,
+            target: None,
             elapsed_secs: 100,
             remaining_secs: 200,
+            hr_hold_elapsed_secs: 0,
+            hr_hold_required_secs: 0,
         };
 
         assert_eq!(phase.duration_secs(), 300);
         assert!((phase.progress_fraction() - 0.333).abs() < 0.01);
     }
 
+    #[test]
+    fn test_phase_transition_is_completion() {
+        let next = PhaseTransition {
+            from_index: 0,
+            to_index: 1,
+            to_phase_name: Some("Work".to_string()),
+            to_zone: Some(Zone::Zone4),
+        };
+        let done = PhaseTransition {
+            from_index: 1,
+            to_index: 2,
+            to_phase_name: None,
+            to_zone: None,
+        };
+
+        assert!(!next.is_completion());
+        assert!(done.is_completion());
+    }
+
     #[test]
     fn test_zone_status_variants() {
         // Ensure all variants can be created and are distinct
@@ -189,9 +341,19 @@ mod tests {
                 phase_index: 0,
                 phase_name: "Warmup".to_string(),
                 target_zone: Zone::Zone2,
+                target: None,
                 elapsed_secs: 0,
                 remaining_secs: 0,
+                hr_hold_elapsed_secs: 0,
+                hr_hold_required_secs: 0,
             },
+            completion_pct: 0.0,
+            projected_end_ms: 0,
+            connectivity: ConnectivityDuringSession::Connected,
+            bpm_is_fresh: true,
+            session_epoch: 1,
+            trimp: 0.0,
+            trimp_per_min: 0.0,
         };
 
         // Should return 0.0 when total duration is 0
@@ -205,8 +367,11 @@ mod tests {
             phase_index: 0,
             phase_name: "Quick Phase".to_string(),
             target_zone: Zone::Zone2,
+            target: None,
             elapsed_secs: 0,
             remaining_secs: 0,
+            hr_hold_elapsed_secs: 0,
+            hr_hold_required_secs: 0,
         };
 
         // Should return 0.0 when duration is 0
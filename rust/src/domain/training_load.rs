@@ -1,7 +1,9 @@
 //! Training load domain logic for computing TRIMP, CTL, ATL, and TSB.
 //!
 //! Pure computation functions that operate on completed session data to produce
-//! training load metrics using Edwards TRIMP and exponential moving averages.
+//! training load metrics using TRIMP and exponential moving averages. Sessions
+//! use the Banister TRIMP accumulated live ([`LiveTrimp`]); sessions saved
+//! before it was recorded fall back to Edwards TRIMP.
 //! These metrics help athletes monitor fitness, fatigue, and form over time.
 
 use crate::domain::session_history::CompletedSession;
@@ -17,6 +19,13 @@ const CTL_DAYS: f64 = 42.0;
 /// Number of days for Acute Training Load (fatigue) EMA.
 const ATL_DAYS: f64 = 7.0;
 
+/// Resting HR assumed for live TRIMP when the plan does not set one.
+pub const DEFAULT_RESTING_HR: u16 = 60;
+
+/// Banister TRIMP intensity coefficients (weighting = A * e^(B * HRr)).
+const BANISTER_A: f64 = 0.64;
+const BANISTER_B: f64 = 1.92;
+
 /// Aggregated TRIMP score for a single calendar day.
 #[derive(Debug, Clone, PartialEq)]
 pub struct DailyTrimp {
@@ -44,19 +53,24 @@ pub struct TrainingLoadMetrics {
     pub date: NaiveDate,
 }
 
-/// Compute Edwards TRIMP score for a single completed session.
+/// Compute the TRIMP score for a single completed session.
 ///
-/// Edwards TRIMP weights time spent in each heart rate zone by a zone factor:
-/// Zone 1 = 1x, Zone 2 = 2x, Zone 3 = 3x, Zone 4 = 4x, Zone 5 = 5x.
-/// Time is converted from seconds to minutes before weighting.
+/// This is the Banister TRIMP recorded during the session, so it matches the
+/// live value shown in [`SessionProgress`](crate::domain::session_progress::SessionProgress).
+/// Sessions saved without it fall back to Edwards TRIMP, which weights time
+/// spent in each heart rate zone by a zone factor: Zone 1 = 1x, Zone 2 = 2x,
+/// Zone 3 = 3x, Zone 4 = 4x, Zone 5 = 5x. Time is converted from seconds to
+/// minutes before weighting.
 pub fn compute_session_trimp(session: &CompletedSession) -> f64 {
-    session
-        .summary
-        .time_in_zone
-        .iter()
-        .zip(ZONE_FACTORS.iter())
-        .map(|(&secs, &factor)| (secs as f64 / 60.0) * factor)
-        .sum()
+    session.summary.trimp.unwrap_or_else(|| {
+        session
+            .summary
+            .time_in_zone
+            .iter()
+            .zip(ZONE_FACTORS.iter())
+            .map(|(&secs, &factor)| (secs as f64 / 60.0) * factor)
+            .sum()
+    })
 }
 
 /// Aggregate TRIMP scores per calendar day from completed sessions.
@@ -129,6 +143,58 @@ pub fn current_training_load(daily_trimp: &[DailyTrimp]) -> Option<TrainingLoadM
     compute_training_load(daily_trimp).into_iter().last()
 }
 
+/// Banister TRIMP accrued per minute at `bpm`.
+///
+/// Uses the heart rate reserve fraction HRr = (bpm - resting) / (max - resting),
+/// clamped to 0..=1, weighted as HRr * 0.64 * e^(1.92 * HRr). Returns 0.0 when
+/// `max_hr` is not above `resting_hr`.
+pub fn banister_trimp_per_minute(bpm: u16, resting_hr: u16, max_hr: u16) -> f64 {
+    if max_hr <= resting_hr {
+        return 0.0;
+    }
+    let reserve = (bpm as f64 - resting_hr as f64) / (max_hr as f64 - resting_hr as f64);
+    let reserve = reserve.clamp(0.0, 1.0);
+    reserve * BANISTER_A * (BANISTER_B * reserve).exp()
+}
+
+/// TRIMP accumulated live while a session runs.
+///
+/// Fed once per active second with the current BPM; seconds without HR still
+/// count towards the active time so TRIMP/min reflects the whole session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LiveTrimp {
+    trimp: f64,
+    active_secs: u32,
+}
+
+impl LiveTrimp {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Account for one active second at `bpm` (`None` if no HR is available).
+    pub fn add_second(&mut self, bpm: Option<u16>, resting_hr: u16, max_hr: u16) {
+        self.active_secs += 1;
+        if let Some(bpm) = bpm {
+            self.trimp += banister_trimp_per_minute(bpm, resting_hr, max_hr) / 60.0;
+        }
+    }
+
+    /// TRIMP accumulated so far.
+    pub fn trimp(&self) -> f64 {
+        self.trimp
+    }
+
+    /// Average TRIMP per active minute (0.0 before the first second).
+    pub fn trimp_per_minute(&self) -> f64 {
+        if self.active_secs == 0 {
+            return 0.0;
+        }
+        self.trimp / (self.active_secs as f64 / 60.0)
+    }
+}
+
 /// Advance a `NaiveDate` by one day.
 fn next_day(date: NaiveDate) -> NaiveDate {
     date.succ_opt().expect("date within valid range")
@@ -178,8 +244,19 @@ mod tests {
                 avg_hr,
                 max_hr: avg_hr + 20,
                 min_hr: avg_hr.saturating_sub(20),
+                hr_p50: avg_hr,
+                hr_p90: avg_hr + 20,
+                hr_p95: avg_hr + 20,
                 time_in_zone: tiz,
+                zone_hr_stats: [None; 5],
+                data_completeness_pct: None,
+                elapsed_wall_secs: None,
+                active_duration_secs: None,
+                trimp: None,
             },
+            context: None,
+            phase_results: Vec::new(),
+            connection_summary: None,
         }
     }
 
@@ -214,6 +291,13 @@ mod tests {
         assert!((compute_session_trimp(&s) - 25.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn trimp_prefers_recorded_live_trimp() {
+        let mut s = make_session("s1", "2026-03-01", 300, 180, [0, 0, 0, 0, 300]);
+        s.summary.trimp = Some(12.5);
+        assert_eq!(compute_session_trimp(&s), 12.5);
+    }
+
     // --- compute_daily_trimp ---
 
     #[test]
@@ -333,6 +417,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn banister_rate_follows_heart_rate_reserve() {
+        assert_eq!(banister_trimp_per_minute(60, 60, 180), 0.0);
+        assert_eq!(banister_trimp_per_minute(50, 60, 180), 0.0);
+        // Half of HR reserve: 0.5 * 0.64 * e^0.96
+        let half = banister_trimp_per_minute(120, 60, 180);
+        assert!((half - 0.5 * 0.64 * 0.96_f64.exp()).abs() < 1e-10);
+        // Above max HR is clamped to the full reserve
+        assert_eq!(
+            banister_trimp_per_minute(200, 60, 180),
+            banister_trimp_per_minute(180, 60, 180)
+        );
+        assert_eq!(banister_trimp_per_minute(150, 180, 180), 0.0);
+    }
+
+    #[test]
+    fn live_trimp_accumulates_linearly_at_constant_hr() {
+        let rate = banister_trimp_per_minute(150, 60, 190);
+        let mut live = LiveTrimp::new();
+        assert_eq!(live.trimp_per_minute(), 0.0);
+
+        for _ in 0..600 {
+            live.add_second(Some(150), 60, 190);
+        }
+        let after_10_min = live.trimp();
+        for _ in 0..600 {
+            live.add_second(Some(150), 60, 190);
+        }
+
+        assert!((after_10_min - 10.0 * rate).abs() < 1e-9);
+        assert!((live.trimp() - 2.0 * after_10_min).abs() < 1e-9);
+        assert!((live.trimp_per_minute() - rate).abs() < 1e-9);
+    }
+
+    #[test]
+    fn live_trimp_counts_seconds_without_hr_as_active() {
+        let mut live = LiveTrimp::new();
+        for _ in 0..60 {
+            live.add_second(Some(150), 60, 190);
+        }
+        for _ in 0..60 {
+            live.add_second(None, 60, 190);
+        }
+        let rate = banister_trimp_per_minute(150, 60, 190);
+        assert!((live.trimp() - rate).abs() < 1e-9);
+        assert!((live.trimp_per_minute() - rate / 2.0).abs() < 1e-9);
+    }
+
     // --- current_training_load ---
 
     #[test]
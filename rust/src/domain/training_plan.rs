@@ -4,10 +4,11 @@
 //! multiple phases, automatic zone transitions, and validation. All types are
 //! pure data structures with no I/O dependencies.
 
-use crate::domain::heart_rate::Zone;
+use crate::domain::heart_rate::{BpmBounds, PhysioSimulator, Zone};
 use anyhow::{anyhow, bail, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// A complete training plan with multiple phases.
 ///
@@ -29,10 +30,14 @@ use serde::{Deserialize, Serialize};
 ///             target_zone: Zone::Zone2,
 ///             duration_secs: 600,
 ///             transition: TransitionCondition::TimeElapsed,
+///             alerts_enabled: true,
+///             target: None,
 ///         },
 ///     ],
 ///     created_at: Utc::now(),
 ///     max_hr: 180,
+///     warmup_grace_secs: None,
+///     resting_hr: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -51,12 +56,25 @@ pub struct TrainingPlan {
     /// Used for zone calculation. Typically 220 - age, but should be
     /// personalized through testing for accuracy.
     pub max_hr: u16,
+
+    /// Seconds at the start of a session during which zone-deviation alerts
+    /// are suppressed while HR rises. `None` uses the executor's default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warmup_grace_secs: Option<u32>,
+
+    /// User's resting heart rate in BPM.
+    ///
+    /// Used with `max_hr` for heart-rate-reserve metrics such as live TRIMP.
+    /// `None` falls back to a typical adult resting HR.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resting_hr: Option<u16>,
 }
 
 /// A single phase within a training plan.
 ///
 /// Each phase has a target heart rate zone, expected duration, and
 /// transition condition that determines when to move to the next phase.
+/// A phase may instead prescribe an explicit BPM band (see [`PhaseTarget`]).
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TrainingPhase {
     /// Human-readable name for this phase (e.g., "Warmup", "Work", "Recovery").
@@ -73,6 +91,128 @@ pub struct TrainingPhase {
 
     /// Condition that triggers transition to the next phase.
     pub transition: TransitionCondition,
+
+    /// Whether zone deviation alerts are sent during this phase.
+    ///
+    /// Disable for phases that intentionally leave the target zone, such as
+    /// hard intervals. Defaults to `true` when missing from stored plans.
+    #[serde(default = "default_alerts_enabled")]
+    pub alerts_enabled: bool,
+
+    /// Target that zone status, deviation alerts and pace cues are judged
+    /// against. `None` (the default for stored plans) targets `target_zone`.
+    ///
+    /// `target_zone` still labels the phase and is used for time-in-zone
+    /// accounting, so set it to the zone closest to a BPM band.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<PhaseTarget>,
+}
+
+fn default_alerts_enabled() -> bool {
+    true
+}
+
+/// Heart rate a phase asks the user to hold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PhaseTarget {
+    /// Stay within a heart rate zone, relative to the plan's max HR.
+    Zone(Zone),
+
+    /// Stay within an explicit BPM band, e.g. "hold 150" as 148-152.
+    Bpm {
+        /// Lowest BPM of the band, inclusive.
+        low: u16,
+        /// Highest BPM of the band, inclusive.
+        high: u16,
+    },
+}
+
+impl PhaseTarget {
+    /// Inclusive BPM band of this target for a plan with `max_hr`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::training_plan::PhaseTarget;
+    /// use heart_beat::domain::heart_rate::Zone;
+    ///
+    /// assert_eq!(PhaseTarget::Zone(Zone::Zone3).bpm_bounds(200).unwrap(), (140, 159));
+    /// assert_eq!(PhaseTarget::Bpm { low: 148, high: 152 }.bpm_bounds(200).unwrap(), (148, 152));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for a zone target if `max_hr` is invalid (see
+    /// [`zone_bpm_bounds`]).
+    pub fn bpm_bounds(&self, max_hr: u16) -> Result<(u16, u16)> {
+        match *self {
+            PhaseTarget::Zone(zone) => zone_bpm_bounds(zone, max_hr),
+            PhaseTarget::Bpm { low, high } => Ok((low, high)),
+        }
+    }
+
+    /// This target with a BPM band scaled by `ratio`.
+    ///
+    /// Zone targets are already relative to max HR and are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::training_plan::PhaseTarget;
+    ///
+    /// let band = PhaseTarget::Bpm { low: 148, high: 152 };
+    /// assert_eq!(band.scaled(190.0 / 180.0), PhaseTarget::Bpm { low: 156, high: 160 });
+    /// ```
+    pub fn scaled(self, ratio: f64) -> Self {
+        match self {
+            PhaseTarget::Zone(_) => self,
+            PhaseTarget::Bpm { low, high } => PhaseTarget::Bpm {
+                low: (low as f64 * ratio).round() as u16,
+                high: (high as f64 * ratio).round() as u16,
+            },
+        }
+    }
+}
+
+impl From<Zone> for PhaseTarget {
+    fn from(zone: Zone) -> Self {
+        PhaseTarget::Zone(zone)
+    }
+}
+
+impl fmt::Display for PhaseTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhaseTarget::Zone(zone) => f.write_str(&zone.display_name()),
+            PhaseTarget::Bpm { low, high } => write!(f, "{}-{} BPM", low, high),
+        }
+    }
+}
+
+impl TrainingPhase {
+    /// Create a phase that transitions after `duration_secs`.
+    pub fn timed(name: &str, target_zone: Zone, duration_secs: u32) -> Self {
+        Self {
+            name: name.to_string(),
+            target_zone,
+            duration_secs,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        }
+    }
+
+    /// Judge this phase against `target` instead of its zone.
+    pub fn with_target(mut self, target: PhaseTarget) -> Self {
+        self.target = Some(target);
+        self
+    }
+
+    /// The target this phase is judged against: [`target`](Self::target) if
+    /// set, otherwise [`target_zone`](Self::target_zone).
+    pub fn effective_target(&self) -> PhaseTarget {
+        self.target.unwrap_or(PhaseTarget::Zone(self.target_zone))
+    }
 }
 
 /// Condition that determines when to transition to the next phase.
@@ -146,7 +286,223 @@ pub fn calculate_zone(bpm: u16, max_hr: u16) -> Result<Option<Zone>> {
     }
 }
 
+/// Get the inclusive BPM range that [`calculate_zone`] maps to a zone.
+///
+/// The bounds follow from the zone's percentage thresholds of `max_hr`: the
+/// lowest BPM at or above the zone's lower threshold, up to the BPM before the
+/// next zone's. Zone 5 is capped at `max_hr`.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::training_plan::zone_bpm_bounds;
+/// use heart_beat::domain::heart_rate::Zone;
+///
+/// // Zone 3 at 200 max_hr = 70-80% = 140-159 BPM
+/// assert_eq!(zone_bpm_bounds(Zone::Zone3, 200).unwrap(), (140, 159));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if max_hr is invalid (<100 or >220).
+pub fn zone_bpm_bounds(zone: Zone, max_hr: u16) -> Result<(u16, u16)> {
+    if !(100..=220).contains(&max_hr) {
+        return Err(anyhow!("Invalid max_hr: {} (must be 100-220)", max_hr));
+    }
+
+    // Lowest BPM at or above `pct` percent of max_hr
+    let threshold = |pct: u32| (pct * max_hr as u32).div_ceil(100) as u16;
+    let (lower_pct, upper_pct) = match zone {
+        Zone::Zone1 => (50, Some(60)),
+        Zone::Zone2 => (60, Some(70)),
+        Zone::Zone3 => (70, Some(80)),
+        Zone::Zone4 => (80, Some(90)),
+        Zone::Zone5 => (90, None),
+    };
+    let upper = upper_pct.map_or(max_hr, |pct| threshold(pct) - 1);
+    Ok((threshold(lower_pct), upper))
+}
+
+/// BPM by which a session's peak must exceed the plan's max HR before a
+/// revised max HR is suggested, so a single noisy reading doesn't count.
+pub const MAX_HR_EXCEEDED_MARGIN_BPM: u16 = 5;
+
+/// Suggest a revised max HR when a session peaked above the configured one.
+///
+/// Returns `observed_max` if it exceeds `configured_max` by at least
+/// [`MAX_HR_EXCEEDED_MARGIN_BPM`], meaning the plan's zones are too low.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::training_plan::suggest_max_hr;
+///
+/// assert_eq!(suggest_max_hr(195, 185), Some(195));
+/// assert_eq!(suggest_max_hr(187, 185), None);
+/// ```
+pub fn suggest_max_hr(observed_max: u16, configured_max: u16) -> Option<u16> {
+    (observed_max >= configured_max.saturating_add(MAX_HR_EXCEEDED_MARGIN_BPM))
+        .then_some(observed_max)
+}
+
+impl Default for TrainingPlan {
+    /// An empty plan created now for a max HR of 180 BPM, with no warmup grace
+    /// or resting HR set. Fill in the rest with struct update syntax.
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            phases: Vec::new(),
+            created_at: Utc::now(),
+            max_hr: 180,
+            warmup_grace_secs: None,
+            resting_hr: None,
+        }
+    }
+}
+
 impl TrainingPlan {
+    /// Create a plan that holds one zone for the whole duration.
+    ///
+    /// The single phase is named after the plan and transitions on time.
+    /// The plan is not validated; call [`validate`](Self::validate) before use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::training_plan::TrainingPlan;
+    /// use heart_beat::domain::heart_rate::Zone;
+    ///
+    /// // Hold Zone 2 for 45 minutes
+    /// let plan = TrainingPlan::steady("Zone 2 Base", Zone::Zone2, 45 * 60, 185);
+    /// assert_eq!(plan.phases.len(), 1);
+    /// assert!(plan.validate().is_ok());
+    /// ```
+    pub fn steady(name: &str, zone: Zone, duration_secs: u32, max_hr: u16) -> Self {
+        Self {
+            name: name.to_string(),
+            phases: vec![TrainingPhase::timed(name, zone, duration_secs)],
+            created_at: Utc::now(),
+            max_hr,
+            warmup_grace_secs: None,
+            resting_hr: None,
+        }
+    }
+
+    /// Simulate HR following this plan from `start_bpm`.
+    ///
+    /// Each phase lasts its `duration_secs` and targets the midpoint of its
+    /// [effective target](TrainingPhase::effective_target) at the plan's max HR.
+    pub fn hr_simulator(&self, start_bpm: u16) -> PhysioSimulator {
+        let targets: Vec<(u32, f64)> = self
+            .phases
+            .iter()
+            .map(|phase| {
+                let target = phase
+                    .effective_target()
+                    .bpm_bounds(self.max_hr)
+                    .map(|(low, high)| (low as f64 + high as f64) / 2.0)
+                    .unwrap_or(start_bpm as f64);
+                (phase.duration_secs, target)
+            })
+            .collect();
+        PhysioSimulator::new(&targets, start_bpm)
+    }
+
+    /// Create a plan of `reps` repetitions of a work phase followed by a rest phase.
+    ///
+    /// Phases are named "Interval N" and "Recovery N" (1-based) and transition on
+    /// time. Add a warmup or cooldown with [`with_warmup`](Self::with_warmup) and
+    /// [`with_cooldown`](Self::with_cooldown). The plan is not validated.
+    pub fn interval(
+        name: &str,
+        work_zone: Zone,
+        work_secs: u32,
+        rest_zone: Zone,
+        rest_secs: u32,
+        reps: u32,
+        max_hr: u16,
+    ) -> Self {
+        let phases = (1..=reps)
+            .flat_map(|rep| {
+                [
+                    TrainingPhase::timed(&format!("Interval {}", rep), work_zone, work_secs),
+                    TrainingPhase::timed(&format!("Recovery {}", rep), rest_zone, rest_secs),
+                ]
+            })
+            .collect();
+
+        Self {
+            name: name.to_string(),
+            phases,
+            created_at: Utc::now(),
+            max_hr,
+            warmup_grace_secs: None,
+            resting_hr: None,
+        }
+    }
+
+    /// Prepend a timed "Warmup" phase.
+    pub fn with_warmup(mut self, zone: Zone, duration_secs: u32) -> Self {
+        self.phases
+            .insert(0, TrainingPhase::timed("Warmup", zone, duration_secs));
+        self
+    }
+
+    /// Append a timed "Cooldown" phase.
+    pub fn with_cooldown(mut self, zone: Zone, duration_secs: u32) -> Self {
+        self.phases
+            .push(TrainingPhase::timed("Cooldown", zone, duration_secs));
+        self
+    }
+
+    /// Chain several plans into one, running their phases back to back.
+    ///
+    /// Phases keep their order and names. All plans must share the same
+    /// `max_hr`, since zones are computed from it; plans built for different
+    /// max HRs would silently change intensity when merged. The warmup grace
+    /// of the first plan applies to the whole session. The result is not
+    /// validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `plans` is empty or the plans' max HRs differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::training_plan::TrainingPlan;
+    /// use heart_beat::domain::heart_rate::Zone;
+    ///
+    /// let bike = TrainingPlan::steady("Bike", Zone::Zone2, 3600, 185);
+    /// let run = TrainingPlan::steady("Run", Zone::Zone3, 1200, 185);
+    /// let brick = TrainingPlan::concat(&[bike, run], "Brick").unwrap();
+    /// assert_eq!(brick.phases.len(), 2);
+    /// ```
+    pub fn concat(plans: &[TrainingPlan], name: &str) -> Result<Self> {
+        let first = plans
+            .first()
+            .ok_or_else(|| anyhow!("Cannot compose an empty list of plans"))?;
+
+        if let Some(other) = plans.iter().find(|p| p.max_hr != first.max_hr) {
+            bail!(
+                "Plans use different max HR: '{}' has {} BPM, '{}' has {} BPM",
+                first.name,
+                first.max_hr,
+                other.name,
+                other.max_hr
+            );
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            phases: plans.iter().flat_map(|p| p.phases.clone()).collect(),
+            created_at: Utc::now(),
+            max_hr: first.max_hr,
+            warmup_grace_secs: first.warmup_grace_secs,
+            resting_hr: first.resting_hr,
+        })
+    }
+
     /// Validate that the training plan is well-formed.
     ///
     /// Checks:
@@ -154,6 +510,7 @@ impl TrainingPlan {
     /// - All phase durations are positive
     /// - Total duration is less than 4 hours (14400 seconds)
     /// - HeartRateReached targets are physiologically valid (30-220 BPM)
+    /// - The resting HR, if set, is physiologically valid and below `max_hr`
     ///
     /// # Returns
     ///
@@ -172,6 +529,8 @@ impl TrainingPlan {
     ///     phases: vec![],
     ///     created_at: Utc::now(),
     ///     max_hr: 180,
+    ///     warmup_grace_secs: None,
+    ///     resting_hr: None,
     /// };
     ///
     /// // Empty plan should fail validation
@@ -183,6 +542,8 @@ impl TrainingPlan {
     ///     target_zone: Zone::Zone3,
     ///     duration_secs: 1200,
     ///     transition: TransitionCondition::TimeElapsed,
+    ///     alerts_enabled: true,
+    ///     target: None,
     /// });
     ///
     /// // Now should be valid
@@ -198,6 +559,17 @@ impl TrainingPlan {
             bail!("Plan exceeds 4 hours (total: {}s)", total_secs);
         }
 
+        if let Some(resting_hr) = self.resting_hr {
+            BpmBounds::PHYSIOLOGICAL.check("Resting HR", resting_hr)?;
+            if resting_hr >= self.max_hr {
+                bail!(
+                    "Resting HR {} must be below max HR {}",
+                    resting_hr,
+                    self.max_hr
+                );
+            }
+        }
+
         for (idx, phase) in self.phases.iter().enumerate() {
             if phase.duration_secs == 0 {
                 bail!("Phase {} '{}' has zero duration", idx, phase.name);
@@ -205,12 +577,30 @@ impl TrainingPlan {
 
             // Validate HeartRateReached targets
             if let TransitionCondition::HeartRateReached { target_bpm, .. } = phase.transition {
-                if !(30..=220).contains(&target_bpm) {
+                let bounds = BpmBounds::PHYSIOLOGICAL;
+                if !bounds.contains(target_bpm) {
                     bail!(
-                        "Phase {} '{}' has invalid target_bpm: {} (must be 30-220)",
+                        "Phase {} '{}' has invalid target_bpm: {} (must be {}-{})",
                         idx,
                         phase.name,
-                        target_bpm
+                        target_bpm,
+                        bounds.min,
+                        bounds.max
+                    );
+                }
+            }
+
+            if let Some(PhaseTarget::Bpm { low, high }) = phase.target {
+                let bounds = BpmBounds::PHYSIOLOGICAL;
+                if !bounds.contains(low) || !bounds.contains(high) || low > high {
+                    bail!(
+                        "Phase {} '{}' has invalid target band: {}-{} BPM (must be within {}-{})",
+                        idx,
+                        phase.name,
+                        low,
+                        high,
+                        bounds.min,
+                        bounds.max
                     );
                 }
             }
@@ -218,6 +608,447 @@ impl TrainingPlan {
 
         Ok(())
     }
+
+    /// Validate the plan and report non-fatal issues.
+    ///
+    /// Runs [`validate`](Self::validate) first; a plan that fails it is an
+    /// error. Otherwise returns the warnings (possibly none), which a user
+    /// may choose to ignore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::training_plan::{PlanWarning, TrainingPlan};
+    /// use heart_beat::domain::heart_rate::Zone;
+    ///
+    /// let plan = TrainingPlan::steady("Threshold", Zone::Zone4, 600, 180)
+    ///     .with_cooldown(Zone::Zone5, 300);
+    /// assert_eq!(
+    ///     plan.validate_with_warnings().unwrap(),
+    ///     vec![PlanWarning::NoRecoveryBetweenIntervals { first_phase: 0, second_phase: 1 }]
+    /// );
+    /// ```
+    pub fn validate_with_warnings(&self) -> Result<Vec<PlanWarning>> {
+        self.validate()?;
+
+        let is_hard =
+            |phase: &TrainingPhase| matches!(phase.target_zone, Zone::Zone4 | Zone::Zone5);
+        let warnings = self
+            .phases
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| is_hard(&pair[0]) && is_hard(&pair[1]))
+            .map(|(idx, _)| PlanWarning::NoRecoveryBetweenIntervals {
+                first_phase: idx,
+                second_phase: idx + 1,
+            })
+            .collect();
+        Ok(warnings)
+    }
+
+    /// Rate how hard the plan is, from 1 (easy) to 5 (very hard).
+    ///
+    /// Each phase contributes its duration in minutes times a zone weight
+    /// (1, 2, 3, 5 and 8 for Zone 1-5), so time in the high zones counts
+    /// far more than time in the low ones. The weighted minutes are then
+    /// bucketed into the 1-5 scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::training_plan::TrainingPlan;
+    /// use heart_beat::domain::heart_rate::Zone;
+    ///
+    /// let easy = TrainingPlan::steady("Easy", Zone::Zone1, 30 * 60, 180);
+    /// let hard = TrainingPlan::steady("Hard", Zone::Zone5, 30 * 60, 180);
+    /// assert_eq!(easy.difficulty(), 1);
+    /// assert!(hard.difficulty() > easy.difficulty());
+    /// ```
+    pub fn difficulty(&self) -> u8 {
+        let weighted_mins: f64 = self
+            .phases
+            .iter()
+            .map(|phase| {
+                let weight = match phase.target_zone {
+                    Zone::Zone1 => 1.0,
+                    Zone::Zone2 => 2.0,
+                    Zone::Zone3 => 3.0,
+                    Zone::Zone4 => 5.0,
+                    Zone::Zone5 => 8.0,
+                };
+                phase.duration_secs as f64 / 60.0 * weight
+            })
+            .sum();
+
+        match weighted_mins {
+            m if m < 60.0 => 1,
+            m if m < 120.0 => 2,
+            m if m < 200.0 => 3,
+            m if m < 320.0 => 4,
+            _ => 5,
+        }
+    }
+
+    /// Summarize the plan for display in a plan list.
+    pub fn summary(&self) -> PlanSummary {
+        PlanSummary {
+            name: self.name.clone(),
+            total_duration_secs: self.phases.iter().map(|p| p.duration_secs).sum(),
+            phase_count: self.phases.len() as u32,
+            difficulty: self.difficulty(),
+        }
+    }
+}
+
+/// Non-fatal issue in a training plan, see [`TrainingPlan::validate_with_warnings`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlanWarning {
+    /// Two consecutive phases both target Zone 4 or Zone 5, with no
+    /// recovery phase between them.
+    NoRecoveryBetweenIntervals {
+        /// Index of the first hard phase (0-based).
+        first_phase: usize,
+        /// Index of the hard phase that follows it.
+        second_phase: usize,
+    },
+}
+
+impl fmt::Display for PlanWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanWarning::NoRecoveryBetweenIntervals {
+                first_phase,
+                second_phase,
+            } => write!(
+                f,
+                "Phases {} and {} are both high intensity with no recovery between them",
+                first_phase + 1,
+                second_phase + 1
+            ),
+        }
+    }
+}
+
+/// One difference between two versions of a plan, see [`diff`].
+///
+/// Phases are compared by position: a phase at the same index in both plans
+/// that differs in any field is reported as modified.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PlanChange {
+    /// The plan was renamed.
+    NameChanged {
+        /// Previous name.
+        old: String,
+        /// New name.
+        new: String,
+    },
+
+    /// The max HR used for zones changed.
+    MaxHrChanged {
+        /// Previous max HR in BPM.
+        old: u16,
+        /// New max HR in BPM.
+        new: u16,
+    },
+
+    /// The resting HR changed.
+    RestingHrChanged {
+        /// Previous resting HR in BPM.
+        old: Option<u16>,
+        /// New resting HR in BPM.
+        new: Option<u16>,
+    },
+
+    /// The warmup grace period changed.
+    WarmupGraceChanged {
+        /// Previous grace in seconds.
+        old: Option<u32>,
+        /// New grace in seconds.
+        new: Option<u32>,
+    },
+
+    /// A phase exists only in the new plan.
+    PhaseAdded {
+        /// Index of the phase in the new plan (0-based).
+        index: usize,
+        /// The added phase.
+        phase: TrainingPhase,
+    },
+
+    /// A phase exists only in the old plan.
+    PhaseRemoved {
+        /// Index of the phase in the old plan (0-based).
+        index: usize,
+        /// The removed phase.
+        phase: TrainingPhase,
+    },
+
+    /// The phase at `index` differs between the plans.
+    PhaseModified {
+        /// Index of the phase (0-based).
+        index: usize,
+        /// The phase in the old plan.
+        old: TrainingPhase,
+        /// The phase in the new plan.
+        new: TrainingPhase,
+    },
+}
+
+impl fmt::Display for PlanChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlanChange::NameChanged { old, new } => write!(f, "Renamed '{}' to '{}'", old, new),
+            PlanChange::MaxHrChanged { old, new } => {
+                write!(f, "Max HR changed from {} to {} BPM", old, new)
+            }
+            PlanChange::RestingHrChanged { old, new } => write!(
+                f,
+                "Resting HR changed from {} to {}",
+                optional_value(*old, "BPM"),
+                optional_value(*new, "BPM")
+            ),
+            PlanChange::WarmupGraceChanged { old, new } => write!(
+                f,
+                "Warmup grace changed from {} to {}",
+                optional_value(*old, "s"),
+                optional_value(*new, "s")
+            ),
+            PlanChange::PhaseAdded { index, phase } => {
+                write!(f, "Added phase {} '{}'", index + 1, phase.name)
+            }
+            PlanChange::PhaseRemoved { index, phase } => {
+                write!(f, "Removed phase {} '{}'", index + 1, phase.name)
+            }
+            PlanChange::PhaseModified { index, new, .. } => {
+                write!(f, "Changed phase {} '{}'", index + 1, new.name)
+            }
+        }
+    }
+}
+
+fn optional_value(value: Option<impl fmt::Display>, unit: &str) -> String {
+    match value {
+        Some(value) => format!("{} {}", value, unit),
+        None => "default".to_string(),
+    }
+}
+
+/// List the differences between two versions of a plan.
+///
+/// Compares the name, max HR, resting HR and warmup grace, then the phases
+/// position by position; see [`PlanChange`]. The creation time is ignored.
+/// Identical plans yield no changes.
+///
+/// # Examples
+///
+/// ```
+/// use heart_beat::domain::training_plan::{diff, PlanChange, TrainingPlan};
+/// use heart_beat::domain::heart_rate::Zone;
+///
+/// let old = TrainingPlan::steady("Easy", Zone::Zone2, 1800, 180);
+/// let mut new = old.clone();
+/// new.max_hr = 185;
+///
+/// assert_eq!(diff(&old, &new), vec![PlanChange::MaxHrChanged { old: 180, new: 185 }]);
+/// ```
+pub fn diff(old: &TrainingPlan, new: &TrainingPlan) -> Vec<PlanChange> {
+    let mut changes = Vec::new();
+
+    if old.name != new.name {
+        changes.push(PlanChange::NameChanged {
+            old: old.name.clone(),
+            new: new.name.clone(),
+        });
+    }
+    if old.max_hr != new.max_hr {
+        changes.push(PlanChange::MaxHrChanged {
+            old: old.max_hr,
+            new: new.max_hr,
+        });
+    }
+    if old.resting_hr != new.resting_hr {
+        changes.push(PlanChange::RestingHrChanged {
+            old: old.resting_hr,
+            new: new.resting_hr,
+        });
+    }
+    if old.warmup_grace_secs != new.warmup_grace_secs {
+        changes.push(PlanChange::WarmupGraceChanged {
+            old: old.warmup_grace_secs,
+            new: new.warmup_grace_secs,
+        });
+    }
+
+    for (index, (old_phase, new_phase)) in old.phases.iter().zip(&new.phases).enumerate() {
+        if old_phase != new_phase {
+            changes.push(PlanChange::PhaseModified {
+                index,
+                old: old_phase.clone(),
+                new: new_phase.clone(),
+            });
+        }
+    }
+    let common = old.phases.len().min(new.phases.len());
+    for (index, phase) in new.phases.iter().enumerate().skip(common) {
+        changes.push(PlanChange::PhaseAdded {
+            index,
+            phase: phase.clone(),
+        });
+    }
+    for (index, phase) in old.phases.iter().enumerate().skip(common) {
+        changes.push(PlanChange::PhaseRemoved {
+            index,
+            phase: phase.clone(),
+        });
+    }
+
+    changes
+}
+
+/// Overview of a training plan for choosing a workout.
+///
+/// Lets a plan list show length and effort without the full phase list.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanSummary {
+    /// Name of the plan.
+    pub name: String,
+
+    /// Sum of all phase durations in seconds.
+    pub total_duration_secs: u32,
+
+    /// Number of phases in the plan.
+    pub phase_count: u32,
+
+    /// Difficulty from 1 (easy) to 5 (very hard), see [`TrainingPlan::difficulty`].
+    pub difficulty: u8,
+}
+
+/// A reusable plan shape that is turned into concrete plans.
+///
+/// A template leaves the athlete-specific parts open: the max HR is supplied
+/// when instantiating, and phase durations are scaled by a factor, so one
+/// "40-min tempo" template yields a 20-min or 60-min tempo for any athlete.
+///
+/// `HeartRateReached` targets are written for `reference_max_hr` and are
+/// rescaled to the instantiated max HR so they keep the same relative
+/// intensity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanTemplate {
+    /// Human-readable name of the template.
+    pub name: String,
+
+    /// Phases at their unscaled durations.
+    pub phases: Vec<TrainingPhase>,
+
+    /// Max HR that absolute BPM targets in `phases` were written for.
+    pub reference_max_hr: u16,
+
+    /// Warmup grace copied into instantiated plans.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warmup_grace_secs: Option<u32>,
+
+    /// Resting HR copied into instantiated plans.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resting_hr: Option<u16>,
+}
+
+/// Values substituted into a [`PlanTemplate`] when instantiating it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateParams {
+    /// Name of the resulting plan.
+    pub name: String,
+
+    /// Max HR of the athlete the plan is for.
+    pub max_hr: u16,
+
+    /// Factor applied to every phase duration (2.0 doubles the plan length).
+    pub duration_scale: f64,
+}
+
+impl PlanTemplate {
+    /// Create a template with the shape of an existing plan.
+    pub fn from_plan(plan: &TrainingPlan) -> Self {
+        Self {
+            name: plan.name.clone(),
+            phases: plan.phases.clone(),
+            reference_max_hr: plan.max_hr,
+            warmup_grace_secs: plan.warmup_grace_secs,
+            resting_hr: plan.resting_hr,
+        }
+    }
+
+    /// Build a concrete plan from the template.
+    ///
+    /// Phase durations are multiplied by `duration_scale` and rounded to whole
+    /// seconds (at least 1). Hold times of `HeartRateReached` phases are not
+    /// scaled. `HeartRateReached` targets and BPM-band targets are scaled by
+    /// the ratio of the new max HR to the template's. The result is validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scale is not a positive number, the template
+    /// has no reference max HR, or the resulting plan is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use heart_beat::domain::training_plan::{PlanTemplate, TemplateParams, TrainingPlan};
+    /// use heart_beat::domain::heart_rate::Zone;
+    ///
+    /// let template = PlanTemplate::from_plan(&TrainingPlan::steady("Tempo", Zone::Zone3, 1200, 180));
+    /// let plan = template
+    ///     .instantiate(&TemplateParams {
+    ///         name: "Long Tempo".to_string(),
+    ///         max_hr: 190,
+    ///         duration_scale: 1.5,
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(plan.max_hr, 190);
+    /// assert_eq!(plan.phases[0].duration_secs, 1800);
+    /// ```
+    pub fn instantiate(&self, params: &TemplateParams) -> Result<TrainingPlan> {
+        let scale = params.duration_scale;
+        if !scale.is_finite() || scale <= 0.0 {
+            bail!("Duration scale must be a positive number, got {}", scale);
+        }
+        if self.reference_max_hr == 0 {
+            bail!("Template '{}' has no reference max HR", self.name);
+        }
+
+        let hr_ratio = params.max_hr as f64 / self.reference_max_hr as f64;
+        let phases = self
+            .phases
+            .iter()
+            .map(|phase| TrainingPhase {
+                name: phase.name.clone(),
+                target_zone: phase.target_zone,
+                duration_secs: ((phase.duration_secs as f64 * scale).round() as u32).max(1),
+                transition: match phase.transition {
+                    TransitionCondition::TimeElapsed => TransitionCondition::TimeElapsed,
+                    TransitionCondition::HeartRateReached {
+                        target_bpm,
+                        hold_secs,
+                    } => TransitionCondition::HeartRateReached {
+                        target_bpm: (target_bpm as f64 * hr_ratio).round() as u16,
+                        hold_secs,
+                    },
+                },
+                alerts_enabled: phase.alerts_enabled,
+                target: phase.target.map(|target| target.scaled(hr_ratio)),
+            })
+            .collect();
+
+        let plan = TrainingPlan {
+            name: params.name.clone(),
+            phases,
+            created_at: Utc::now(),
+            max_hr: params.max_hr,
+            warmup_grace_secs: self.warmup_grace_secs,
+            resting_hr: self.resting_hr,
+        };
+        plan.validate()?;
+        Ok(plan)
+    }
 }
 
 #[cfg(test)]
@@ -277,6 +1108,41 @@ mod tests {
         assert_eq!(calculate_zone(190, max_hr).unwrap(), Some(Zone::Zone5));
     }
 
+    #[test]
+    fn test_zone_bpm_bounds() {
+        assert_eq!(zone_bpm_bounds(Zone::Zone1, 200).unwrap(), (100, 119));
+        assert_eq!(zone_bpm_bounds(Zone::Zone3, 200).unwrap(), (140, 159));
+        assert_eq!(zone_bpm_bounds(Zone::Zone5, 200).unwrap(), (180, 200));
+        assert!(zone_bpm_bounds(Zone::Zone3, 50).is_err());
+
+        // Bounds agree with calculate_zone at the edges
+        let (lower, upper) = zone_bpm_bounds(Zone::Zone2, 185).unwrap();
+        assert_eq!(calculate_zone(lower, 185).unwrap(), Some(Zone::Zone2));
+        assert_eq!(calculate_zone(upper, 185).unwrap(), Some(Zone::Zone2));
+        assert_eq!(calculate_zone(lower - 1, 185).unwrap(), Some(Zone::Zone1));
+        assert_eq!(calculate_zone(upper + 1, 185).unwrap(), Some(Zone::Zone3));
+
+        // ... and for every zone at every valid max_hr
+        for max_hr in 100..=220 {
+            for zone in [
+                Zone::Zone1,
+                Zone::Zone2,
+                Zone::Zone3,
+                Zone::Zone4,
+                Zone::Zone5,
+            ] {
+                let (lower, upper) = zone_bpm_bounds(zone, max_hr).unwrap();
+                for bpm in lower..=upper {
+                    assert_eq!(calculate_zone(bpm, max_hr).unwrap(), Some(zone));
+                }
+                assert_ne!(calculate_zone(lower - 1, max_hr).unwrap(), Some(zone));
+                if zone != Zone::Zone5 {
+                    assert_ne!(calculate_zone(upper + 1, max_hr).unwrap(), Some(zone));
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_calculate_zone_invalid_max_hr() {
         // max_hr too low
@@ -304,6 +1170,7 @@ mod tests {
             phases: vec![],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         let result = plan.validate();
@@ -320,9 +1187,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 0,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         let result = plan.validate();
@@ -339,9 +1209,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 14401, // 4 hours + 1 second
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         let result = plan.validate();
@@ -349,6 +1222,186 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("exceeds 4 hours"));
     }
 
+    #[test]
+    fn test_concat_preserves_order_and_totals_duration() {
+        let bike =
+            TrainingPlan::steady("Bike", Zone::Zone2, 3600, 185).with_warmup(Zone::Zone1, 300);
+        let run = TrainingPlan::interval("Run", Zone::Zone4, 120, Zone::Zone2, 60, 2, 185);
+
+        let brick = TrainingPlan::concat(&[bike.clone(), run.clone()], "Brick").unwrap();
+
+        assert_eq!(brick.name, "Brick");
+        assert_eq!(brick.max_hr, 185);
+        let names: Vec<&str> = brick.phases.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Warmup",
+                "Bike",
+                "Interval 1",
+                "Recovery 1",
+                "Interval 2",
+                "Recovery 2"
+            ]
+        );
+        let total = |plan: &TrainingPlan| plan.phases.iter().map(|p| p.duration_secs).sum::<u32>();
+        assert_eq!(total(&brick), total(&bike) + total(&run));
+        assert_eq!(total(&brick), 300 + 3600 + 2 * (120 + 60));
+        assert!(brick.validate().is_ok());
+    }
+
+    #[test]
+    fn test_concat_rejects_empty_and_mismatched_max_hr() {
+        assert!(TrainingPlan::concat(&[], "Nothing").is_err());
+
+        let bike = TrainingPlan::steady("Bike", Zone::Zone2, 3600, 175);
+        let run = TrainingPlan::steady("Run", Zone::Zone3, 1200, 185);
+        let err = TrainingPlan::concat(&[bike, run], "Brick").unwrap_err();
+        assert!(err.to_string().contains("different max HR"));
+    }
+
+    #[test]
+    fn test_template_scales_durations_proportionally() {
+        // 30 minutes: 10 warmup, 15 tempo, 5 cooldown
+        let template = PlanTemplate::from_plan(
+            &TrainingPlan::steady("Tempo", Zone::Zone3, 900, 180)
+                .with_warmup(Zone::Zone2, 600)
+                .with_cooldown(Zone::Zone1, 300),
+        );
+        let plan = template
+            .instantiate(&TemplateParams {
+                name: "Tempo 60".to_string(),
+                max_hr: 192,
+                duration_scale: 2.0,
+            })
+            .unwrap();
+
+        let durations: Vec<u32> = plan.phases.iter().map(|p| p.duration_secs).collect();
+        assert_eq!(durations, vec![1200, 1800, 600]);
+        assert_eq!(durations.iter().sum::<u32>(), 3600);
+        assert_eq!(plan.name, "Tempo 60");
+        assert_eq!(plan.max_hr, 192);
+    }
+
+    #[test]
+    fn test_template_rescales_hr_targets_and_rejects_bad_scale() {
+        let mut plan = TrainingPlan::steady("Climb", Zone::Zone4, 600, 200);
+        plan.phases[0].transition = TransitionCondition::HeartRateReached {
+            target_bpm: 170,
+            hold_secs: 60,
+        };
+        let template = PlanTemplate::from_plan(&plan);
+        let params = |max_hr, duration_scale| TemplateParams {
+            name: "Climb".to_string(),
+            max_hr,
+            duration_scale,
+        };
+
+        let plan = template.instantiate(&params(180, 1.0)).unwrap();
+        assert_eq!(
+            plan.phases[0].transition,
+            TransitionCondition::HeartRateReached {
+                target_bpm: 153,
+                hold_secs: 60
+            }
+        );
+
+        assert!(template.instantiate(&params(180, 0.0)).is_err());
+        assert!(template.instantiate(&params(180, f64::NAN)).is_err());
+        // 6 hours exceeds the plan limit
+        assert!(template.instantiate(&params(180, 36.0)).is_err());
+    }
+
+    #[test]
+    fn test_validate_resting_hr() {
+        let mut plan = TrainingPlan::steady("Easy", Zone::Zone2, 1200, 180);
+        plan.resting_hr = Some(55);
+        assert!(plan.validate().is_ok());
+
+        plan.resting_hr = Some(180);
+        assert!(plan.validate().is_err());
+        plan.resting_hr = Some(20);
+        assert!(plan.validate().is_err());
+    }
+
+    #[test]
+    fn test_template_carries_resting_hr() {
+        let mut plan = TrainingPlan::steady("Easy", Zone::Zone2, 1200, 180);
+        plan.resting_hr = Some(52);
+        let template = PlanTemplate::from_plan(&plan);
+
+        let plan = template
+            .instantiate(&TemplateParams {
+                name: "Easy".to_string(),
+                max_hr: 190,
+                duration_scale: 1.0,
+            })
+            .unwrap();
+        assert_eq!(plan.resting_hr, Some(52));
+    }
+
+    #[test]
+    fn test_template_rescales_band_targets() {
+        let mut plan = TrainingPlan::steady("Hold", Zone::Zone3, 600, 180);
+        plan.phases[0].target = Some(PhaseTarget::Bpm {
+            low: 148,
+            high: 152,
+        });
+        plan = plan.with_warmup(Zone::Zone2, 300);
+        plan.phases[0].target = Some(PhaseTarget::Zone(Zone::Zone1));
+        let template = PlanTemplate::from_plan(&plan);
+
+        let plan = template
+            .instantiate(&TemplateParams {
+                name: "Hold".to_string(),
+                max_hr: 190,
+                duration_scale: 1.0,
+            })
+            .unwrap();
+        assert_eq!(plan.phases[0].target, Some(PhaseTarget::Zone(Zone::Zone1)));
+        assert_eq!(
+            plan.phases[1].target,
+            Some(PhaseTarget::Bpm {
+                low: 156,
+                high: 160
+            })
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_modified_and_added_phases() {
+        let old =
+            TrainingPlan::steady("Tempo", Zone::Zone3, 1200, 180).with_warmup(Zone::Zone2, 600);
+        let mut new = old.clone();
+        new.created_at = old.created_at + chrono::Duration::days(1);
+        new.phases[1].duration_secs = 1500;
+        let cooldown = TrainingPhase::timed("Cooldown", Zone::Zone1, 300);
+        new.phases.push(cooldown.clone());
+
+        assert!(diff(&old, &old).is_empty());
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                PlanChange::PhaseModified {
+                    index: 1,
+                    old: old.phases[1].clone(),
+                    new: new.phases[1].clone(),
+                },
+                PlanChange::PhaseAdded {
+                    index: 2,
+                    phase: cooldown.clone(),
+                },
+            ]
+        );
+        assert_eq!(
+            diff(&new, &old)[1],
+            PlanChange::PhaseRemoved {
+                index: 2,
+                phase: cooldown,
+            }
+        );
+    }
+
     #[test]
     fn test_validate_invalid_heart_rate_target() {
         let plan = TrainingPlan {
@@ -361,9 +1414,12 @@ mod tests {
                     target_bpm: 250, // Too high
                     hold_secs: 10,
                 },
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         let result = plan.validate();
@@ -384,6 +1440,8 @@ mod tests {
                     target_zone: Zone::Zone2,
                     duration_secs: 600,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
                 TrainingPhase {
                     name: "Work".to_string(),
@@ -393,10 +1451,13 @@ mod tests {
                         target_bpm: 160,
                         hold_secs: 10,
                     },
+                    alerts_enabled: true,
+                    target: None,
                 },
             ],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         assert!(plan.validate().is_ok());
@@ -411,9 +1472,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 14400, // Exactly 4 hours
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         assert!(plan.validate().is_ok());
@@ -436,22 +1500,29 @@ mod tests {
                     target_zone: Zone::Zone2,
                     duration_secs: 600,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
                 TrainingPhase {
                     name: "Tempo".to_string(),
                     target_zone: Zone::Zone3,
                     duration_secs: 1200,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
                 TrainingPhase {
                     name: "Cooldown".to_string(),
                     target_zone: Zone::Zone1,
                     duration_secs: 600,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
             ],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         }
     }
 
@@ -467,9 +1538,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 2700,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         }
     }
 
@@ -485,6 +1559,8 @@ mod tests {
             target_zone: Zone::Zone2,
             duration_secs: 300,
             transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
         }];
 
         // 5 intervals: 3min work + 2min recovery
@@ -494,12 +1570,16 @@ mod tests {
                 target_zone: Zone::Zone5,
                 duration_secs: 180,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             });
             phases.push(TrainingPhase {
                 name: format!("Interval {} - Recovery", i),
                 target_zone: Zone::Zone2,
                 duration_secs: 120,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             });
         }
 
@@ -508,6 +1588,8 @@ mod tests {
             target_zone: Zone::Zone1,
             duration_secs: 300,
             transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
         });
 
         TrainingPlan {
@@ -515,6 +1597,7 @@ mod tests {
             phases,
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         }
     }
 
@@ -562,4 +1645,157 @@ mod tests {
             assert_eq!(plan.phases.len(), deserialized.phases.len());
         }
     }
+
+    #[test]
+    fn test_interval_constructor_expands_reps() {
+        let plan = TrainingPlan::interval("4x4", Zone::Zone4, 240, Zone::Zone2, 180, 4, 190);
+        assert!(plan.validate().is_ok());
+        assert_eq!(plan.max_hr, 190);
+        assert_eq!(plan.phases.len(), 8);
+
+        for (i, pair) in plan.phases.chunks(2).enumerate() {
+            assert_eq!(pair[0].name, format!("Interval {}", i + 1));
+            assert_eq!(pair[0].target_zone, Zone::Zone4);
+            assert_eq!(pair[0].duration_secs, 240);
+            assert_eq!(pair[1].name, format!("Recovery {}", i + 1));
+            assert_eq!(pair[1].target_zone, Zone::Zone2);
+            assert_eq!(pair[1].duration_secs, 180);
+        }
+
+        let plan = plan
+            .with_warmup(Zone::Zone2, 600)
+            .with_cooldown(Zone::Zone1, 300);
+        assert_eq!(plan.phases.len(), 10);
+        assert_eq!(plan.phases[0].name, "Warmup");
+        assert_eq!(plan.phases[9].target_zone, Zone::Zone1);
+
+        // Zero reps yields an empty plan that fails validation
+        assert!(
+            TrainingPlan::interval("None", Zone::Zone4, 60, Zone::Zone1, 60, 0, 190)
+                .validate()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_steady_constructor() {
+        let plan = TrainingPlan::steady("Zone 2 Base", Zone::Zone2, 2700, 185);
+        assert_eq!(
+            plan.phases,
+            vec![TrainingPhase::timed("Zone 2 Base", Zone::Zone2, 2700)]
+        );
+        assert_eq!(plan.phases[0].transition, TransitionCondition::TimeElapsed);
+    }
+
+    #[test]
+    fn test_warns_about_back_to_back_hard_phases() {
+        // Work intervals separated by recovery are fine
+        let intervals =
+            TrainingPlan::interval("Intervals", Zone::Zone5, 180, Zone::Zone1, 120, 4, 190)
+                .with_warmup(Zone::Zone2, 600)
+                .with_cooldown(Zone::Zone1, 300);
+        assert!(intervals.validate_with_warnings().unwrap().is_empty());
+
+        let back_to_back = TrainingPlan {
+            name: "No Rest".to_string(),
+            phases: vec![
+                TrainingPhase::timed("Warmup", Zone::Zone2, 600),
+                TrainingPhase::timed("Threshold", Zone::Zone4, 300),
+                TrainingPhase::timed("VO2", Zone::Zone5, 180),
+                TrainingPhase::timed("Threshold 2", Zone::Zone4, 300),
+                TrainingPhase::timed("Cooldown", Zone::Zone1, 300),
+            ],
+            created_at: Utc::now(),
+            max_hr: 190,
+            ..Default::default()
+        };
+        assert_eq!(
+            back_to_back.validate_with_warnings().unwrap(),
+            vec![
+                PlanWarning::NoRecoveryBetweenIntervals {
+                    first_phase: 1,
+                    second_phase: 2
+                },
+                PlanWarning::NoRecoveryBetweenIntervals {
+                    first_phase: 2,
+                    second_phase: 3
+                },
+            ]
+        );
+
+        // Invalid plans are still errors
+        assert!(TrainingPlan::steady("Empty", Zone::Zone4, 0, 190)
+            .validate_with_warnings()
+            .is_err());
+    }
+
+    #[test]
+    fn test_difficulty_weights_high_zones() {
+        let zone1 = TrainingPlan::steady("Easy", Zone::Zone1, 1800, 180);
+        let zone5 = TrainingPlan::steady("Hard", Zone::Zone5, 1800, 180);
+        assert_eq!(zone1.difficulty(), 1);
+        assert!(zone5.difficulty() > zone1.difficulty());
+
+        // Longer plans rate harder, capped at 5
+        assert_eq!(
+            TrainingPlan::steady("Long", Zone::Zone1, 14400, 180).difficulty(),
+            4
+        );
+        assert_eq!(
+            TrainingPlan::steady("Brutal", Zone::Zone5, 3600, 180).difficulty(),
+            5
+        );
+
+        let summary = zone5.summary();
+        assert_eq!(summary.name, "Hard");
+        assert_eq!(summary.total_duration_secs, 1800);
+        assert_eq!(summary.phase_count, 1);
+        assert_eq!(summary.difficulty, zone5.difficulty());
+    }
+
+    #[test]
+    fn test_hr_simulator_rises_gradually_into_next_zone() {
+        let plan = TrainingPlan {
+            name: "Intervals".to_string(),
+            phases: vec![
+                TrainingPhase::timed("Easy", Zone::Zone2, 300),
+                TrainingPhase::timed("Hard", Zone::Zone4, 300),
+            ],
+            created_at: chrono::Utc::now(),
+            max_hr: 200,
+            warmup_grace_secs: None,
+            resting_hr: None,
+        };
+        let (z4_low, z4_high) = zone_bpm_bounds(Zone::Zone4, 200).unwrap();
+        let mut sim = plan.hr_simulator(120);
+
+        // Settle into Zone 2
+        for _ in 0..300 {
+            sim.step(1.0);
+        }
+        assert_eq!(
+            calculate_zone(sim.bpm().round() as u16, 200).unwrap(),
+            Some(Zone::Zone2)
+        );
+        let before = sim.bpm();
+
+        // Zone 4 starts: no step, a steady climb that slows as it nears the band
+        let climb: Vec<f64> = (0..120).map(|_| sim.step(1.0)).collect();
+        assert!(climb[0] - before < 2.0, "jumped by {}", climb[0] - before);
+        assert!(climb.windows(2).all(|w| w[1] > w[0]));
+        assert!(climb[1] - climb[0] > climb[100] - climb[99]);
+        assert!(climb[10] < z4_low as f64);
+        let settled = *climb.last().unwrap();
+        assert!(
+            (z4_low as f64..=z4_high as f64).contains(&settled),
+            "settled at {}",
+            settled
+        );
+
+        // After the plan HR falls back toward the start
+        for _ in 0..600 {
+            sim.step(1.0);
+        }
+        assert!((sim.bpm() - 120.0).abs() < 1.0);
+    }
 }
@@ -60,6 +60,8 @@ impl WorkoutTemplate {
             phases: self.phases.clone(),
             created_at: Utc::now(),
             max_hr,
+            warmup_grace_secs: None,
+            resting_hr: None,
         }
     }
 }
@@ -71,6 +73,8 @@ fn phase(name: &str, zone: Zone, duration_secs: u32) -> TrainingPhase {
         target_zone: zone,
         duration_secs,
         transition: TransitionCondition::TimeElapsed,
+        alerts_enabled: true,
+        target: None,
     }
 }
 
@@ -11,6 +11,10 @@ use std::path::PathBuf;
 use tokio::fs::{File, OpenOptions};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
+pub mod raw_log;
+
+pub use raw_log::RawHrLogger;
+
 /// A single HR sample for JSON serialization and deserialization.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Sample {
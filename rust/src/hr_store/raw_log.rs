@@ -0,0 +1,183 @@
+//! Opt-in raw HR sample log.
+//!
+//! Appends every `FilteredHeartRate` (raw and filtered BPM, RMSSD, timestamps)
+//! to a JSONL file as it arrives from the device, independent of any running
+//! workout session. The file is capped at a maximum size: once the cap would be
+//! exceeded it is rotated to `<path>.1` (replacing any previous backup) and a
+//! fresh file is started.
+
+use crate::domain::heart_rate::FilteredHeartRate;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncWriteExt, BufWriter};
+
+/// Default size cap for the raw log file (10 MiB).
+pub const DEFAULT_MAX_LOG_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Append-only JSONL writer for raw HR samples with size-based rotation.
+#[derive(Debug)]
+pub struct RawHrLogger {
+    /// Path of the active log file.
+    path: PathBuf,
+    /// Buffered writer for the active log file.
+    writer: BufWriter<File>,
+    /// Current size of the active log file in bytes.
+    bytes_written: u64,
+    /// Size at which the file is rotated.
+    max_bytes: u64,
+}
+
+impl RawHrLogger {
+    /// Open (or create) a raw log at `path` using [`DEFAULT_MAX_LOG_BYTES`].
+    ///
+    /// Existing content is preserved; new samples are appended.
+    pub async fn open(path: PathBuf) -> Result<Self> {
+        Self::with_max_bytes(path, DEFAULT_MAX_LOG_BYTES).await
+    }
+
+    /// Open (or create) a raw log at `path` that rotates after `max_bytes`.
+    pub async fn with_max_bytes(path: PathBuf, max_bytes: u64) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .with_context(|| format!("Failed to create log directory: {:?}", parent))?;
+            }
+        }
+
+        let (writer, bytes_written) = Self::open_writer(&path).await?;
+
+        Ok(Self {
+            path,
+            writer,
+            bytes_written,
+            max_bytes,
+        })
+    }
+
+    /// Path of the active log file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append a single sample as one JSON line, rotating first if needed.
+    pub async fn append(&mut self, data: &FilteredHeartRate) -> Result<()> {
+        let mut line = serde_json::to_vec(data).context("Failed to serialize HR sample")?;
+        line.push(b'\n');
+
+        if self.bytes_written > 0 && self.bytes_written + line.len() as u64 > self.max_bytes {
+            self.rotate().await?;
+        }
+
+        self.writer.write_all(&line).await?;
+        self.bytes_written += line.len() as u64;
+        Ok(())
+    }
+
+    /// Flush buffered samples to disk without closing the log.
+    pub async fn flush(&mut self) -> Result<()> {
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Flush, sync and close the log.
+    pub async fn close(mut self) -> Result<()> {
+        self.writer.flush().await?;
+        self.writer.get_ref().sync_all().await?;
+        Ok(())
+    }
+
+    /// Move the active file to `<path>.1` and start a fresh one.
+    async fn rotate(&mut self) -> Result<()> {
+        self.writer.flush().await?;
+
+        let mut backup = self.path.as_os_str().to_owned();
+        backup.push(".1");
+        tokio::fs::rename(&self.path, PathBuf::from(backup))
+            .await
+            .with_context(|| format!("Failed to rotate raw HR log: {:?}", self.path))?;
+
+        let (writer, bytes_written) = Self::open_writer(&self.path).await?;
+        self.writer = writer;
+        self.bytes_written = bytes_written;
+        Ok(())
+    }
+
+    async fn open_writer(path: &Path) -> Result<(BufWriter<File>, u64)> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+            .with_context(|| format!("Failed to open raw HR log: {:?}", path))?;
+        let len = file.metadata().await?.len();
+        Ok((BufWriter::new(file), len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample(bpm: u16, timestamp: u64) -> FilteredHeartRate {
+        FilteredHeartRate {
+            raw_bpm: bpm,
+            filtered_bpm: bpm - 1,
+            rmssd: Some(42.0),
+            rmssd_trend: None,
+            filter_variance: Some(1.0),
+            battery_level: None,
+            timestamp,
+            receive_timestamp_micros: None,
+            stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_append_and_close_writes_jsonl() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("logs").join("raw.jsonl");
+
+        let mut logger = RawHrLogger::open(path.clone()).await.unwrap();
+        for i in 0..5 {
+            logger
+                .append(&sample(70 + i, 1000 + i as u64))
+                .await
+                .unwrap();
+        }
+        logger.close().await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<FilteredHeartRate> = contents
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0].raw_bpm, 70);
+        assert_eq!(lines[4].filtered_bpm, 73);
+    }
+
+    #[tokio::test]
+    async fn test_rotates_when_cap_exceeded() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("raw.jsonl");
+
+        let line_len = serde_json::to_vec(&sample(70, 1000)).unwrap().len() as u64 + 1;
+        let mut logger = RawHrLogger::with_max_bytes(path.clone(), line_len * 3)
+            .await
+            .unwrap();
+        for i in 0..5 {
+            logger.append(&sample(70, 1000 + i)).await.unwrap();
+        }
+        logger.close().await.unwrap();
+
+        let current = std::fs::read_to_string(&path).unwrap();
+        let backup = std::fs::read_to_string(dir.path().join("raw.jsonl.1")).unwrap();
+        assert_eq!(backup.lines().count(), 3);
+        assert_eq!(current.lines().count(), 2);
+    }
+}
@@ -6,6 +6,7 @@
 //! - Optional file-based daily-rotating log appender via `tracing-appender`
 
 use crate::api::LogMessage;
+use crate::domain::clock::unix_millis;
 use parking_lot::RwLock;
 use std::collections::VecDeque;
 use std::io::Write;
@@ -126,10 +127,7 @@ impl Write for BroadcastLogWriter {
             return Ok(buf.len());
         }
 
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+        let timestamp = unix_millis();
 
         // Try to find a log level keyword to orient parsing
         let level_keywords = ["TRACE", "DEBUG", " INFO", " WARN", "ERROR"];
@@ -7,7 +7,30 @@
 use crate::domain::heart_rate::DiscoveredDevice;
 use anyhow::Result;
 use async_trait::async_trait;
-use tokio::sync::mpsc::Receiver;
+use std::time::SystemTime;
+use tokio::sync::mpsc::{self, Receiver};
+
+/// A raw heart rate notification and the time the adapter received it.
+///
+/// Stamping on arrival keeps async scheduling delays in the processing task
+/// out of sample timestamps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HrNotification {
+    /// Raw Heart Rate Measurement bytes.
+    pub data: Vec<u8>,
+    /// Wall-clock time the notification arrived at the adapter.
+    pub received_at: SystemTime,
+}
+
+impl HrNotification {
+    /// Stamp `data` with the current time.
+    pub fn now(data: Vec<u8>) -> Self {
+        Self {
+            data,
+            received_at: SystemTime::now(),
+        }
+    }
+}
 
 /// Abstraction for BLE operations to enable testing and swappable implementations.
 ///
@@ -78,6 +101,28 @@ pub trait BleAdapter: Send + Sync {
     /// connected or the device doesn't support the Heart Rate Service.
     async fn subscribe_hr(&self) -> Result<Receiver<Vec<u8>>>;
 
+    /// Subscribe to heart rate notifications stamped with their arrival time.
+    ///
+    /// Adapters should capture [`HrNotification::received_at`] as soon as the
+    /// notification arrives. The default implementation stamps the packets of
+    /// [`subscribe_hr`](Self::subscribe_hr) as they are forwarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the subscription fails (see `subscribe_hr`).
+    async fn subscribe_hr_timestamped(&self) -> Result<Receiver<HrNotification>> {
+        let mut raw = self.subscribe_hr().await?;
+        let (tx, rx) = mpsc::channel(32);
+        tokio::spawn(async move {
+            while let Some(data) = raw.recv().await {
+                if tx.send(HrNotification::now(data)).await.is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(rx)
+    }
+
     /// Read the battery level from the connected device.
     ///
     /// Reads the Battery Level characteristic (if available) from the connected device.
@@ -92,4 +137,79 @@ pub trait BleAdapter: Send + Sync {
     /// Returns an error if the read fails due to connection issues, but returns
     /// `Ok(None)` if the Battery Service is not found.
     async fn read_battery(&self) -> Result<Option<u8>>;
+
+    /// Read the signal strength of the connected device.
+    ///
+    /// # Returns
+    ///
+    /// The latest RSSI in dBm, or `None` if the adapter cannot report it. The
+    /// default implementation always returns `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the read fails, e.g. because no device is connected.
+    async fn read_rssi(&self) -> Result<Option<i16>> {
+        Ok(None)
+    }
+}
+
+/// Typed failures for BLE adapter initialization and device lookup.
+///
+/// Adapters wrap these in `anyhow::Error`, so callers that need to react to a
+/// specific cause (e.g. the CLI `doctor` command) can recover them with
+/// `err.downcast_ref::<BleError>()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BleError {
+    /// The platform BLE manager could not be created (e.g. BlueZ not running).
+    ManagerUnavailable(String),
+
+    /// The OS denied access to Bluetooth.
+    PermissionDenied,
+
+    /// No Bluetooth adapter is present on the system.
+    NoAdapter,
+
+    /// A Bluetooth adapter exists but is powered off.
+    PoweredOff,
+
+    /// No device with this ID was discovered.
+    DeviceNotFound(String),
+}
+
+impl BleError {
+    /// Human-readable remediation hint for this error.
+    pub fn remediation(&self) -> &'static str {
+        match self {
+            BleError::ManagerUnavailable(_) => {
+                "Ensure the Bluetooth service is running (Linux: `systemctl start bluetooth`)."
+            }
+            BleError::PermissionDenied => {
+                "Grant Bluetooth permission to this application (Linux: add your user to the \
+                 `bluetooth` group; macOS: System Settings > Privacy & Security > Bluetooth)."
+            }
+            BleError::NoAdapter => {
+                "Plug in a Bluetooth adapter or enable the built-in one in your system settings."
+            }
+            BleError::PoweredOff => "Turn Bluetooth on (Linux: `bluetoothctl power on`).",
+            BleError::DeviceNotFound(_) => {
+                "Make sure the device is switched on and nearby, then scan again."
+            }
+        }
+    }
 }
+
+impl std::fmt::Display for BleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BleError::ManagerUnavailable(reason) => {
+                write!(f, "BLE manager unavailable: {}", reason)
+            }
+            BleError::PermissionDenied => write!(f, "BLE permission denied"),
+            BleError::NoAdapter => write!(f, "No BLE adapter found"),
+            BleError::PoweredOff => write!(f, "BLE adapter is powered off"),
+            BleError::DeviceNotFound(id) => write!(f, "Device not found: {}", id),
+        }
+    }
+}
+
+impl std::error::Error for BleError {}
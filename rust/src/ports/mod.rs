@@ -8,6 +8,6 @@ pub mod ble_adapter;
 pub mod notification;
 pub mod session_repository;
 
-pub use ble_adapter::BleAdapter;
+pub use ble_adapter::{BleAdapter, BleError, HrNotification};
 pub use notification::*;
-pub use session_repository::{SessionRepository, SessionSummaryPreview};
+pub use session_repository::{RepairReport, SessionRepository, SessionSummaryPreview};
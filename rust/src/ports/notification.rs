@@ -5,6 +5,7 @@
 //! notifications without coupling to specific UI frameworks or output mechanisms.
 
 use crate::domain::heart_rate::Zone;
+use crate::domain::session_history::SessionSummary;
 use crate::state::session::ZoneDeviation;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -85,6 +86,57 @@ pub enum NotificationEvent {
     /// is unexpectedly disconnected during a workout.
     ConnectionLost,
 
+    /// The sensor has lost skin contact.
+    ///
+    /// This event is triggered when the heart rate monitor sends a run of
+    /// zero-BPM readings, which many straps use to signal that they are no
+    /// longer touching the skin.
+    ContactLost,
+
+    /// A training session has started.
+    SessionStarted,
+
+    /// A training phase has started.
+    ///
+    /// This event is triggered for the first phase when the session starts and
+    /// for every later phase the session advances to.
+    PhaseStarted {
+        /// Name of the phase
+        name: String,
+        /// Target zone of the phase
+        zone: Zone,
+    },
+
+    /// The current phase is about to end.
+    ///
+    /// This event is triggered once per timed phase when its remaining time
+    /// reaches the executor's lead time, so the user can prepare for the
+    /// next phase (e.g. an interval).
+    PhaseEndingSoon {
+        /// Seconds left in the current phase
+        remaining_secs: u32,
+        /// Target zone of the next phase, or None if this is the last phase
+        next_zone: Option<Zone>,
+    },
+
+    /// A session peaked above the plan's max HR.
+    ///
+    /// This event is triggered after a session completes whose highest BPM
+    /// exceeded the plan's `max_hr` by a margin, which means the plan's zones
+    /// are calibrated too low and its max HR should be revised.
+    MaxHrExceeded {
+        /// Highest BPM observed during the session
+        observed: u16,
+        /// Max HR configured in the plan
+        configured: u16,
+    },
+
+    /// A training session has run through all of its phases.
+    SessionCompleted {
+        /// Summary statistics of the finished session
+        summary: SessionSummary,
+    },
+
     /// Workout is ready to start.
     ///
     /// This event is triggered when all prerequisites are met (device connected,
@@ -94,3 +146,46 @@ pub enum NotificationEvent {
         plan_name: String,
     },
 }
+
+/// How urgently a notification needs the user's attention.
+///
+/// Used by [`crate::adapters::NotificationRouter`] to send events to different
+/// outputs, e.g. a toast for information and haptic feedback for critical alerts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum NotificationSeverity {
+    /// Informational progress updates (session and phase lifecycle).
+    Info,
+    /// Conditions the user should deal with soon, such as a low battery.
+    Warning,
+    /// Conditions that need immediate attention during a workout.
+    Critical,
+}
+
+impl NotificationEvent {
+    /// Severity of this event.
+    ///
+    /// Zone deviations and lost connection or skin contact are critical, since
+    /// they affect the workout in progress. A low battery or an exceeded max HR
+    /// is a warning; session and phase lifecycle events, including returning to
+    /// the target zone, are informational.
+    pub fn severity(&self) -> NotificationSeverity {
+        match self {
+            NotificationEvent::ZoneDeviation {
+                deviation: ZoneDeviation::InZone,
+                ..
+            } => NotificationSeverity::Info,
+            NotificationEvent::ZoneDeviation { .. }
+            | NotificationEvent::ConnectionLost
+            | NotificationEvent::ContactLost => NotificationSeverity::Critical,
+            NotificationEvent::BatteryLow { .. } | NotificationEvent::MaxHrExceeded { .. } => {
+                NotificationSeverity::Warning
+            }
+            NotificationEvent::PhaseTransition { .. }
+            | NotificationEvent::SessionStarted
+            | NotificationEvent::PhaseStarted { .. }
+            | NotificationEvent::PhaseEndingSoon { .. }
+            | NotificationEvent::SessionCompleted { .. }
+            | NotificationEvent::WorkoutReady { .. } => NotificationSeverity::Info,
+        }
+    }
+}
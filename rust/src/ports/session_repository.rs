@@ -36,6 +36,25 @@ pub struct SessionSummaryPreview {
     pub status: String,
 }
 
+/// A stored session that failed verification.
+///
+/// Produced by [`SessionRepository::verify`] for each session that cannot be
+/// read back, typically because of a partial write.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepairReport {
+    /// Session ID, if it could be determined from the storage location.
+    pub id: Option<String>,
+
+    /// Where the session is stored (e.g. a file path).
+    pub location: String,
+
+    /// Why the session could not be read.
+    pub error: String,
+
+    /// Whether the session was moved out of the repository's active storage.
+    pub quarantined: bool,
+}
+
 /// Abstraction for session storage operations.
 ///
 /// This trait defines the interface for persisting and retrieving completed
@@ -58,6 +77,18 @@ pub trait SessionRepository: Send + Sync {
     /// permission problems, or serialization failures.
     async fn save(&self, session: &CompletedSession) -> Result<()>;
 
+    /// Write any saves the repository has buffered.
+    ///
+    /// Repositories that coalesce rapid saves should persist the latest state
+    /// of every session here. The default implementation does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a buffered session cannot be written.
+    async fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+
     /// List all sessions with summary information.
     ///
     /// Returns a lightweight list of session previews without loading full
@@ -74,6 +105,61 @@ pub trait SessionRepository: Send + Sync {
     /// or permission problems.
     async fn list(&self) -> Result<Vec<SessionSummaryPreview>>;
 
+    /// Find sessions whose plan name contains `query`, ignoring case.
+    ///
+    /// Works on the same lightweight previews as [`list`](Self::list), so no
+    /// HR samples are returned. An empty or whitespace-only query matches
+    /// every session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session list cannot be read.
+    async fn search(&self, query: &str) -> Result<Vec<SessionSummaryPreview>> {
+        let query = query.trim().to_lowercase();
+        let mut previews = self.list().await?;
+        previews.retain(|p| p.plan_name.to_lowercase().contains(&query));
+        Ok(previews)
+    }
+
+    /// List the IDs of all stored sessions.
+    ///
+    /// Unlike [`list`](Self::list), implementations should not need to read
+    /// session contents, so IDs of unreadable sessions are included and can be
+    /// reported individually by callers. The default implementation derives the
+    /// IDs from `list`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the session list cannot be read.
+    async fn list_ids(&self) -> Result<Vec<String>> {
+        Ok(self.list().await?.into_iter().map(|p| p.id).collect())
+    }
+
+    /// Check that every stored session can be read back.
+    ///
+    /// Returns a report for each unreadable or invalid session; an empty list
+    /// means the repository is intact. Verification does not modify storage.
+    /// The default implementation loads each session from
+    /// [`list_ids`](Self::list_ids) with [`get`](Self::get).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository itself cannot be read.
+    async fn verify(&self) -> Result<Vec<RepairReport>> {
+        let mut reports = Vec::new();
+        for id in self.list_ids().await? {
+            if let Err(e) = self.get(&id).await {
+                reports.push(RepairReport {
+                    location: id.clone(),
+                    id: Some(id),
+                    error: format!("{:#}", e),
+                    quarantined: false,
+                });
+            }
+        }
+        Ok(reports)
+    }
+
     /// Get a complete session by its ID.
     ///
     /// Loads the full session data including all heart rate samples and phase results.
@@ -4,13 +4,23 @@
 //! sessions including starting/stopping, tick-based progress tracking, HR data integration,
 //! session persistence, and cron-based scheduling.
 
+use crate::domain::clock::{rebase_clock, unix_millis, utc_now};
+use crate::domain::connection_health::{ConnectionHealth, ConnectionLog, ConnectionSummary};
 use crate::domain::heart_rate::FilteredHeartRate;
+use crate::domain::pacer::{compute_pace_cue, PaceCue};
 use crate::domain::reconnection::ConnectionStatus;
-use crate::domain::session_history::{CompletedSession, HrSample, SessionStatus, SessionSummary};
+use crate::domain::session_history::{
+    data_completeness_pct, new_session_id, time_in_zone_smoothed, CompletedSession, HrSample,
+    PhaseResult, RunningStats, SampleDecimator, SessionStatus, SessionSummary, MAX_SAMPLE_GAP_SECS,
+};
 use crate::domain::session_progress::{
-    PhaseProgress, SessionProgress, SessionState as ProgressState, ZoneStatus,
+    projected_end_ms, ConnectivityDuringSession, PhaseProgress, PhaseTransition, SessionProgress,
+    SessionState as ProgressState, ZoneStatus,
+};
+use crate::domain::training_load::{LiveTrimp, DEFAULT_RESTING_HR};
+use crate::domain::training_plan::{
+    suggest_max_hr, TrainingPhase, TrainingPlan, TransitionCondition,
 };
-use crate::domain::training_plan::TrainingPlan;
 use crate::ports::notification::{NotificationEvent, NotificationPort};
 use crate::ports::session_repository::SessionRepository;
 use crate::state::session::{SessionEvent, SessionStateMachineWrapper, State, ZoneDeviation};
@@ -19,11 +29,23 @@ use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use tokio::sync::{broadcast, mpsc, Mutex};
 use tokio::task::JoinHandle;
 use tokio::time::{interval, Duration, Instant};
 use tokio_cron_scheduler::{Job, JobScheduler};
+use tokio_util::sync::CancellationToken;
+
+/// Default number of ticks between session checkpoints.
+pub const DEFAULT_CHECKPOINT_INTERVAL_TICKS: u32 = 10;
+
+/// Default time after the last HR sample during which its BPM counts as fresh.
+pub const DEFAULT_HR_FRESHNESS_WINDOW: Duration = Duration::from_secs(5);
+
+/// Default seconds before the end of a timed phase at which
+/// [`NotificationEvent::PhaseEndingSoon`] is sent.
+pub const DEFAULT_PHASE_ENDING_LEAD_SECS: u32 = 10;
 
 /// Serializable checkpoint for session persistence.
 ///
@@ -38,6 +60,12 @@ struct SessionCheckpoint {
     elapsed_secs: u32,
     /// Whether the session was paused when checkpointed
     is_paused: bool,
+    /// Zone tolerance in effect when checkpointed; absent in older checkpoints
+    #[serde(default)]
+    zone_tolerance_bpm: Option<u16>,
+    /// Default warmup grace in effect when checkpointed; absent in older checkpoints
+    #[serde(default)]
+    warmup_grace_secs: Option<u32>,
 }
 
 /// Metadata for a pending scheduled session.
@@ -62,6 +90,17 @@ enum PauseReason {
     ConnectionLoss,
 }
 
+/// What the executor does with a running session when the sensor link drops.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DisconnectPolicy {
+    /// Pause the session, resuming it automatically once reconnected.
+    #[default]
+    Pause,
+    /// Keep timing phases with HR marked unavailable, resuming zone logic
+    /// once HR returns.
+    KeepTiming,
+}
+
 /// Executes training sessions with real-time HR monitoring and state management.
 ///
 /// The executor manages the lifecycle of training sessions, coordinating between
@@ -82,9 +121,15 @@ pub struct SessionExecutor {
     /// Path where session checkpoints are saved (None disables persistence)
     checkpoint_path: Option<PathBuf>,
 
+    /// Number of ticks between checkpoint saves
+    checkpoint_interval_ticks: u32,
+
     /// Cron job scheduler for scheduled workouts
     scheduler: Option<Arc<JobScheduler>>,
 
+    /// Cancelled on scheduler shutdown so in-flight jobs stop short
+    scheduler_cancel: CancellationToken,
+
     /// Pending scheduled sessions awaiting user action
     pending_sessions: Arc<Mutex<HashMap<String, PendingSession>>>,
 
@@ -94,17 +139,86 @@ pub struct SessionExecutor {
     /// HR samples collected during the current session
     hr_samples: Arc<Mutex<Vec<HrSample>>>,
 
+    /// Groups filtered BPMs into stored samples at the configured interval
+    sample_decimator: Arc<Mutex<SampleDecimator>>,
+
+    /// Statistics over the stored samples of the current session
+    running_stats: Arc<Mutex<RunningStats>>,
+
+    /// Results of the phases completed so far in the current session
+    phase_results: Arc<Mutex<Vec<PhaseResult>>>,
+
+    /// When the current phase started
+    phase_start_time: Arc<Mutex<Option<PhaseStart>>>,
+
     /// Start time of the current session
     session_start_time: Arc<Mutex<Option<chrono::DateTime<Utc>>>>,
 
     /// Optional progress sender for streaming session state to the UI
     progress_sender: Option<mpsc::UnboundedSender<SessionProgress>>,
 
+    /// Optional sender for phase transition events
+    phase_transition_sender: Option<mpsc::UnboundedSender<PhaseTransition>>,
+
+    /// Optional sender for per-tick pace cues
+    pace_cue_sender: Option<mpsc::UnboundedSender<PaceCue>>,
+
     /// Optional connection status receiver for automatic pause/resume
     connection_status_receiver: Option<broadcast::Receiver<ConnectionStatus>>,
 
+    /// Optional connection health receiver, for the RSSI in the connection summary
+    connection_health_receiver: Option<broadcast::Receiver<ConnectionHealth>>,
+
     /// Tracks the reason why the session was paused
     pause_reason: Arc<Mutex<Option<PauseReason>>>,
+
+    /// How a running session reacts to connection loss
+    disconnect_policy: DisconnectPolicy,
+
+    /// Sensor link state as last reported by the connection status receiver
+    connectivity: Arc<Mutex<ConnectivityDuringSession>>,
+
+    /// Link state changes and RSSI readings of the current session
+    connection_log: Arc<Mutex<ConnectionLog>>,
+
+    /// When the last HR sample of the current session was received
+    last_hr_at: Arc<Mutex<Option<Instant>>>,
+
+    /// How long after the last HR sample its BPM is reported as fresh
+    hr_freshness_window: Duration,
+
+    /// Seconds the current session has spent paused, counted per tick
+    paused_secs: Arc<Mutex<u32>>,
+
+    /// Epoch of the current session (0 before the first start)
+    session_epoch: u64,
+
+    /// TRIMP accumulated over the running seconds of the current session
+    live_trimp: Arc<Mutex<LiveTrimp>>,
+
+    /// Seconds before a timed phase ends to warn about it (0 disables)
+    phase_ending_lead_secs: u32,
+
+    /// Seconds HR must stay in a new zone before time-in-zone counts it (0 disables)
+    zone_min_dwell_secs: u32,
+
+    /// Max HR suggested by the last completed session that exceeded the plan's
+    max_hr_suggestion: Arc<Mutex<Option<u16>>>,
+}
+
+/// Source of session epochs, shared by all executors so epochs never repeat.
+static SESSION_EPOCH_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Epoch of the most recently started session (0 if none was started yet).
+///
+/// See [`SessionProgress::session_epoch`].
+pub fn latest_session_epoch() -> u64 {
+    SESSION_EPOCH_COUNTER.load(Ordering::SeqCst)
+}
+
+/// Allocate the epoch for a newly started session.
+fn next_session_epoch() -> u64 {
+    SESSION_EPOCH_COUNTER.fetch_add(1, Ordering::SeqCst) + 1
 }
 
 impl SessionExecutor {
@@ -120,14 +234,34 @@ impl SessionExecutor {
             tick_task: None,
             hr_receiver: None,
             checkpoint_path: None,
+            checkpoint_interval_ticks: DEFAULT_CHECKPOINT_INTERVAL_TICKS,
             scheduler: None,
+            scheduler_cancel: CancellationToken::new(),
             pending_sessions: Arc::new(Mutex::new(HashMap::new())),
             session_repository: None,
             hr_samples: Arc::new(Mutex::new(Vec::new())),
+            sample_decimator: Arc::new(Mutex::new(SampleDecimator::default())),
+            running_stats: Arc::new(Mutex::new(RunningStats::default())),
+            phase_results: Arc::new(Mutex::new(Vec::new())),
+            phase_start_time: Arc::new(Mutex::new(None)),
             session_start_time: Arc::new(Mutex::new(None)),
             progress_sender: None,
+            phase_transition_sender: None,
+            pace_cue_sender: None,
             connection_status_receiver: None,
+            connection_health_receiver: None,
             pause_reason: Arc::new(Mutex::new(None)),
+            disconnect_policy: DisconnectPolicy::default(),
+            connectivity: Arc::new(Mutex::new(ConnectivityDuringSession::default())),
+            connection_log: Arc::new(Mutex::new(ConnectionLog::new())),
+            last_hr_at: Arc::new(Mutex::new(None)),
+            hr_freshness_window: DEFAULT_HR_FRESHNESS_WINDOW,
+            paused_secs: Arc::new(Mutex::new(0)),
+            session_epoch: 0,
+            live_trimp: Arc::new(Mutex::new(LiveTrimp::new())),
+            phase_ending_lead_secs: DEFAULT_PHASE_ENDING_LEAD_SECS,
+            zone_min_dwell_secs: 0,
+            max_hr_suggestion: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -147,14 +281,34 @@ impl SessionExecutor {
             tick_task: None,
             hr_receiver: None,
             checkpoint_path: Some(checkpoint_path),
+            checkpoint_interval_ticks: DEFAULT_CHECKPOINT_INTERVAL_TICKS,
             scheduler: None,
+            scheduler_cancel: CancellationToken::new(),
             pending_sessions: Arc::new(Mutex::new(HashMap::new())),
             session_repository: None,
             hr_samples: Arc::new(Mutex::new(Vec::new())),
+            sample_decimator: Arc::new(Mutex::new(SampleDecimator::default())),
+            running_stats: Arc::new(Mutex::new(RunningStats::default())),
+            phase_results: Arc::new(Mutex::new(Vec::new())),
+            phase_start_time: Arc::new(Mutex::new(None)),
             session_start_time: Arc::new(Mutex::new(None)),
             progress_sender: None,
+            phase_transition_sender: None,
+            pace_cue_sender: None,
             connection_status_receiver: None,
+            connection_health_receiver: None,
             pause_reason: Arc::new(Mutex::new(None)),
+            disconnect_policy: DisconnectPolicy::default(),
+            connectivity: Arc::new(Mutex::new(ConnectivityDuringSession::default())),
+            connection_log: Arc::new(Mutex::new(ConnectionLog::new())),
+            last_hr_at: Arc::new(Mutex::new(None)),
+            hr_freshness_window: DEFAULT_HR_FRESHNESS_WINDOW,
+            paused_secs: Arc::new(Mutex::new(0)),
+            session_epoch: 0,
+            live_trimp: Arc::new(Mutex::new(LiveTrimp::new())),
+            phase_ending_lead_secs: DEFAULT_PHASE_ENDING_LEAD_SECS,
+            zone_min_dwell_secs: 0,
+            max_hr_suggestion: Arc::new(Mutex::new(None)),
         };
 
         // Try to load existing checkpoint
@@ -179,14 +333,34 @@ impl SessionExecutor {
             tick_task: None,
             hr_receiver: Some(hr_receiver),
             checkpoint_path: None,
+            checkpoint_interval_ticks: DEFAULT_CHECKPOINT_INTERVAL_TICKS,
             scheduler: None,
+            scheduler_cancel: CancellationToken::new(),
             pending_sessions: Arc::new(Mutex::new(HashMap::new())),
             session_repository: None,
             hr_samples: Arc::new(Mutex::new(Vec::new())),
+            sample_decimator: Arc::new(Mutex::new(SampleDecimator::default())),
+            running_stats: Arc::new(Mutex::new(RunningStats::default())),
+            phase_results: Arc::new(Mutex::new(Vec::new())),
+            phase_start_time: Arc::new(Mutex::new(None)),
             session_start_time: Arc::new(Mutex::new(None)),
             progress_sender: None,
+            phase_transition_sender: None,
+            pace_cue_sender: None,
             connection_status_receiver: None,
+            connection_health_receiver: None,
             pause_reason: Arc::new(Mutex::new(None)),
+            disconnect_policy: DisconnectPolicy::default(),
+            connectivity: Arc::new(Mutex::new(ConnectivityDuringSession::default())),
+            connection_log: Arc::new(Mutex::new(ConnectionLog::new())),
+            last_hr_at: Arc::new(Mutex::new(None)),
+            hr_freshness_window: DEFAULT_HR_FRESHNESS_WINDOW,
+            paused_secs: Arc::new(Mutex::new(0)),
+            session_epoch: 0,
+            live_trimp: Arc::new(Mutex::new(LiveTrimp::new())),
+            phase_ending_lead_secs: DEFAULT_PHASE_ENDING_LEAD_SECS,
+            zone_min_dwell_secs: 0,
+            max_hr_suggestion: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -210,6 +384,36 @@ impl SessionExecutor {
         self
     }
 
+    /// Set the sender for phase transition events.
+    ///
+    /// The executor sends exactly one [`PhaseTransition`] each time the session
+    /// advances to the next phase, plus a final one when the last phase completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - Unbounded sender for PhaseTransition events
+    pub fn with_phase_transition_sender(
+        mut self,
+        sender: mpsc::UnboundedSender<PhaseTransition>,
+    ) -> Self {
+        self.phase_transition_sender = Some(sender);
+        self
+    }
+
+    /// Set the sender for pace cues.
+    ///
+    /// While a session is running and a BPM reading is available, the executor
+    /// sends one [`PaceCue`] per tick describing how far the current BPM is from
+    /// the target zone band.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - Unbounded sender for PaceCue updates
+    pub fn with_pace_cue_sender(mut self, sender: mpsc::UnboundedSender<PaceCue>) -> Self {
+        self.pace_cue_sender = Some(sender);
+        self
+    }
+
     /// Set the connection status receiver for automatic pause/resume on connection loss.
     ///
     /// When a connection status receiver is set, the executor will automatically:
@@ -228,17 +432,194 @@ impl SessionExecutor {
         self
     }
 
+    /// Set the connection health receiver used for the session's connection summary.
+    ///
+    /// The weakest RSSI reported while a session runs is stored as
+    /// [`ConnectionSummary::worst_rssi`]; without a receiver it stays `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `receiver` - Broadcast receiver for ConnectionHealth updates
+    pub fn with_connection_health(
+        mut self,
+        receiver: broadcast::Receiver<ConnectionHealth>,
+    ) -> Self {
+        self.connection_health_receiver = Some(receiver);
+        self
+    }
+
+    /// Set how a running session reacts to connection loss.
+    ///
+    /// Only has an effect together with [`with_connection_status`](Self::with_connection_status).
+    /// Defaults to [`DisconnectPolicy::Pause`]. With [`DisconnectPolicy::KeepTiming`]
+    /// phases keep running through a drop, progress updates report the link
+    /// state in [`SessionProgress::connectivity`], and zone logic resumes with
+    /// the first reading after reconnection.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Reaction to Disconnected/Reconnecting statuses
+    pub fn with_disconnect_policy(mut self, policy: DisconnectPolicy) -> Self {
+        self.disconnect_policy = policy;
+        self
+    }
+
+    /// Enable persistence without loading an existing checkpoint.
+    ///
+    /// Unlike [`with_persistence`](Self::with_persistence), a checkpoint left
+    /// at `checkpoint_path` is not restored until
+    /// [`resume_from_checkpoint`](Self::resume_from_checkpoint) is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `checkpoint_path` - Path where session state will be periodically saved
+    pub fn with_checkpoint_path(mut self, checkpoint_path: PathBuf) -> Self {
+        self.checkpoint_path = Some(checkpoint_path);
+        self
+    }
+
+    /// Set how often session checkpoints are saved.
+    ///
+    /// Only has an effect when persistence is enabled. Larger intervals save
+    /// power; smaller ones lose less progress on a crash. Defaults to
+    /// [`DEFAULT_CHECKPOINT_INTERVAL_TICKS`]; values below 1 are treated as 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticks` - Number of one-second ticks between checkpoint saves
+    pub fn with_checkpoint_interval(mut self, ticks: u32) -> Self {
+        self.checkpoint_interval_ticks = ticks.max(1);
+        self
+    }
+
+    /// Set how often an HR sample is stored for session history.
+    ///
+    /// The executor stores one [`HrSample`] per interval, holding the average of
+    /// the filtered BPMs received during it. Zone tracking still sees every
+    /// reading. Defaults to 1 (every reading is stored); values below 1 are
+    /// treated as 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `secs` - Number of seconds covered by each stored sample
+    pub fn with_sample_interval(mut self, secs: u32) -> Self {
+        self.sample_decimator = Arc::new(Mutex::new(SampleDecimator::new(secs)));
+        self
+    }
+
+    /// Set how long the last HR sample's BPM is reported as fresh.
+    ///
+    /// When no new sample arrives within `window`,
+    /// [`SessionProgress::bpm_is_fresh`] becomes false. Defaults to
+    /// [`DEFAULT_HR_FRESHNESS_WINDOW`].
+    pub fn with_hr_freshness_window(mut self, window: Duration) -> Self {
+        self.hr_freshness_window = window;
+        self
+    }
+
+    /// Set how long before the end of a timed phase the user is warned.
+    ///
+    /// [`NotificationEvent::PhaseEndingSoon`] is sent once per phase when
+    /// `secs` remain. Phases no longer than `secs` are not announced, and 0
+    /// disables the warning. Defaults to [`DEFAULT_PHASE_ENDING_LEAD_SECS`].
+    pub fn with_phase_ending_lead(mut self, secs: u32) -> Self {
+        self.phase_ending_lead_secs = secs;
+        self
+    }
+
+    /// Ignore brief zone excursions in the saved time-in-zone summary.
+    ///
+    /// A zone change only counts once HR stays in the new zone for
+    /// `min_dwell_secs`; shorter excursions are attributed to the previous
+    /// zone (see [`time_in_zone_smoothed`]). Defaults to 0, which counts every
+    /// sample in the zone it falls in.
+    pub fn with_zone_smoothing(mut self, min_dwell_secs: u32) -> Self {
+        self.set_zone_smoothing(min_dwell_secs);
+        self
+    }
+
+    /// Change the zone smoothing of an existing executor (see
+    /// [`with_zone_smoothing`](Self::with_zone_smoothing)).
+    ///
+    /// Call it before [`start_session`](Self::start_session); a session that
+    /// is already running may be summarized with either value.
+    pub fn set_zone_smoothing(&mut self, min_dwell_secs: u32) {
+        self.zone_min_dwell_secs = min_dwell_secs;
+    }
+
+    /// Change the session repository of an existing executor (see
+    /// [`with_session_repository`](Self::with_session_repository)).
+    ///
+    /// A session that is still running is stopped first, so it is saved to
+    /// the repository it was started with; sessions started afterwards are
+    /// saved to `repository`.
+    pub async fn set_session_repository(
+        &mut self,
+        repository: Arc<dyn SessionRepository>,
+    ) -> Result<()> {
+        if self.tick_task.is_some() {
+            self.stop_session().await?;
+        }
+        self.session_repository = Some(repository);
+        Ok(())
+    }
+
+    /// Set the BPM tolerance used when deciding whether HR is in the target zone.
+    ///
+    /// Readings up to `tolerance_bpm` beats outside the target zone's BPM band
+    /// are treated as in-zone, so a reading one beat into a neighbouring zone
+    /// does not trigger a deviation. Defaults to 0 (strict zone buckets).
+    ///
+    /// # Arguments
+    ///
+    /// * `tolerance_bpm` - Allowed distance in BPM outside the target zone band
+    pub async fn with_zone_tolerance(self, tolerance_bpm: u16) -> Self {
+        self.session_state
+            .lock()
+            .await
+            .context_mut()
+            .zone_tolerance_bpm = tolerance_bpm;
+        self
+    }
+
+    /// Set the warmup grace period used when the plan does not set its own.
+    ///
+    /// For the first `secs` seconds of a session, zone deviations are tracked
+    /// but not reported, so the rising HR of a warmup does not trigger a
+    /// "too low" alert. Defaults to 0 (no grace).
+    ///
+    /// # Arguments
+    ///
+    /// * `secs` - Length of the grace period in seconds
+    pub async fn with_warmup_grace(self, secs: u32) -> Self {
+        self.session_state
+            .lock()
+            .await
+            .context_mut()
+            .warmup_grace_secs = secs;
+        self
+    }
+
     /// Load session checkpoint from disk if it exists.
     ///
     /// If a checkpoint exists, it will resume the session in the saved state (InProgress or Paused).
     async fn load_checkpoint(&mut self) -> Result<()> {
+        if let Some(checkpoint) = self.read_checkpoint().await? {
+            let mut state = self.session_state.lock().await;
+            restore_checkpoint(&mut state, checkpoint);
+        }
+        Ok(())
+    }
+
+    /// Read the checkpoint file, if persistence is enabled and one exists.
+    async fn read_checkpoint(&self) -> Result<Option<SessionCheckpoint>> {
         let checkpoint_path = match &self.checkpoint_path {
             Some(path) => path,
-            None => return Ok(()), // No persistence enabled
+            None => return Ok(None), // No persistence enabled
         };
 
         if !checkpoint_path.exists() {
-            return Ok(()); // No checkpoint to load
+            return Ok(None); // No checkpoint to load
         }
 
         // Read and deserialize checkpoint
@@ -247,31 +628,54 @@ impl SessionExecutor {
             .context("Failed to read checkpoint file")?;
         let checkpoint: SessionCheckpoint =
             serde_json::from_slice(&checkpoint_data).context("Failed to deserialize checkpoint")?;
+        Ok(Some(checkpoint))
+    }
 
-        // Restore session state
-        let mut state = self.session_state.lock().await;
-
-        // Start the session with the saved plan
-        state.handle(SessionEvent::Start(checkpoint.plan));
-
-        // Fast-forward to the saved phase and elapsed time
-        if checkpoint.current_phase > 0 {
-            state.handle(SessionEvent::NextPhase(checkpoint.current_phase));
-        }
+    /// Resume the session saved in the checkpoint file and restart the tick loop.
+    ///
+    /// The plan is taken from the checkpoint, so the caller does not need to
+    /// know it. The session continues from the saved phase and elapsed time;
+    /// a session saved while paused is resumed paused. Returns `Ok(false)` if
+    /// persistence is disabled or there is no checkpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the checkpoint cannot be read or parsed.
+    pub async fn resume_from_checkpoint(&mut self) -> Result<bool> {
+        let Some(checkpoint) = self.read_checkpoint().await? else {
+            return Ok(false);
+        };
 
-        // Simulate ticks to restore elapsed time
-        for _ in 0..checkpoint.elapsed_secs {
-            // We need to manually update the state without triggering phase progression
-            // This is a bit tricky - we'll just send ticks and hope we don't exceed the phase
-            state.handle(SessionEvent::Tick);
+        // Stop any existing session first
+        if self.tick_task.is_some() {
+            self.stop_session().await?;
         }
 
-        // If it was paused, pause it now
-        if checkpoint.is_paused {
-            state.handle(SessionEvent::Pause);
-        }
+        let plan_name = checkpoint.plan.name.clone();
+        let checkpoint_max_hr = checkpoint.plan.max_hr;
+        let session_elapsed = {
+            let mut state = self.session_state.lock().await;
+            restore_checkpoint(&mut state, checkpoint);
+            state.session_elapsed_secs()
+        };
 
-        Ok(())
+        // Samples from before the restart are lost; keep the original start time
+        self.hr_samples.lock().await.clear();
+        self.sample_decimator.lock().await.flush();
+        *self.running_stats.lock().await = RunningStats::new(Some(checkpoint_max_hr));
+        self.phase_results.lock().await.clear();
+        *self.phase_start_time.lock().await = Some(PhaseStart::new(utc_now()));
+        *self.session_start_time.lock().await =
+            Some(utc_now() - chrono::Duration::seconds(session_elapsed as i64));
+        *self.connectivity.lock().await = ConnectivityDuringSession::Connected;
+        *self.connection_log.lock().await = ConnectionLog::new();
+        *self.last_hr_at.lock().await = None;
+        *self.paused_secs.lock().await = 0;
+        *self.live_trimp.lock().await = LiveTrimp::new();
+        self.session_epoch = next_session_epoch();
+
+        self.spawn_tick_loop(plan_name);
+        Ok(true)
     }
 
     /// Save current session state to checkpoint file.
@@ -300,6 +704,8 @@ impl SessionExecutor {
                     current_phase: *current_phase,
                     elapsed_secs: *elapsed_secs,
                     is_paused: false,
+                    zone_tolerance_bpm: Some(state.context().zone_tolerance_bpm),
+                    warmup_grace_secs: Some(state.context().warmup_grace_secs),
                 }
             }
             State::Paused { phase, elapsed, .. } => {
@@ -312,6 +718,8 @@ impl SessionExecutor {
                     current_phase: *phase,
                     elapsed_secs: *elapsed,
                     is_paused: true,
+                    zone_tolerance_bpm: Some(state.context().zone_tolerance_bpm),
+                    warmup_grace_secs: Some(state.context().warmup_grace_secs),
                 }
             }
             _ => {
@@ -338,9 +746,12 @@ impl SessionExecutor {
         Ok(())
     }
 
-    /// Clear checkpoint file from disk.
-    #[allow(dead_code)]
-    async fn clear_checkpoint(&self) -> Result<()> {
+    /// Clear checkpoint file from disk, so the session cannot be resumed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an existing checkpoint cannot be removed.
+    pub async fn clear_checkpoint(&self) -> Result<()> {
         let checkpoint_path = match &self.checkpoint_path {
             Some(path) => path,
             None => return Ok(()), // No persistence enabled
@@ -389,11 +800,24 @@ impl SessionExecutor {
         {
             let mut samples = self.hr_samples.lock().await;
             samples.clear();
+            // Drop any partial bucket left over from a previous session
+            self.sample_decimator.lock().await.flush();
+            *self.running_stats.lock().await = RunningStats::new(Some(plan.max_hr));
         }
+        // Timestamps only need to be monotonic within a session
+        rebase_clock();
         {
             let mut start_time = self.session_start_time.lock().await;
-            *start_time = Some(Utc::now());
+            *start_time = Some(utc_now());
+            *self.phase_start_time.lock().await = start_time.map(PhaseStart::new);
+            self.phase_results.lock().await.clear();
         }
+        *self.connectivity.lock().await = ConnectivityDuringSession::Connected;
+        *self.connection_log.lock().await = ConnectionLog::new();
+        *self.last_hr_at.lock().await = None;
+        *self.paused_secs.lock().await = 0;
+        *self.live_trimp.lock().await = LiveTrimp::new();
+        self.session_epoch = next_session_epoch();
 
         // Send Start event to the state machine
         {
@@ -401,26 +825,82 @@ impl SessionExecutor {
             state.handle(SessionEvent::Start(plan.clone()));
         }
 
+        let _ = self
+            .notification_port
+            .notify(NotificationEvent::SessionStarted)
+            .await;
+        if let Some(first_phase) = plan.phases.first() {
+            let _ = self
+                .notification_port
+                .notify(NotificationEvent::PhaseStarted {
+                    name: first_phase.name.clone(),
+                    zone: first_phase.target_zone,
+                })
+                .await;
+        }
+
+        self.spawn_tick_loop(plan.name.clone());
+
+        Ok(())
+    }
+
+    /// Spawn the loop that ticks the session once per second.
+    ///
+    /// Besides advancing the state machine, the loop feeds HR data into zone
+    /// tracking, reacts to connection status, streams progress, saves
+    /// checkpoints, and saves the session once it completes.
+    fn spawn_tick_loop(&mut self, plan_name: String) {
         // Spawn tick loop with optional HR monitoring and persistence
         let state_clone = Arc::clone(&self.session_state);
         let notifier_clone = Arc::clone(&self.notification_port);
         let mut hr_rx = self.hr_receiver.as_ref().map(|rx| rx.resubscribe());
         let checkpoint_path = self.checkpoint_path.clone();
+        let checkpoint_interval = self.checkpoint_interval_ticks;
         let hr_samples_clone = Arc::clone(&self.hr_samples);
+        let sample_decimator_clone = Arc::clone(&self.sample_decimator);
+        let running_stats_clone = Arc::clone(&self.running_stats);
+        let phase_results_clone = Arc::clone(&self.phase_results);
+        let phase_start_time_clone = Arc::clone(&self.phase_start_time);
         let session_start_time_clone = Arc::clone(&self.session_start_time);
         let session_repository_clone = self.session_repository.clone();
-        let plan_name = plan.name.clone();
         let progress_tx = self.progress_sender.clone();
+        let transition_tx = self.phase_transition_sender.clone();
+        let pace_cue_tx = self.pace_cue_sender.clone();
         let mut connection_rx = self
             .connection_status_receiver
             .as_ref()
             .map(|rx| rx.resubscribe());
         let pause_reason_clone = Arc::clone(&self.pause_reason);
+        let disconnect_policy = self.disconnect_policy;
+        let connectivity_clone = Arc::clone(&self.connectivity);
+        let connection_log_clone = Arc::clone(&self.connection_log);
+        // Stopped by the tick task's drop guard when the tick loop ends
+        let connection_log_cancel = CancellationToken::new();
+        spawn_connection_log_task(
+            connection_rx.as_ref().map(|rx| rx.resubscribe()),
+            self.connection_health_receiver
+                .as_ref()
+                .map(|rx| rx.resubscribe()),
+            hr_rx.as_ref().map(|rx| rx.resubscribe()),
+            Arc::clone(&self.connection_log),
+            connection_log_cancel.clone(),
+        );
+        let last_hr_at_clone = Arc::clone(&self.last_hr_at);
+        let hr_freshness_window = self.hr_freshness_window;
+        let paused_secs_clone = Arc::clone(&self.paused_secs);
+        let session_epoch = self.session_epoch;
+        let live_trimp_clone = Arc::clone(&self.live_trimp);
+        let phase_ending_lead_secs = self.phase_ending_lead_secs;
+        let zone_min_dwell_secs = self.zone_min_dwell_secs;
+        let max_hr_suggestion_clone = Arc::clone(&self.max_hr_suggestion);
 
         let tick_task = tokio::spawn(async move {
+            let _stop_connection_log = connection_log_cancel.drop_guard();
             let mut ticker = interval(Duration::from_secs(1));
             ticker.tick().await; // First tick completes immediately, skip it
             let mut tick_count = 0u32;
+            // Phase whose end was already announced
+            let mut phase_ending_announced: Option<usize> = None;
 
             loop {
                 ticker.tick().await;
@@ -430,14 +910,17 @@ impl SessionExecutor {
                     loop {
                         match rx.try_recv() {
                             Ok(hr_data) => {
+                                *last_hr_at_clone.lock().await = Some(Instant::now());
+
                                 // Collect HR sample for session history
-                                {
-                                    let mut samples = hr_samples_clone.lock().await;
-                                    samples.push(HrSample {
-                                        timestamp: Utc::now(),
-                                        bpm: hr_data.filtered_bpm,
-                                    });
-                                }
+                                record_hr_sample(
+                                    &sample_decimator_clone,
+                                    &hr_samples_clone,
+                                    &running_stats_clone,
+                                    utc_now(),
+                                    hr_data.filtered_bpm,
+                                )
+                                .await;
 
                                 // Update BPM and check for zone deviation
                                 let deviation = {
@@ -455,7 +938,12 @@ impl SessionExecutor {
                                             let state = state_clone.lock().await;
                                             state.get_progress()
                                         } {
-                                            if phase_idx < plan_context.phases.len() {
+                                            // Phases may opt out of deviation alerts
+                                            if plan_context
+                                                .phases
+                                                .get(phase_idx)
+                                                .is_some_and(|phase| phase.alerts_enabled)
+                                            {
                                                 let target_zone =
                                                     plan_context.phases[phase_idx].target_zone;
                                                 let _ = notifier_clone
@@ -481,6 +969,9 @@ impl SessionExecutor {
                             Err(broadcast::error::TryRecvError::Closed) => {
                                 // Channel closed, stop HR monitoring but continue session
                                 hr_rx = None;
+                                *connectivity_clone.lock().await =
+                                    ConnectivityDuringSession::Disconnected;
+                                state_clone.lock().await.handle(SessionEvent::HrLost);
                                 break;
                             }
                         }
@@ -492,6 +983,19 @@ impl SessionExecutor {
                     loop {
                         match rx.try_recv() {
                             Ok(status) => {
+                                if let Some(connectivity) = session_connectivity(&status) {
+                                    *connectivity_clone.lock().await = connectivity;
+
+                                    // Keep timing through the drop; only HR goes away
+                                    if disconnect_policy == DisconnectPolicy::KeepTiming {
+                                        if !connectivity.hr_available() {
+                                            let mut state = state_clone.lock().await;
+                                            state.handle(SessionEvent::HrLost);
+                                        }
+                                        continue;
+                                    }
+                                }
+
                                 match status {
                                     ConnectionStatus::Disconnected
                                     | ConnectionStatus::Reconnecting { .. } => {
@@ -562,30 +1066,99 @@ impl SessionExecutor {
                 }
 
                 // Handle the tick
-                {
+                let (transition, completed) = {
                     let mut state = state_clone.lock().await;
+                    if matches!(state.state(), State::Paused { .. }) {
+                        *paused_secs_clone.lock().await += 1;
+                    }
+                    if matches!(state.state(), State::InProgress { .. }) {
+                        let hr_available = connectivity_clone.lock().await.hr_available()
+                            && hr_is_fresh(
+                                *last_hr_at_clone.lock().await,
+                                Instant::now(),
+                                hr_freshness_window,
+                            );
+                        accumulate_live_trimp(&live_trimp_clone, &state, hr_available).await;
+                    }
+                    let phase_before = current_phase_index(&state);
                     state.handle(SessionEvent::Tick);
-
-                    // Check if session is completed or stopped
-                    if matches!(
+                    let phase_after = current_phase_index(&state);
+
+                    // Detect whether this tick advanced or finished the plan.
+                    // Comparing within one lock guarantees a single event per change.
+                    let transition = phase_before
+                        .and_then(|from| detect_phase_transition(&state, from, phase_after))
+                        .map(|transition| (finished_phase(&state, &transition), transition));
+                    let completed = matches!(
                         state.state(),
                         crate::state::session::State::Completed { .. }
-                    ) {
-                        break;
-                    }
+                    );
+                    (transition, completed)
+                };
+
+                if let Some((finished, transition)) = transition {
+                    record_phase_result(
+                        finished,
+                        &phase_start_time_clone,
+                        &paused_secs_clone,
+                        &hr_samples_clone,
+                        &phase_results_clone,
+                    )
+                    .await;
+                    announce_phase_transition(
+                        transition,
+                        transition_tx.as_ref(),
+                        notifier_clone.as_ref(),
+                    )
+                    .await;
+                }
+
+                // Check if session is completed or stopped
+                if completed {
+                    break;
+                }
+
+                // Warn once per phase shortly before it ends
+                let ending_soon = {
+                    let state = state_clone.lock().await;
+                    phase_ending_soon(&state, phase_ending_lead_secs, phase_ending_announced)
+                };
+                if let Some((phase_idx, event)) = ending_soon {
+                    phase_ending_announced = Some(phase_idx);
+                    let _ = notifier_clone.notify(event).await;
                 }
 
                 // Emit progress update if a sender is configured
                 if let Some(ref tx) = progress_tx {
-                    if let Some(progress) = build_session_progress(&state_clone).await {
+                    if let Some(progress) = build_session_progress(
+                        &state_clone,
+                        &connectivity_clone,
+                        &last_hr_at_clone,
+                        hr_freshness_window,
+                        session_epoch,
+                        &live_trimp_clone,
+                    )
+                    .await
+                    {
                         // Ignore send errors (receiver may have been dropped)
                         let _ = tx.send(progress);
                     }
                 }
 
-                // Increment tick count and save checkpoint every 10 ticks
+                // Emit a pace cue for the current BPM if a sender is configured
+                if let Some(ref tx) = pace_cue_tx {
+                    let cue = {
+                        let state = state_clone.lock().await;
+                        current_pace_cue(&state)
+                    };
+                    if let Some(cue) = cue {
+                        let _ = tx.send(cue);
+                    }
+                }
+
+                // Increment tick count and save checkpoint every interval
                 tick_count += 1;
-                if tick_count.is_multiple_of(10) {
+                if tick_count.is_multiple_of(checkpoint_interval) {
                     if let Some(ref path) = checkpoint_path {
                         // Save checkpoint (ignoring errors to not disrupt session)
                         let state = state_clone.lock().await;
@@ -601,6 +1174,8 @@ impl SessionExecutor {
                                 current_phase: *current_phase,
                                 elapsed_secs: *elapsed_secs,
                                 is_paused: false,
+                                zone_tolerance_bpm: Some(state.context().zone_tolerance_bpm),
+                                warmup_grace_secs: Some(state.context().warmup_grace_secs),
                             }),
                             State::Paused { phase, elapsed, .. } => {
                                 state.context().plan().map(|plan| SessionCheckpoint {
@@ -608,6 +1183,8 @@ impl SessionExecutor {
                                     current_phase: *phase,
                                     elapsed_secs: *elapsed,
                                     is_paused: true,
+                                    zone_tolerance_bpm: Some(state.context().zone_tolerance_bpm),
+                                    warmup_grace_secs: Some(state.context().warmup_grace_secs),
                                 })
                             }
                             _ => None,
@@ -626,37 +1203,71 @@ impl SessionExecutor {
                 }
             }
 
-            // Session completed - save to repository if enabled
-            if let Some(ref repository) = session_repository_clone {
-                let start_time = session_start_time_clone.lock().await;
-                if let Some(start) = *start_time {
-                    let end_time = Utc::now();
-                    let duration = (end_time - start).num_seconds().max(0) as u32;
-
-                    // Collect HR samples
-                    let samples = hr_samples_clone.lock().await.clone();
+            // Session completed - announce it and save to repository if enabled
+            let start_time = *session_start_time_clone.lock().await;
+            if let Some(start) = start_time {
+                let end_time = utc_now();
+                let wall_duration = (end_time - start).num_seconds().max(0) as u32;
+                let duration = wall_duration.saturating_sub(*paused_secs_clone.lock().await);
 
-                    // Get session state to determine status and phases completed
-                    let (status, phases_completed) = {
-                        let state = state_clone.lock().await;
-                        let status = match state.state() {
-                            crate::state::session::State::Completed {} => SessionStatus::Completed,
-                            _ => SessionStatus::Stopped,
-                        };
-                        let phases = if let Some((phase_idx, _, _)) = state.get_progress() {
-                            phase_idx as u32
-                        } else {
-                            0
-                        };
-                        (status, phases)
+                // Collect HR samples
+                let (samples, stats, max_gap_secs) = collect_hr_samples(
+                    &sample_decimator_clone,
+                    &hr_samples_clone,
+                    &running_stats_clone,
+                )
+                .await;
+                let sample_interval_secs = sample_decimator_clone.lock().await.interval_secs();
+
+                // Get session state to determine status and phases completed
+                let (status, phases_completed, max_hr) = {
+                    let state = state_clone.lock().await;
+                    let status = match state.state() {
+                        crate::state::session::State::Completed {} => SessionStatus::Completed,
+                        _ => SessionStatus::Stopped,
+                    };
+                    let phases = if let Some((phase_idx, _, _)) = state.get_progress() {
+                        phase_idx as u32
+                    } else {
+                        0
                     };
+                    (status, phases, state.context().plan().map(|p| p.max_hr))
+                };
 
-                    // Calculate summary statistics
-                    let summary = SessionSummary::from_samples(&samples, duration, [0, 0, 0, 0, 0]);
+                // Calculate summary statistics
+                let time_in_zone = max_hr
+                    .map(|max_hr| {
+                        time_in_zone_smoothed(&samples, max_hr, max_gap_secs, zone_min_dwell_secs)
+                    })
+                    .unwrap_or_default();
+                let summary = SessionSummary::from_running_stats(&stats, duration, time_in_zone)
+                    .with_durations(wall_duration, duration)
+                    .with_data_completeness(data_completeness_pct(
+                        samples.len(),
+                        duration,
+                        sample_interval_secs,
+                    ))
+                    .with_trimp(live_trimp_clone.lock().await.trimp());
+
+                if status == SessionStatus::Completed {
+                    let _ = notifier_clone
+                        .notify(NotificationEvent::SessionCompleted {
+                            summary: summary.clone(),
+                        })
+                        .await;
+                    report_max_hr_exceeded(
+                        summary.max_hr,
+                        max_hr,
+                        &max_hr_suggestion_clone,
+                        notifier_clone.as_ref(),
+                    )
+                    .await;
+                }
 
+                if let Some(ref repository) = session_repository_clone {
                     // Create completed session
                     let session = CompletedSession {
-                        id: uuid::Uuid::new_v4().to_string(),
+                        id: new_session_id(),
                         plan_name: plan_name.clone(),
                         start_time: start,
                         end_time,
@@ -664,10 +1275,16 @@ impl SessionExecutor {
                         hr_samples: samples,
                         phases_completed,
                         summary,
+                        context: None,
+                        phase_results: phase_results_clone.lock().await.clone(),
+                        connection_summary: Some(
+                            connection_log_clone.lock().await.summary(end_time),
+                        ),
                     };
 
                     // Save the session (ignore errors to not disrupt cleanup)
                     let _ = repository.save(&session).await;
+                    let _ = repository.flush().await;
                 }
             }
 
@@ -680,8 +1297,6 @@ impl SessionExecutor {
         });
 
         self.tick_task = Some(tick_task);
-
-        Ok(())
     }
 
     /// Pause the current session.
@@ -716,10 +1331,92 @@ impl SessionExecutor {
         Ok(())
     }
 
+    /// Skip the rest of the current phase.
+    ///
+    /// Advances to the next phase immediately, or completes the session if this
+    /// was the last phase. The time already spent in the skipped phase is
+    /// recorded as its elapsed time rather than the planned duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no phase is currently in progress (e.g. while paused).
+    pub async fn skip_phase(&mut self) -> Result<()> {
+        let transition = {
+            let mut state = self.session_state.lock().await;
+            let from = current_phase_index(&state).context("No phase in progress")?;
+            state.handle(SessionEvent::SkipPhase);
+            detect_phase_transition(&state, from, current_phase_index(&state))
+                .map(|transition| (finished_phase(&state, &transition), transition))
+        };
+
+        if let Some((finished, transition)) = transition {
+            record_phase_result(
+                finished,
+                &self.phase_start_time,
+                &self.paused_secs,
+                &self.hr_samples,
+                &self.phase_results,
+            )
+            .await;
+            announce_phase_transition(
+                transition,
+                self.phase_transition_sender.as_ref(),
+                self.notification_port.as_ref(),
+            )
+            .await;
+        }
+        Ok(())
+    }
+
+    /// Add time to the current phase.
+    ///
+    /// Increases the active phase's effective duration by `extra_secs` for this
+    /// session only; the saved plan is not modified. Works while paused.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no session is running or paused.
+    pub async fn extend_phase(&mut self, extra_secs: u32) -> Result<()> {
+        let mut state = self.session_state.lock().await;
+        if !matches!(
+            state.state(),
+            State::InProgress { .. } | State::Paused { .. }
+        ) {
+            anyhow::bail!("No phase in progress");
+        }
+        state.handle(SessionEvent::ExtendPhase(extra_secs));
+        Ok(())
+    }
+
     /// Stop the current session.
     ///
     /// Sends a Stop event to the state machine and cancels the tick loop task.
     pub async fn stop_session(&mut self) -> Result<()> {
+        // Record the phase cut short by the stop
+        let running = running_phase(&*self.session_state.lock().await);
+        if let Some((_, plan_max_hr)) = running {
+            // A workout ended early can reveal a too-low max HR as well
+            if let Some(observed) = self.running_stats.lock().await.max() {
+                report_max_hr_exceeded(
+                    observed,
+                    Some(plan_max_hr),
+                    &self.max_hr_suggestion,
+                    self.notification_port.as_ref(),
+                )
+                .await;
+            }
+        }
+        if running.is_some() {
+            record_phase_result(
+                running,
+                &self.phase_start_time,
+                &self.paused_secs,
+                &self.hr_samples,
+                &self.phase_results,
+            )
+            .await;
+        }
+
         // Save the session before stopping
         self.save_current_session(SessionStatus::Stopped).await;
 
@@ -744,14 +1441,21 @@ impl SessionExecutor {
         if let Some(ref repository) = self.session_repository {
             let start_time = self.session_start_time.lock().await;
             if let Some(start) = *start_time {
-                let end_time = Utc::now();
-                let duration = (end_time - start).num_seconds().max(0) as u32;
+                let end_time = utc_now();
+                let wall_duration = (end_time - start).num_seconds().max(0) as u32;
+                let duration = wall_duration.saturating_sub(*self.paused_secs.lock().await);
 
                 // Collect HR samples
-                let samples = self.hr_samples.lock().await.clone();
+                let (samples, stats, max_gap_secs) = collect_hr_samples(
+                    &self.sample_decimator,
+                    &self.hr_samples,
+                    &self.running_stats,
+                )
+                .await;
+                let sample_interval_secs = self.sample_decimator.lock().await.interval_secs();
 
                 // Get session state to determine phases completed and plan name
-                let (phases_completed, plan_name) = {
+                let (phases_completed, plan_name, max_hr) = {
                     let state = self.session_state.lock().await;
                     let phases = if let Some((phase_idx, _, _)) = state.get_progress() {
                         phase_idx as u32
@@ -763,15 +1467,32 @@ impl SessionExecutor {
                         .plan()
                         .map(|p| p.name.clone())
                         .unwrap_or_else(|| "Unknown".to_string());
-                    (phases, plan_name)
+                    (phases, plan_name, state.context().plan().map(|p| p.max_hr))
                 };
 
                 // Calculate summary statistics
-                let summary = SessionSummary::from_samples(&samples, duration, [0, 0, 0, 0, 0]);
+                let time_in_zone = max_hr
+                    .map(|max_hr| {
+                        time_in_zone_smoothed(
+                            &samples,
+                            max_hr,
+                            max_gap_secs,
+                            self.zone_min_dwell_secs,
+                        )
+                    })
+                    .unwrap_or_default();
+                let summary = SessionSummary::from_running_stats(&stats, duration, time_in_zone)
+                    .with_durations(wall_duration, duration)
+                    .with_data_completeness(data_completeness_pct(
+                        samples.len(),
+                        duration,
+                        sample_interval_secs,
+                    ))
+                    .with_trimp(self.live_trimp.lock().await.trimp());
 
                 // Create completed session
                 let session = CompletedSession {
-                    id: uuid::Uuid::new_v4().to_string(),
+                    id: new_session_id(),
                     plan_name,
                     start_time: start,
                     end_time,
@@ -779,10 +1500,14 @@ impl SessionExecutor {
                     hr_samples: samples,
                     phases_completed,
                     summary,
+                    context: None,
+                    phase_results: self.phase_results.lock().await.clone(),
+                    connection_summary: Some(self.connection_log.lock().await.summary(end_time)),
                 };
 
                 // Save the session (ignore errors)
                 let _ = repository.save(&session).await;
+                let _ = repository.flush().await;
             }
         }
     }
@@ -796,6 +1521,29 @@ impl SessionExecutor {
         state.get_progress()
     }
 
+    /// Get a full progress snapshot of the current session.
+    ///
+    /// Returns the same data as the progress stream, or None if no session
+    /// is running or paused.
+    pub async fn current_progress(&self) -> Option<SessionProgress> {
+        build_session_progress(
+            &self.session_state,
+            &self.connectivity,
+            &self.last_hr_at,
+            self.hr_freshness_window,
+            self.session_epoch,
+            &self.live_trimp,
+        )
+        .await
+    }
+
+    /// Epoch of the most recently started session (0 if none was started).
+    ///
+    /// See [`SessionProgress::session_epoch`].
+    pub fn session_epoch(&self) -> u64 {
+        self.session_epoch
+    }
+
     /// Get the training plan being executed.
     ///
     /// Returns a reference to the plan if a session is active, None otherwise.
@@ -804,20 +1552,81 @@ impl SessionExecutor {
         state.context().plan().cloned()
     }
 
-    /// Schedule a training session to start at a specific time using a cron expression.
+    /// Max HR suggested after a session peaked above the plan's.
     ///
-    /// When the scheduled time arrives, a `WorkoutReady` notification is emitted.
-    /// If the user calls `start_session` with a matching plan within 10 minutes,
-    /// the session begins. Otherwise, the scheduled session is marked as skipped.
+    /// Set when a session completes or is stopped with a peak BPM at least
+    /// [`MAX_HR_EXCEEDED_MARGIN_BPM`](crate::domain::MAX_HR_EXCEEDED_MARGIN_BPM)
+    /// above the plan's `max_hr`, alongside
+    /// [`NotificationEvent::MaxHrExceeded`]. `None` until that happens.
+    pub async fn suggested_max_hr(&self) -> Option<u16> {
+        *self.max_hr_suggestion.lock().await
+    }
+
+    /// Results of the phases completed so far in the current session.
     ///
-    /// # Arguments
+    /// A result is recorded each time a phase ends, whether it ran its course
+    /// or was skipped, and saved with the session.
+    pub async fn phase_results(&self) -> Vec<PhaseResult> {
+        self.phase_results.lock().await.clone()
+    }
+
+    /// Connection reliability of the current session so far.
+    pub async fn connection_summary(&self) -> ConnectionSummary {
+        self.connection_log.lock().await.summary(utc_now())
+    }
+
+    /// Statistics over the HR samples stored so far in the current session.
     ///
-    /// * `plan` - The training plan to schedule
+    /// Updated as each sample is stored (see
+    /// [`with_sample_interval`](Self::with_sample_interval)); a reading still
+    /// in the decimator's open bucket is not yet included.
+    pub async fn live_stats(&self) -> RunningStats {
+        self.running_stats.lock().await.clone()
+    }
+
+    /// Get up to `count` phases that follow the current one, with their
+    /// indices in the plan.
+    ///
+    /// Works while the session is running or paused. Returns fewer phases
+    /// near the end of the plan, and none on the last phase or when no
+    /// session is active.
+    pub async fn get_upcoming_phases(&self, count: usize) -> Vec<(usize, TrainingPhase)> {
+        let state = self.session_state.lock().await;
+        let current = match *state.state() {
+            State::InProgress { current_phase, .. } => current_phase,
+            State::Paused { phase, .. } => phase,
+            State::Completed { .. } | State::Idle { .. } => return Vec::new(),
+        };
+        state
+            .context()
+            .plan()
+            .map(|plan| {
+                plan.phases
+                    .iter()
+                    .enumerate()
+                    .skip(current + 1)
+                    .take(count)
+                    .map(|(index, phase)| (index, phase.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Schedule a training session to start at a specific time using a cron expression.
+    ///
+    /// When the scheduled time arrives, a `WorkoutReady` notification is emitted.
+    /// If the user calls `start_session` with a matching plan within 10 minutes,
+    /// the session begins. Otherwise, the scheduled session is marked as skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `plan` - The training plan to schedule
     /// * `cron_expr` - A cron expression defining when the session should fire (e.g., "0 30 17 * * *" for 5:30 PM daily)
     ///
     /// # Returns
     ///
-    /// Result indicating success or failure. Fails if the cron expression is invalid.
+    /// Result indicating success or failure. Fails if the cron expression is invalid,
+    /// in which case nothing is scheduled.
     ///
     /// # Examples
     ///
@@ -826,6 +1635,8 @@ impl SessionExecutor {
     /// executor.schedule_session(plan, "0 0 6 * * MON,WED,FRI").await?;
     /// ```
     pub async fn schedule_session(&mut self, plan: TrainingPlan, cron_expr: &str) -> Result<()> {
+        validate_cron_expression(cron_expr)?;
+
         // Initialize scheduler if not already done
         if self.scheduler.is_none() {
             let sched = JobScheduler::new()
@@ -833,6 +1644,7 @@ impl SessionExecutor {
                 .context("Failed to create job scheduler")?;
             sched.start().await.context("Failed to start scheduler")?;
             self.scheduler = Some(Arc::new(sched));
+            self.scheduler_cancel = CancellationToken::new();
         }
 
         let scheduler = self
@@ -846,6 +1658,7 @@ impl SessionExecutor {
         let plan_clone = plan.clone();
         let notification_port = Arc::clone(&self.notification_port);
         let pending_sessions = Arc::clone(&self.pending_sessions);
+        let cancel = self.scheduler_cancel.clone();
 
         // Create the cron job
         let job = Job::new_async(cron_expr, move |_uuid, _lock| {
@@ -853,6 +1666,7 @@ impl SessionExecutor {
             let plan = plan_clone.clone();
             let notifier = Arc::clone(&notification_port);
             let pending = Arc::clone(&pending_sessions);
+            let cancel = cancel.clone();
 
             Box::pin(async move {
                 // Store the scheduled session as pending
@@ -861,7 +1675,12 @@ impl SessionExecutor {
                     scheduled_time: Instant::now(),
                 };
                 {
+                    // Checked under the lock shutdown_scheduler clears pending
+                    // sessions with, so a job racing shutdown leaves no trace
                     let mut pending_map = pending.lock().await;
+                    if cancel.is_cancelled() {
+                        return;
+                    }
                     pending_map.insert(plan_name.clone(), session);
                 }
 
@@ -898,6 +1717,224 @@ impl SessionExecutor {
 
         Ok(())
     }
+
+    /// Shut down the cron scheduler and drop scheduled workouts awaiting a start.
+    ///
+    /// No scheduled job fires afterwards. The scheduler is detached from the
+    /// executor before shutting it down, so a cancelled call never leaves a
+    /// half-stopped scheduler behind; a later [`schedule_session`](Self::schedule_session)
+    /// starts a fresh one. Does nothing to the scheduler if none was started.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scheduler fails to shut down.
+    pub async fn shutdown_scheduler(&mut self) -> Result<()> {
+        self.scheduler_cancel.cancel();
+        self.pending_sessions.lock().await.clear();
+
+        if let Some(scheduler) = self.scheduler.take() {
+            let mut scheduler = JobScheduler::clone(&scheduler);
+            scheduler
+                .shutdown()
+                .await
+                .context("Failed to shut down job scheduler")?;
+        }
+        Ok(())
+    }
+}
+
+/// Check that `cron_expr` is a valid schedule for [`SessionExecutor::schedule_session`].
+///
+/// Expressions have six fields, starting with seconds: `sec min hour day month weekday`.
+///
+/// # Errors
+///
+/// Returns an error describing the expected format if the expression cannot be parsed.
+pub fn validate_cron_expression(cron_expr: &str) -> Result<()> {
+    Job::new_async(cron_expr, |_uuid, _lock| Box::pin(async {}))
+        .map(|_| ())
+        .with_context(|| {
+            format!(
+                "Invalid cron expression '{}': expected six fields \
+                 (sec min hour day month weekday), e.g. \"0 30 17 * * *\"",
+                cron_expr
+            )
+        })
+}
+
+/// Put `state` at the position saved in `checkpoint`, paused if it was saved paused.
+///
+/// Zone tolerance and warmup grace saved in the checkpoint replace the
+/// executor's own settings; older checkpoints without them keep the current ones.
+fn restore_checkpoint(state: &mut SessionStateMachineWrapper, checkpoint: SessionCheckpoint) {
+    let SessionCheckpoint {
+        plan,
+        current_phase,
+        elapsed_secs,
+        is_paused,
+        zone_tolerance_bpm,
+        warmup_grace_secs,
+    } = checkpoint;
+
+    if let Some(tolerance) = zone_tolerance_bpm {
+        state.context_mut().zone_tolerance_bpm = tolerance;
+    }
+    if let Some(secs) = warmup_grace_secs {
+        state.context_mut().warmup_grace_secs = secs;
+    }
+    state.handle(SessionEvent::Start(plan));
+    state.handle(SessionEvent::Restore {
+        phase: current_phase,
+        elapsed_secs,
+    });
+    if is_paused {
+        state.handle(SessionEvent::Pause);
+    }
+}
+
+/// Send a phase transition to the UI channel, if any, and notify the user
+/// when a new phase has started.
+async fn announce_phase_transition(
+    transition: PhaseTransition,
+    sender: Option<&mpsc::UnboundedSender<PhaseTransition>>,
+    notifier: &dyn NotificationPort,
+) {
+    if let (Some(name), Some(zone)) = (transition.to_phase_name.clone(), transition.to_zone) {
+        let _ = notifier
+            .notify(NotificationEvent::PhaseStarted { name, zone })
+            .await;
+    }
+    if let Some(tx) = sender {
+        let _ = tx.send(transition);
+    }
+}
+
+/// The phase a transition left and the plan's max HR.
+fn finished_phase(
+    state: &SessionStateMachineWrapper,
+    transition: &PhaseTransition,
+) -> Option<(TrainingPhase, u16)> {
+    let plan = state.context().plan()?;
+    let phase = plan.phases.get(transition.from_index as usize)?;
+    Some((phase.clone(), plan.max_hr))
+}
+
+/// When a phase started, with the session's paused seconds at that time.
+#[derive(Debug, Clone, Copy)]
+struct PhaseStart {
+    at: chrono::DateTime<Utc>,
+    paused_secs: u32,
+}
+
+impl PhaseStart {
+    /// A phase starting at `at`, before any pause.
+    fn new(at: chrono::DateTime<Utc>) -> Self {
+        Self { at, paused_secs: 0 }
+    }
+}
+
+/// Record the result of a phase that just ended and start timing the next one.
+///
+/// Time the session spent paused during the phase is excluded from the time
+/// its target is judged over.
+async fn record_phase_result(
+    finished: Option<(TrainingPhase, u16)>,
+    phase_start_time: &Mutex<Option<PhaseStart>>,
+    paused_secs: &Mutex<u32>,
+    samples: &Mutex<Vec<HrSample>>,
+    results: &Mutex<Vec<PhaseResult>>,
+) {
+    let end_time = utc_now();
+    let paused_now = *paused_secs.lock().await;
+    let next = PhaseStart {
+        at: end_time,
+        paused_secs: paused_now,
+    };
+    let start = phase_start_time.lock().await.replace(next);
+    if let (Some((phase, max_hr)), Some(start)) = (finished, start) {
+        let result = PhaseResult::from_samples(
+            &phase,
+            start.at,
+            end_time,
+            &samples.lock().await,
+            max_hr,
+            paused_now.saturating_sub(start.paused_secs),
+        );
+        results.lock().await.push(result);
+    }
+}
+
+/// The phase running (or paused) in `state`, with the plan's max HR.
+fn running_phase(state: &SessionStateMachineWrapper) -> Option<(TrainingPhase, u16)> {
+    let index = match state.state() {
+        State::InProgress { current_phase, .. } => *current_phase,
+        State::Paused { phase, .. } => *phase,
+        _ => return None,
+    };
+    let plan = state.context().plan()?;
+    Some((plan.phases.get(index)?.clone(), plan.max_hr))
+}
+
+/// Pass a filtered BPM reading through the decimator, storing any completed
+/// sample and adding it to the running statistics.
+async fn record_hr_sample(
+    decimator: &Mutex<SampleDecimator>,
+    samples: &Mutex<Vec<HrSample>>,
+    stats: &Mutex<RunningStats>,
+    timestamp: chrono::DateTime<Utc>,
+    bpm: u16,
+) {
+    if let Some(sample) = decimator.lock().await.push(timestamp, bpm) {
+        samples.lock().await.push(sample);
+        stats.lock().await.push(sample.bpm);
+    }
+}
+
+/// Store the decimator's partial bucket and return all samples of the session
+/// with their statistics.
+///
+/// Also returns the gap cap to use for time in zone, which must cover the
+/// regular spacing of samples stored at a coarse interval.
+async fn collect_hr_samples(
+    decimator: &Mutex<SampleDecimator>,
+    samples: &Mutex<Vec<HrSample>>,
+    stats: &Mutex<RunningStats>,
+) -> (Vec<HrSample>, RunningStats, u32) {
+    let mut decimator = decimator.lock().await;
+    let mut samples = samples.lock().await;
+    let mut stats = stats.lock().await;
+    if let Some(sample) = decimator.flush() {
+        samples.push(sample);
+        stats.push(sample.bpm);
+    }
+    let max_gap_secs = MAX_SAMPLE_GAP_SECS.max(decimator.interval_secs());
+    (samples.clone(), stats.clone(), max_gap_secs)
+}
+
+/// Suggest a new max HR and notify if a finished session peaked above the plan's.
+///
+/// Returns whether [`NotificationEvent::MaxHrExceeded`] was sent.
+async fn report_max_hr_exceeded(
+    observed_max: u16,
+    plan_max_hr: Option<u16>,
+    suggestion: &Mutex<Option<u16>>,
+    notifier: &dyn NotificationPort,
+) -> bool {
+    let Some(configured) = plan_max_hr else {
+        return false;
+    };
+    let Some(observed) = suggest_max_hr(observed_max, configured) else {
+        return false;
+    };
+
+    *suggestion.lock().await = Some(observed);
+    let _ = notifier
+        .notify(NotificationEvent::MaxHrExceeded {
+            observed,
+            configured,
+        })
+        .await;
+    true
 }
 
 /// Build a SessionProgress snapshot from the current session state.
@@ -905,20 +1942,144 @@ impl SessionExecutor {
 /// Returns None if the session is not in a trackable state (Idle).
 async fn build_session_progress(
     state: &Arc<Mutex<SessionStateMachineWrapper>>,
+    connectivity: &Mutex<ConnectivityDuringSession>,
+    last_hr_at: &Mutex<Option<Instant>>,
+    freshness_window: Duration,
+    session_epoch: u64,
+    live_trimp: &Mutex<LiveTrimp>,
 ) -> Option<SessionProgress> {
+    let connectivity = *connectivity.lock().await;
+    let last_hr_at = *last_hr_at.lock().await;
     let state_lock = state.lock().await;
+    let now_ms = unix_millis();
+    let mut progress = session_progress_at(&state_lock, now_ms)?;
+    progress.connectivity = connectivity;
+    progress.bpm_is_fresh = hr_is_fresh(last_hr_at, Instant::now(), freshness_window);
+    progress.session_epoch = session_epoch;
+    let live_trimp = live_trimp.lock().await;
+    progress.trimp = live_trimp.trimp();
+    progress.trimp_per_min = live_trimp.trimp_per_minute();
+    Some(progress)
+}
+
+/// Add one running second to the session's TRIMP at the current BPM.
+///
+/// Resting and max HR come from the plan; without HR the second only counts
+/// towards the active time.
+async fn accumulate_live_trimp(
+    live_trimp: &Mutex<LiveTrimp>,
+    state: &SessionStateMachineWrapper,
+    hr_available: bool,
+) {
+    let Some(plan) = state.context().plan() else {
+        return;
+    };
+    let bpm = state.context().current_bpm;
+    let bpm = (hr_available && bpm > 0).then_some(bpm);
+    let resting_hr = plan.resting_hr.unwrap_or(DEFAULT_RESTING_HR);
+    live_trimp
+        .lock()
+        .await
+        .add_second(bpm, resting_hr, plan.max_hr);
+}
+
+/// Whether an HR sample received at `last_hr_at` is still within `window` at `now`.
+fn hr_is_fresh(last_hr_at: Option<Instant>, now: Instant, window: Duration) -> bool {
+    last_hr_at.is_some_and(|at| now.saturating_duration_since(at) <= window)
+}
+
+/// Spawn the task that records link state changes and RSSI readings in `log`.
+///
+/// Events are stamped when they arrive rather than on the next tick, so drop
+/// durations are accurate to the moment. The HR stream closing counts as a
+/// disconnect. The task ends when `cancel` fires or every stream has closed.
+fn spawn_connection_log_task(
+    mut status_rx: Option<broadcast::Receiver<ConnectionStatus>>,
+    mut health_rx: Option<broadcast::Receiver<ConnectionHealth>>,
+    mut hr_rx: Option<broadcast::Receiver<FilteredHeartRate>>,
+    log: Arc<Mutex<ConnectionLog>>,
+    cancel: CancellationToken,
+) -> JoinHandle<()> {
+    use broadcast::error::RecvError;
+
+    tokio::spawn(async move {
+        while status_rx.is_some() || health_rx.is_some() || hr_rx.is_some() {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                status = recv_if_some(&mut status_rx) => match status {
+                    Ok(status) => {
+                        if let Some(connectivity) = session_connectivity(&status) {
+                            log.lock().await.record(utc_now(), connectivity);
+                        }
+                    }
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => status_rx = None,
+                },
+                health = recv_if_some(&mut health_rx) => match health {
+                    Ok(health) => {
+                        if let Some(rssi) = health.rssi {
+                            log.lock().await.record_rssi(rssi);
+                        }
+                    }
+                    Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => health_rx = None,
+                },
+                hr = recv_if_some(&mut hr_rx) => {
+                    if let Err(RecvError::Closed) = hr {
+                        log.lock()
+                            .await
+                            .record(utc_now(), ConnectivityDuringSession::Disconnected);
+                        hr_rx = None;
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Receive from `rx`, or wait forever if there is no receiver.
+async fn recv_if_some<T: Clone>(
+    rx: &mut Option<broadcast::Receiver<T>>,
+) -> std::result::Result<T, broadcast::error::RecvError> {
+    match rx {
+        Some(rx) => rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
 
-    // Get the current state
-    let session_state = match state_lock.state() {
-        State::InProgress { .. } => ProgressState::Running,
-        State::Paused { .. } => ProgressState::Paused,
-        State::Completed { .. } => ProgressState::Completed,
-        State::Idle { .. } => return None, // No active session
+/// Session-level link state for a connection status, or None for statuses
+/// that do not change it (an initial connection attempt).
+fn session_connectivity(status: &ConnectionStatus) -> Option<ConnectivityDuringSession> {
+    match status {
+        ConnectionStatus::Connected { .. } => Some(ConnectivityDuringSession::Connected),
+        ConnectionStatus::Reconnecting { .. } => Some(ConnectivityDuringSession::Reconnecting),
+        ConnectionStatus::Disconnected
+        | ConnectionStatus::ReconnectFailed { .. }
+        | ConnectionStatus::CircuitOpen { .. } => Some(ConnectivityDuringSession::Disconnected),
+        ConnectionStatus::Connecting => None,
+    }
+}
+
+/// Build a SessionProgress snapshot as seen at `now_ms` (Unix epoch milliseconds).
+///
+/// Returns None if there is no running or paused phase.
+fn session_progress_at(
+    state_lock: &SessionStateMachineWrapper,
+    now_ms: u64,
+) -> Option<SessionProgress> {
+    // Get the current state and position within the plan
+    let (session_state, current_phase_idx, phase_elapsed) = match *state_lock.state() {
+        State::InProgress {
+            current_phase,
+            elapsed_secs,
+            ..
+        } => (ProgressState::Running, current_phase, elapsed_secs),
+        State::Paused { phase, elapsed, .. } => (ProgressState::Paused, phase, elapsed),
+        State::Completed { .. } | State::Idle { .. } => return None,
     };
 
-    // Get plan and progress information
+    // Get plan information
     let plan = state_lock.context().plan()?;
-    let (current_phase_idx, phase_elapsed, _phase_duration) = state_lock.get_progress()?;
 
     // Ensure phase index is valid
     if current_phase_idx >= plan.phases.len() {
@@ -926,19 +2087,15 @@ async fn build_session_progress(
     }
 
     let current_phase = &plan.phases[current_phase_idx];
+    let durations = state_lock.context().phase_durations();
 
-    // Calculate total elapsed and remaining time
-    let mut total_elapsed_secs = 0u32;
-    for (i, phase) in plan.phases.iter().enumerate() {
-        if i < current_phase_idx {
-            total_elapsed_secs += phase.duration_secs;
-        } else if i == current_phase_idx {
-            total_elapsed_secs += phase_elapsed;
-        }
-    }
-
-    let total_plan_duration: u32 = plan.phases.iter().map(|p| p.duration_secs).sum();
-    let total_remaining_secs = total_plan_duration.saturating_sub(total_elapsed_secs);
+    // Finished phases count the time actually spent in them, which is less
+    // than planned for skipped phases; the remaining time is still as planned
+    let total_elapsed_secs = state_lock.session_elapsed_secs();
+    let total_remaining_secs = durations[current_phase_idx..]
+        .iter()
+        .sum::<u32>()
+        .saturating_sub(phase_elapsed);
 
     // Get current BPM and zone status from context
     let current_bpm = state_lock.context().current_bpm;
@@ -952,15 +2109,20 @@ async fn build_session_progress(
     };
 
     // Calculate phase remaining time
-    let phase_remaining_secs = current_phase.duration_secs.saturating_sub(phase_elapsed);
+    let phase_remaining_secs = durations[current_phase_idx].saturating_sub(phase_elapsed);
 
     // Build phase progress
+    let (hr_hold_elapsed_secs, hr_hold_required_secs) =
+        state_lock.hr_hold_progress().unwrap_or((0, 0));
     let phase_progress = PhaseProgress {
         phase_index: current_phase_idx as u32,
         phase_name: current_phase.name.clone(),
         target_zone: current_phase.target_zone,
+        target: current_phase.target,
         elapsed_secs: phase_elapsed,
         remaining_secs: phase_remaining_secs,
+        hr_hold_elapsed_secs,
+        hr_hold_required_secs,
     };
 
     Some(SessionProgress {
@@ -971,15 +2133,156 @@ async fn build_session_progress(
         zone_status,
         current_bpm,
         phase_progress,
+        completion_pct: completion_percentage(plan, &durations, current_phase_idx, phase_elapsed),
+        projected_end_ms: projected_end_ms(now_ms, total_remaining_secs),
+        connectivity: ConnectivityDuringSession::Connected,
+        // Depend on when HR was last received and which run this is;
+        // set by build_session_progress
+        bpm_is_fresh: false,
+        session_epoch: 0,
+        // Accumulated by the tick loop; set by build_session_progress
+        trimp: 0.0,
+        trimp_per_min: 0.0,
     })
 }
 
+/// Overall completion of `plan` as a percentage (0.0 to 100.0).
+///
+/// `durations` are the effective phase durations (including any extensions).
+/// Uses completed duration / total plan duration when every phase is timed.
+/// If any phase ends on an HR condition the total duration is indeterminate, so
+/// each phase counts as an equal share and the current phase contributes its
+/// elapsed fraction (capped at 1).
+fn completion_percentage(
+    plan: &TrainingPlan,
+    durations: &[u32],
+    phase_idx: usize,
+    phase_elapsed: u32,
+) -> f64 {
+    if plan.phases.is_empty() || durations.len() != plan.phases.len() {
+        return 0.0;
+    }
+
+    let time_based = plan
+        .phases
+        .iter()
+        .all(|p| matches!(p.transition, TransitionCondition::TimeElapsed));
+
+    let fraction = if time_based {
+        let total: u32 = durations.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+        let completed: u32 = durations.iter().take(phase_idx).sum();
+        (completed + phase_elapsed) as f64 / total as f64
+    } else {
+        let phase_fraction = match durations.get(phase_idx) {
+            Some(&duration) if duration > 0 => (phase_elapsed as f64 / duration as f64).min(1.0),
+            _ => 0.0,
+        };
+        (phase_idx as f64 + phase_fraction) / plan.phases.len() as f64
+    };
+
+    (fraction * 100.0).clamp(0.0, 100.0)
+}
+
+/// Index of the phase currently in progress, if any.
+fn current_phase_index(state: &SessionStateMachineWrapper) -> Option<usize> {
+    match state.state() {
+        State::InProgress { current_phase, .. } => Some(*current_phase),
+        _ => None,
+    }
+}
+
+/// Heads-up for the running phase if it is about to end.
+///
+/// Returns the phase index and a [`NotificationEvent::PhaseEndingSoon`] once
+/// `lead_secs` or less remain in a timed phase longer than `lead_secs`, unless
+/// `announced` is that phase already. HR-based phases have no fixed end and
+/// are never announced.
+fn phase_ending_soon(
+    state: &SessionStateMachineWrapper,
+    lead_secs: u32,
+    announced: Option<usize>,
+) -> Option<(usize, NotificationEvent)> {
+    let (phase_idx, elapsed, duration) = state.get_progress()?;
+    if lead_secs == 0 || announced == Some(phase_idx) || duration <= lead_secs {
+        return None;
+    }
+    let plan = state.context().plan()?;
+    let phase = plan.phases.get(phase_idx)?;
+    if phase.transition != TransitionCondition::TimeElapsed {
+        return None;
+    }
+    let remaining_secs = duration.saturating_sub(elapsed);
+    if remaining_secs == 0 || remaining_secs > lead_secs {
+        return None;
+    }
+    let next_zone = plan.phases.get(phase_idx + 1).map(|next| next.target_zone);
+    Some((
+        phase_idx,
+        NotificationEvent::PhaseEndingSoon {
+            remaining_secs,
+            next_zone,
+        },
+    ))
+}
+
+/// Pace cue for the current BPM against the target of the running phase.
+///
+/// Returns `None` when no phase is in progress or no BPM has been received yet.
+fn current_pace_cue(state: &SessionStateMachineWrapper) -> Option<PaceCue> {
+    let context = state.context();
+    if context.current_bpm == 0 {
+        return None;
+    }
+    let plan = context.plan()?;
+    let phase = plan.phases.get(current_phase_index(state)?)?;
+    compute_pace_cue(
+        context.current_bpm,
+        phase.effective_target(),
+        plan.max_hr,
+        context.zone_tolerance_bpm,
+    )
+    .ok()
+}
+
+/// Build the transition event for a tick that moved the session off phase `from`.
+///
+/// `to` is the phase in progress after the tick; `None` means the session is no
+/// longer running, which is reported as completion only if it actually completed.
+fn detect_phase_transition(
+    state: &SessionStateMachineWrapper,
+    from: usize,
+    to: Option<usize>,
+) -> Option<PhaseTransition> {
+    let plan = state.context().plan()?;
+    match to {
+        Some(to) if to != from => {
+            let phase = plan.phases.get(to)?;
+            Some(PhaseTransition {
+                from_index: from as u32,
+                to_index: to as u32,
+                to_phase_name: Some(phase.name.clone()),
+                to_zone: Some(phase.target_zone),
+            })
+        }
+        None if matches!(state.state(), State::Completed { .. }) => Some(PhaseTransition {
+            from_index: from as u32,
+            to_index: plan.phases.len() as u32,
+            to_phase_name: None,
+            to_zone: None,
+        }),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::adapters::MockNotificationAdapter;
     use crate::domain::heart_rate::Zone;
-    use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+    use crate::domain::training_plan::TrainingPhase;
     use chrono::Utc;
     use tokio::time::{sleep, Duration};
 
@@ -996,16 +2299,21 @@ mod tests {
                     target_zone: Zone::Zone2,
                     duration_secs: 2,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
                 TrainingPhase {
                     name: "Phase 2".to_string(),
                     target_zone: Zone::Zone3,
                     duration_secs: 2,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
             ],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1056,9 +2364,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 100,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1094,9 +2405,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 10,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1109,11 +2423,14 @@ mod tests {
             raw_bpm: 120,
             filtered_bpm: 120,
             rmssd: Some(45.0),
+            rmssd_trend: None,
             filter_variance: Some(1.5),
             battery_level: Some(85),
             timestamp: 0,
             receive_timestamp_micros: None,
             stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
         };
 
         // Send HR data continuously
@@ -1145,6 +2462,64 @@ mod tests {
         // 3. UpdateBpm events are sent to the state machine
     }
 
+    #[tokio::test]
+    async fn test_phase_with_alerts_disabled_sends_no_deviation_notifications() {
+        use tokio::sync::broadcast;
+
+        async fn deviation_count(alerts_enabled: bool) -> usize {
+            let notifier = Arc::new(MockNotificationAdapter::new());
+            let (hr_tx, hr_rx) = broadcast::channel(100);
+            let mut executor = SessionExecutor::with_hr_stream(notifier.clone(), hr_rx);
+
+            let plan = TrainingPlan {
+                name: "VO2 Intervals".to_string(),
+                phases: vec![TrainingPhase {
+                    name: "VO2 Max".to_string(),
+                    target_zone: Zone::Zone2,
+                    duration_secs: 60,
+                    transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled,
+                    target: None,
+                }],
+                created_at: Utc::now(),
+                max_hr: 180,
+                ..Default::default()
+            };
+            executor.start_session(plan).await.unwrap();
+            sleep(Duration::from_millis(200)).await;
+
+            // Well above Zone 2 for more than the 5 readings needed to alert
+            let hr_data = FilteredHeartRate {
+                raw_bpm: 175,
+                filtered_bpm: 175,
+                rmssd: None,
+                rmssd_trend: None,
+                filter_variance: None,
+                battery_level: None,
+                timestamp: 0,
+                receive_timestamp_micros: None,
+                stale: false,
+                intensity_factor: None,
+                avg_bpm_10s: None,
+            };
+            for _ in 0..10 {
+                hr_tx.send(hr_data.clone()).unwrap();
+                sleep(Duration::from_millis(150)).await;
+            }
+            executor.stop_session().await.unwrap();
+
+            notifier
+                .get_events()
+                .await
+                .iter()
+                .filter(|e| matches!(e, NotificationEvent::ZoneDeviation { .. }))
+                .count()
+        }
+
+        assert!(deviation_count(true).await > 0);
+        assert_eq!(deviation_count(false).await, 0);
+    }
+
     #[tokio::test]
     async fn test_session_persistence_save_and_load() {
         use tempfile::tempdir;
@@ -1167,16 +2542,21 @@ mod tests {
                     target_zone: Zone::Zone2,
                     duration_secs: 20,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
                 TrainingPhase {
                     name: "Phase 2".to_string(),
                     target_zone: Zone::Zone3,
                     duration_secs: 20,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
             ],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan.clone()).await.unwrap();
@@ -1215,6 +2595,85 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_resume_from_checkpoint() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let checkpoint_path = temp_dir.path().join("resume.json");
+        let notifier = Arc::new(MockNotificationAdapter::new());
+
+        // Nothing to resume without a checkpoint
+        let mut executor =
+            SessionExecutor::new(notifier.clone()).with_checkpoint_path(checkpoint_path.clone());
+        assert!(!executor.resume_from_checkpoint().await.unwrap());
+        assert!(executor.get_progress().await.is_none());
+
+        let checkpoint = SessionCheckpoint {
+            plan: TrainingPlan {
+                name: "Resume Test".to_string(),
+                phases: vec![
+                    TrainingPhase {
+                        name: "Warmup".to_string(),
+                        target_zone: Zone::Zone1,
+                        duration_secs: 30,
+                        transition: TransitionCondition::TimeElapsed,
+                        alerts_enabled: true,
+                        target: None,
+                    },
+                    TrainingPhase {
+                        name: "Work".to_string(),
+                        target_zone: Zone::Zone3,
+                        duration_secs: 30,
+                        transition: TransitionCondition::TimeElapsed,
+                        alerts_enabled: true,
+                        target: None,
+                    },
+                ],
+                created_at: Utc::now(),
+                max_hr: 180,
+                ..Default::default()
+            },
+            current_phase: 1,
+            elapsed_secs: 4,
+            is_paused: false,
+            zone_tolerance_bpm: Some(7),
+            warmup_grace_secs: None,
+        };
+        tokio::fs::write(
+            &checkpoint_path,
+            serde_json::to_string(&checkpoint).unwrap(),
+        )
+        .await
+        .unwrap();
+
+        assert!(executor.resume_from_checkpoint().await.unwrap());
+        let (phase, elapsed, _) = executor.get_progress().await.unwrap();
+        assert_eq!(phase, 1, "Should resume at the saved phase");
+        {
+            let state = executor.session_state.lock().await;
+            assert_eq!(state.context().zone_tolerance_bpm, 7);
+            assert_eq!(state.context().warmup_grace_secs, 0);
+        }
+        assert!(
+            elapsed >= 4,
+            "Should resume at the saved time, got {}",
+            elapsed
+        );
+
+        // The tick loop runs again after the resume
+        sleep(Duration::from_millis(1500)).await;
+        let (phase, elapsed, _) = executor.get_progress().await.unwrap();
+        assert_eq!(phase, 1);
+        assert!(
+            elapsed >= 5,
+            "Tick loop should advance time, got {}",
+            elapsed
+        );
+
+        executor.stop_session().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_session_persistence_checkpoint_cleared_on_completion() {
         use tempfile::tempdir;
@@ -1234,9 +2693,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 12, // Just over 10 seconds to ensure checkpoint save
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1260,6 +2722,63 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_checkpoint_interval_controls_first_save() {
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let plan = TrainingPlan {
+            name: "Interval Test".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Long Phase".to_string(),
+                target_zone: Zone::Zone2,
+                duration_secs: 60,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        let start = |name: &str, interval: u32| {
+            let path = temp_dir.path().join(name);
+            let plan = plan.clone();
+            async move {
+                let notifier = Arc::new(MockNotificationAdapter::new());
+                let mut executor = SessionExecutor::with_persistence(notifier, path.clone())
+                    .await
+                    .unwrap()
+                    .with_checkpoint_interval(interval);
+                executor.start_session(plan).await.unwrap();
+                (executor, path)
+            }
+        };
+
+        let (mut every_tick, every_tick_path) = start("every_tick.json", 1).await;
+        let (mut every_five, every_five_path) = start("every_five.json", 5).await;
+
+        sleep(Duration::from_millis(1500)).await;
+        assert!(every_tick_path.exists(), "Interval 1 saves after one tick");
+        assert!(!every_five_path.exists(), "Interval 5 has not saved yet");
+
+        sleep(Duration::from_millis(2500)).await;
+        assert!(
+            !every_five_path.exists(),
+            "Interval 5 has not saved after 4 ticks"
+        );
+
+        sleep(Duration::from_millis(1500)).await;
+        assert!(
+            every_five_path.exists(),
+            "Interval 5 saves after five ticks"
+        );
+
+        every_tick.stop_session().await.unwrap();
+        every_five.stop_session().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_schedule_session_fires_notification() {
         let notifier = Arc::new(MockNotificationAdapter::new());
@@ -1272,9 +2791,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 10,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         // Schedule a session to fire every 2 seconds (for testing purposes)
@@ -1324,9 +2846,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 20,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         // Schedule a session to fire every 2 seconds
@@ -1374,9 +2899,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 10,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         // Try to schedule with an invalid cron expression
@@ -1402,9 +2930,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1467,9 +2998,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1515,9 +3049,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1575,9 +3112,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1637,9 +3177,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 2,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan.clone()).await.unwrap();
@@ -1658,54 +3201,170 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_session_repository_saves_stopped_session() {
+    async fn test_set_session_repository_saves_running_session_to_previous_repository() {
         use crate::adapters::FileSessionRepository;
         use tempfile::tempdir;
 
-        let temp_dir = tempdir().unwrap();
-        let sessions_dir = temp_dir.path().to_path_buf();
-        let repository = Arc::new(
-            FileSessionRepository::with_directory(sessions_dir)
+        let first_dir = tempdir().unwrap();
+        let second_dir = tempdir().unwrap();
+        let first = Arc::new(
+            FileSessionRepository::with_directory(first_dir.path().to_path_buf())
+                .await
+                .unwrap(),
+        );
+        let second = Arc::new(
+            FileSessionRepository::with_directory(second_dir.path().to_path_buf())
                 .await
                 .unwrap(),
         );
 
         let notifier = Arc::new(MockNotificationAdapter::new());
-        let mut executor =
-            SessionExecutor::new(notifier).with_session_repository(repository.clone());
+        let mut executor = SessionExecutor::new(notifier).with_session_repository(first.clone());
 
         let plan = TrainingPlan {
-            name: "Stop Test".to_string(),
+            name: "Switch Test".to_string(),
             phases: vec![TrainingPhase {
                 name: "Long Phase".to_string(),
                 target_zone: Zone::Zone2,
-                duration_secs: 60,
+                duration_secs: 600,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan.clone()).await.unwrap();
-        sleep(Duration::from_secs(2)).await;
+        sleep(Duration::from_millis(200)).await;
 
-        // Stop the session
-        executor.stop_session().await.unwrap();
-        sleep(Duration::from_millis(500)).await;
+        executor
+            .set_session_repository(second.clone())
+            .await
+            .unwrap();
+        let saved = first.list().await.unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].status, "Stopped");
+        assert!(second.list().await.unwrap().is_empty());
 
-        // Verify session was saved with Stopped status
-        let sessions = repository.list().await.unwrap();
-        assert_eq!(sessions.len(), 1, "Should have saved 1 stopped session");
-        assert_eq!(sessions[0].plan_name, plan.name);
-        assert_eq!(sessions[0].status, "Stopped");
+        executor.start_session(plan).await.unwrap();
+        sleep(Duration::from_millis(200)).await;
+        executor.stop_session().await.unwrap();
+        assert_eq!(first.list().await.unwrap().len(), 1);
+        assert_eq!(second.list().await.unwrap().len(), 1);
     }
 
     #[tokio::test]
-    async fn test_progress_sender_streams_updates() {
-        use tokio::sync::mpsc;
+    async fn test_connection_summary_counts_reconnects() {
+        use crate::adapters::FileSessionRepository;
+        use crate::domain::connection_health::assess_connection_health;
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let repository = Arc::new(
+            FileSessionRepository::with_directory(temp_dir.path().to_path_buf())
+                .await
+                .unwrap(),
+        );
+        let (conn_tx, conn_rx) = broadcast::channel(100);
+        let (health_tx, health_rx) = broadcast::channel(100);
 
         let notifier = Arc::new(MockNotificationAdapter::new());
-        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+        let mut executor = SessionExecutor::new(notifier)
+            .with_session_repository(repository.clone())
+            .with_connection_status(conn_rx)
+            .with_connection_health(health_rx)
+            .with_disconnect_policy(DisconnectPolicy::KeepTiming);
+        executor
+            .start_session(TrainingPlan::steady("Flaky", Zone::Zone2, 60, 180))
+            .await
+            .unwrap();
+
+        let connected = || ConnectionStatus::Connected {
+            device_id: "AA:BB:CC:DD:EE:FF".to_string(),
+        };
+        // Two drops of one second each, stamped as they arrive
+        for _ in 0..2 {
+            conn_tx
+                .send(ConnectionStatus::Reconnecting {
+                    attempt: 1,
+                    max_attempts: 5,
+                })
+                .unwrap();
+            sleep(Duration::from_millis(1000)).await;
+            conn_tx.send(connected()).unwrap();
+        }
+        health_tx
+            .send(assess_connection_health(Some(-88), 0.0, None))
+            .unwrap();
+        health_tx
+            .send(assess_connection_health(Some(-60), 0.0, None))
+            .unwrap();
+        sleep(Duration::from_millis(50)).await;
+
+        executor.stop_session().await.unwrap();
+
+        let id = repository.list().await.unwrap()[0].id.clone();
+        let session = repository.get(&id).await.unwrap().unwrap();
+        let summary = session.connection_summary.unwrap();
+        assert_eq!(summary.reconnect_count, 2);
+        assert_eq!(summary.total_dropout_secs, 2);
+        assert_eq!(summary.worst_rssi, Some(-88));
+    }
+
+    #[tokio::test]
+    async fn test_session_repository_saves_stopped_session() {
+        use crate::adapters::FileSessionRepository;
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let sessions_dir = temp_dir.path().to_path_buf();
+        let repository = Arc::new(
+            FileSessionRepository::with_directory(sessions_dir)
+                .await
+                .unwrap(),
+        );
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor =
+            SessionExecutor::new(notifier).with_session_repository(repository.clone());
+
+        let plan = TrainingPlan {
+            name: "Stop Test".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Long Phase".to_string(),
+                target_zone: Zone::Zone2,
+                duration_secs: 60,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        executor.start_session(plan.clone()).await.unwrap();
+        sleep(Duration::from_secs(2)).await;
+
+        // Stop the session
+        executor.stop_session().await.unwrap();
+        sleep(Duration::from_millis(500)).await;
+
+        // Verify session was saved with Stopped status
+        let sessions = repository.list().await.unwrap();
+        assert_eq!(sessions.len(), 1, "Should have saved 1 stopped session");
+        assert_eq!(sessions[0].plan_name, plan.name);
+        assert_eq!(sessions[0].status, "Stopped");
+    }
+
+    #[tokio::test]
+    async fn test_progress_sender_streams_updates() {
+        use tokio::sync::mpsc;
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
 
         let mut executor = SessionExecutor::new(notifier).with_progress_sender(progress_tx);
 
@@ -1716,9 +3375,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 5,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1760,6 +3422,234 @@ mod tests {
         executor.stop_session().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_pace_cue_sender_reports_distance_from_band() {
+        use crate::domain::pacer::{Direction, Severity};
+        use tokio::sync::{broadcast, mpsc};
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let (hr_tx, hr_rx) = broadcast::channel(100);
+        let (cue_tx, mut cue_rx) = mpsc::unbounded_channel();
+        let mut executor =
+            SessionExecutor::with_hr_stream(notifier, hr_rx).with_pace_cue_sender(cue_tx);
+
+        // Zone 3 at 200 max_hr is 140-159 BPM
+        let plan = TrainingPlan {
+            name: "Pace Test".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Tempo".to_string(),
+                target_zone: Zone::Zone3,
+                duration_secs: 10,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 200,
+            ..Default::default()
+        };
+        executor.start_session(plan).await.unwrap();
+
+        hr_tx
+            .send(FilteredHeartRate {
+                raw_bpm: 167,
+                filtered_bpm: 167,
+                rmssd: None,
+                rmssd_trend: None,
+                filter_variance: None,
+                battery_level: None,
+                timestamp: 0,
+                receive_timestamp_micros: None,
+                stale: false,
+                intensity_factor: None,
+                avg_bpm_10s: None,
+            })
+            .unwrap();
+
+        let cue = tokio::time::timeout(Duration::from_secs(3), cue_rx.recv())
+            .await
+            .expect("pace cue should be emitted on the next tick")
+            .unwrap();
+        assert_eq!(cue.direction, Direction::SlowDown);
+        assert_eq!(cue.delta_bpm, 8);
+        assert_eq!(cue.severity, Severity::Moderate);
+
+        executor.stop_session().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_phase_transitions_emitted_once_per_change() {
+        use tokio::sync::mpsc;
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let (transition_tx, mut transition_rx) = mpsc::unbounded_channel();
+
+        let mut executor =
+            SessionExecutor::new(notifier).with_phase_transition_sender(transition_tx);
+
+        let phase = |name: &str, zone| TrainingPhase {
+            name: name.to_string(),
+            target_zone: zone,
+            duration_secs: 1,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "Transition Test".to_string(),
+            phases: vec![
+                phase("Warmup", Zone::Zone2),
+                phase("Work", Zone::Zone4),
+                phase("Cooldown", Zone::Zone1),
+            ],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        executor.start_session(plan).await.unwrap();
+        sleep(Duration::from_millis(4500)).await;
+
+        let mut transitions = Vec::new();
+        while let Ok(transition) = transition_rx.try_recv() {
+            transitions.push(transition);
+        }
+
+        assert_eq!(transitions.len(), 3, "Got {:?}", transitions);
+        assert_eq!((transitions[0].from_index, transitions[0].to_index), (0, 1));
+        assert_eq!(transitions[0].to_phase_name.as_deref(), Some("Work"));
+        assert_eq!(transitions[0].to_zone, Some(Zone::Zone4));
+        assert_eq!((transitions[1].from_index, transitions[1].to_index), (1, 2));
+        assert_eq!(transitions[1].to_phase_name.as_deref(), Some("Cooldown"));
+        assert_eq!((transitions[2].from_index, transitions[2].to_index), (2, 3));
+        assert!(transitions[2].is_completion());
+    }
+
+    #[tokio::test]
+    async fn test_progress_after_skip_counts_time_spent() {
+        tokio::time::pause();
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier);
+        let plan = TrainingPlan {
+            name: "Skip Progress".to_string(),
+            phases: vec![
+                TrainingPhase::timed("Warmup", Zone::Zone2, 60),
+                TrainingPhase::timed("Work", Zone::Zone3, 60),
+            ],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        // With time paused, sleeping advances the clock tick by tick
+        executor.start_session(plan).await.unwrap();
+        sleep(Duration::from_millis(2500)).await;
+        executor.skip_phase().await.unwrap();
+        sleep(Duration::from_secs(3)).await;
+
+        {
+            let state = executor.session_state.lock().await;
+            let progress = session_progress_at(&state, 0).unwrap();
+            assert_eq!(progress.current_phase, 1);
+            assert_eq!(progress.phase_progress.elapsed_secs, 3);
+            // 2s of the skipped warmup plus 3s of work, not the warmup's 60s
+            assert_eq!(progress.total_elapsed_secs, 5);
+            assert_eq!(progress.total_remaining_secs, 57);
+        }
+
+        executor.stop_session().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_skip_phase_records_partial_elapsed_time() {
+        use tokio::sync::mpsc;
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let (transition_tx, mut transition_rx) = mpsc::unbounded_channel();
+        let mut executor =
+            SessionExecutor::new(notifier).with_phase_transition_sender(transition_tx);
+
+        let phase = |name: &str| TrainingPhase {
+            name: name.to_string(),
+            target_zone: Zone::Zone2,
+            duration_secs: 60,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "Skip Test".to_string(),
+            phases: vec![phase("Warmup"), phase("Work")],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        executor.start_session(plan).await.unwrap();
+        sleep(Duration::from_millis(2500)).await;
+
+        executor.skip_phase().await.unwrap();
+        {
+            let state = executor.session_state.lock().await;
+            let (phase_idx, elapsed, _) = state.get_progress().unwrap();
+            assert_eq!(phase_idx, 1);
+            assert_eq!(elapsed, 0);
+            assert_eq!(state.context().phase_elapsed_secs, vec![2]);
+        }
+        let transition = transition_rx.try_recv().unwrap();
+        assert_eq!((transition.from_index, transition.to_index), (0, 1));
+
+        // Skipping the last phase completes the session
+        executor.skip_phase().await.unwrap();
+        {
+            let state = executor.session_state.lock().await;
+            assert!(matches!(state.state(), State::Completed { .. }));
+            assert_eq!(state.context().phase_elapsed_secs, vec![2, 0]);
+        }
+        assert!(transition_rx.try_recv().unwrap().is_completion());
+        assert!(executor.skip_phase().await.is_err());
+
+        executor.stop_session().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_extend_phase_overrides_duration_only() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier);
+
+        let plan = TrainingPlan {
+            name: "Extend Test".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Work".to_string(),
+                target_zone: Zone::Zone3,
+                duration_secs: 60,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        assert!(executor.extend_phase(30).await.is_err());
+
+        executor.start_session(plan).await.unwrap();
+        executor.extend_phase(30).await.unwrap();
+        executor.pause_session().await.unwrap();
+        executor.extend_phase(15).await.unwrap();
+        executor.resume_session().await.unwrap();
+
+        let (_, _, duration) = executor.get_progress().await.unwrap();
+        assert_eq!(duration, 105);
+        assert_eq!(
+            executor.get_plan().await.unwrap().phases[0].duration_secs,
+            60
+        );
+
+        executor.stop_session().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_hr_samples_collected_during_session() {
         use tokio::sync::broadcast;
@@ -1775,9 +3665,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 3,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1789,11 +3682,14 @@ mod tests {
                 raw_bpm: 120 + i,
                 filtered_bpm: 120 + i,
                 rmssd: Some(45.0),
+                rmssd_trend: None,
                 filter_variance: Some(1.5),
                 battery_level: Some(85),
                 timestamp: 0,
                 receive_timestamp_micros: None,
                 stale: false,
+                intensity_factor: None,
+                avg_bpm_10s: None,
             };
             hr_tx.send(hr_data).unwrap();
             sleep(Duration::from_millis(100)).await;
@@ -1812,6 +3708,147 @@ mod tests {
         executor.stop_session().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_sample_interval_stores_bucket_averages() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let executor = SessionExecutor::new(notifier).with_sample_interval(5);
+        let start = Utc::now();
+
+        // A 20-second session at 1 Hz, BPM rising by one each second
+        for i in 0..20 {
+            record_hr_sample(
+                &executor.sample_decimator,
+                &executor.hr_samples,
+                &executor.running_stats,
+                start + chrono::Duration::seconds(i),
+                130 + i as u16,
+            )
+            .await;
+        }
+        let (samples, stats, max_gap_secs) = collect_hr_samples(
+            &executor.sample_decimator,
+            &executor.hr_samples,
+            &executor.running_stats,
+        )
+        .await;
+
+        let bpms: Vec<u16> = samples.iter().map(|s| s.bpm).collect();
+        assert_eq!(bpms, vec![132, 137, 142, 147]);
+        assert_eq!(max_gap_secs, MAX_SAMPLE_GAP_SECS);
+        assert_eq!(executor.hr_samples.lock().await.len(), 4);
+
+        // Statistics cover the stored samples, including the flushed bucket
+        assert_eq!(stats, RunningStats::from_samples(&samples, None));
+        assert_eq!(executor.live_stats().await.count(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_phase_results_record_target_met() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let executor = SessionExecutor::new(notifier);
+        let start = utc_now() - chrono::Duration::seconds(120);
+        *executor.phase_start_time.lock().await = Some(PhaseStart::new(start));
+
+        // max_hr 180: Zone 2 is 108-125. The first minute stays in Zone 2,
+        // the second mostly runs above it.
+        for i in 0..120 {
+            let bpm = if i < 60 || i >= 110 { 115 } else { 150 };
+            record_hr_sample(
+                &executor.sample_decimator,
+                &executor.hr_samples,
+                &executor.running_stats,
+                start + chrono::Duration::seconds(i),
+                bpm,
+            )
+            .await;
+        }
+
+        let phase = TrainingPhase::timed("Easy", Zone::Zone2, 60);
+        let first_end = start + chrono::Duration::seconds(60);
+        let samples = executor.hr_samples.lock().await.clone();
+        let first = PhaseResult::from_samples(&phase, start, first_end, &samples, 180, 0);
+        executor.phase_results.lock().await.push(first);
+        *executor.phase_start_time.lock().await = Some(PhaseStart::new(first_end));
+
+        record_phase_result(
+            Some((phase.clone(), 180)),
+            &executor.phase_start_time,
+            &executor.paused_secs,
+            &executor.hr_samples,
+            &executor.phase_results,
+        )
+        .await;
+
+        let results = executor.phase_results().await;
+        assert_eq!(results.len(), 2);
+        assert!(results[0].target_met);
+        assert!(!results[1].target_met);
+        assert_eq!(results[1].start_time, first_end);
+        assert!(executor.phase_start_time.lock().await.unwrap().at >= results[1].end_time);
+    }
+
+    #[tokio::test]
+    async fn test_stop_session_records_running_phase() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier);
+
+        executor
+            .start_session(TrainingPlan::steady("Cut Short", Zone::Zone2, 600, 180))
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(1100)).await;
+        executor.stop_session().await.unwrap();
+
+        let results = executor.phase_results().await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].phase_name, "Cut Short");
+        assert!(results[0].end_time > results[0].start_time);
+        assert!(!results[0].target_met);
+    }
+
+    #[tokio::test]
+    async fn test_stop_session_reports_max_hr_exceeded() {
+        use tokio::sync::broadcast;
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let (hr_tx, hr_rx) = broadcast::channel(100);
+        let mut executor = SessionExecutor::with_hr_stream(notifier.clone(), hr_rx);
+
+        executor
+            .start_session(TrainingPlan::steady("Too Easy", Zone::Zone2, 600, 180))
+            .await
+            .unwrap();
+        for _ in 0..15 {
+            hr_tx
+                .send(FilteredHeartRate {
+                    raw_bpm: 195,
+                    filtered_bpm: 195,
+                    rmssd: None,
+                    rmssd_trend: None,
+                    filter_variance: None,
+                    battery_level: None,
+                    timestamp: 0,
+                    receive_timestamp_micros: None,
+                    stale: false,
+                    intensity_factor: None,
+                    avg_bpm_10s: None,
+                })
+                .unwrap();
+            sleep(Duration::from_millis(100)).await;
+        }
+        executor.stop_session().await.unwrap();
+
+        assert_eq!(executor.suggested_max_hr().await, Some(195));
+        let events = notifier.get_events().await;
+        assert!(events.iter().any(|e| matches!(
+            e,
+            NotificationEvent::MaxHrExceeded {
+                observed: 195,
+                configured: 180
+            }
+        )));
+    }
+
     #[tokio::test]
     async fn test_reconnecting_status_triggers_pause() {
         use tokio::sync::broadcast;
@@ -1828,9 +3865,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         executor.start_session(plan).await.unwrap();
@@ -1858,35 +3898,776 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_plan_returns_current_plan() {
+    async fn test_keep_timing_through_connection_drop() {
+        use tokio::sync::broadcast;
+
         let notifier = Arc::new(MockNotificationAdapter::new());
-        let mut executor = SessionExecutor::new(notifier);
+        let (hr_tx, hr_rx) = broadcast::channel(100);
+        let (conn_tx, conn_rx) = broadcast::channel(100);
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+
+        let mut executor = SessionExecutor::with_hr_stream(notifier, hr_rx)
+            .with_connection_status(conn_rx)
+            .with_disconnect_policy(DisconnectPolicy::KeepTiming)
+            .with_progress_sender(progress_tx);
 
         let plan = TrainingPlan {
-            name: "Get Plan Test".to_string(),
+            name: "Continuity Test".to_string(),
             phases: vec![TrainingPhase {
-                name: "Test Phase".to_string(),
+                name: "Steady".to_string(),
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
+        };
+        let hr = |bpm: u16| FilteredHeartRate {
+            raw_bpm: bpm,
+            filtered_bpm: bpm,
+            rmssd: None,
+            rmssd_trend: None,
+            filter_variance: None,
+            battery_level: None,
+            timestamp: 0,
+            receive_timestamp_micros: None,
+            stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
+        };
+        let latest_progress = |rx: &mut mpsc::UnboundedReceiver<SessionProgress>| {
+            let mut latest = None;
+            while let Ok(progress) = rx.try_recv() {
+                latest = Some(progress);
+            }
+            latest.expect("progress should be emitted every tick")
         };
 
-        // No plan before starting
-        assert!(executor.get_plan().await.is_none());
-
-        executor.start_session(plan.clone()).await.unwrap();
-        sleep(Duration::from_millis(500)).await;
+        executor.start_session(plan).await.unwrap();
+        hr_tx.send(hr(115)).unwrap();
+        sleep(Duration::from_millis(1500)).await;
+        assert_eq!(latest_progress(&mut progress_rx).current_bpm, 115);
 
-        // Plan should be available after starting
-        let retrieved_plan = executor.get_plan().await;
-        assert!(retrieved_plan.is_some());
-        assert_eq!(retrieved_plan.unwrap().name, plan.name);
+        // Link drops: the session keeps timing with HR unavailable
+        conn_tx
+            .send(ConnectionStatus::Reconnecting {
+                attempt: 1,
+                max_attempts: 5,
+            })
+            .unwrap();
+        sleep(Duration::from_millis(2000)).await;
+        let during_drop = latest_progress(&mut progress_rx);
+        assert_eq!(during_drop.state, ProgressState::Running);
+        assert_eq!(
+            during_drop.connectivity,
+            ConnectivityDuringSession::Reconnecting
+        );
+        assert_eq!(during_drop.current_bpm, 0);
+        assert!(during_drop.total_elapsed_secs >= 2);
 
-        executor.stop_session().await.unwrap();
-    }
+        // Link recovers and HR resumes
+        conn_tx
+            .send(ConnectionStatus::Connected {
+                device_id: "mock".to_string(),
+            })
+            .unwrap();
+        hr_tx.send(hr(118)).unwrap();
+        sleep(Duration::from_millis(1500)).await;
+        let recovered = latest_progress(&mut progress_rx);
+        assert_eq!(recovered.state, ProgressState::Running);
+        assert_eq!(recovered.connectivity, ConnectivityDuringSession::Connected);
+        assert_eq!(recovered.current_bpm, 118);
+        assert!(recovered.total_elapsed_secs > during_drop.total_elapsed_secs);
+        {
+            let state = executor.session_state.lock().await;
+            assert!(matches!(state.state(), State::InProgress { .. }));
+        }
+
+        executor.stop_session().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_live_trimp_at_constant_hr() {
+        use crate::domain::training_load::banister_trimp_per_minute;
+        use tokio::sync::broadcast;
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let (hr_tx, hr_rx) = broadcast::channel(100);
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+        let mut executor =
+            SessionExecutor::with_hr_stream(notifier, hr_rx).with_progress_sender(progress_tx);
+
+        let mut plan = TrainingPlan::steady("Trimp Test", Zone::Zone3, 60, 190);
+        plan.resting_hr = Some(50);
+        let hr = FilteredHeartRate {
+            raw_bpm: 150,
+            filtered_bpm: 150,
+            rmssd: None,
+            rmssd_trend: None,
+            filter_variance: None,
+            battery_level: None,
+            timestamp: 0,
+            receive_timestamp_micros: None,
+            stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
+        };
+
+        executor.start_session(plan).await.unwrap();
+        hr_tx.send(hr.clone()).unwrap();
+        let mut updates = Vec::new();
+        for _ in 0..3 {
+            sleep(Duration::from_millis(1000)).await;
+            hr_tx.send(hr.clone()).unwrap();
+        }
+        sleep(Duration::from_millis(500)).await;
+        while let Ok(progress) = progress_rx.try_recv() {
+            updates.push(progress);
+        }
+        executor.stop_session().await.unwrap();
+
+        let rate = banister_trimp_per_minute(150, 50, 190);
+        assert!(updates.len() >= 3, "expected one update per tick");
+        for (i, progress) in updates.iter().enumerate() {
+            let expected = rate * (i + 1) as f64 / 60.0;
+            assert!((progress.trimp - expected).abs() < 1e-9);
+            assert!((progress.trimp_per_min - rate).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_hr_is_fresh_within_window() {
+        let window = Duration::from_secs(5);
+        let at = Instant::now();
+        assert!(!hr_is_fresh(None, at, window));
+        assert!(hr_is_fresh(Some(at), at, window));
+        assert!(hr_is_fresh(Some(at), at + window, window));
+        assert!(!hr_is_fresh(Some(at), at + Duration::from_secs(6), window));
+    }
+
+    #[tokio::test]
+    async fn test_bpm_freshness_follows_hr_samples() {
+        use tokio::sync::broadcast;
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let (hr_tx, hr_rx) = broadcast::channel(100);
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+
+        let mut executor = SessionExecutor::with_hr_stream(notifier, hr_rx)
+            .with_hr_freshness_window(Duration::from_millis(1500))
+            .with_progress_sender(progress_tx);
+
+        let plan = TrainingPlan {
+            name: "Freshness Test".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Steady".to_string(),
+                target_zone: Zone::Zone2,
+                duration_secs: 60,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+        let hr = |bpm: u16| FilteredHeartRate {
+            raw_bpm: bpm,
+            filtered_bpm: bpm,
+            rmssd: None,
+            rmssd_trend: None,
+            filter_variance: None,
+            battery_level: None,
+            timestamp: 0,
+            receive_timestamp_micros: None,
+            stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
+        };
+        let latest_progress = |rx: &mut mpsc::UnboundedReceiver<SessionProgress>| {
+            let mut latest = None;
+            while let Ok(progress) = rx.try_recv() {
+                latest = Some(progress);
+            }
+            latest.expect("progress should be emitted every tick")
+        };
+
+        executor.start_session(plan).await.unwrap();
+        hr_tx.send(hr(120)).unwrap();
+        sleep(Duration::from_millis(1200)).await;
+        assert!(latest_progress(&mut progress_rx).bpm_is_fresh);
+
+        // No new HR for longer than the window
+        sleep(Duration::from_millis(2500)).await;
+        let stale = latest_progress(&mut progress_rx);
+        assert!(!stale.bpm_is_fresh);
+        assert_eq!(stale.current_bpm, 120);
+
+        hr_tx.send(hr(122)).unwrap();
+        sleep(Duration::from_millis(1100)).await;
+        assert!(latest_progress(&mut progress_rx).bpm_is_fresh);
+
+        executor.stop_session().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upcoming_phases() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier);
+        assert!(executor.get_upcoming_phases(2).await.is_empty());
+
+        let plan = TrainingPlan {
+            name: "Preview".to_string(),
+            phases: vec![
+                TrainingPhase::timed("Warmup", Zone::Zone2, 300),
+                TrainingPhase::timed("Work", Zone::Zone4, 180),
+                TrainingPhase::timed("Cooldown", Zone::Zone1, 300),
+            ],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+        executor.start_session(plan).await.unwrap();
+        let names = |phases: Vec<(usize, TrainingPhase)>| {
+            phases
+                .into_iter()
+                .map(|(index, phase)| (index, phase.name))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(executor.get_upcoming_phases(2).await),
+            vec![(1, "Work".to_string()), (2, "Cooldown".to_string())]
+        );
+
+        // Near the end fewer phases remain; paused sessions still preview
+        executor.skip_phase().await.unwrap();
+        executor.pause_session().await.unwrap();
+        assert_eq!(
+            names(executor.get_upcoming_phases(2).await),
+            vec![(2, "Cooldown".to_string())]
+        );
+        executor.resume_session().await.unwrap();
+
+        // Nothing follows the last phase
+        executor.skip_phase().await.unwrap();
+        assert!(executor.get_upcoming_phases(2).await.is_empty());
+
+        executor.stop_session().await.unwrap();
+        assert!(executor.get_upcoming_phases(2).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_lifecycle_notifications_in_order() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier.clone());
+
+        let plan = TrainingPlan {
+            name: "Lifecycle Test".to_string(),
+            phases: vec![
+                TrainingPhase {
+                    name: "Warmup".to_string(),
+                    target_zone: Zone::Zone1,
+                    duration_secs: 1,
+                    transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
+                },
+                TrainingPhase {
+                    name: "Work".to_string(),
+                    target_zone: Zone::Zone3,
+                    duration_secs: 1,
+                    transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
+                },
+            ],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        executor.start_session(plan).await.unwrap();
+        sleep(Duration::from_millis(3500)).await;
+
+        let events = notifier.get_events().await;
+        assert_eq!(events.len(), 4, "unexpected events: {:?}", events);
+        assert!(matches!(events[0], NotificationEvent::SessionStarted));
+        assert!(matches!(
+            &events[1],
+            NotificationEvent::PhaseStarted { name, zone: Zone::Zone1 } if name == "Warmup"
+        ));
+        assert!(matches!(
+            &events[2],
+            NotificationEvent::PhaseStarted { name, zone: Zone::Zone3 } if name == "Work"
+        ));
+        match &events[3] {
+            NotificationEvent::SessionCompleted { summary } => {
+                assert!(summary.duration_secs >= 2);
+            }
+            other => panic!("Expected SessionCompleted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_summary_excludes_paused_time() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier.clone());
+
+        let plan = TrainingPlan {
+            name: "Pause Test".to_string(),
+            phases: vec![TrainingPhase::timed("Steady", Zone::Zone2, 2)],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        executor.start_session(plan).await.unwrap();
+        sleep(Duration::from_millis(500)).await;
+        executor.pause_session().await.unwrap();
+        sleep(Duration::from_millis(3000)).await;
+        executor.resume_session().await.unwrap();
+        sleep(Duration::from_millis(3000)).await;
+
+        let summary = notifier
+            .get_events()
+            .await
+            .into_iter()
+            .find_map(|e| match e {
+                NotificationEvent::SessionCompleted { summary } => Some(summary),
+                _ => None,
+            })
+            .expect("Session should have completed");
+
+        let wall = summary.elapsed_wall_secs.unwrap();
+        let active = summary.active_duration_secs.unwrap();
+        assert_eq!(summary.duration_secs, active);
+        assert!(
+            wall >= 5,
+            "wall time should include the pause, got {}",
+            wall
+        );
+        assert!(
+            wall - active >= 2,
+            "active time should exclude the pause: wall {}, active {}",
+            wall,
+            active
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_cron_rejected_before_scheduling() {
+        assert!(validate_cron_expression("0 30 17 * * *").is_ok());
+
+        let err = validate_cron_expression("every day at five").unwrap_err();
+        assert!(err.to_string().contains("Invalid cron expression"));
+        // The parser's own error is kept as the cause
+        assert!(err.source().is_some());
+        // Five-field crontab syntax lacks the seconds field
+        assert!(validate_cron_expression("30 17 * * *").is_err());
+
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier);
+        let plan = TrainingPlan {
+            name: "Cron Test".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Work".to_string(),
+                target_zone: Zone::Zone2,
+                duration_secs: 60,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+        assert!(executor
+            .schedule_session(plan, "61 * * * * *")
+            .await
+            .is_err());
+        assert!(executor.scheduler.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_no_scheduled_jobs_fire_after_shutdown() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier.clone());
+
+        let plan = TrainingPlan::steady("Shutdown Workout", Zone::Zone2, 60, 180);
+        executor
+            .schedule_session(plan.clone(), "* * * * * *")
+            .await
+            .unwrap();
+
+        // Let it fire at least once
+        sleep(Duration::from_millis(2100)).await;
+        assert!(notifier.event_count().await > 0);
+        assert!(executor
+            .pending_sessions
+            .lock()
+            .await
+            .contains_key(&plan.name));
+
+        executor.shutdown_scheduler().await.unwrap();
+        assert!(executor.scheduler.is_none());
+        assert!(executor.pending_sessions.lock().await.is_empty());
+
+        let fired = notifier.event_count().await;
+        sleep(Duration::from_millis(2100)).await;
+        assert_eq!(notifier.event_count().await, fired);
+        assert!(executor.pending_sessions.lock().await.is_empty());
+
+        // Shutting down again is a no-op
+        executor.shutdown_scheduler().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_zone_tolerance_applied_to_session() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let executor = SessionExecutor::new(notifier).with_zone_tolerance(3).await;
+
+        let state = executor.session_state.lock().await;
+        assert_eq!(state.context().zone_tolerance_bpm, 3);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_grace_applied_to_session() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let executor = SessionExecutor::new(notifier).with_warmup_grace(90).await;
+
+        let state = executor.session_state.lock().await;
+        assert_eq!(state.context().warmup_grace_secs, 90);
+        assert_eq!(state.context().warmup_grace_secs(), 90);
+    }
+
+    #[test]
+    fn test_bpm_target_phase_reports_against_its_band() {
+        use crate::domain::training_plan::PhaseTarget;
+
+        // 145-155 BPM is all Zone 3 at 200 max HR, but outside "hold 150"
+        let band = PhaseTarget::Bpm {
+            low: 148,
+            high: 152,
+        };
+        let plan = TrainingPlan {
+            name: "Hold 150".to_string(),
+            phases: vec![TrainingPhase::timed("Hold", Zone::Zone3, 600).with_target(band)],
+            created_at: Utc::now(),
+            max_hr: 200,
+            warmup_grace_secs: Some(0),
+            resting_hr: None,
+        };
+        let mut machine = SessionStateMachineWrapper::new();
+        machine.handle(SessionEvent::Start(plan));
+
+        let mut feed = |bpm: u16| {
+            (0..5)
+                .filter_map(|_| machine.handle(SessionEvent::UpdateBpm(bpm)))
+                .last()
+        };
+        assert_eq!(feed(145), Some(ZoneDeviation::TooLow));
+        assert_eq!(feed(155), Some(ZoneDeviation::TooHigh));
+
+        let progress = session_progress_at(&machine, 0).unwrap();
+        assert_eq!(progress.zone_status, ZoneStatus::TooHigh);
+        assert_eq!(progress.phase_progress.target_zone, Zone::Zone3);
+        assert_eq!(progress.phase_progress.target, Some(band));
+        let cue = current_pace_cue(&machine).unwrap();
+        assert_eq!(cue.direction, crate::domain::pacer::Direction::SlowDown);
+        assert_eq!(cue.delta_bpm, 3);
+
+        assert_eq!(
+            machine.handle(SessionEvent::UpdateBpm(150)),
+            Some(ZoneDeviation::InZone)
+        );
+    }
+
+    #[test]
+    fn test_projected_end_freezes_remaining_while_paused() {
+        let phase = |name: &str, duration_secs| TrainingPhase {
+            name: name.to_string(),
+            target_zone: Zone::Zone2,
+            duration_secs,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "ETA".to_string(),
+            phases: vec![phase("Warmup", 60), phase("Work", 120)],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        let mut machine = SessionStateMachineWrapper::new();
+        machine.handle(SessionEvent::Start(plan));
+        for _ in 0..30 {
+            machine.handle(SessionEvent::Tick);
+        }
+
+        // Running: now + remaining (150s)
+        let now_ms = 1_700_000_000_000;
+        let progress = session_progress_at(&machine, now_ms).unwrap();
+        assert_eq!(progress.total_remaining_secs, 150);
+        assert_eq!(progress.projected_end_ms, now_ms + 150_000);
+
+        // Paused for 10s: remaining is frozen, so the ETA moves out by 10s
+        machine.handle(SessionEvent::Pause);
+        let later_ms = now_ms + 10_000;
+        let progress = session_progress_at(&machine, later_ms).unwrap();
+        assert_eq!(progress.state, ProgressState::Paused);
+        assert_eq!(progress.total_remaining_secs, 150);
+        assert_eq!(progress.projected_end_ms, later_ms + 150_000);
+
+        // Resumed: the countdown continues from where it stopped
+        machine.handle(SessionEvent::Resume);
+        machine.handle(SessionEvent::Tick);
+        let progress = session_progress_at(&machine, later_ms + 1_000).unwrap();
+        assert_eq!(progress.projected_end_ms, later_ms + 150_000);
+    }
+
+    #[test]
+    fn test_phase_ending_soon_fires_once_at_lead_time() {
+        let plan = TrainingPlan {
+            name: "Heads-up".to_string(),
+            phases: vec![
+                TrainingPhase::timed("Interval", Zone::Zone4, 30),
+                TrainingPhase::timed("Recovery", Zone::Zone2, 60),
+            ],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        let mut machine = SessionStateMachineWrapper::new();
+        machine.handle(SessionEvent::Start(plan));
+        let mut announced = None;
+        let mut fired = Vec::new();
+        for tick in 1..=40 {
+            machine.handle(SessionEvent::Tick);
+            if let Some((phase_idx, event)) = phase_ending_soon(&machine, 10, announced) {
+                announced = Some(phase_idx);
+                fired.push((tick, event));
+            }
+        }
+
+        assert_eq!(fired.len(), 1, "unexpected events: {:?}", fired);
+        assert_eq!(fired[0].0, 20);
+        assert!(matches!(
+            fired[0].1,
+            NotificationEvent::PhaseEndingSoon {
+                remaining_secs: 10,
+                next_zone: Some(Zone::Zone2),
+            }
+        ));
+
+        // Disabled, or a phase too short for the lead time: nothing to announce
+        assert!(phase_ending_soon(&machine, 0, None).is_none());
+        assert!(phase_ending_soon(&machine, 60, None).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_executor_sends_phase_ending_soon() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier.clone()).with_phase_ending_lead(1);
+
+        executor
+            .start_session(TrainingPlan::steady("Short", Zone::Zone2, 3, 180))
+            .await
+            .unwrap();
+        sleep(Duration::from_millis(4500)).await;
+
+        let ending: Vec<_> = notifier
+            .get_events()
+            .await
+            .into_iter()
+            .filter(|e| matches!(e, NotificationEvent::PhaseEndingSoon { .. }))
+            .collect();
+        assert_eq!(ending.len(), 1, "unexpected events: {:?}", ending);
+        assert!(matches!(
+            ending[0],
+            NotificationEvent::PhaseEndingSoon {
+                remaining_secs: 1,
+                next_zone: None,
+            }
+        ));
+    }
+
+    #[test]
+    fn test_hr_hold_progress_advances_only_at_target() {
+        let plan = TrainingPlan {
+            name: "Warmup to HR".to_string(),
+            phases: vec![
+                TrainingPhase {
+                    name: "Warmup".to_string(),
+                    target_zone: Zone::Zone2,
+                    duration_secs: 600,
+                    transition: TransitionCondition::HeartRateReached {
+                        target_bpm: 120,
+                        hold_secs: 5,
+                    },
+                    alerts_enabled: true,
+                    target: None,
+                },
+                TrainingPhase {
+                    name: "Work".to_string(),
+                    target_zone: Zone::Zone3,
+                    duration_secs: 600,
+                    transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
+                },
+            ],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+        let hold = |machine: &SessionStateMachineWrapper| {
+            let phase = session_progress_at(machine, 0).unwrap().phase_progress;
+            (phase.hr_hold_elapsed_secs, phase.hr_hold_required_secs)
+        };
+
+        let mut machine = SessionStateMachineWrapper::new();
+        machine.handle(SessionEvent::Start(plan));
+        assert_eq!(hold(&machine), (0, 5));
+
+        // Below target: the phase runs but the hold does not start
+        machine.handle(SessionEvent::UpdateBpm(110));
+        machine.handle(SessionEvent::Tick);
+        machine.handle(SessionEvent::Tick);
+        assert_eq!(hold(&machine), (0, 5));
+
+        // At target: one second of hold per tick
+        machine.handle(SessionEvent::UpdateBpm(125));
+        for _ in 0..3 {
+            machine.handle(SessionEvent::Tick);
+        }
+        assert_eq!(hold(&machine), (3, 5));
+
+        // Dropping out of the band restarts the hold
+        machine.handle(SessionEvent::UpdateBpm(115));
+        machine.handle(SessionEvent::Tick);
+        assert_eq!(hold(&machine), (0, 5));
+
+        // Holding for the full requirement finishes the phase
+        machine.handle(SessionEvent::UpdateBpm(122));
+        for _ in 0..5 {
+            machine.handle(SessionEvent::Tick);
+        }
+        let progress = session_progress_at(&machine, 0).unwrap();
+        assert_eq!(progress.current_phase, 1);
+        assert_eq!(
+            (
+                progress.phase_progress.hr_hold_elapsed_secs,
+                progress.phase_progress.hr_hold_required_secs
+            ),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn test_completion_percentage_time_based_midpoint() {
+        let phase = |name: &str| TrainingPhase {
+            name: name.to_string(),
+            target_zone: Zone::Zone2,
+            duration_secs: 600,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "Two Halves".to_string(),
+            phases: vec![phase("First"), phase("Second")],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        let durations = [600, 600];
+        assert_eq!(completion_percentage(&plan, &durations, 0, 0), 0.0);
+        assert!((completion_percentage(&plan, &durations, 1, 0) - 50.0).abs() < 0.01);
+        assert!((completion_percentage(&plan, &durations, 0, 300) - 25.0).abs() < 0.01);
+        assert!((completion_percentage(&plan, &durations, 1, 600) - 100.0).abs() < 0.01);
+
+        // An extended first phase counts towards the total
+        let extended = [900, 600];
+        assert!((completion_percentage(&plan, &extended, 0, 300) - 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_completion_percentage_hr_based_uses_phase_index() {
+        let plan = TrainingPlan {
+            name: "HR Driven".to_string(),
+            phases: vec![
+                TrainingPhase {
+                    name: "Warmup".to_string(),
+                    target_zone: Zone::Zone2,
+                    duration_secs: 600,
+                    transition: TransitionCondition::HeartRateReached {
+                        target_bpm: 120,
+                        hold_secs: 10,
+                    },
+                    alerts_enabled: true,
+                    target: None,
+                },
+                TrainingPhase {
+                    name: "Work".to_string(),
+                    target_zone: Zone::Zone4,
+                    duration_secs: 1200,
+                    transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
+                },
+            ],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        // Warmup overran its nominal duration: capped at its share of the plan
+        let durations = [600, 1200];
+        assert!((completion_percentage(&plan, &durations, 0, 900) - 50.0).abs() < 0.01);
+        assert!((completion_percentage(&plan, &durations, 1, 600) - 75.0).abs() < 0.01);
+    }
+
+    #[tokio::test]
+    async fn test_get_plan_returns_current_plan() {
+        let notifier = Arc::new(MockNotificationAdapter::new());
+        let mut executor = SessionExecutor::new(notifier);
+
+        let plan = TrainingPlan {
+            name: "Get Plan Test".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Test Phase".to_string(),
+                target_zone: Zone::Zone2,
+                duration_secs: 60,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        // No plan before starting
+        assert!(executor.get_plan().await.is_none());
+
+        executor.start_session(plan.clone()).await.unwrap();
+        sleep(Duration::from_millis(500)).await;
+
+        // Plan should be available after starting
+        let retrieved_plan = executor.get_plan().await;
+        assert!(retrieved_plan.is_some());
+        assert_eq!(retrieved_plan.unwrap().name, plan.name);
+
+        executor.stop_session().await.unwrap();
+    }
 
     #[tokio::test]
     async fn test_get_progress_returns_current_state() {
@@ -1900,9 +4681,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         // No progress before starting
@@ -1922,4 +4706,32 @@ mod tests {
 
         executor.stop_session().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn test_max_hr_exceeded_only_above_margin() {
+        let notifier = MockNotificationAdapter::new();
+        let suggestion = Mutex::new(None);
+
+        // Peak stays below the plan max: nothing to suggest
+        assert!(!report_max_hr_exceeded(180, Some(185), &suggestion, &notifier).await);
+        assert_eq!(notifier.event_count().await, 0);
+        assert_eq!(*suggestion.lock().await, None);
+
+        // Peak 10 BPM above the plan max
+        assert!(report_max_hr_exceeded(195, Some(185), &suggestion, &notifier).await);
+        let events = notifier.get_events().await;
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            NotificationEvent::MaxHrExceeded {
+                observed: 195,
+                configured: 185
+            }
+        ));
+        assert_eq!(*suggestion.lock().await, Some(195));
+
+        // Without a plan there is nothing to compare against
+        assert!(!report_max_hr_exceeded(250, None, &suggestion, &notifier).await);
+        assert_eq!(notifier.event_count().await, 1);
+    }
 }
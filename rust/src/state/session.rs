@@ -5,10 +5,15 @@
 
 #![allow(missing_docs)] // statig macro generates code that triggers missing_docs warnings
 
+use crate::domain::filters::is_valid_bpm;
 use crate::domain::heart_rate::Zone;
-use crate::domain::training_plan::{calculate_zone, TrainingPlan};
+use crate::domain::training_plan::{
+    calculate_zone, zone_bpm_bounds, PhaseTarget, TrainingPlan, TransitionCondition,
+};
+use chrono::{DateTime, Utc};
 use statig::prelude::*;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
 /// Events that drive state transitions in the session state machine.
 #[derive(Debug, Clone)]
@@ -19,6 +24,8 @@ pub enum SessionEvent {
     Tick,
     /// Update current heart rate
     UpdateBpm(u16),
+    /// HR data became unavailable (e.g. the sensor link dropped)
+    HrLost,
     /// User pauses the session
     Pause,
     /// User resumes a paused session
@@ -27,6 +34,22 @@ pub enum SessionEvent {
     Stop,
     /// Internal: Advance to next phase
     NextPhase(usize),
+    /// User skips the rest of the current phase
+    SkipPhase,
+    /// User adds the given number of seconds to the current phase
+    ExtendPhase(u32),
+    /// Internal: Store the zone tracker updated by a BPM reading
+    UpdateZoneTracker(ZoneTracker),
+    /// Internal: Store the consecutive seconds the HR target has been held
+    UpdateHrHold(u32),
+    /// Internal: Jump to a saved position after a restart, counting earlier
+    /// phases as completed in full
+    Restore {
+        /// Index of the phase to resume
+        phase: usize,
+        /// Seconds already elapsed in that phase
+        elapsed_secs: u32,
+    },
 }
 
 /// Zone deviation status for biofeedback.
@@ -65,13 +88,56 @@ impl ZoneTracker {
     /// Check current heart rate against target zone and detect deviations.
     ///
     /// Returns Some(deviation) if a new deviation event should be emitted.
+    #[allow(dead_code)] // the session applies its tolerance via check_with_tolerance
     fn check(&mut self, current_bpm: u16, target_zone: Zone, max_hr: u16) -> Option<ZoneDeviation> {
-        let current_zone = match calculate_zone(current_bpm, max_hr) {
-            Ok(Some(zone)) => zone,
-            _ => return None, // Invalid data, don't update state
+        self.check_with_tolerance(current_bpm, target_zone, max_hr, 0)
+    }
+
+    /// Like [`check`](Self::check), but readings within `tolerance_bpm` of the
+    /// target's BPM band count as in-zone. The target is either a zone or an
+    /// explicit BPM band (see [`PhaseTarget`]).
+    fn check_with_tolerance(
+        &mut self,
+        current_bpm: u16,
+        target: impl Into<PhaseTarget>,
+        max_hr: u16,
+        tolerance_bpm: u16,
+    ) -> Option<ZoneDeviation> {
+        let ordering = match target.into() {
+            PhaseTarget::Zone(target_zone) => {
+                let current_zone = match calculate_zone(current_bpm, max_hr) {
+                    Ok(Some(zone)) => zone,
+                    _ => return None, // Invalid data, don't update state
+                };
+
+                let mut ordering = current_zone.cmp(&target_zone);
+                if ordering != Ordering::Equal && tolerance_bpm > 0 {
+                    if let Ok((lower, upper)) = zone_bpm_bounds(target_zone, max_hr) {
+                        if current_bpm >= lower.saturating_sub(tolerance_bpm)
+                            && current_bpm <= upper.saturating_add(tolerance_bpm)
+                        {
+                            ordering = Ordering::Equal;
+                        }
+                    }
+                }
+                ordering
+            }
+            PhaseTarget::Bpm { low, high } => {
+                if !is_valid_bpm(current_bpm) {
+                    return None; // Invalid data, don't update state
+                }
+
+                if current_bpm < low.saturating_sub(tolerance_bpm) {
+                    Ordering::Less
+                } else if current_bpm > high.saturating_add(tolerance_bpm) {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            }
         };
 
-        match current_zone.cmp(&target_zone) {
+        match ordering {
             Ordering::Less => {
                 self.consecutive_low_secs += 1;
                 self.consecutive_high_secs = 0;
@@ -189,6 +255,15 @@ impl SessionState {
                     ZoneTracker::default(),
                 ))
             }
+            SessionEvent::Restore {
+                phase,
+                elapsed_secs,
+            } => Transition(State::in_progress(
+                *phase,
+                *elapsed_secs,
+                0,
+                ZoneTracker::default(),
+            )),
             SessionEvent::UpdateBpm(_bpm) => {
                 // Update zone tracker
                 // The wrapper will check deviation and return it
@@ -197,6 +272,18 @@ impl SessionState {
                 // So this is handled in wrapper
                 Super
             }
+            SessionEvent::UpdateZoneTracker(tracker) => Transition(State::in_progress(
+                *current_phase,
+                *elapsed_secs,
+                *hr_hold_secs,
+                tracker.clone(),
+            )),
+            SessionEvent::UpdateHrHold(hold_secs) => Transition(State::in_progress(
+                *current_phase,
+                *elapsed_secs,
+                *hold_secs,
+                zone_tracker.clone(),
+            )),
             SessionEvent::Pause => Transition(State::paused(
                 *current_phase,
                 *elapsed_secs,
@@ -229,8 +316,14 @@ impl SessionState {
 
     /// Handle completed state (terminal state)
     #[state]
-    fn completed() -> Response<State> {
-        Super
+    fn completed(event: &SessionEvent) -> Response<State> {
+        match event {
+            // A finished session can be followed by a new one
+            SessionEvent::Start(_plan) => {
+                Transition(State::in_progress(0, 0, 0, ZoneTracker::default()))
+            }
+            _ => Super,
+        }
     }
 
     /// Called on state transitions
@@ -247,6 +340,16 @@ pub struct SessionContext {
     pub current_bpm: u16,
     /// Last zone deviation state
     pub last_deviation: ZoneDeviation,
+    /// BPM tolerance around the target zone's band that still counts as in-zone
+    pub zone_tolerance_bpm: u16,
+    /// Seconds actually spent in each finished phase, in plan order
+    pub phase_elapsed_secs: Vec<u32>,
+    /// Effective durations for phases extended during this session, by phase
+    /// index. The plan itself is never modified.
+    pub phase_duration_overrides: HashMap<usize, u32>,
+    /// Seconds at the start of a session during which zone-deviation alerts
+    /// are suppressed; used when the plan does not set its own
+    pub warmup_grace_secs: u32,
 }
 
 impl SessionContext {
@@ -256,6 +359,10 @@ impl SessionContext {
             plan: None,
             current_bpm: 0,
             last_deviation: ZoneDeviation::InZone,
+            zone_tolerance_bpm: 0,
+            phase_elapsed_secs: Vec::new(),
+            phase_duration_overrides: HashMap::new(),
+            warmup_grace_secs: 0,
         }
     }
 
@@ -263,6 +370,32 @@ impl SessionContext {
     pub fn plan(&self) -> Option<&TrainingPlan> {
         self.plan.as_ref()
     }
+
+    /// Effective duration of the phase at `index`, including any extension.
+    pub fn phase_duration(&self, index: usize) -> Option<u32> {
+        let phase = self.plan.as_ref()?.phases.get(index)?;
+        Some(
+            self.phase_duration_overrides
+                .get(&index)
+                .copied()
+                .unwrap_or(phase.duration_secs),
+        )
+    }
+
+    /// Effective durations of all phases in the plan, including extensions.
+    pub fn phase_durations(&self) -> Vec<u32> {
+        let count = self.plan.as_ref().map_or(0, |p| p.phases.len());
+        (0..count).filter_map(|i| self.phase_duration(i)).collect()
+    }
+
+    /// Warmup grace in effect: the plan's setting if present, otherwise the
+    /// context default.
+    pub fn warmup_grace_secs(&self) -> u32 {
+        self.plan
+            .as_ref()
+            .and_then(|p| p.warmup_grace_secs)
+            .unwrap_or(self.warmup_grace_secs)
+    }
 }
 
 impl Default for SessionContext {
@@ -271,10 +404,21 @@ impl Default for SessionContext {
     }
 }
 
+/// A `SessionEvent` captured by the event log, with the time it was handled.
+#[derive(Debug, Clone)]
+pub struct LoggedEvent {
+    /// When the event was passed to [`SessionStateMachineWrapper::handle`]
+    pub timestamp: DateTime<Utc>,
+    /// The event itself
+    pub event: SessionEvent,
+}
+
 /// Public state machine wrapper
 pub struct SessionStateMachineWrapper {
     machine: statig::blocking::InitializedStateMachine<SessionState>,
     context: SessionContext,
+    /// Events handled so far; `None` unless created with `with_event_log`
+    event_log: Option<Vec<LoggedEvent>>,
 }
 
 impl SessionStateMachineWrapper {
@@ -283,15 +427,61 @@ impl SessionStateMachineWrapper {
         Self {
             machine: SessionState::default().uninitialized_state_machine().init(),
             context: SessionContext::new(),
+            event_log: None,
+        }
+    }
+
+    /// Create a session state machine that records every handled event.
+    ///
+    /// Intended for debugging: the captured log can be fed to [`replay`](Self::replay)
+    /// to reproduce a session deterministically. Only externally handled events
+    /// are recorded; phase advances derived from them are not.
+    pub fn with_event_log() -> Self {
+        Self {
+            event_log: Some(Vec::new()),
+            ..Self::new()
+        }
+    }
+
+    /// Build a state machine by handling `events` in order.
+    ///
+    /// The zone tolerance and warmup grace are copied from `settings`, which
+    /// should be the context of the machine the events were recorded on, so
+    /// zones are evaluated as they were originally. The returned machine also
+    /// logs, so its [`event_log`](Self::event_log) mirrors the input.
+    pub fn replay<'a>(
+        settings: &SessionContext,
+        events: impl IntoIterator<Item = &'a LoggedEvent>,
+    ) -> Self {
+        let mut machine = Self::with_event_log();
+        machine.context.zone_tolerance_bpm = settings.zone_tolerance_bpm;
+        machine.context.warmup_grace_secs = settings.warmup_grace_secs;
+        for logged in events {
+            machine.handle(logged.event.clone());
         }
+        machine
+    }
+
+    /// Events recorded so far, oldest first (empty when logging is disabled).
+    pub fn event_log(&self) -> &[LoggedEvent] {
+        self.event_log.as_deref().unwrap_or_default()
     }
 
     /// Handle an event with additional business logic
     pub fn handle(&mut self, event: SessionEvent) -> Option<ZoneDeviation> {
+        if let Some(log) = self.event_log.as_mut() {
+            log.push(LoggedEvent {
+                timestamp: Utc::now(),
+                event: event.clone(),
+            });
+        }
+
         match &event {
             SessionEvent::Start(plan) => {
                 // Store the plan in context before transitioning
                 self.context.plan = Some(plan.clone());
+                self.context.phase_elapsed_secs.clear();
+                self.context.phase_duration_overrides.clear();
                 self.machine.handle(&event);
                 None
             }
@@ -303,39 +493,72 @@ impl SessionStateMachineWrapper {
                 if let State::InProgress {
                     current_phase,
                     elapsed_secs,
-                    hr_hold_secs: _,
+                    hr_hold_secs,
                     zone_tracker: _,
-                } = self.machine.state()
+                } = *self.machine.state()
                 {
                     if let Some(plan) = &self.context.plan {
-                        if *current_phase >= plan.phases.len() {
+                        if current_phase >= plan.phases.len() {
                             // Invalid state - complete session
                             self.machine.handle(&SessionEvent::Stop);
                             return None;
                         }
 
-                        let phase = &plan.phases[*current_phase];
-
-                        // Check if phase duration exceeded (for TimeElapsed transitions)
-                        let should_advance = matches!(
-                            phase.transition,
-                            crate::domain::training_plan::TransitionCondition::TimeElapsed
-                        ) && *elapsed_secs >= phase.duration_secs;
-
-                        if should_advance {
-                            if current_phase + 1 < plan.phases.len() {
-                                // Advance to next phase using NextPhase event
-                                let next_phase = current_phase + 1;
-                                self.machine.handle(&SessionEvent::NextPhase(next_phase));
-                            } else {
-                                // No more phases - complete the session
-                                self.machine.handle(&SessionEvent::Stop);
+                        match plan.phases[current_phase].transition {
+                            TransitionCondition::TimeElapsed => {
+                                // Check if phase duration exceeded
+                                if Some(elapsed_secs) >= self.context.phase_duration(current_phase)
+                                {
+                                    self.finish_phase(current_phase, elapsed_secs);
+                                }
+                            }
+                            TransitionCondition::HeartRateReached {
+                                target_bpm,
+                                hold_secs,
+                            } => {
+                                // The hold counts consecutive in-band seconds only
+                                let held = if self.hr_at_target(target_bpm) {
+                                    hr_hold_secs + 1
+                                } else {
+                                    0
+                                };
+                                if held >= hold_secs {
+                                    self.finish_phase(current_phase, elapsed_secs);
+                                } else {
+                                    self.machine.handle(&SessionEvent::UpdateHrHold(held));
+                                }
                             }
                         }
                     }
                 }
                 None
             }
+            SessionEvent::SkipPhase => {
+                // Only a running phase can be skipped; the partial time counts
+                if let State::InProgress {
+                    current_phase,
+                    elapsed_secs,
+                    ..
+                } = *self.machine.state()
+                {
+                    self.finish_phase(current_phase, elapsed_secs);
+                }
+                None
+            }
+            SessionEvent::ExtendPhase(extra_secs) => {
+                // Running or paused phases can be extended
+                let current_phase = match *self.machine.state() {
+                    State::InProgress { current_phase, .. } => current_phase,
+                    State::Paused { phase, .. } => phase,
+                    _ => return None,
+                };
+                if let Some(duration) = self.context.phase_duration(current_phase) {
+                    self.context
+                        .phase_duration_overrides
+                        .insert(current_phase, duration.saturating_add(*extra_secs));
+                }
+                None
+            }
             SessionEvent::UpdateBpm(bpm) => {
                 // Store current BPM in context
                 self.context.current_bpm = *bpm;
@@ -355,30 +578,62 @@ impl SessionStateMachineWrapper {
 
                         let phase = &plan.phases[*current_phase];
                         let mut tracker = zone_tracker.clone();
-                        let deviation = tracker.check(*bpm, phase.target_zone, plan.max_hr);
+                        let mut deviation = tracker.check_with_tolerance(
+                            *bpm,
+                            phase.effective_target(),
+                            plan.max_hr,
+                            self.context.zone_tolerance_bpm,
+                        );
+
+                        // During warmup grace the counters keep running, but no
+                        // alert is raised. Keeping the previous deviation lets a
+                        // persisting deviation fire as soon as grace ends.
+                        if deviation.is_some()
+                            && self.session_elapsed_secs() < self.context.warmup_grace_secs()
+                        {
+                            tracker.last_deviation = zone_tracker.last_deviation;
+                            deviation = None;
+                        }
 
                         // Store deviation in context if it changed
                         if let Some(dev) = deviation {
                             self.context.last_deviation = dev;
                         }
 
-                        // If tracker changed, we need to update state
-                        // Create a synthetic transition to update the tracker
-                        if deviation.is_some() {
-                            // The tracker state changed - we should update it in the state machine
-                            // But we can't easily do this with statig without adding a specific event
-                            // For now, we'll handle this by having UpdateBpm trigger a state update
-                            // This is a limitation we'll address
-
-                            // Workaround: Return deviation and expect caller to handle it
-                            // The tracker will be updated on the next UpdateBpm anyway
-                        }
+                        // Persist the tracker so consecutive counts carry over
+                        self.machine
+                            .handle(&SessionEvent::UpdateZoneTracker(tracker));
 
                         return deviation;
                     }
                 }
                 None
             }
+            SessionEvent::Restore { phase, .. } => {
+                // Only a started session with a valid phase can be restored
+                let phase_count = self.context.plan.as_ref().map_or(0, |p| p.phases.len());
+                if !matches!(self.machine.state(), State::InProgress { .. })
+                    || *phase >= phase_count
+                {
+                    return None;
+                }
+                self.context.phase_elapsed_secs = (0..*phase)
+                    .filter_map(|i| self.context.phase_duration(i))
+                    .collect();
+                self.machine.handle(&event);
+                None
+            }
+            SessionEvent::HrLost => {
+                // Forget the last reading and any deviation streak, so zone
+                // logic starts fresh once HR returns
+                self.context.current_bpm = 0;
+                self.context.last_deviation = ZoneDeviation::InZone;
+                if matches!(self.machine.state(), State::InProgress { .. }) {
+                    self.machine
+                        .handle(&SessionEvent::UpdateZoneTracker(ZoneTracker::default()));
+                }
+                None
+            }
             _ => {
                 self.machine.handle(&event);
                 None
@@ -386,6 +641,42 @@ impl SessionStateMachineWrapper {
         }
     }
 
+    /// Whether the latest BPM reading has reached `target_bpm`, allowing the
+    /// context's zone tolerance. No reading (0 BPM) never counts.
+    fn hr_at_target(&self, target_bpm: u16) -> bool {
+        let bpm = self.context.current_bpm;
+        bpm > 0 && bpm.saturating_add(self.context.zone_tolerance_bpm) >= target_bpm
+    }
+
+    /// Record the time spent in `current_phase` and move on to the next phase,
+    /// completing the session if it was the last one.
+    fn finish_phase(&mut self, current_phase: usize, elapsed_secs: u32) {
+        let phase_count = self.context.plan.as_ref().map_or(0, |p| p.phases.len());
+        self.context.phase_elapsed_secs.push(elapsed_secs);
+
+        if current_phase + 1 < phase_count {
+            // Advance to next phase using NextPhase event
+            self.machine
+                .handle(&SessionEvent::NextPhase(current_phase + 1));
+        } else {
+            // No more phases - complete the session
+            self.machine.handle(&SessionEvent::Stop);
+        }
+    }
+
+    /// Seconds of the session elapsed so far, across all phases.
+    ///
+    /// Paused time is not counted.
+    pub fn session_elapsed_secs(&self) -> u32 {
+        let finished: u32 = self.context.phase_elapsed_secs.iter().sum();
+        let current = match self.machine.state() {
+            State::InProgress { elapsed_secs, .. } => *elapsed_secs,
+            State::Paused { elapsed, .. } => *elapsed,
+            _ => 0,
+        };
+        finished.saturating_add(current)
+    }
+
     /// Get current state
     pub fn state(&self) -> &State {
         self.machine.state()
@@ -411,11 +702,8 @@ impl SessionStateMachineWrapper {
             ..
         } = self.machine.state()
         {
-            if let Some(plan) = &self.context.plan {
-                if *current_phase < plan.phases.len() {
-                    let phase = &plan.phases[*current_phase];
-                    return Some((*current_phase, *elapsed_secs, phase.duration_secs));
-                }
+            if let Some(duration) = self.context.phase_duration(*current_phase) {
+                return Some((*current_phase, *elapsed_secs, duration));
             }
         }
         None
@@ -435,6 +723,27 @@ impl SessionStateMachineWrapper {
         None
     }
 
+    /// Get the heart rate hold progress of the current phase.
+    ///
+    /// Returns (held_secs, required_secs) if the running or paused phase ends
+    /// on `HeartRateReached`, None otherwise. The hold restarts from zero after
+    /// a pause.
+    pub fn hr_hold_progress(&self) -> Option<(u32, u32)> {
+        let (phase, held) = match *self.machine.state() {
+            State::InProgress {
+                current_phase,
+                hr_hold_secs,
+                ..
+            } => (current_phase, hr_hold_secs),
+            State::Paused { phase, .. } => (phase, 0),
+            _ => return None,
+        };
+        match self.context.plan.as_ref()?.phases.get(phase)?.transition {
+            TransitionCondition::HeartRateReached { hold_secs, .. } => Some((held, hold_secs)),
+            TransitionCondition::TimeElapsed => None,
+        }
+    }
+
     /// Get time remaining in the current phase.
     ///
     /// Returns seconds left in current phase if in progress, None otherwise.
@@ -445,15 +754,8 @@ impl SessionStateMachineWrapper {
             ..
         } = self.machine.state()
         {
-            if let Some(plan) = &self.context.plan {
-                if *current_phase < plan.phases.len() {
-                    let phase = &plan.phases[*current_phase];
-                    if *elapsed_secs < phase.duration_secs {
-                        return Some(phase.duration_secs - elapsed_secs);
-                    } else {
-                        return Some(0);
-                    }
-                }
+            if let Some(duration) = self.context.phase_duration(*current_phase) {
+                return Some(duration.saturating_sub(*elapsed_secs));
             }
         }
         None
@@ -554,6 +856,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_zone_tracker_tolerance() {
+        // Zone3 at max_hr 200 is 140-159 BPM
+        let mut tracker = ZoneTracker::default();
+        for _ in 0..5 {
+            let result = tracker.check_with_tolerance(161, Zone::Zone3, 200, 3);
+            assert_eq!(result, None);
+        }
+        assert_eq!(tracker.consecutive_high_secs, 0);
+
+        let mut tracker = ZoneTracker::default();
+        for _ in 0..4 {
+            tracker.check_with_tolerance(164, Zone::Zone3, 200, 3);
+        }
+        let result = tracker.check_with_tolerance(164, Zone::Zone3, 200, 3);
+        assert_eq!(result, Some(ZoneDeviation::TooHigh));
+
+        // Tolerance applies below the band too
+        let mut tracker = ZoneTracker::default();
+        for _ in 0..5 {
+            assert_eq!(tracker.check_with_tolerance(138, Zone::Zone3, 200, 3), None);
+        }
+    }
+
     #[test]
     fn test_session_start() {
         use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
@@ -569,9 +895,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan.clone()));
@@ -604,9 +933,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -646,9 +978,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -693,16 +1028,21 @@ mod tests {
                     target_zone: Zone::Zone2,
                     duration_secs: 5,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
                 TrainingPhase {
                     name: "Work".to_string(),
                     target_zone: Zone::Zone4,
                     duration_secs: 5,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
             ],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -723,6 +1063,224 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_skip_phase_records_partial_elapsed() {
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+        use chrono::Utc;
+
+        let mut machine = SessionStateMachineWrapper::new();
+
+        let phase = |name: &str| TrainingPhase {
+            name: name.to_string(),
+            target_zone: Zone::Zone2,
+            duration_secs: 5,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "Test Plan".to_string(),
+            phases: vec![phase("Warmup"), phase("Work")],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        machine.handle(SessionEvent::Start(plan));
+        machine.handle(SessionEvent::Tick);
+        machine.handle(SessionEvent::Tick);
+        machine.handle(SessionEvent::SkipPhase);
+
+        assert!(matches!(
+            machine.state(),
+            State::InProgress {
+                current_phase: 1,
+                elapsed_secs: 0,
+                ..
+            }
+        ));
+
+        // The second phase runs to its planned duration
+        for _ in 0..5 {
+            machine.handle(SessionEvent::Tick);
+        }
+        assert!(matches!(machine.state(), State::Completed { .. }));
+        assert_eq!(machine.context().phase_elapsed_secs, vec![2, 5]);
+    }
+
+    #[test]
+    fn test_restore_jumps_to_saved_position() {
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+        use chrono::Utc;
+
+        let mut machine = SessionStateMachineWrapper::new();
+
+        let phase = |name: &str| TrainingPhase {
+            name: name.to_string(),
+            target_zone: Zone::Zone2,
+            duration_secs: 5,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "Test Plan".to_string(),
+            phases: vec![phase("Warmup"), phase("Work"), phase("Cooldown")],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        // Restoring before a session has started does nothing
+        machine.handle(SessionEvent::Restore {
+            phase: 1,
+            elapsed_secs: 3,
+        });
+        assert!(matches!(machine.state(), State::Idle { .. }));
+
+        machine.handle(SessionEvent::Start(plan));
+        machine.handle(SessionEvent::Restore {
+            phase: 1,
+            elapsed_secs: 3,
+        });
+        assert!(matches!(
+            machine.state(),
+            State::InProgress {
+                current_phase: 1,
+                elapsed_secs: 3,
+                ..
+            }
+        ));
+        assert_eq!(machine.session_elapsed_secs(), 8);
+
+        // The restored phase finishes after its remaining two seconds
+        machine.handle(SessionEvent::Tick);
+        machine.handle(SessionEvent::Tick);
+        assert!(matches!(
+            machine.state(),
+            State::InProgress {
+                current_phase: 2,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_extend_phase_delays_transition() {
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+        use chrono::Utc;
+
+        let phase = |name: &str| TrainingPhase {
+            name: name.to_string(),
+            target_zone: Zone::Zone2,
+            duration_secs: 5,
+            transition: TransitionCondition::TimeElapsed,
+            alerts_enabled: true,
+            target: None,
+        };
+        let plan = TrainingPlan {
+            name: "Test Plan".to_string(),
+            phases: vec![phase("Warmup"), phase("Work")],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        // Number of ticks until the first phase transition
+        let ticks_until_transition = |extend: Option<u32>| {
+            let mut machine = SessionStateMachineWrapper::new();
+            machine.handle(SessionEvent::Start(plan.clone()));
+            machine.handle(SessionEvent::Tick);
+            if let Some(extra) = extend {
+                machine.handle(SessionEvent::ExtendPhase(extra));
+            }
+            let mut ticks = 1;
+            while matches!(
+                machine.state(),
+                State::InProgress {
+                    current_phase: 0,
+                    ..
+                }
+            ) {
+                machine.handle(SessionEvent::Tick);
+                ticks += 1;
+            }
+            (ticks, machine)
+        };
+
+        let (baseline, _) = ticks_until_transition(None);
+        let (extended, machine) = ticks_until_transition(Some(30));
+        assert_eq!(baseline, 5);
+        assert_eq!(extended, baseline + 30);
+
+        // Only the active phase is extended and the plan is untouched
+        assert_eq!(machine.get_progress(), Some((1, 0, 5)));
+        assert_eq!(machine.context().phase_durations(), vec![35, 5]);
+        assert_eq!(machine.context().plan().unwrap().phases[0].duration_secs, 5);
+    }
+
+    #[test]
+    fn test_event_log_records_events_in_order() {
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+
+        let plan = TrainingPlan {
+            name: "Test Plan".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Warmup".to_string(),
+                target_zone: Zone::Zone2,
+                duration_secs: 60,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        // Disabled by default
+        let mut plain = SessionStateMachineWrapper::new();
+        plain.handle(SessionEvent::Start(plan.clone()));
+        assert!(plain.event_log().is_empty());
+
+        let mut machine = SessionStateMachineWrapper::with_event_log();
+        machine.handle(SessionEvent::Start(plan));
+        machine.handle(SessionEvent::Tick);
+        machine.handle(SessionEvent::Tick);
+        machine.handle(SessionEvent::Pause);
+        machine.handle(SessionEvent::Resume);
+        machine.handle(SessionEvent::Tick);
+
+        let before_stop =
+            SessionStateMachineWrapper::replay(machine.context(), machine.event_log());
+        assert_eq!(before_stop.get_progress(), machine.get_progress());
+        assert_eq!(before_stop.get_progress(), Some((0, 3, 60)));
+
+        machine.handle(SessionEvent::Stop);
+
+        let log = machine.event_log();
+        let kinds: Vec<&str> = log
+            .iter()
+            .map(|logged| match logged.event {
+                SessionEvent::Start(_) => "start",
+                SessionEvent::Tick => "tick",
+                SessionEvent::Pause => "pause",
+                SessionEvent::Resume => "resume",
+                SessionEvent::Stop => "stop",
+                _ => "other",
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec!["start", "tick", "tick", "pause", "resume", "tick", "stop"]
+        );
+        assert!(log.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+
+        let replayed = SessionStateMachineWrapper::replay(machine.context(), log);
+        assert!(matches!(replayed.state(), State::Completed { .. }));
+        assert_eq!(replayed.event_log().len(), log.len());
+    }
+
     #[test]
     fn test_session_completion() {
         use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
@@ -737,9 +1295,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 3,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -767,9 +1328,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -797,9 +1361,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -835,9 +1402,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -871,9 +1441,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 10,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -1028,31 +1601,129 @@ mod tests {
                 target_zone: Zone::Zone3,
                 duration_secs: 300,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 200,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
 
         // UpdateBpm should update context.current_bpm and check zone
         // Zone 3 is 70-80% of max_hr = 140-160 bpm with max_hr 200
-        // Note: Zone tracker state is cloned on each UpdateBpm, so deviation
-        // detection requires the Tick cycle to persist tracker state
         let result = machine.handle(SessionEvent::UpdateBpm(150));
         assert_eq!(result, None); // In zone, no deviation
 
         // Verify BPM was updated in context
         assert_eq!(machine.context().current_bpm, 150);
 
-        // Test with BPM below zone (will show as TooLow on first check
-        // if zone comparison triggers, but tracker doesn't persist across calls)
+        // Test with BPM below zone
         let result = machine.handle(SessionEvent::UpdateBpm(100));
         // First below-zone reading doesn't trigger deviation (need 5 consecutive)
         assert_eq!(result, None);
         assert_eq!(machine.context().current_bpm, 100);
     }
 
+    fn warmup_test_plan(warmup_grace_secs: Option<u32>) -> TrainingPlan {
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+        use chrono::Utc;
+
+        TrainingPlan {
+            name: "Warmup".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Zone 3 Work".to_string(),
+                target_zone: Zone::Zone3,
+                duration_secs: 300,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 200,
+            warmup_grace_secs,
+            resting_hr: None,
+        }
+    }
+
+    #[test]
+    fn test_zone_deviation_persists_across_bpm_updates() {
+        let mut machine = SessionStateMachineWrapper::new();
+        machine.handle(SessionEvent::Start(warmup_test_plan(None)));
+
+        let deviations: Vec<_> = (0..5)
+            .map(|_| machine.handle(SessionEvent::UpdateBpm(100)))
+            .collect();
+        assert_eq!(deviations[..4], [None; 4]);
+        assert_eq!(deviations[4], Some(ZoneDeviation::TooLow));
+        assert_eq!(machine.context().last_deviation, ZoneDeviation::TooLow);
+    }
+
+    #[test]
+    fn test_warmup_grace_suppresses_deviation_alerts() {
+        let mut machine = SessionStateMachineWrapper::new();
+        machine.context_mut().warmup_grace_secs = 60;
+        machine.handle(SessionEvent::Start(warmup_test_plan(None)));
+
+        // One below-zone reading per second for the whole grace period
+        for second in 0..60 {
+            assert_eq!(machine.session_elapsed_secs(), second);
+            assert_eq!(machine.handle(SessionEvent::UpdateBpm(100)), None);
+            machine.handle(SessionEvent::Tick);
+        }
+        assert_eq!(machine.context().last_deviation, ZoneDeviation::InZone);
+
+        // HR is still low once grace ends, so the alert fires immediately
+        assert_eq!(
+            machine.handle(SessionEvent::UpdateBpm(100)),
+            Some(ZoneDeviation::TooLow)
+        );
+        assert_eq!(machine.context().last_deviation, ZoneDeviation::TooLow);
+    }
+
+    #[test]
+    fn test_replay_keeps_zone_tolerance_and_warmup_grace() {
+        let mut machine = SessionStateMachineWrapper::with_event_log();
+        machine.context_mut().zone_tolerance_bpm = 5;
+        machine.context_mut().warmup_grace_secs = 60;
+        machine.handle(SessionEvent::Start(warmup_test_plan(None)));
+        for _ in 0..10 {
+            machine.handle(SessionEvent::UpdateBpm(100));
+            machine.handle(SessionEvent::Tick);
+        }
+        assert_eq!(machine.context().last_deviation, ZoneDeviation::InZone);
+
+        let replayed = SessionStateMachineWrapper::replay(machine.context(), machine.event_log());
+        assert_eq!(replayed.context().zone_tolerance_bpm, 5);
+        assert_eq!(replayed.context().warmup_grace_secs, 60);
+        assert_eq!(replayed.context().last_deviation, ZoneDeviation::InZone);
+        assert_eq!(replayed.get_progress(), machine.get_progress());
+    }
+
+    #[test]
+    fn test_plan_warmup_grace_overrides_context_default() {
+        let mut machine = SessionStateMachineWrapper::new();
+        machine.context_mut().warmup_grace_secs = 600;
+        machine.handle(SessionEvent::Start(warmup_test_plan(Some(3))));
+        assert_eq!(machine.context().warmup_grace_secs(), 3);
+
+        for _ in 0..3 {
+            assert_eq!(machine.handle(SessionEvent::UpdateBpm(100)), None);
+            machine.handle(SessionEvent::Tick);
+        }
+        // Counters kept running during grace
+        assert_eq!(
+            machine.handle(SessionEvent::UpdateBpm(100)),
+            None,
+            "only four consecutive low readings so far"
+        );
+        assert_eq!(
+            machine.handle(SessionEvent::UpdateBpm(100)),
+            Some(ZoneDeviation::TooLow)
+        );
+    }
+
     #[test]
     fn test_session_update_bpm_invalid_phase() {
         use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
@@ -1067,9 +1738,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 2,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 200,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -1100,9 +1774,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 1,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -1125,6 +1802,44 @@ mod tests {
         assert!(matches!(machine.state(), State::Completed {}));
     }
 
+    #[test]
+    fn test_start_after_completion_begins_new_session() {
+        use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
+        use chrono::Utc;
+
+        let mut machine = SessionStateMachineWrapper::new();
+
+        let plan = TrainingPlan {
+            name: "Test Plan".to_string(),
+            phases: vec![TrainingPhase {
+                name: "Warmup".to_string(),
+                target_zone: Zone::Zone2,
+                duration_secs: 1,
+                transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
+            }],
+            created_at: Utc::now(),
+            max_hr: 180,
+            ..Default::default()
+        };
+
+        machine.handle(SessionEvent::Start(plan.clone()));
+        machine.handle(SessionEvent::Tick);
+        assert!(matches!(machine.state(), State::Completed {}));
+
+        // A new session can start once the previous one has finished
+        machine.handle(SessionEvent::Start(plan));
+        assert!(matches!(
+            machine.state(),
+            State::InProgress {
+                current_phase: 0,
+                elapsed_secs: 0,
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn test_session_pause_stop() {
         use crate::domain::training_plan::{TrainingPhase, TransitionCondition};
@@ -1139,9 +1854,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -1170,9 +1888,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan.clone()));
@@ -1211,16 +1932,21 @@ mod tests {
                     target_zone: Zone::Zone2,
                     duration_secs: 3,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
                 TrainingPhase {
                     name: "Phase 2".to_string(),
                     target_zone: Zone::Zone4,
                     duration_secs: 100,
                     transition: TransitionCondition::TimeElapsed,
+                    alerts_enabled: true,
+                    target: None,
                 },
             ],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -1261,9 +1987,12 @@ mod tests {
                 target_zone: Zone::Zone2,
                 duration_secs: 60,
                 transition: TransitionCondition::TimeElapsed,
+                alerts_enabled: true,
+                target: None,
             }],
             created_at: Utc::now(),
             max_hr: 180,
+            ..Default::default()
         };
 
         machine.handle(SessionEvent::Start(plan));
@@ -3,7 +3,7 @@
 //! This test validates that the end-to-end latency from BLE packet arrival
 //! to FilteredHeartRate emission meets the hard requirement of <100ms P95 latency.
 
-use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig};
+use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig, MockHrMode};
 use heart_beat::domain::filters::KalmanFilter;
 use heart_beat::domain::heart_rate::{parse_heart_rate, FilteredHeartRate};
 use heart_beat::domain::hrv::calculate_rmssd;
@@ -32,6 +32,8 @@ async fn test_p95_latency_under_100ms() {
         spike_magnitude: 10,
         update_rate: 10.0, // Fast updates to collect 1000 samples quickly
         battery_level: 90,
+        hr_mode: MockHrMode::Static,
+        force_uint16_bpm: false,
     };
 
     let adapter = MockAdapter::with_config(config);
@@ -89,6 +91,7 @@ async fn test_p95_latency_under_100ms() {
             raw_bpm,
             filtered_bpm,
             rmssd,
+            rmssd_trend: None,
             filter_variance: None,
             battery_level: Some(90),
             timestamp: std::time::SystemTime::now()
@@ -96,6 +99,9 @@ async fn test_p95_latency_under_100ms() {
                 .unwrap()
                 .as_millis() as u64,
             receive_timestamp_micros: None,
+            stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
         };
 
         // End timing - full pipeline complete
@@ -168,6 +174,8 @@ async fn test_latency_under_stress() {
         spike_magnitude: 25,
         update_rate: 20.0, // Very fast updates (20 Hz)
         battery_level: 75,
+        hr_mode: MockHrMode::Static,
+        force_uint16_bpm: false,
     };
 
     let adapter = MockAdapter::with_config(config);
@@ -207,6 +215,7 @@ async fn test_latency_under_stress() {
             raw_bpm,
             filtered_bpm,
             rmssd,
+            rmssd_trend: None,
             filter_variance: None,
             battery_level: Some(75),
             timestamp: std::time::SystemTime::now()
@@ -214,6 +223,9 @@ async fn test_latency_under_stress() {
                 .unwrap()
                 .as_millis() as u64,
             receive_timestamp_micros: None,
+            stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
         };
 
         let elapsed = start.elapsed();
@@ -3,7 +3,7 @@
 //! This test verifies the end-to-end data flow from BLE adapter through
 //! parsing, filtering, and HRV calculation to final output.
 
-use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig};
+use heart_beat::adapters::mock_adapter::{MockAdapter, MockConfig, MockHrMode};
 use heart_beat::domain::filters::KalmanFilter;
 use heart_beat::domain::heart_rate::{parse_heart_rate, FilteredHeartRate};
 use heart_beat::domain::hrv::calculate_rmssd;
@@ -30,6 +30,8 @@ async fn test_full_pipeline_with_mock_adapter() {
         spike_magnitude: 0,
         update_rate: 10.0, // Fast for testing
         battery_level: 90,
+        hr_mode: MockHrMode::Static,
+        force_uint16_bpm: false,
     };
 
     let adapter = MockAdapter::with_config(config);
@@ -95,6 +97,7 @@ async fn test_full_pipeline_with_mock_adapter() {
             raw_bpm,
             filtered_bpm,
             rmssd,
+            rmssd_trend: None,
             filter_variance: None,   // Not using Kalman filter in this test
             battery_level: Some(90), // Would come from adapter.read_battery()
             timestamp: std::time::SystemTime::now()
@@ -102,6 +105,9 @@ async fn test_full_pipeline_with_mock_adapter() {
                 .unwrap()
                 .as_millis() as u64,
             receive_timestamp_micros: None, // Not measuring latency in this test
+            stale: false,
+            intensity_factor: None,
+            avg_bpm_10s: None,
         };
 
         results.push(output);
@@ -247,6 +253,8 @@ async fn test_pipeline_high_frequency_stream() {
         spike_magnitude: 15,
         update_rate: 5.0, // 5 Hz (higher than typical 1 Hz)
         battery_level: 75,
+        hr_mode: MockHrMode::Static,
+        force_uint16_bpm: false,
     };
 
     let adapter = MockAdapter::with_config(config);
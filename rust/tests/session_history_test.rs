@@ -37,6 +37,9 @@ fn create_test_session(
         hr_samples: samples,
         phases_completed: 1,
         summary,
+        context: None,
+        phase_results: Vec::new(),
+        connection_summary: None,
     }
 }
 